@@ -0,0 +1,1239 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::vec::IntoIter;
+
+use crate::node::{HeapNode, Node};
+use crate::BinarySearchTree;
+
+/// Element stored inside the nodes of an [AaBST] along with the level of its node, ordered by
+/// the element **only**.
+#[derive(Debug, Clone)]
+struct Leveled<T> {
+    value: T,
+    level: usize,
+}
+
+impl<T> Leveled<T> {
+    fn values(elements: Vec<&Leveled<T>>) -> Vec<&T> {
+        elements.into_iter().map(|current| &current.value).collect()
+    }
+
+    fn into_values(elements: Vec<Leveled<T>>) -> IntoIter<T> {
+        elements
+            .into_iter()
+            .map(|current| current.value)
+            .collect::<Vec<T>>()
+            .into_iter()
+    }
+}
+
+impl<T: Ord> PartialEq for Leveled<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Ord> Eq for Leveled<T> {}
+
+impl<T: Ord> PartialOrd for Leveled<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for Leveled<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+type AaNode<T> = Box<Node<Leveled<T>>>;
+
+/// Level of the given subtree, empty subtrees being at level `0`.
+fn level<T: Ord>(node: &HeapNode<Leveled<T>>) -> usize {
+    node.as_ref().map_or(0, |node| node.value.level)
+}
+
+/// Removes a left horizontal link by rotating right, so the left child becomes the parent.
+///
+/// ```text
+///     L <- T             L -> T
+///    / \    \    =>     /    / \
+///   A   B    R         A    B   R
+/// ```
+fn skew<T: Ord>(mut node: AaNode<T>) -> AaNode<T> {
+    if level(&node.left) != node.value.level {
+        return node;
+    }
+
+    let mut left = node.left.take().unwrap();
+    node.left = left.right.take();
+    left.right = Some(node);
+    left
+}
+
+/// Removes two consecutive right horizontal links by rotating left and promoting the middle
+/// node to the next level.
+///
+/// ```text
+///                           R
+///   T -> R -> X            / \
+///  /    /         =>      T   X
+/// A    B                 / \
+///                       A   B
+/// ```
+fn split<T: Ord>(mut node: AaNode<T>) -> AaNode<T> {
+    let right_right = node.right.as_ref().map_or(0, |right| level(&right.right));
+    if right_right != node.value.level {
+        return node;
+    }
+
+    let mut right = node.right.take().unwrap();
+    node.right = right.left.take();
+    right.left = Some(node);
+    right.value.level += 1;
+    right
+}
+
+/// Inserts `value` below `node`, restoring the invariants on the way back up.
+///
+/// Returns the new root of the subtree and whether the value was inserted.
+fn insert<T: Ord>(node: HeapNode<Leveled<T>>, value: T) -> (AaNode<T>, bool) {
+    let mut node = match node {
+        None => return (Box::new(Node::new(Leveled { value, level: 1 })), true),
+        Some(node) => node,
+    };
+
+    let inserted = match value.cmp(&node.value.value) {
+        Ordering::Equal => false,
+        Ordering::Less => {
+            let (left, inserted) = insert(node.left.take(), value);
+            node.left = Some(left);
+            inserted
+        }
+        Ordering::Greater => {
+            let (right, inserted) = insert(node.right.take(), value);
+            node.right = Some(right);
+            inserted
+        }
+    };
+
+    (split(skew(node)), inserted)
+}
+
+/// Removes the element equal to `value` below `node`, restoring the invariants on the way back
+/// up.
+///
+/// Returns the new root of the subtree and the removed element.
+fn remove<T: Ord>(node: HeapNode<Leveled<T>>, value: &T) -> (HeapNode<Leveled<T>>, Option<T>) {
+    let mut node = match node {
+        None => return (None, None),
+        Some(node) => node,
+    };
+
+    let removed = match value.cmp(&node.value.value) {
+        Ordering::Less => {
+            let (left, removed) = remove(node.left.take(), value);
+            node.left = left;
+            removed
+        }
+        Ordering::Greater => {
+            let (right, removed) = remove(node.right.take(), value);
+            node.right = right;
+            removed
+        }
+        // Without a left child the node is at level 1, so its right child is a leaf
+        Ordering::Equal if node.left.is_none() => {
+            return (node.right.take(), Some(node.value.value))
+        }
+        Ordering::Equal => {
+            let (left, predecessor) = remove_max(node.left.take());
+            node.left = left;
+            let predecessor = predecessor.unwrap();
+            Some(std::mem::replace(&mut node.value.value, predecessor))
+        }
+    };
+
+    if removed.is_none() {
+        return (Some(node), None);
+    }
+    (Some(rebalance(node)), removed)
+}
+
+/// Removes the minimum element below `node`, see [remove()].
+fn remove_min<T: Ord>(node: HeapNode<Leveled<T>>) -> (HeapNode<Leveled<T>>, Option<T>) {
+    let mut node = match node {
+        None => return (None, None),
+        Some(node) => node,
+    };
+    if node.left.is_none() {
+        return (node.right.take(), Some(node.value.value));
+    }
+
+    let (left, removed) = remove_min(node.left.take());
+    node.left = left;
+    (Some(rebalance(node)), removed)
+}
+
+/// Removes the maximum element below `node`, see [remove()].
+fn remove_max<T: Ord>(node: HeapNode<Leveled<T>>) -> (HeapNode<Leveled<T>>, Option<T>) {
+    let mut node = match node {
+        None => return (None, None),
+        Some(node) => node,
+    };
+    if node.right.is_none() {
+        return (node.left.take(), Some(node.value.value));
+    }
+
+    let (right, removed) = remove_max(node.right.take());
+    node.right = right;
+    (Some(rebalance(node)), removed)
+}
+
+/// Restores the invariants of a node after one of its subtrees lost an element.
+///
+/// The node and its right horizontal link are lowered to one level above their lowest child,
+/// after which at most three skews and two splits along the right spine remove the horizontal
+/// links this created.
+fn rebalance<T: Ord>(mut node: AaNode<T>) -> AaNode<T> {
+    let expected = level(&node.left).min(level(&node.right)) + 1;
+    if expected < node.value.level {
+        node.value.level = expected;
+        if let Some(right) = &mut node.right {
+            right.value.level = right.value.level.min(expected);
+        }
+    }
+
+    node = skew(node);
+    if let Some(right) = node.right.take() {
+        let mut right = skew(right);
+        right.right = right.right.take().map(skew);
+        node.right = Some(right);
+    }
+
+    node = split(node);
+    node.right = node.right.take().map(split);
+    node
+}
+
+/// Copies a subtree node by node, keeping its shape and levels.
+fn clone_subtree<T: Ord + Clone>(node: &HeapNode<Leveled<T>>) -> HeapNode<Leveled<T>> {
+    node.as_ref().map(|node| {
+        Box::new(Node {
+            value: node.value.clone(),
+            left: clone_subtree(&node.left),
+            right: clone_subtree(&node.right),
+        })
+    })
+}
+
+/// AA tree, a self-balancing Binary Search Tree which keeps the same guarantees as a red-black
+/// tree with far fewer cases to handle.
+///
+/// Every node has a level, leaves being at level 1. A left child is always one level below its
+/// parent, while a right child may be on the same level, forming a _horizontal link_, as long
+/// as there are never two horizontal links in a row. This keeps the height below
+/// `2 * log2(n + 1)` no matter the order of insertions and removals.
+///
+/// All restructuring is done by two rotations: `skew` turns a left horizontal link into a right
+/// one and `split` breaks up two consecutive right horizontal links by promoting the middle
+/// node. This makes the implementation a good middle ground between the plain [IterativeBST]
+/// and [RecursiveBST], which never rebalance on their own, and a full red-black tree.
+///
+/// Insertions and removals are recursive, which is safe since the height is logarithmic.
+///
+/// [IterativeBST]: crate::IterativeBST
+/// [RecursiveBST]: crate::RecursiveBST
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{AaBST, BinarySearchTree};
+///
+/// // Ascending insertions which degenerate a plain binary search tree into a list
+/// let bst = AaBST::from_iter(1..=1023);
+///
+/// assert_eq!(bst.size(), 1023);
+/// assert!(bst.height().unwrap() < 20);
+/// ```
+#[derive(Debug)]
+pub struct AaBST<T: Ord> {
+    root: HeapNode<Leveled<T>>,
+    size: usize,
+}
+
+impl<T: Ord> AaBST<T> {
+    /// Creates an empty `AaBST<T>`
+    ///
+    /// No nodes are allocated on the heap yet
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// // Empty tree is created
+    /// let mut bst: AaBST<i32> = AaBST::new();
+    /// assert!(bst.is_empty())
+    /// ```
+    pub const fn new() -> AaBST<T> {
+        AaBST {
+            root: None,
+            size: 0,
+        }
+    }
+
+    /// Returns the level of the node holding the given element or `None` if the element does not
+    /// exist.
+    ///
+    /// Leaves are at level 1 and the root is at the highest level of the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::AaBST;
+    ///
+    /// let bst = AaBST::from_iter(vec![1, 2, 3]);
+    ///
+    /// assert_eq!(bst.level(&2), Some(2));
+    /// assert_eq!(bst.level(&3), Some(1));
+    /// assert_eq!(bst.level(&4), None);
+    /// ```
+    pub fn level(&self, value: &T) -> Option<usize> {
+        Node::iterative_retrieve_by(&self.root, |current| current.value.cmp(value))
+            .map(|current| current.level)
+    }
+}
+
+impl<T: Ord> Default for AaBST<T> {
+    /// Creates an empty `AaBST<T>`
+    fn default() -> AaBST<T> {
+        AaBST::new()
+    }
+}
+
+impl<T: Ord> PartialEq for AaBST<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && self.asc_order_iter().eq(other.asc_order_iter())
+    }
+}
+
+impl<T: Ord> Extend<T> for AaBST<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter.into_iter() {
+            self.insert(value)
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for AaBST<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bst = AaBST::new();
+        bst.extend(iter);
+        bst
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for AaBST<T> {
+    fn from(vec: Vec<T>) -> Self {
+        let mut bst = AaBST::new();
+        for value in vec.into_iter() {
+            bst.insert(value);
+        }
+        bst
+    }
+}
+
+impl<T: Ord + Clone> From<&[T]> for AaBST<T> {
+    fn from(slice: &[T]) -> Self {
+        let mut bst = AaBST::new();
+        for value in slice {
+            bst.insert((*value).clone());
+        }
+        bst
+    }
+}
+
+impl<T: Ord + Clone> Clone for AaBST<T> {
+    fn clone(&self) -> Self {
+        AaBST {
+            root: clone_subtree(&self.root),
+            size: self.size,
+        }
+    }
+}
+
+impl<T: Ord + Debug> Display for AaBST<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.asc_order_vec())
+    }
+}
+
+impl<T: Ord> BinarySearchTree<T> for AaBST<T> {
+    /// Returns the total **number of nodes** within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// bst.insert(5);
+    /// bst.insert(10);
+    /// bst.insert(3);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// ```
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the binary search tree contains no nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst: AaBST<i32> = AaBST::new();
+    /// assert!(bst.is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns `true` if the binary search tree contains one or more nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// assert!(bst.is_empty());
+    ///
+    /// bst.insert(2);
+    ///
+    /// assert!(bst.is_not_empty());
+    /// ```
+    fn is_not_empty(&self) -> bool {
+        self.size != 0
+    }
+
+    /// Inserts given value as a node.
+    ///
+    /// **Duplicate values are _not allowed_**.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    ///
+    /// bst.insert(10);
+    /// bst.insert(10);   // Element is not inserted
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(15);
+    /// bst.insert(25);
+    ///
+    /// assert_eq!(bst.size(), 5);
+    /// ```
+    fn insert(&mut self, value: T) {
+        let (root, inserted) = insert(self.root.take(), value);
+        self.root = Some(root);
+        if inserted {
+            self.size += 1;
+        }
+    }
+
+    /// Returns `true` if the binary search tree contains an element with the given value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert!(bst.contains(&5));
+    /// assert!(!bst.contains(&10));
+    /// ```
+    fn contains(&self, value: &T) -> bool {
+        self.retrieve(value).is_some()
+    }
+
+    /// Removes the given value.
+    ///
+    /// Tree will not be modified if trying to remove element that does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// assert_eq!(bst.size(), 3);
+    ///
+    /// bst.remove(&5);
+    /// bst.remove(&10); // Element is not removed
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove(&mut self, value: &T) {
+        let (root, removed) = remove(self.root.take(), value);
+        self.root = root;
+        if removed.is_some() {
+            self.size -= 1;
+        }
+    }
+
+    /// Returns a reference to the element or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert_eq!(bst.retrieve(&5), Some(&5));
+    /// assert_eq!(bst.retrieve(&10), None);
+    /// ```
+    fn retrieve(&self, value: &T) -> Option<&T> {
+        Node::iterative_retrieve_by(&self.root, |current| current.value.cmp(value))
+            .map(|current| &current.value)
+    }
+
+    /// Returns a mutable reference to the element (see [AaBST::retrieve()])
+    /// or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// bst.insert(10);
+    /// bst.insert(5);
+    ///
+    /// let optional_retrieved_value_as_mut = bst.retrieve_as_mut(&5);
+    /// assert_eq!(optional_retrieved_value_as_mut, Some(&mut 5));
+    ///
+    /// let mut retrieved_value = optional_retrieved_value_as_mut.unwrap();
+    /// *retrieved_value = 2; // Change value inside tree to '2'
+    ///
+    /// assert_eq!(bst.retrieve_as_mut(&5), None); // 5 does not exist anymore
+    /// assert_eq!(bst.retrieve_as_mut(&2), Some(&mut 2));
+    /// ```
+    fn retrieve_as_mut(&mut self, value: &T) -> Option<&mut T> {
+        Node::iterative_slot_by(&mut self.root, |current| current.value.cmp(value))
+            .as_mut()
+            .map(|node| &mut node.value.value)
+    }
+
+    /// Returns the **height** or `None` if tree is empty.
+    ///
+    /// The height is the number of edges between the root and it's furthest leaf node.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = AaBST::new();
+    /// assert_eq!(bst.height(), None);
+    ///
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The height is 2.
+    /// assert_eq!(bst.height(), Some(2));
+    /// ```
+    fn height(&self) -> Option<isize> {
+        self.root
+            .as_ref()
+            .map(|_| Node::iterative_height(&self.root))
+    }
+
+    /// Returns a reference to the minimum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// assert_eq!(bst.min(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.min(), Some(&2));
+    /// ```
+    fn min(&self) -> Option<&T> {
+        Node::iterative_min(&self.root).map(|current| &current.value)
+    }
+
+    /// Returns a reference to the maximum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// assert_eq!(bst.max(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.max(), Some(&10));
+    /// ```
+    fn max(&self) -> Option<&T> {
+        Node::iterative_max(&self.root).map(|current| &current.value)
+    }
+
+    /// Removes and returns the minimum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// assert_eq!(bst.remove_min(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_min(), Some(2));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_min(&mut self) -> Option<T> {
+        let (root, removed_min) = remove_min(self.root.take());
+        self.root = root;
+        let removed_min = removed_min?;
+        self.size -= 1;
+        Some(removed_min)
+    }
+
+    /// Removes and returns the maximum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// assert_eq!(bst.remove_max(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_max(), Some(10));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_max(&mut self) -> Option<T> {
+        let (root, removed_max) = remove_max(self.root.take());
+        self.root = root;
+        let removed_max = removed_max?;
+        self.size -= 1;
+        Some(removed_max)
+    }
+
+    /// Returns references to the elements of the tree in **ascending order.**`
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [AaBST::in_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn asc_order_vec(&self) -> Vec<&T> {
+        self.in_order_vec()
+    }
+
+    /// Returns references to the elements of the tree in the order of a **pre-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = AaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The pre_order_vec is: [&4, &2, &1, &3, &6, &5, &7]
+    /// assert_eq!(bst.pre_order_vec(), vec![&4, &2, &1, &3, &6, &5, &7]);
+    /// ```
+    fn pre_order_vec(&self) -> Vec<&T> {
+        Leveled::values(Node::iterative_pre_order_vec(&self.root))
+    }
+
+    /// Returns references to the elements of the tree in the order of an **in-order traversal.**
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [AaBST::asc_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = AaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The in_order_vec is: [&1, &2, &3, &4, &5, &6, &7]
+    /// assert_eq!(bst.in_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn in_order_vec(&self) -> Vec<&T> {
+        Leveled::values(Node::iterative_in_order_vec(&self.root))
+    }
+
+    /// Returns references to the elements of the tree in the order of a **post-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = AaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The post_order_vec is: [&1, &3, &2, &5, &7, &6, &4]
+    /// assert_eq!(bst.post_order_vec(), vec![&1, &3, &2, &5, &7, &6, &4]);
+    /// ```
+    fn post_order_vec(&self) -> Vec<&T> {
+        Leveled::values(Node::iterative_post_order_vec(&self.root))
+    }
+
+    /// Returns references to the elements of the tree in the order of a **level-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = AaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The level_order_vec is: [&4, &2, &6, &1, &3, &5, &7]
+    /// assert_eq!(bst.level_order_vec(), vec![&4, &2, &6, &1, &3, &5, &7]);
+    /// ```
+    fn level_order_vec(&self) -> Vec<&T> {
+        Leveled::values(Node::iterative_level_order_vec(&self.root))
+    }
+
+    /// Returns an iterator over [AaBST::asc_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [AaBST::in_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut asc_order_iter = bst.asc_order_iter();
+    ///
+    /// assert_eq!(asc_order_iter.next(), Some(&1));
+    /// assert_eq!(asc_order_iter.next(), Some(&2));
+    /// assert_eq!(asc_order_iter.next(), Some(&3));
+    /// assert_eq!(asc_order_iter.next(), Some(&4));
+    /// assert_eq!(asc_order_iter.next(), Some(&5));
+    /// assert_eq!(asc_order_iter.next(), None);
+    /// ```
+    fn asc_order_iter(&self) -> IntoIter<&T> {
+        self.in_order_iter()
+    }
+
+    /// Returns an iterator over [AaBST::pre_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut pre_order_iter = bst.pre_order_iter();
+    ///
+    /// assert_eq!(pre_order_iter.next(), Some(&2));
+    /// assert_eq!(pre_order_iter.next(), Some(&1));
+    /// assert_eq!(pre_order_iter.next(), Some(&4));
+    /// assert_eq!(pre_order_iter.next(), Some(&3));
+    /// assert_eq!(pre_order_iter.next(), Some(&5));
+    /// assert_eq!(pre_order_iter.next(), None);
+    /// ```
+    fn pre_order_iter(&self) -> IntoIter<&T> {
+        self.pre_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [AaBST::in_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [AaBST::asc_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut in_order_iter = bst.in_order_iter();
+    ///
+    /// assert_eq!(in_order_iter.next(), Some(&1));
+    /// assert_eq!(in_order_iter.next(), Some(&2));
+    /// assert_eq!(in_order_iter.next(), Some(&3));
+    /// assert_eq!(in_order_iter.next(), Some(&4));
+    /// assert_eq!(in_order_iter.next(), Some(&5));
+    /// assert_eq!(in_order_iter.next(), None);
+    /// ```
+    fn in_order_iter(&self) -> IntoIter<&T> {
+        self.in_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [AaBST::post_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut post_order_iter = bst.post_order_iter();
+    ///
+    /// assert_eq!(post_order_iter.next(), Some(&1));
+    /// assert_eq!(post_order_iter.next(), Some(&3));
+    /// assert_eq!(post_order_iter.next(), Some(&5));
+    /// assert_eq!(post_order_iter.next(), Some(&4));
+    /// assert_eq!(post_order_iter.next(), Some(&2));
+    /// assert_eq!(post_order_iter.next(), None);
+    /// ```
+    fn post_order_iter(&self) -> IntoIter<&T> {
+        self.post_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [AaBST::level_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut level_order_iter = bst.level_order_iter();
+    ///
+    /// assert_eq!(level_order_iter.next(), Some(&2));
+    /// assert_eq!(level_order_iter.next(), Some(&1));
+    /// assert_eq!(level_order_iter.next(), Some(&4));
+    /// assert_eq!(level_order_iter.next(), Some(&3));
+    /// assert_eq!(level_order_iter.next(), Some(&5));
+    /// assert_eq!(level_order_iter.next(), None);
+    /// ```
+    fn level_order_iter(&self) -> IntoIter<&T> {
+        self.level_order_vec().into_iter()
+    }
+
+    /// Returns [AaBST::asc_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [AaBST::into_in_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_asc_order_iter = bst.into_asc_order_iter();
+    ///
+    /// assert_eq!(into_asc_order_iter.next(), Some(1));
+    /// assert_eq!(into_asc_order_iter.next(), Some(2));
+    /// assert_eq!(into_asc_order_iter.next(), Some(3));
+    /// assert_eq!(into_asc_order_iter.next(), Some(4));
+    /// assert_eq!(into_asc_order_iter.next(), Some(5));
+    /// assert_eq!(into_asc_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_asc_order_iter(self) -> IntoIter<T> {
+        self.into_in_order_iter()
+    }
+
+    /// Returns [AaBST::pre_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_pre_order_iter = bst.into_pre_order_iter();
+    ///
+    /// assert_eq!(into_pre_order_iter.next(), Some(2));
+    /// assert_eq!(into_pre_order_iter.next(), Some(1));
+    /// assert_eq!(into_pre_order_iter.next(), Some(4));
+    /// assert_eq!(into_pre_order_iter.next(), Some(3));
+    /// assert_eq!(into_pre_order_iter.next(), Some(5));
+    /// assert_eq!(into_pre_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_pre_order_iter(self) -> IntoIter<T> {
+        Leveled::into_values(Node::iterative_consume_pre_order_vec(self.root))
+    }
+
+    /// Returns [AaBST::in_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [AaBST::asc_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_in_order_iter = bst.into_in_order_iter();
+    ///
+    /// assert_eq!(into_in_order_iter.next(), Some(1));
+    /// assert_eq!(into_in_order_iter.next(), Some(2));
+    /// assert_eq!(into_in_order_iter.next(), Some(3));
+    /// assert_eq!(into_in_order_iter.next(), Some(4));
+    /// assert_eq!(into_in_order_iter.next(), Some(5));
+    /// assert_eq!(into_in_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_in_order_iter(self) -> IntoIter<T> {
+        Leveled::into_values(Node::iterative_consume_in_order_vec(self.root))
+    }
+
+    /// Returns [AaBST::post_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_post_order_iter = bst.into_post_order_iter();
+    ///
+    /// assert_eq!(into_post_order_iter.next(), Some(1));
+    /// assert_eq!(into_post_order_iter.next(), Some(3));
+    /// assert_eq!(into_post_order_iter.next(), Some(5));
+    /// assert_eq!(into_post_order_iter.next(), Some(4));
+    /// assert_eq!(into_post_order_iter.next(), Some(2));
+    /// assert_eq!(into_post_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_post_order_iter(self) -> IntoIter<T> {
+        Leveled::into_values(Node::iterative_consume_post_order_vec(self.root))
+    }
+
+    /// Returns [AaBST::level_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AaBST, BinarySearchTree};
+    ///
+    /// let mut bst = AaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_level_order_iter = bst.into_level_order_iter();
+    ///
+    /// assert_eq!(into_level_order_iter.next(), Some(2));
+    /// assert_eq!(into_level_order_iter.next(), Some(1));
+    /// assert_eq!(into_level_order_iter.next(), Some(4));
+    /// assert_eq!(into_level_order_iter.next(), Some(3));
+    /// assert_eq!(into_level_order_iter.next(), Some(5));
+    /// assert_eq!(into_level_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_level_order_iter(self) -> IntoIter<T> {
+        Leveled::into_values(Node::iterative_consume_level_order_vec(self.root))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{level, Leveled};
+    use crate::node::HeapNode;
+    #[cfg(feature = "iterative")]
+    use crate::IterativeBST;
+    use crate::{AaBST, BinarySearchTree};
+
+    /// Deterministic sequence of pseudo random values for comparisons with [IterativeBST].
+    #[cfg(feature = "iterative")]
+    fn pseudo_random_values(count: usize) -> Vec<u32> {
+        let mut state: u32 = 0x2545_f491;
+        (0..count)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state % 500
+            })
+            .collect()
+    }
+
+    /// Panics unless every node below `node` satisfies the AA tree invariants.
+    fn assert_invariants<T: Ord>(node: &HeapNode<Leveled<T>>) {
+        let node = match node {
+            None => return,
+            Some(node) => node,
+        };
+        let current = node.value.level;
+
+        assert!(current >= 1);
+        assert_eq!(level(&node.left) + 1, current);
+        assert!(level(&node.right) == current || level(&node.right) + 1 == current);
+        if let Some(right) = &node.right {
+            assert!(level(&right.right) < current);
+        }
+        if node.left.is_none() && node.right.is_none() {
+            assert_eq!(current, 1);
+        }
+
+        assert_invariants(&node.left);
+        assert_invariants(&node.right);
+    }
+
+    #[test]
+    fn successfully_keep_ascending_insertions_balanced() {
+        let bst = AaBST::from_iter(0..4096);
+
+        assert_invariants(&bst.root);
+        assert_eq!(bst.size(), 4096);
+        assert!(bst.height().unwrap() <= 24);
+        assert_eq!(bst.min(), Some(&0));
+        assert_eq!(bst.max(), Some(&4095));
+    }
+
+    #[test]
+    fn successfully_ignore_duplicates() {
+        let mut bst = AaBST::from_iter(vec![3, 1, 2]);
+        bst.insert(2);
+        bst.remove(&4);
+
+        assert_invariants(&bst.root);
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.pre_order_vec(), vec![&2, &1, &3]);
+    }
+
+    #[test]
+    fn successfully_remove_while_keeping_invariants() {
+        let mut bst = AaBST::from_iter(0..256);
+
+        for value in (0..256).step_by(3) {
+            bst.remove(&value);
+            assert_invariants(&bst.root);
+        }
+        while bst.size() > 100 {
+            bst.remove_min();
+            bst.remove_max();
+            assert_invariants(&bst.root);
+        }
+
+        assert_eq!(bst.size(), 100);
+        assert!(!bst.contains(&0));
+        assert!(bst.height().unwrap() <= 14);
+    }
+
+    #[test]
+    #[cfg(feature = "iterative")]
+    fn successfully_match_iterative_bst() {
+        let values = pseudo_random_values(2000);
+        let mut aa = AaBST::new();
+        let mut expected = IterativeBST::new();
+
+        for (index, value) in values.into_iter().enumerate() {
+            if index % 3 == 2 {
+                aa.remove(&value);
+                expected.remove(&value);
+            } else {
+                aa.insert(value);
+                expected.insert(value);
+            }
+            assert_eq!(aa.size(), expected.size());
+        }
+
+        assert_invariants(&aa.root);
+        assert_eq!(aa.asc_order_vec(), expected.asc_order_vec());
+        assert_eq!(aa.remove_min(), expected.remove_min());
+        assert_eq!(aa.remove_max(), expected.remove_max());
+    }
+
+    #[test]
+    fn successfully_clone_shape_and_levels() {
+        let bst = AaBST::from_iter(1..=10);
+        let clone = bst.clone();
+
+        assert_eq!(clone, bst);
+        assert_eq!(clone.level_order_vec(), bst.level_order_vec());
+        assert_eq!(clone.level(&4), bst.level(&4));
+        assert_eq!(clone.to_string(), "[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]");
+    }
+}
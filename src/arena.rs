@@ -0,0 +1,2132 @@
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::sync::atomic::{AtomicU64, Ordering as AtomicOrdering};
+use std::sync::Arc;
+use std::vec::IntoIter;
+
+use crate::BinarySearchTree;
+
+/// Stable handle to an element of an [ArenaBST], returned when the element is inserted.
+///
+/// The handle stays valid while the tree restructures around the element and is invalidated
+/// once the element is removed, even if its slot gets reused by a later insertion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ElementId {
+    index: usize,
+    generation: u64,
+}
+
+/// Handle to an element of an [ArenaBST] comparing by the position of the element in the tree,
+/// in O(1) and without access to the tree, see [ArenaBST::order_handle()].
+///
+/// Handles read the order-maintenance label of their element, which the tree keeps up to date
+/// while it relabels elements on insertion. Comparing handles of different trees, or of
+/// elements which have been removed, gives an arbitrary result.
+#[derive(Debug, Clone)]
+pub struct OrderHandle {
+    label: Arc<AtomicU64>,
+}
+
+impl OrderHandle {
+    fn label(&self) -> u64 {
+        self.label.load(AtomicOrdering::Relaxed)
+    }
+}
+
+impl PartialEq for OrderHandle {
+    fn eq(&self, other: &Self) -> bool {
+        self.label() == other.label()
+    }
+}
+
+impl Eq for OrderHandle {}
+
+impl PartialOrd for OrderHandle {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OrderHandle {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.label().cmp(&other.label())
+    }
+}
+
+/// Detached cursor walking the elements of an [ArenaBST] in ascending order, see
+/// [ArenaBST::cursor()].
+///
+/// The cursor does not borrow the tree, it is handed the tree on every step instead. It records
+/// the [generation](ArenaBST::generation()) of the tree it was created from and refuses to step
+/// once the tree has been modified, rather than walking links which may not exist anymore.
+/// Stepping with another tree gives an arbitrary result or panics.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArenaCursor {
+    index: Option<usize>,
+    generation: u64,
+}
+
+impl ArenaCursor {
+    /// Returns the generation of the tree this cursor was created from.
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns the element under the cursor and moves it to the next one, or returns `None`
+    /// once every element has been visited.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [StaleCursor] if the tree has been modified since the cursor was created. The
+    /// cursor is left untouched.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::from_iter(vec![2, 1, 3]);
+    /// let mut cursor = bst.cursor();
+    /// assert_eq!(cursor.try_next(&bst), Ok(Some(&1)));
+    ///
+    /// bst.insert(4);
+    /// let error = cursor.try_next(&bst).unwrap_err();
+    /// assert_eq!(error.tree_generation(), bst.generation());
+    /// ```
+    pub fn try_next<'a, T: Ord, O: ArenaObserver<T>>(
+        &mut self,
+        bst: &'a ArenaBST<T, O>,
+    ) -> Result<Option<&'a T>, StaleCursor> {
+        if self.generation != bst.generation {
+            return Err(StaleCursor::new(self.generation, bst.generation));
+        }
+        Ok(self.index.map(|index| {
+            self.index = bst.successor(index);
+            &bst.node(index).value
+        }))
+    }
+
+    /// Returns the element under the cursor and moves it to the next one, or returns `None`
+    /// once every element has been visited.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tree has been modified since the cursor was created, see
+    /// [ArenaCursor::try_next()] for the fallible version.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let bst = ArenaBST::from_iter(vec![5, 3, 8]);
+    /// let mut cursor = bst.cursor();
+    ///
+    /// let mut visited = Vec::new();
+    /// while let Some(value) = cursor.next(&bst) {
+    ///     visited.push(*value);
+    /// }
+    /// assert_eq!(visited, vec![3, 5, 8]);
+    /// ```
+    pub fn next<'a, T: Ord, O: ArenaObserver<T>>(
+        &mut self,
+        bst: &'a ArenaBST<T, O>,
+    ) -> Option<&'a T> {
+        self.try_next(bst)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+}
+
+/// Error returned by [ArenaCursor::try_next()] when the tree
+/// has been modified since the cursor was created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StaleCursor {
+    cursor: u64,
+    tree: u64,
+}
+
+impl StaleCursor {
+    pub(crate) fn new(cursor: u64, tree: u64) -> StaleCursor {
+        StaleCursor { cursor, tree }
+    }
+
+    /// Returns the generation of the tree when the cursor was created.
+    pub fn cursor_generation(&self) -> u64 {
+        self.cursor
+    }
+
+    /// Returns the generation of the tree the cursor was used with.
+    pub fn tree_generation(&self) -> u64 {
+        self.tree
+    }
+}
+
+impl Display for StaleCursor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "cursor created at generation {} used after the tree was modified, it is at generation {}",
+            self.cursor, self.tree
+        )
+    }
+}
+
+impl Error for StaleCursor {}
+
+/// Callbacks invoked by an [ArenaBST] with the [ElementId] of every element it inserts, removes
+/// or moves, see [ArenaBST::with_observer()].
+///
+/// Observers keep secondary indexes keyed by [ElementId], such as a map from ids to metadata,
+/// in sync with the tree without wrapping every place which modifies it. Each callback runs
+/// right after the tree has been modified. All methods do nothing by default, so observers only
+/// implement the events they need. Elements handed out by the consuming traversals or dropped
+/// along with the tree are not reported.
+///
+/// # Example
+///
+/// ```rust
+/// use std::collections::HashMap;
+/// use bst_rs::{ArenaBST, ArenaObserver, BinarySearchTree, ElementId};
+///
+/// #[derive(Default)]
+/// struct Names(HashMap<ElementId, String>);
+///
+/// impl ArenaObserver<u32> for Names {
+///     fn inserted(&mut self, id: ElementId, value: &u32) {
+///         self.0.insert(id, format!("user-{}", value));
+///     }
+///
+///     fn removed(&mut self, id: ElementId, _: &u32) {
+///         self.0.remove(&id);
+///     }
+/// }
+///
+/// let mut bst = ArenaBST::with_observer(Names::default());
+/// bst.extend(vec![7, 3, 9]);
+/// bst.remove(&7);
+///
+/// let three = bst.id_of(&3).unwrap();
+/// assert_eq!(bst.observer().0[&three], "user-3");
+/// assert_eq!(bst.observer().0.len(), 2);
+/// ```
+pub trait ArenaObserver<T> {
+    /// Called after `value` has been inserted as the element of `id`.
+    fn inserted(&mut self, id: ElementId, value: &T) {
+        let _ = (id, value);
+    }
+
+    /// Called after `value`, the element of `id`, has been removed. The id is no longer valid.
+    fn removed(&mut self, id: ElementId, value: &T) {
+        let _ = (id, value);
+    }
+
+    /// Called after `value` has been moved from `from` to `to` by [ArenaBST::compact_bfs()].
+    fn relocated(&mut self, from: ElementId, to: ElementId, value: &T) {
+        let _ = (from, to, value);
+    }
+}
+
+/// Observer of an [ArenaBST] which ignores every event.
+impl<T> ArenaObserver<T> for () {}
+
+#[derive(Debug)]
+struct ArenaNode<T> {
+    value: T,
+    parent: Option<usize>,
+    left: Option<usize>,
+    right: Option<usize>,
+    /// Order-maintenance label, strictly increasing in ascending order of the elements.
+    label: u64,
+    /// Shared with the [OrderHandle]s of the element, allocated by the first one.
+    handle: Option<Arc<AtomicU64>>,
+}
+
+impl<T: Clone> Clone for ArenaNode<T> {
+    /// Clones the node without its handle, so handles keep following the original tree.
+    fn clone(&self) -> Self {
+        ArenaNode {
+            value: self.value.clone(),
+            parent: self.parent,
+            left: self.left,
+            right: self.right,
+            label: self.label,
+            handle: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Slot<T> {
+    generation: u64,
+    node: Option<ArenaNode<T>>,
+}
+
+/// Binary Search Tree implementation handing out stable [ElementId]s.
+///
+/// Nodes are stored in an arena and linked by index, including a link to their parent. Removing
+/// an element relinks the surrounding nodes instead of moving values between them, so every
+/// other element keeps its slot and [ArenaBST::get_by_id()] as well as [ArenaBST::remove_by_id()]
+/// never have to search the tree. Secondary indexes keyed by [ElementId] can be kept in sync by
+/// an [ArenaObserver].
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{BinarySearchTree, ArenaBST};
+///
+/// let mut bst = ArenaBST::new();
+/// let five = bst.insert_with_id(5).unwrap();
+/// bst.insert(3);
+/// bst.insert(8);
+///
+/// bst.remove(&3);
+/// assert_eq!(bst.get_by_id(five), Some(&5));
+/// assert_eq!(bst.remove_by_id(five), Some(5));
+/// assert_eq!(bst.get_by_id(five), None);
+/// ```
+#[derive(Debug)]
+pub struct ArenaBST<T: Ord, O: ArenaObserver<T> = ()> {
+    slots: Vec<Slot<T>>,
+    free: Vec<usize>,
+    root: Option<usize>,
+    size: usize,
+    /// Bumped by every mutation, see [ArenaBST::generation()].
+    generation: u64,
+    observer: O,
+}
+
+impl<T: Ord> ArenaBST<T> {
+    /// Creates an empty `ArenaBST<T>`
+    ///
+    /// No nodes are allocated on the heap yet
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// // Empty tree is created
+    /// let mut bst: ArenaBST<i32> = ArenaBST::new();
+    /// assert!(bst.is_empty())
+    /// ```
+    pub const fn new() -> ArenaBST<T> {
+        ArenaBST {
+            slots: Vec::new(),
+            free: Vec::new(),
+            root: None,
+            size: 0,
+            generation: 0,
+            observer: (),
+        }
+    }
+}
+
+impl<T: Ord, O: ArenaObserver<T>> ArenaBST<T, O> {
+    /// Creates an empty `ArenaBST<T, O>` reporting every insertion, removal and move of an
+    /// element to `observer`, see [ArenaObserver].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{ArenaBST, ArenaObserver, BinarySearchTree, ElementId};
+    ///
+    /// #[derive(Default)]
+    /// struct Removed(Vec<u32>);
+    ///
+    /// impl ArenaObserver<u32> for Removed {
+    ///     fn removed(&mut self, _: ElementId, value: &u32) {
+    ///         self.0.push(*value);
+    ///     }
+    /// }
+    ///
+    /// let mut bst = ArenaBST::with_observer(Removed::default());
+    /// bst.extend(vec![2, 1, 3]);
+    /// bst.remove_min();
+    /// bst.remove(&3);
+    ///
+    /// assert_eq!(bst.observer().0, vec![1, 3]);
+    /// ```
+    pub const fn with_observer(observer: O) -> ArenaBST<T, O> {
+        ArenaBST {
+            slots: Vec::new(),
+            free: Vec::new(),
+            root: None,
+            size: 0,
+            generation: 0,
+            observer,
+        }
+    }
+
+    /// Returns a reference to the [ArenaObserver] of the tree.
+    pub fn observer(&self) -> &O {
+        &self.observer
+    }
+
+    /// Returns a mutable reference to the [ArenaObserver] of the tree.
+    pub fn observer_mut(&mut self) -> &mut O {
+        &mut self.observer
+    }
+
+    /// Inserts the given value and returns its [ElementId].
+    ///
+    /// Returns `None` and leaves the tree untouched if an equal element already exists, its
+    /// handle can be looked up with [ArenaBST::id_of()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    ///
+    /// let id = bst.insert_with_id(10).unwrap();
+    /// assert_eq!(bst.insert_with_id(10), None);
+    /// assert_eq!(bst.id_of(&10), Some(id));
+    /// ```
+    pub fn insert_with_id(&mut self, value: T) -> Option<ElementId> {
+        let mut current = match self.root {
+            Some(root) => root,
+            None => {
+                let inserted = self.allocate(value, None);
+                self.root = Some(inserted);
+                self.assign_label(inserted, None, None);
+                return Some(self.notify_inserted(inserted));
+            }
+        };
+        let (mut predecessor, mut successor) = (None, None);
+
+        loop {
+            let node = self.node(current);
+            let next = match value.cmp(&node.value) {
+                Ordering::Equal => return None,
+                Ordering::Less => {
+                    successor = Some(current);
+                    node.left
+                }
+                Ordering::Greater => {
+                    predecessor = Some(current);
+                    node.right
+                }
+            };
+            match next {
+                Some(next) => current = next,
+                None => {
+                    let less = value < self.node(current).value;
+                    let inserted = self.allocate(value, Some(current));
+                    if less {
+                        self.node_mut(current).left = Some(inserted);
+                    } else {
+                        self.node_mut(current).right = Some(inserted);
+                    }
+                    self.assign_label(inserted, predecessor, successor);
+                    return Some(self.notify_inserted(inserted));
+                }
+            }
+        }
+    }
+
+    /// Returns the [ElementId] of the element equal to the given value or `None` if it does not
+    /// exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(3);
+    ///
+    /// let id = bst.id_of(&3).unwrap();
+    /// assert_eq!(bst.get_by_id(id), Some(&3));
+    /// assert_eq!(bst.id_of(&4), None);
+    /// ```
+    pub fn id_of(&self, value: &T) -> Option<ElementId> {
+        self.find(value).map(|index| self.id(index))
+    }
+
+    /// Returns a reference to the element of the given [ElementId] in O(1) or `None` if it has
+    /// been removed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// let id = bst.insert_with_id(7).unwrap();
+    /// bst.extend(vec![3, 9, 1, 5]);
+    ///
+    /// assert_eq!(bst.get_by_id(id), Some(&7));
+    ///
+    /// bst.remove(&7);
+    /// assert_eq!(bst.get_by_id(id), None);
+    /// ```
+    pub fn get_by_id(&self, id: ElementId) -> Option<&T> {
+        self.live(id).map(|index| &self.node(index).value)
+    }
+
+    /// Removes and returns the element of the given [ElementId] or `None` if it has already been
+    /// removed.
+    ///
+    /// No search is needed, the element is unlinked through its parent link in O(h) at worst
+    /// when it has two children and O(1) otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(2);
+    /// let id = bst.insert_with_id(4).unwrap();
+    /// bst.insert(3);
+    ///
+    /// assert_eq!(bst.remove_by_id(id), Some(4));
+    /// assert_eq!(bst.remove_by_id(id), None);
+    /// assert_eq!(bst.asc_order_vec(), vec![&2, &3]);
+    /// ```
+    pub fn remove_by_id(&mut self, id: ElementId) -> Option<T> {
+        let index = self.live(id)?;
+        Some(self.unlink(index))
+    }
+
+    /// Returns an [OrderHandle] of the element of the given [ElementId] or `None` if it has been
+    /// removed.
+    ///
+    /// Every element carries an order-maintenance label which is strictly increasing in
+    /// ascending order, so comparing two handles compares the positions of their elements in
+    /// O(1) without comparing the elements themselves. When an insertion finds no free label
+    /// between its neighbours, the smallest window of labels around it which is sparse enough is
+    /// spread out again, which keeps the amortized cost of relabeling low.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// let low = bst.insert_with_id(String::from("apple")).unwrap();
+    /// let high = bst.insert_with_id(String::from("banana")).unwrap();
+    ///
+    /// let low = bst.order_handle(low).unwrap();
+    /// let high = bst.order_handle(high).unwrap();
+    /// assert!(low < high);
+    ///
+    /// // Handles stay valid while neighbours get relabeled
+    /// for fruit in ["avocado", "apricot", "almond", "aubergine"] {
+    ///     bst.insert(String::from(fruit));
+    /// }
+    /// assert!(low < high);
+    /// ```
+    pub fn order_handle(&mut self, id: ElementId) -> Option<OrderHandle> {
+        let index = self.live(id)?;
+        let node = self.node_mut(index);
+        let label = node.label;
+        let label = node
+            .handle
+            .get_or_insert_with(|| Arc::new(AtomicU64::new(label)));
+
+        Some(OrderHandle {
+            label: Arc::clone(label),
+        })
+    }
+
+    /// Returns the **generation** of the tree, which changes whenever the tree is modified.
+    ///
+    /// Inserting, removing and handing out mutable references to elements all bump the
+    /// generation, even if the element turns out to be left as it was. Detached cursors such as
+    /// [ArenaCursor] compare it to the generation they were created at to detect that the tree
+    /// changed under them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// let before = bst.generation();
+    ///
+    /// bst.insert(1);
+    /// assert_ne!(bst.generation(), before);
+    ///
+    /// // Lookups leave it alone
+    /// let after = bst.generation();
+    /// assert!(bst.contains(&1));
+    /// assert_eq!(bst.generation(), after);
+    /// ```
+    pub fn generation(&self) -> u64 {
+        self.generation
+    }
+
+    /// Returns an [ArenaCursor] positioned at the minimum element, which walks the tree in
+    /// ascending order without borrowing it.
+    ///
+    /// The cursor can be stored next to the tree and stepped whenever the tree is at hand, but
+    /// only as long as the tree is not modified in between.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::from_iter(vec![4, 2, 6]);
+    /// let mut cursor = bst.cursor();
+    /// assert_eq!(cursor.next(&bst), Some(&2));
+    ///
+    /// // Modifying the tree invalidates the cursor
+    /// bst.remove(&4);
+    /// assert!(cursor.try_next(&bst).is_err());
+    /// ```
+    pub fn cursor(&self) -> ArenaCursor {
+        ArenaCursor {
+            index: self.root.map(|root| self.leftmost(root)),
+            generation: self.generation,
+        }
+    }
+
+    /// Returns an [ArenaCursor] positioned at the element of the given [ElementId] or `None` if
+    /// it has been removed, see [ArenaBST::cursor()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::from_iter(vec![1, 3, 5]);
+    /// let three = bst.id_of(&3).unwrap();
+    ///
+    /// let mut cursor = bst.cursor_at(three).unwrap();
+    /// assert_eq!(cursor.next(&bst), Some(&3));
+    /// assert_eq!(cursor.next(&bst), Some(&5));
+    /// assert_eq!(cursor.next(&bst), None);
+    /// ```
+    pub fn cursor_at(&self, id: ElementId) -> Option<ArenaCursor> {
+        Some(ArenaCursor {
+            index: Some(self.live(id)?),
+            generation: self.generation,
+        })
+    }
+
+    /// Moves the nodes into **breadth-first order** in memory, returning how many of them moved.
+    ///
+    /// Insertions and removals scatter nodes over the arena, while searches walk from the root
+    /// downwards. Afterwards the root is stored first, followed by the nodes of every level from
+    /// left to right, so the top levels visited by every search share a few cache lines. Meant
+    /// to be called once before a read-heavy phase. Freed slots end up after the nodes and are
+    /// reused lowest first.
+    ///
+    /// Values are moved along with their nodes without being cloned, and [OrderHandle]s keep
+    /// following their elements. Unlike any other operation, this **invalidates the
+    /// [ElementId]s** of the moved elements, their new ids can be looked up with
+    /// [ArenaBST::id_of()]. Cursors are invalidated unless nothing moved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// // Removing the root leaves the first slot empty
+    /// let mut bst = ArenaBST::from_iter(vec![1, 3, 2]);
+    /// bst.remove(&1);
+    /// let three = bst.id_of(&3).unwrap();
+    ///
+    /// assert_eq!(bst.compact_bfs(), 2);
+    /// assert_eq!(bst.get_by_id(three), None);
+    /// assert_eq!(bst.get_by_id(bst.id_of(&3).unwrap()), Some(&3));
+    ///
+    /// // Already compact
+    /// assert_eq!(bst.compact_bfs(), 0);
+    /// ```
+    pub fn compact_bfs(&mut self) -> usize {
+        let order = self.level_order_indices();
+        let ids: Vec<ElementId> = order.iter().map(|&index| self.id(index)).collect();
+        let mut targets = vec![0; self.slots.len()];
+        for (target, &index) in order.iter().enumerate() {
+            targets[index] = target;
+        }
+
+        let mut nodes: Vec<Option<ArenaNode<T>>> =
+            self.slots.iter_mut().map(|slot| slot.node.take()).collect();
+        let mut moved = 0;
+        for (target, slot) in self.slots.iter_mut().enumerate() {
+            let index = order.get(target).copied();
+            // Ids of the element which was stored here must not match the one moved in
+            if index != Some(target) {
+                slot.generation += 1;
+            }
+            if let Some(index) = index {
+                let mut node = nodes[index].take().unwrap();
+                node.parent = node.parent.map(|parent| targets[parent]);
+                node.left = node.left.map(|left| targets[left]);
+                node.right = node.right.map(|right| targets[right]);
+                slot.node = Some(node);
+                if index != target {
+                    moved += 1;
+                }
+            }
+        }
+
+        self.root = self.root.map(|root| targets[root]);
+        self.free = (self.size..self.slots.len()).rev().collect();
+        if moved > 0 {
+            self.generation += 1;
+        }
+
+        for (target, from) in ids.into_iter().enumerate() {
+            if from.index != target {
+                let to = self.id(target);
+                let value = &self.slots[target].node.as_ref().unwrap().value;
+                self.observer.relocated(from, to, value);
+            }
+        }
+        moved
+    }
+
+    fn id(&self, index: usize) -> ElementId {
+        ElementId {
+            index,
+            generation: self.slots[index].generation,
+        }
+    }
+
+    /// Reports the element at `index` as inserted and returns its id.
+    fn notify_inserted(&mut self, index: usize) -> ElementId {
+        let id = self.id(index);
+        self.observer
+            .inserted(id, &self.slots[index].node.as_ref().unwrap().value);
+        id
+    }
+
+    fn live(&self, id: ElementId) -> Option<usize> {
+        let slot = self.slots.get(id.index)?;
+        (slot.generation == id.generation && slot.node.is_some()).then_some(id.index)
+    }
+
+    fn node(&self, index: usize) -> &ArenaNode<T> {
+        self.slots[index].node.as_ref().unwrap()
+    }
+
+    fn node_mut(&mut self, index: usize) -> &mut ArenaNode<T> {
+        self.slots[index].node.as_mut().unwrap()
+    }
+
+    fn allocate(&mut self, value: T, parent: Option<usize>) -> usize {
+        let node = Some(ArenaNode {
+            value,
+            parent,
+            left: None,
+            right: None,
+            label: 0,
+            handle: None,
+        });
+        self.size += 1;
+        self.generation += 1;
+        match self.free.pop() {
+            Some(index) => {
+                self.slots[index].node = node;
+                index
+            }
+            None => {
+                self.slots.push(Slot {
+                    generation: 0,
+                    node,
+                });
+                self.slots.len() - 1
+            }
+        }
+    }
+
+    fn find(&self, value: &T) -> Option<usize> {
+        let mut current = self.root?;
+        loop {
+            let node = self.node(current);
+            current = match value.cmp(&node.value) {
+                Ordering::Equal => return Some(current),
+                Ordering::Less => node.left?,
+                Ordering::Greater => node.right?,
+            };
+        }
+    }
+
+    fn leftmost(&self, mut index: usize) -> usize {
+        while let Some(left) = self.node(index).left {
+            index = left;
+        }
+        index
+    }
+
+    fn rightmost(&self, mut index: usize) -> usize {
+        while let Some(right) = self.node(index).right {
+            index = right;
+        }
+        index
+    }
+
+    fn predecessor(&self, index: usize) -> Option<usize> {
+        if let Some(left) = self.node(index).left {
+            return Some(self.rightmost(left));
+        }
+        let mut current = index;
+        while let Some(parent) = self.node(current).parent {
+            if self.node(parent).right == Some(current) {
+                return Some(parent);
+            }
+            current = parent;
+        }
+        None
+    }
+
+    fn successor(&self, index: usize) -> Option<usize> {
+        if let Some(right) = self.node(index).right {
+            return Some(self.leftmost(right));
+        }
+        let mut current = index;
+        while let Some(parent) = self.node(current).parent {
+            if self.node(parent).left == Some(current) {
+                return Some(parent);
+            }
+            current = parent;
+        }
+        None
+    }
+
+    fn set_label(&mut self, index: usize, label: u64) {
+        let node = self.node_mut(index);
+        node.label = label;
+        if let Some(handle) = &node.handle {
+            handle.store(label, AtomicOrdering::Relaxed);
+        }
+    }
+
+    /// Labels the node at `index`, just inserted between `predecessor` and `successor`, in the
+    /// middle of the gap between their labels.
+    fn assign_label(&mut self, index: usize, predecessor: Option<usize>, successor: Option<usize>) {
+        let low = predecessor.map_or(Some(0), |predecessor| {
+            self.node(predecessor).label.checked_add(1)
+        });
+        let high = successor.map_or(Some(u64::MAX), |successor| {
+            self.node(successor).label.checked_sub(1)
+        });
+
+        match (low, high) {
+            (Some(low), Some(high)) if low <= high => self.set_label(index, low + (high - low) / 2),
+            _ => self.relabel_around(index, predecessor.or(successor).unwrap()),
+        }
+    }
+
+    /// Spreads out the labels of the smallest aligned window around the label of `neighbour`
+    /// holding at most `2^(bits / 2)` of its `2^bits` labels, `index` being the node without a
+    /// label next to `neighbour`.
+    fn relabel_around(&mut self, index: usize, neighbour: usize) {
+        let anchor = self.node(neighbour).label;
+
+        for bits in 1..=u64::BITS {
+            let mask = u64::MAX >> (u64::BITS - bits);
+            let base = anchor & !mask;
+            let limit = match bits {
+                u64::BITS => usize::MAX,
+                _ => 1usize.checked_shl(bits / 2).unwrap_or(usize::MAX),
+            };
+
+            // Labels are increasing in order, so the labeled window is contiguous around `index`
+            let in_window = |node: usize| self.node(node).label & !mask == base;
+            let mut window = Vec::new();
+            let mut current = self.predecessor(index);
+            while let Some(before) = current.filter(|&before| in_window(before)) {
+                if window.len() >= limit {
+                    break;
+                }
+                window.push(before);
+                current = self.predecessor(before);
+            }
+            window.reverse();
+            window.push(index);
+            let mut current = self.successor(index);
+            while let Some(after) = current.filter(|&after| in_window(after)) {
+                if window.len() > limit {
+                    break;
+                }
+                window.push(after);
+                current = self.successor(after);
+            }
+            if window.len() > limit {
+                continue;
+            }
+
+            let spacing = (mask as u128 + 1) / window.len() as u128;
+            for (position, node) in window.into_iter().enumerate() {
+                let offset = position as u128 * spacing + spacing / 2;
+                self.set_label(node, base + offset as u64);
+            }
+            return;
+        }
+    }
+
+    /// Makes `child` take the place of `index` below the parent of `index`.
+    fn replace_child(&mut self, index: usize, child: Option<usize>) {
+        let parent = self.node(index).parent;
+        if let Some(child) = child {
+            self.node_mut(child).parent = parent;
+        }
+        match parent {
+            None => self.root = child,
+            Some(parent) if self.node(parent).left == Some(index) => {
+                self.node_mut(parent).left = child
+            }
+            Some(parent) => self.node_mut(parent).right = child,
+        }
+    }
+
+    /// Removes the node at `index`, moving its in-order successor node into its place when it
+    /// has two children so that no value changes slots.
+    fn unlink(&mut self, index: usize) -> T {
+        let id = self.id(index);
+        let (left, right) = (self.node(index).left, self.node(index).right);
+        match (left, right) {
+            (Some(left), Some(right)) => {
+                let successor = self.leftmost(right);
+                if successor != right {
+                    let successor_right = self.node(successor).right;
+                    self.replace_child(successor, successor_right);
+                    self.node_mut(successor).right = Some(right);
+                    self.node_mut(right).parent = Some(successor);
+                }
+                self.replace_child(index, Some(successor));
+                self.node_mut(successor).left = Some(left);
+                self.node_mut(left).parent = Some(successor);
+            }
+            (child, None) | (None, child) => self.replace_child(index, child),
+        }
+
+        let slot = &mut self.slots[index];
+        slot.generation += 1;
+        self.free.push(index);
+        self.size -= 1;
+        self.generation += 1;
+        let value = slot.node.take().unwrap().value;
+        self.observer.removed(id, &value);
+        value
+    }
+
+    fn pre_order_indices(&self) -> Vec<usize> {
+        let mut indices = Vec::with_capacity(self.size);
+        let mut stack = Vec::from_iter(self.root);
+
+        while let Some(current) = stack.pop() {
+            indices.push(current);
+            stack.extend(self.node(current).right);
+            stack.extend(self.node(current).left);
+        }
+
+        indices
+    }
+
+    fn in_order_indices(&self) -> Vec<usize> {
+        let mut indices = Vec::with_capacity(self.size);
+        let mut stack = Vec::new();
+        let mut current = self.root;
+
+        while current.is_some() || !stack.is_empty() {
+            while let Some(index) = current {
+                stack.push(index);
+                current = self.node(index).left;
+            }
+            let index = stack.pop().unwrap();
+            indices.push(index);
+            current = self.node(index).right;
+        }
+
+        indices
+    }
+
+    fn post_order_indices(&self) -> Vec<usize> {
+        let mut indices = Vec::with_capacity(self.size);
+        let mut stack = Vec::from_iter(self.root);
+
+        while let Some(current) = stack.pop() {
+            indices.push(current);
+            stack.extend(self.node(current).left);
+            stack.extend(self.node(current).right);
+        }
+        indices.reverse();
+
+        indices
+    }
+
+    fn level_order_indices(&self) -> Vec<usize> {
+        let mut indices = Vec::with_capacity(self.size);
+        let mut queue = VecDeque::from_iter(self.root);
+
+        while let Some(current) = queue.pop_front() {
+            indices.push(current);
+            queue.extend(self.node(current).left);
+            queue.extend(self.node(current).right);
+        }
+
+        indices
+    }
+
+    fn values(&self, indices: Vec<usize>) -> Vec<&T> {
+        indices
+            .into_iter()
+            .map(|index| &self.node(index).value)
+            .collect()
+    }
+
+    fn into_values(mut self, indices: Vec<usize>) -> IntoIter<T> {
+        indices
+            .into_iter()
+            .map(|index| self.slots[index].node.take().unwrap().value)
+            .collect::<Vec<T>>()
+            .into_iter()
+    }
+}
+
+impl<T: Ord> Default for ArenaBST<T> {
+    /// Creates an empty `ArenaBST<T>`
+    fn default() -> ArenaBST<T> {
+        ArenaBST::new()
+    }
+}
+
+impl<T: Ord, O: ArenaObserver<T>> PartialEq for ArenaBST<T, O> {
+    fn eq(&self, other: &Self) -> bool {
+        self.asc_order_vec() == other.asc_order_vec()
+    }
+}
+
+impl<T: Ord, O: ArenaObserver<T>> Extend<T> for ArenaBST<T, O> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter.into_iter() {
+            self.insert(value)
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for ArenaBST<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bst = ArenaBST::new();
+        bst.extend(iter);
+        bst
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for ArenaBST<T> {
+    fn from(vec: Vec<T>) -> Self {
+        let mut bst = ArenaBST::new();
+        for value in vec.into_iter() {
+            bst.insert(value);
+        }
+        bst
+    }
+}
+
+impl<T: Ord + Clone> From<&[T]> for ArenaBST<T> {
+    fn from(slice: &[T]) -> Self {
+        let mut bst = ArenaBST::new();
+        for value in slice {
+            bst.insert((*value).clone());
+        }
+        bst
+    }
+}
+
+impl<T: Ord + Clone, O: ArenaObserver<T> + Clone> Clone for ArenaBST<T, O> {
+    /// Clones the arena as is, so every [ElementId] and [ArenaCursor] of the original tree refers
+    /// to the same element in the clone, which also gets a clone of the observer.
+    fn clone(&self) -> Self {
+        ArenaBST {
+            slots: self.slots.clone(),
+            free: self.free.clone(),
+            root: self.root,
+            size: self.size,
+            generation: self.generation,
+            observer: self.observer.clone(),
+        }
+    }
+}
+
+impl<T: Ord + Debug, O: ArenaObserver<T>> Display for ArenaBST<T, O> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.asc_order_vec())
+    }
+}
+
+impl<T: Ord, O: ArenaObserver<T>> BinarySearchTree<T> for ArenaBST<T, O> {
+    /// Returns the total **number of nodes** within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(5);
+    /// bst.insert(10);
+    /// bst.insert(3);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// ```
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the binary search tree contains no nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst: ArenaBST<i32> = ArenaBST::new();
+    /// assert!(bst.is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns `true` if the binary search tree contains one or more nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// assert!(bst.is_empty());
+    ///
+    /// bst.insert(2);
+    ///
+    /// assert!(bst.is_not_empty());
+    /// ```
+    fn is_not_empty(&self) -> bool {
+        self.size != 0
+    }
+
+    /// Inserts given value as a node.
+    ///
+    /// **Duplicate values are _not allowed_**.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    ///
+    /// bst.insert(10);
+    /// bst.insert(10);   // Element is not inserted
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(15);
+    /// bst.insert(25);
+    ///
+    /// assert_eq!(bst.size(), 5);
+    /// ```
+    fn insert(&mut self, value: T) {
+        self.insert_with_id(value);
+    }
+
+    /// Returns `true` if the binary search tree contains an element with the given value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert!(bst.contains(&5));
+    /// assert!(!bst.contains(&10));
+    /// ```
+    fn contains(&self, value: &T) -> bool {
+        self.find(value).is_some()
+    }
+
+    /// Removes the given value.
+    ///
+    /// Tree will not be modified if trying to remove element that does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// assert_eq!(bst.size(), 3);
+    ///
+    /// bst.remove(&5);
+    /// bst.remove(&10); // Element is not removed
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove(&mut self, value: &T) {
+        if let Some(index) = self.find(value) {
+            self.unlink(index);
+        }
+    }
+
+    /// Returns a reference to the element or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert_eq!(bst.retrieve(&5), Some(&5));
+    /// assert_eq!(bst.retrieve(&10), None);
+    /// ```
+    fn retrieve(&self, value: &T) -> Option<&T> {
+        self.find(value).map(|index| &self.node(index).value)
+    }
+
+    /// Returns a mutable reference to the element (see [ArenaBST::retrieve()])
+    /// or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(10);
+    /// bst.insert(5);
+    ///
+    /// let optional_retrieved_value_as_mut = bst.retrieve_as_mut(&5);
+    /// assert_eq!(optional_retrieved_value_as_mut, Some(&mut 5));
+    ///
+    /// let mut retrieved_value = optional_retrieved_value_as_mut.unwrap();
+    /// *retrieved_value = 2; // Change value inside tree to '2'
+    ///
+    /// assert_eq!(bst.retrieve_as_mut(&5), None); // 5 does not exist anymore
+    /// assert_eq!(bst.retrieve_as_mut(&2), Some(&mut 2));
+    /// ```
+    fn retrieve_as_mut(&mut self, value: &T) -> Option<&mut T> {
+        let index = self.find(value)?;
+        self.generation += 1;
+        Some(&mut self.node_mut(index).value)
+    }
+
+    /// Returns the **height** or `None` if tree is empty.
+    ///
+    /// The height is the number of edges between the root and it's furthest leaf node.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = ArenaBST::new();
+    /// assert_eq!(bst.height(), None);
+    ///
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The height is 2.
+    /// assert_eq!(bst.height(), Some(2));
+    /// ```
+    fn height(&self) -> Option<isize> {
+        let mut height = -1;
+        let mut level = Vec::from_iter(self.root);
+
+        while !level.is_empty() {
+            level = level
+                .into_iter()
+                .flat_map(|current| {
+                    let node = self.node(current);
+                    node.left.into_iter().chain(node.right)
+                })
+                .collect();
+            height += 1;
+        }
+
+        self.root.map(|_| height)
+    }
+
+    /// Returns a reference to the minimum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// assert_eq!(bst.min(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.min(), Some(&2));
+    /// ```
+    fn min(&self) -> Option<&T> {
+        self.root.map(|root| &self.node(self.leftmost(root)).value)
+    }
+
+    /// Returns a reference to the maximum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// assert_eq!(bst.max(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.max(), Some(&10));
+    /// ```
+    fn max(&self) -> Option<&T> {
+        self.root.map(|root| &self.node(self.rightmost(root)).value)
+    }
+
+    /// Removes and returns the minimum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// assert_eq!(bst.remove_min(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_min(), Some(2));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_min(&mut self) -> Option<T> {
+        let index = self.leftmost(self.root?);
+        Some(self.unlink(index))
+    }
+
+    /// Removes and returns the maximum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// assert_eq!(bst.remove_max(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_max(), Some(10));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_max(&mut self) -> Option<T> {
+        let index = self.rightmost(self.root?);
+        Some(self.unlink(index))
+    }
+
+    /// Returns references to the elements of the tree in **ascending order.**`
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ArenaBST::in_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn asc_order_vec(&self) -> Vec<&T> {
+        self.in_order_vec()
+    }
+
+    /// Returns references to the elements of the tree in the order of a **pre-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The pre_order_vec is: [&4, &2, &1, &3, &6, &5, &7]
+    /// assert_eq!(bst.pre_order_vec(), vec![&4, &2, &1, &3, &6, &5, &7]);
+    /// ```
+    fn pre_order_vec(&self) -> Vec<&T> {
+        self.values(self.pre_order_indices())
+    }
+
+    /// Returns references to the elements of the tree in the order of an **in-order traversal.**
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ArenaBST::asc_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The in_order_vec is: [&1, &2, &3, &4, &5, &6, &7]
+    /// assert_eq!(bst.in_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn in_order_vec(&self) -> Vec<&T> {
+        self.values(self.in_order_indices())
+    }
+
+    /// Returns references to the elements of the tree in the order of a **post-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The post_order_vec is: [&1, &3, &2, &5, &7, &6, &4]
+    /// assert_eq!(bst.post_order_vec(), vec![&1, &3, &2, &5, &7, &6, &4]);
+    /// ```
+    fn post_order_vec(&self) -> Vec<&T> {
+        self.values(self.post_order_indices())
+    }
+
+    /// Returns references to the elements of the tree in the order of a **level-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The level_order_vec is: [&4, &2, &6, &1, &3, &5, &7]
+    /// assert_eq!(bst.level_order_vec(), vec![&4, &2, &6, &1, &3, &5, &7]);
+    /// ```
+    fn level_order_vec(&self) -> Vec<&T> {
+        self.values(self.level_order_indices())
+    }
+
+    /// Returns an iterator over [ArenaBST::asc_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ArenaBST::in_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut asc_order_iter = bst.asc_order_iter();
+    ///
+    /// assert_eq!(asc_order_iter.next(), Some(&1));
+    /// assert_eq!(asc_order_iter.next(), Some(&2));
+    /// assert_eq!(asc_order_iter.next(), Some(&3));
+    /// assert_eq!(asc_order_iter.next(), Some(&4));
+    /// assert_eq!(asc_order_iter.next(), Some(&5));
+    /// assert_eq!(asc_order_iter.next(), None);
+    /// ```
+    fn asc_order_iter(&self) -> IntoIter<&T> {
+        self.in_order_iter()
+    }
+
+    /// Returns an iterator over [ArenaBST::pre_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut pre_order_iter = bst.pre_order_iter();
+    ///
+    /// assert_eq!(pre_order_iter.next(), Some(&3));
+    /// assert_eq!(pre_order_iter.next(), Some(&1));
+    /// assert_eq!(pre_order_iter.next(), Some(&2));
+    /// assert_eq!(pre_order_iter.next(), Some(&4));
+    /// assert_eq!(pre_order_iter.next(), Some(&5));
+    /// assert_eq!(pre_order_iter.next(), None);
+    /// ```
+    fn pre_order_iter(&self) -> IntoIter<&T> {
+        self.pre_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [ArenaBST::in_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ArenaBST::asc_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut in_order_iter = bst.in_order_iter();
+    ///
+    /// assert_eq!(in_order_iter.next(), Some(&1));
+    /// assert_eq!(in_order_iter.next(), Some(&2));
+    /// assert_eq!(in_order_iter.next(), Some(&3));
+    /// assert_eq!(in_order_iter.next(), Some(&4));
+    /// assert_eq!(in_order_iter.next(), Some(&5));
+    /// assert_eq!(in_order_iter.next(), None);
+    /// ```
+    fn in_order_iter(&self) -> IntoIter<&T> {
+        self.in_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [ArenaBST::post_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut post_order_iter = bst.post_order_iter();
+    ///
+    /// assert_eq!(post_order_iter.next(), Some(&2));
+    /// assert_eq!(post_order_iter.next(), Some(&1));
+    /// assert_eq!(post_order_iter.next(), Some(&5));
+    /// assert_eq!(post_order_iter.next(), Some(&4));
+    /// assert_eq!(post_order_iter.next(), Some(&3));
+    /// assert_eq!(post_order_iter.next(), None);
+    /// ```
+    fn post_order_iter(&self) -> IntoIter<&T> {
+        self.post_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [ArenaBST::level_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut level_order_iter = bst.level_order_iter();
+    ///
+    /// assert_eq!(level_order_iter.next(), Some(&3));
+    /// assert_eq!(level_order_iter.next(), Some(&1));
+    /// assert_eq!(level_order_iter.next(), Some(&4));
+    /// assert_eq!(level_order_iter.next(), Some(&2));
+    /// assert_eq!(level_order_iter.next(), Some(&5));
+    /// assert_eq!(level_order_iter.next(), None);
+    /// ```
+    fn level_order_iter(&self) -> IntoIter<&T> {
+        self.level_order_vec().into_iter()
+    }
+
+    /// Returns [ArenaBST::asc_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ArenaBST::into_in_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_asc_order_iter = bst.into_asc_order_iter();
+    ///
+    /// assert_eq!(into_asc_order_iter.next(), Some(1));
+    /// assert_eq!(into_asc_order_iter.next(), Some(2));
+    /// assert_eq!(into_asc_order_iter.next(), Some(3));
+    /// assert_eq!(into_asc_order_iter.next(), Some(4));
+    /// assert_eq!(into_asc_order_iter.next(), Some(5));
+    /// assert_eq!(into_asc_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_asc_order_iter(self) -> IntoIter<T> {
+        self.into_in_order_iter()
+    }
+
+    /// Returns [ArenaBST::pre_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_pre_order_iter = bst.into_pre_order_iter();
+    ///
+    /// assert_eq!(into_pre_order_iter.next(), Some(3));
+    /// assert_eq!(into_pre_order_iter.next(), Some(1));
+    /// assert_eq!(into_pre_order_iter.next(), Some(2));
+    /// assert_eq!(into_pre_order_iter.next(), Some(4));
+    /// assert_eq!(into_pre_order_iter.next(), Some(5));
+    /// assert_eq!(into_pre_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_pre_order_iter(self) -> IntoIter<T> {
+        let indices = self.pre_order_indices();
+        self.into_values(indices)
+    }
+
+    /// Returns [ArenaBST::in_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ArenaBST::asc_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_in_order_iter = bst.into_in_order_iter();
+    ///
+    /// assert_eq!(into_in_order_iter.next(), Some(1));
+    /// assert_eq!(into_in_order_iter.next(), Some(2));
+    /// assert_eq!(into_in_order_iter.next(), Some(3));
+    /// assert_eq!(into_in_order_iter.next(), Some(4));
+    /// assert_eq!(into_in_order_iter.next(), Some(5));
+    /// assert_eq!(into_in_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_in_order_iter(self) -> IntoIter<T> {
+        let indices = self.in_order_indices();
+        self.into_values(indices)
+    }
+
+    /// Returns [ArenaBST::post_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_post_order_iter = bst.into_post_order_iter();
+    ///
+    /// assert_eq!(into_post_order_iter.next(), Some(2));
+    /// assert_eq!(into_post_order_iter.next(), Some(1));
+    /// assert_eq!(into_post_order_iter.next(), Some(5));
+    /// assert_eq!(into_post_order_iter.next(), Some(4));
+    /// assert_eq!(into_post_order_iter.next(), Some(3));
+    /// assert_eq!(into_post_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_post_order_iter(self) -> IntoIter<T> {
+        let indices = self.post_order_indices();
+        self.into_values(indices)
+    }
+
+    /// Returns [ArenaBST::level_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ArenaBST};
+    ///
+    /// let mut bst = ArenaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_level_order_iter = bst.into_level_order_iter();
+    ///
+    /// assert_eq!(into_level_order_iter.next(), Some(3));
+    /// assert_eq!(into_level_order_iter.next(), Some(1));
+    /// assert_eq!(into_level_order_iter.next(), Some(4));
+    /// assert_eq!(into_level_order_iter.next(), Some(2));
+    /// assert_eq!(into_level_order_iter.next(), Some(5));
+    /// assert_eq!(into_level_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_level_order_iter(self) -> IntoIter<T> {
+        let indices = self.level_order_indices();
+        self.into_values(indices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::ElementId;
+    #[cfg(feature = "iterative")]
+    use crate::IterativeBST;
+    use crate::{ArenaBST, ArenaObserver, BinarySearchTree};
+
+    /// Deterministic sequence of pseudo random values for comparisons with [IterativeBST].
+    fn pseudo_random_values(count: usize) -> Vec<u32> {
+        let mut state: u32 = 0x2545_f491;
+        (0..count)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state % 500
+            })
+            .collect()
+    }
+
+    /// Checks that every child links back to its parent.
+    fn assert_parent_links<T: Ord>(bst: &ArenaBST<T>) {
+        if let Some(root) = bst.root {
+            assert_eq!(bst.node(root).parent, None);
+        }
+        for index in bst.pre_order_indices() {
+            let node = bst.node(index);
+            for child in node.left.into_iter().chain(node.right) {
+                assert_eq!(bst.node(child).parent, Some(index));
+            }
+        }
+    }
+
+    #[test]
+    fn successfully_keep_ids_while_tree_restructures() {
+        let mut bst = ArenaBST::new();
+        let ids: Vec<(i32, ElementId)> = vec![8, 4, 12, 2, 6, 10, 14, 5, 7]
+            .into_iter()
+            .map(|value| (value, bst.insert_with_id(value).unwrap()))
+            .collect();
+
+        bst.remove(&4);
+        bst.remove(&8);
+
+        for (value, id) in ids {
+            match value {
+                4 | 8 => assert_eq!(bst.get_by_id(id), None),
+                _ => assert_eq!(bst.get_by_id(id), Some(&value)),
+            }
+        }
+        assert_eq!(bst.asc_order_vec(), vec![&2, &5, &6, &7, &10, &12, &14]);
+        assert_parent_links(&bst);
+    }
+
+    #[test]
+    fn successfully_remove_root_with_two_children_by_id() {
+        let mut bst = ArenaBST::from(vec![5, 3, 8, 7, 9, 6]);
+        let root = bst.id_of(&5).unwrap();
+        let six = bst.id_of(&6).unwrap();
+
+        assert_eq!(bst.remove_by_id(root), Some(5));
+
+        assert_eq!(bst.size(), 5);
+        assert_eq!(bst.pre_order_vec(), vec![&6, &3, &8, &7, &9]);
+        assert_eq!(bst.get_by_id(six), Some(&6));
+        assert_parent_links(&bst);
+    }
+
+    #[test]
+    fn successfully_invalidate_ids_of_reused_slots() {
+        let mut bst = ArenaBST::new();
+        let first = bst.insert_with_id(1).unwrap();
+        bst.remove(&1);
+        let second = bst.insert_with_id(2).unwrap();
+
+        assert_ne!(first, second);
+        assert_eq!(bst.get_by_id(first), None);
+        assert_eq!(bst.remove_by_id(first), None);
+        assert_eq!(bst.get_by_id(second), Some(&2));
+        assert_eq!(bst.size(), 1);
+    }
+
+    #[test]
+    fn successfully_keep_ids_valid_in_clone() {
+        let mut bst = ArenaBST::from_iter(vec![2, 1, 3]);
+        let id = bst.id_of(&3).unwrap();
+        let clone = bst.clone();
+
+        bst.remove_by_id(id);
+
+        assert_eq!(clone.get_by_id(id), Some(&3));
+        assert_eq!(bst.get_by_id(id), None);
+    }
+
+    #[test]
+    #[cfg(feature = "iterative")]
+    fn successfully_behave_like_iterative_bst() {
+        let values = pseudo_random_values(2000);
+        let mut arena = ArenaBST::new();
+        let mut iterative = IterativeBST::new();
+
+        for (index, value) in values.iter().enumerate() {
+            if index % 3 == 2 {
+                let id = arena.id_of(value);
+                assert_eq!(
+                    id.and_then(|id| arena.remove_by_id(id)).as_ref(),
+                    iterative.retrieve(value)
+                );
+                iterative.remove(value);
+            } else {
+                arena.insert(*value);
+                iterative.insert(*value);
+            }
+        }
+
+        assert_eq!(arena.size(), iterative.size());
+        assert_eq!(arena.asc_order_vec(), iterative.asc_order_vec());
+        assert_eq!(arena.pre_order_vec(), iterative.pre_order_vec());
+        assert_eq!(arena.height(), iterative.height());
+        assert_parent_links(&arena);
+    }
+
+    #[test]
+    fn successfully_remove_min_and_max() {
+        let mut bst = ArenaBST::from_iter(vec![4, 2, 6, 1, 3, 5, 7]);
+
+        assert_eq!(bst.remove_min(), Some(1));
+        assert_eq!(bst.remove_max(), Some(7));
+        assert_eq!(bst.min(), Some(&2));
+        assert_eq!(bst.max(), Some(&6));
+        assert_eq!(
+            bst.into_level_order_iter().collect::<Vec<_>>(),
+            vec![4, 2, 6, 3, 5]
+        );
+    }
+
+    /// Checks that labels are strictly increasing in ascending order.
+    fn assert_increasing_labels<T: Ord>(bst: &ArenaBST<T>) {
+        let labels: Vec<u64> = bst
+            .in_order_indices()
+            .into_iter()
+            .map(|index| bst.node(index).label)
+            .collect();
+        assert!(labels.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn successfully_compare_order_handles_like_elements() {
+        let mut bst = ArenaBST::new();
+        let mut handles = Vec::new();
+        // Ascending, descending and clustered insertions exhaust the gaps between labels
+        let values = (0..300u32)
+            .map(|value| value * 1000)
+            .chain((0..300).map(|value| 1_000_000 - value))
+            .chain((1..300).map(|value| 500_000 + value))
+            .chain(
+                pseudo_random_values(500)
+                    .into_iter()
+                    .map(|value| value * 3 + 1),
+            );
+
+        for value in values {
+            if let Some(id) = bst.insert_with_id(value) {
+                handles.push((value, bst.order_handle(id).unwrap()));
+            }
+        }
+
+        assert_increasing_labels(&bst);
+        for (value_a, handle_a) in handles.iter().step_by(7) {
+            for (value_b, handle_b) in handles.iter().step_by(5) {
+                assert_eq!(handle_a.cmp(handle_b), value_a.cmp(value_b));
+            }
+        }
+    }
+
+    #[test]
+    fn successfully_keep_order_handles_across_removals() {
+        let mut bst = ArenaBST::from(vec![50, 30, 70, 20, 40, 60, 80]);
+        let thirty = bst.id_of(&30).unwrap();
+        let forty = bst.id_of(&40).unwrap();
+        let sixty = bst.id_of(&60).unwrap();
+        let low = bst.order_handle(thirty).unwrap();
+        let high = bst.order_handle(sixty).unwrap();
+
+        bst.remove(&50);
+        bst.remove(&40);
+        bst.extend((31..60).rev());
+
+        assert!(low < high);
+        assert!(low == bst.order_handle(thirty).unwrap());
+        assert_increasing_labels(&bst);
+        assert!(bst.order_handle(bst.id_of(&50).unwrap()).unwrap() < high);
+        assert!(bst.order_handle(forty).is_none());
+    }
+
+    #[test]
+    fn successfully_walk_tree_with_detached_cursor() {
+        let values = pseudo_random_values(300);
+        let mut bst = ArenaBST::from_iter(values.iter().copied());
+        let generation = bst.generation();
+        let mut cursor = bst.cursor();
+
+        let mut walked = Vec::new();
+        while let Some(value) = cursor.next(&bst) {
+            walked.push(value);
+        }
+        assert_eq!(walked, bst.asc_order_vec());
+        assert_eq!(cursor.next(&bst), None);
+
+        // Failed insertions and lookups are not modifications
+        bst.insert(values[0]);
+        assert!(bst.contains(&values[0]));
+        assert_eq!(bst.generation(), generation);
+        assert_eq!(bst.cursor().generation(), generation);
+
+        let id = bst.id_of(&values[0]).unwrap();
+        bst.remove_by_id(id);
+        assert!(cursor.try_next(&bst).is_err());
+        assert_eq!(bst.cursor_at(id), None);
+        assert_eq!(bst.clone().cursor(), bst.cursor());
+    }
+
+    #[test]
+    #[should_panic(expected = "used after the tree was modified")]
+    fn panic_on_cursor_used_across_mutation() {
+        let mut bst = ArenaBST::from_iter(vec![2, 1, 3]);
+        let mut cursor = bst.cursor();
+        cursor.next(&bst);
+
+        *bst.retrieve_as_mut(&3).unwrap() = 4;
+        cursor.next(&bst);
+    }
+
+    #[test]
+    fn successfully_compact_nodes_into_breadth_first_order() {
+        let values = pseudo_random_values(500);
+        let mut bst = ArenaBST::from_iter(values.iter().copied());
+        for value in values.iter().step_by(3) {
+            bst.remove(value);
+        }
+        let kept = bst.id_of(&values[1]).unwrap();
+        let handle = bst.order_handle(kept).unwrap();
+        let pre_order: Vec<u32> = bst.pre_order_iter().copied().collect();
+        let cursor = bst.cursor();
+
+        let moved = bst.compact_bfs();
+
+        assert!(moved > 0);
+        assert!(cursor.clone().try_next(&bst).is_err());
+        assert_eq!(
+            bst.pre_order_iter().copied().collect::<Vec<u32>>(),
+            pre_order
+        );
+        assert_eq!(bst.size(), pre_order.len());
+        assert_parent_links(&bst);
+        assert_increasing_labels(&bst);
+        assert_eq!(
+            bst.level_order_indices(),
+            (0..bst.size()).collect::<Vec<usize>>()
+        );
+
+        let id = bst.id_of(&values[1]).unwrap();
+        assert!(handle == bst.order_handle(id).unwrap());
+        if id != kept {
+            assert_eq!(bst.get_by_id(kept), None);
+        }
+
+        // Freed slots are reused right after the nodes
+        let generation = bst.generation();
+        assert_eq!(bst.compact_bfs(), 0);
+        assert_eq!(bst.generation(), generation);
+        let inserted = bst.insert_with_id(values[0]).unwrap();
+        assert_eq!(inserted.index, bst.size() - 1);
+        assert_eq!(bst.get_by_id(inserted), Some(&values[0]));
+    }
+    /// Secondary index mirroring the elements of a tree by id.
+    #[derive(Default, Clone)]
+    struct Index(HashMap<ElementId, u32>);
+
+    impl ArenaObserver<u32> for Index {
+        fn inserted(&mut self, id: ElementId, value: &u32) {
+            assert_eq!(self.0.insert(id, *value), None);
+        }
+
+        fn removed(&mut self, id: ElementId, value: &u32) {
+            assert_eq!(self.0.remove(&id), Some(*value));
+        }
+
+        fn relocated(&mut self, from: ElementId, to: ElementId, value: &u32) {
+            assert_eq!(self.0.remove(&from), Some(*value));
+            assert_eq!(self.0.insert(to, *value), None);
+        }
+    }
+
+    #[test]
+    fn successfully_keep_observer_index_in_sync() {
+        let values = pseudo_random_values(400);
+        let mut bst = ArenaBST::with_observer(Index::default());
+        bst.extend(values.iter().copied());
+        bst.extend(values.iter().take(50).copied());
+        for value in values.iter().step_by(4) {
+            bst.remove(value);
+        }
+        bst.remove_min();
+        bst.remove_max();
+        let id = bst.id_of(&values[1]).unwrap();
+        bst.remove_by_id(id);
+
+        let expected = |bst: &ArenaBST<u32, Index>| {
+            bst.asc_order_iter()
+                .map(|value| (bst.id_of(value).unwrap(), *value))
+                .collect::<HashMap<ElementId, u32>>()
+        };
+        assert_eq!(bst.observer().0, expected(&bst));
+
+        assert!(bst.compact_bfs() > 0);
+        assert_eq!(bst.observer().0, expected(&bst));
+
+        let clone = bst.clone();
+        bst.observer_mut().0.clear();
+        assert_eq!(clone.observer().0, expected(&clone));
+    }
+}
@@ -0,0 +1,493 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+
+/// Summary of a subtree which is maintained by an [AugmentedBST] for every node.
+///
+/// The summary of a node is derived from its own value and the summaries of its two subtrees,
+/// which makes it possible to answer whole-subtree questions (sizes, weights, hashes, minimum
+/// priorities, ...) without visiting the subtree.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{Augment, AugmentedBST};
+///
+/// /// Sums up all values of a subtree
+/// #[derive(Default)]
+/// struct Sum;
+///
+/// impl Augment<i32> for Sum {
+///     type Summary = i64;
+///
+///     fn summarize(&self, value: &i32, left: Option<&i64>, right: Option<&i64>) -> i64 {
+///         *value as i64 + left.unwrap_or(&0) + right.unwrap_or(&0)
+///     }
+/// }
+///
+/// let bst: AugmentedBST<i32, Sum> = AugmentedBST::from_iter(vec![3, 1, 2]);
+/// assert_eq!(bst.summary(), Some(&6));
+/// ```
+pub trait Augment<T> {
+    /// Data stored alongside every node.
+    type Summary;
+
+    /// Computes the summary of a node from its value and the summaries of its subtrees, `None`
+    /// standing for an empty subtree.
+    fn summarize(
+        &self,
+        value: &T,
+        left: Option<&Self::Summary>,
+        right: Option<&Self::Summary>,
+    ) -> Self::Summary;
+}
+
+pub(crate) type AugmentedLink<T, S> = Option<Box<AugmentedNode<T, S>>>;
+
+#[derive(Debug)]
+pub(crate) struct AugmentedNode<T, S> {
+    pub(crate) value: T,
+    pub(crate) summary: S,
+    pub(crate) left: AugmentedLink<T, S>,
+    pub(crate) right: AugmentedLink<T, S>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Side {
+    Left,
+    Right,
+}
+
+/// Nodes detached on the way down, each with the side the descent continued on.
+type Path<T, S> = Vec<(Box<AugmentedNode<T, S>>, Side)>;
+
+/// Binary Search Tree which keeps an [Augment] summary up to date for every subtree.
+///
+/// Every modification recomputes the summaries along the affected root-to-leaf path only, so
+/// insertions and removals stay O(h). Paths are detached and reattached iteratively, there is
+/// no recursion involved.
+///
+/// Elements can't be mutated in place, as that would silently invalidate the summaries of
+/// their ancestors, which is why this tree does not implement
+/// [BinarySearchTree](crate::BinarySearchTree).
+pub struct AugmentedBST<T: Ord, A: Augment<T>> {
+    pub(crate) root: AugmentedLink<T, A::Summary>,
+    size: usize,
+    pub(crate) augment: A,
+}
+
+impl<T: Ord, A: Augment<T>> AugmentedBST<T, A> {
+    /// Creates an empty `AugmentedBST<T, A>` maintaining the given augmentation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AugmentedBST, Merkle};
+    ///
+    /// let bst: AugmentedBST<i32, Merkle> = AugmentedBST::new(Merkle);
+    /// assert!(bst.is_empty());
+    /// ```
+    pub fn new(augment: A) -> AugmentedBST<T, A> {
+        AugmentedBST {
+            root: None,
+            size: 0,
+            augment,
+        }
+    }
+
+    /// Returns the total **number of nodes** within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::MerkleBST;
+    ///
+    /// let bst = MerkleBST::from_iter(vec![1, 2, 2]);
+    /// assert_eq!(bst.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the tree contains no nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::MerkleBST;
+    ///
+    /// let mut bst = MerkleBST::default();
+    /// assert!(bst.is_empty());
+    ///
+    /// bst.insert(1);
+    /// assert!(!bst.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns the summary of the whole tree or `None` if it is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::MerkleBST;
+    ///
+    /// let mut bst = MerkleBST::default();
+    /// assert_eq!(bst.summary(), None);
+    ///
+    /// bst.insert(1);
+    /// assert!(bst.summary().is_some());
+    /// ```
+    pub fn summary(&self) -> Option<&A::Summary> {
+        self.root.as_ref().map(|root| &root.summary)
+    }
+
+    /// Inserts the given value, returning `false` if it was already present.
+    ///
+    /// **Duplicate values are _not allowed_**.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::MerkleBST;
+    ///
+    /// let mut bst = MerkleBST::default();
+    ///
+    /// assert!(bst.insert(1));
+    /// assert!(!bst.insert(1));
+    /// ```
+    pub fn insert(&mut self, value: T) -> bool {
+        let mut path = Vec::new();
+        let mut current = self.root.take();
+
+        let (subtree, inserted) = loop {
+            match current {
+                None => {
+                    let summary = self.augment.summarize(&value, None, None);
+                    let node = AugmentedNode {
+                        value,
+                        summary,
+                        left: None,
+                        right: None,
+                    };
+                    break (Some(Box::new(node)), true);
+                }
+                Some(mut node) => match value.cmp(&node.value) {
+                    Ordering::Equal => break (Some(node), false),
+                    Ordering::Less => {
+                        current = node.left.take();
+                        path.push((node, Side::Left));
+                    }
+                    Ordering::Greater => {
+                        current = node.right.take();
+                        path.push((node, Side::Right));
+                    }
+                },
+            }
+        };
+
+        self.root = self.reattach(path, subtree);
+        if inserted {
+            self.size += 1;
+        }
+        inserted
+    }
+
+    /// Returns `true` if the tree contains an element with the given value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::MerkleBST;
+    ///
+    /// let bst = MerkleBST::from_iter(vec![1, 2]);
+    ///
+    /// assert!(bst.contains(&1));
+    /// assert!(!bst.contains(&3));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        self.retrieve(value).is_some()
+    }
+
+    /// Returns a reference to the element or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::MerkleBST;
+    ///
+    /// let bst = MerkleBST::from_iter(vec![1, 2]);
+    ///
+    /// assert_eq!(bst.retrieve(&2), Some(&2));
+    /// assert_eq!(bst.retrieve(&3), None);
+    /// ```
+    pub fn retrieve(&self, value: &T) -> Option<&T> {
+        let mut current = &self.root;
+        while let Some(node) = current {
+            match value.cmp(&node.value) {
+                Ordering::Equal => return Some(&node.value),
+                Ordering::Less => current = &node.left,
+                Ordering::Greater => current = &node.right,
+            }
+        }
+        None
+    }
+
+    /// Removes the given value, returning it or `None` if it does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::MerkleBST;
+    ///
+    /// let mut bst = MerkleBST::from_iter(vec![2, 1, 3]);
+    ///
+    /// assert_eq!(bst.remove(&2), Some(2));
+    /// assert_eq!(bst.remove(&2), None);
+    /// assert_eq!(bst.len(), 2);
+    /// ```
+    pub fn remove(&mut self, value: &T) -> Option<T> {
+        let mut path = Vec::new();
+        let mut current = self.root.take();
+
+        let (subtree, removed) = loop {
+            match current {
+                None => break (None, None),
+                Some(mut node) => match value.cmp(&node.value) {
+                    Ordering::Equal => {
+                        let node = *node;
+                        break (self.join(node.left, node.right), Some(node.value));
+                    }
+                    Ordering::Less => {
+                        current = node.left.take();
+                        path.push((node, Side::Left));
+                    }
+                    Ordering::Greater => {
+                        current = node.right.take();
+                        path.push((node, Side::Right));
+                    }
+                },
+            }
+        };
+
+        self.root = self.reattach(path, subtree);
+        if removed.is_some() {
+            self.size -= 1;
+        }
+        removed
+    }
+
+    /// Returns a reference to the minimum element of the tree or `None` if the tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::MerkleBST;
+    ///
+    /// let bst = MerkleBST::from_iter(vec![2, 1, 3]);
+    /// assert_eq!(bst.min(), Some(&1));
+    /// ```
+    pub fn min(&self) -> Option<&T> {
+        let mut current = self.root.as_ref()?;
+        while let Some(left) = &current.left {
+            current = left;
+        }
+        Some(&current.value)
+    }
+
+    /// Returns a reference to the maximum element of the tree or `None` if the tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::MerkleBST;
+    ///
+    /// let bst = MerkleBST::from_iter(vec![2, 1, 3]);
+    /// assert_eq!(bst.max(), Some(&3));
+    /// ```
+    pub fn max(&self) -> Option<&T> {
+        let mut current = self.root.as_ref()?;
+        while let Some(right) = &current.right {
+            current = right;
+        }
+        Some(&current.value)
+    }
+
+    /// Returns an iterator over the elements in **ascending order.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::MerkleBST;
+    ///
+    /// let bst = MerkleBST::from_iter(vec![2, 1, 3]);
+    ///
+    /// assert_eq!(bst.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        let mut stack = Vec::new();
+        let mut current = &self.root;
+        std::iter::from_fn(move || {
+            while let Some(node) = current {
+                stack.push(node);
+                current = &node.left;
+            }
+            let node = stack.pop()?;
+            current = &node.right;
+            Some(&node.value)
+        })
+    }
+
+    /// Recomputes the summary of a node whose subtrees have changed.
+    fn resummarize(&self, node: &mut AugmentedNode<T, A::Summary>) {
+        let left = node.left.as_ref().map(|left| &left.summary);
+        let right = node.right.as_ref().map(|right| &right.summary);
+        node.summary = self.augment.summarize(&node.value, left, right);
+    }
+
+    /// Puts detached path nodes back on top of `subtree`, bottom-up, refreshing their summaries.
+    fn reattach(
+        &self,
+        mut path: Path<T, A::Summary>,
+        mut subtree: AugmentedLink<T, A::Summary>,
+    ) -> AugmentedLink<T, A::Summary> {
+        while let Some((mut node, side)) = path.pop() {
+            match side {
+                Side::Left => node.left = subtree,
+                Side::Right => node.right = subtree,
+            }
+            self.resummarize(&mut node);
+            subtree = Some(node);
+        }
+        subtree
+    }
+
+    /// Joins the two subtrees of a removed node, its successor taking its place.
+    fn join(
+        &self,
+        left: AugmentedLink<T, A::Summary>,
+        right: AugmentedLink<T, A::Summary>,
+    ) -> AugmentedLink<T, A::Summary> {
+        let mut right = match (left.is_some(), right) {
+            (_, None) => return left,
+            (false, right) => return right,
+            (true, Some(right)) => right,
+        };
+
+        let mut path = Vec::new();
+        while let Some(next) = right.left.take() {
+            path.push((right, Side::Left));
+            right = next;
+        }
+        let mut successor = right;
+        let rest = successor.right.take();
+
+        successor.right = self.reattach(path, rest);
+        successor.left = left;
+        self.resummarize(&mut successor);
+        Some(successor)
+    }
+}
+
+impl<T: Ord, A: Augment<T> + Default> Default for AugmentedBST<T, A> {
+    /// Creates an empty `AugmentedBST<T, A>`
+    fn default() -> AugmentedBST<T, A> {
+        AugmentedBST::new(A::default())
+    }
+}
+
+impl<T: Ord + Debug, A: Augment<T>> Debug for AugmentedBST<T, A> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Ord, A: Augment<T>> Extend<T> for AugmentedBST<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter.into_iter() {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T: Ord, A: Augment<T> + Default> FromIterator<T> for AugmentedBST<T, A> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bst = AugmentedBST::default();
+        bst.extend(iter);
+        bst
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Augment, AugmentedBST};
+
+    /// Keeps the number of nodes of every subtree.
+    #[derive(Default)]
+    struct Count;
+
+    impl Augment<i32> for Count {
+        type Summary = usize;
+
+        fn summarize(&self, _: &i32, left: Option<&usize>, right: Option<&usize>) -> usize {
+            1 + left.unwrap_or(&0) + right.unwrap_or(&0)
+        }
+    }
+
+    /// Checks every summary against a freshly computed one.
+    fn assert_summaries(bst: &AugmentedBST<i32, Count>) {
+        let mut stack: Vec<_> = bst.root.iter().collect();
+        while let Some(node) = stack.pop() {
+            let left = node.left.as_ref().map_or(0, |left| left.summary);
+            let right = node.right.as_ref().map_or(0, |right| right.summary);
+            assert_eq!(node.summary, 1 + left + right);
+            stack.extend(node.left.iter());
+            stack.extend(node.right.iter());
+        }
+    }
+
+    #[test]
+    fn successfully_maintain_summaries_on_insert() {
+        let mut bst: AugmentedBST<i32, Count> = AugmentedBST::default();
+
+        for value in [8, 4, 12, 2, 6, 10, 14, 4] {
+            bst.insert(value);
+        }
+
+        assert_eq!(bst.len(), 7);
+        assert_eq!(bst.summary(), Some(&7));
+        assert_summaries(&bst);
+    }
+
+    #[test]
+    fn successfully_maintain_summaries_on_remove() {
+        let mut bst: AugmentedBST<i32, Count> =
+            AugmentedBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14, 5, 7, 9]);
+
+        assert_eq!(bst.remove(&8), Some(8));
+        assert_eq!(bst.remove(&4), Some(4));
+        assert_eq!(bst.remove(&14), Some(14));
+        assert_eq!(bst.remove(&3), None);
+
+        assert_eq!(bst.summary(), Some(&7));
+        assert_summaries(&bst);
+        assert_eq!(
+            bst.iter().collect::<Vec<_>>(),
+            vec![&2, &5, &6, &7, &9, &10, &12]
+        );
+    }
+
+    #[test]
+    fn successfully_empty_augmented_bst() {
+        let mut bst: AugmentedBST<i32, Count> = AugmentedBST::from_iter(vec![2, 1, 3]);
+
+        for value in [2, 1, 3] {
+            bst.remove(&value);
+        }
+
+        assert!(bst.is_empty());
+        assert_eq!(bst.summary(), None);
+        assert_eq!(bst.min(), None);
+        assert_eq!(bst.max(), None);
+    }
+}
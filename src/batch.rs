@@ -0,0 +1,53 @@
+use crate::BatchError;
+
+/// Single operation of a batch passed to [IterativeBST::apply()](crate::IterativeBST::apply()).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Op<T> {
+    /// Inserts the value, which must not be present yet.
+    Insert(T),
+    /// Removes the value, which must be present.
+    Remove(T),
+}
+
+impl<T> Op<T> {
+    fn value(&self) -> &T {
+        match self {
+            Op::Insert(value) | Op::Remove(value) => value,
+        }
+    }
+}
+
+/// Checks that every operation of the batch would succeed when applied in order, without
+/// touching the tree.
+///
+/// Operations are grouped by value so that each group only has to look up the tree once and
+/// can then be replayed on its own.
+pub(crate) fn validate<T: Ord, F>(ops: &[Op<T>], contains: F) -> Result<(), BatchError>
+where
+    F: Fn(&T) -> bool,
+{
+    let mut indices: Vec<usize> = (0..ops.len()).collect();
+    indices.sort_by(|x, y| ops[*x].value().cmp(ops[*y].value()).then(x.cmp(y)));
+
+    let mut first_error: Option<BatchError> = None;
+    for group in indices.chunk_by(|x, y| ops[*x].value() == ops[*y].value()) {
+        let mut present = contains(ops[group[0]].value());
+        for &index in group {
+            let error = match (&ops[index], present) {
+                (Op::Insert(_), true) => Some(BatchError::AlreadyPresent { index }),
+                (Op::Remove(_), false) => Some(BatchError::NotPresent { index }),
+                (Op::Insert(_), false) => None,
+                (Op::Remove(_), true) => None,
+            };
+            if let Some(error) = error {
+                if first_error.is_none_or(|first| index < first.index()) {
+                    first_error = Some(error);
+                }
+                break;
+            }
+            present = !present;
+        }
+    }
+
+    first_error.map_or(Ok(()), Err)
+}
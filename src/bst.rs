@@ -0,0 +1,2462 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{RangeInclusive, Sub};
+use std::time::Instant;
+use std::vec::IntoIter;
+
+use crate::batch::{self, Op};
+use crate::instrument;
+use crate::iter::{
+    CoalescedRanges, DrainSorted, DuplicatePolicy, Inclusivity, MergeIter, RangeMut, SortedIter,
+    Step, TraversalOrder,
+};
+#[cfg(feature = "traversals-extra")]
+use crate::iter::{DiagonalIter, IntoLevels};
+use crate::merkle::{hash_value, Fnv64};
+use crate::node::{HeapNode, Node};
+use crate::prefix::compare_bytes;
+use crate::rebalance::AutoRebalance;
+use crate::{BatchError, BinarySearchTree, DuplicateError, InsertReport, PrefixKey, TimedOut};
+
+/// Number of nodes a search visits between two checks of its deadline.
+const DEADLINE_INTERVAL: usize = 32;
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for super::Iterative {}
+    impl Sealed for super::Recursive {}
+}
+
+/// Decides which algorithms a [Bst] uses to walk its nodes.
+///
+/// The node layout is the same for every strategy, only the way operations traverse it differs.
+/// This trait is sealed, the available strategies are [Iterative] and [Recursive].
+pub trait Strategy: private::Sealed {}
+
+/// [Strategy] walking the tree with loops and explicit stacks, see [IterativeBST](crate::IterativeBST).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Iterative;
+
+/// [Strategy] walking the tree through recursive calls, see [RecursiveBST](crate::RecursiveBST).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Recursive;
+
+impl Strategy for Iterative {}
+impl Strategy for Recursive {}
+
+/// Binary Search Tree generic over the [Strategy] its operations use.
+///
+/// Everything which does not depend on how the nodes are walked, such as construction,
+/// comparison, cloning and formatting, is implemented once for every strategy. Most code
+/// should use one of the aliases [IterativeBST](crate::IterativeBST) or
+/// [RecursiveBST](crate::RecursiveBST).
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{BinarySearchTree, Bst, IterativeBST, Recursive};
+///
+/// let mut bst: Bst<i32, Recursive> = Bst::new();
+/// bst.insert(2);
+/// bst.insert(1);
+///
+/// // Iterative is the default strategy
+/// let other: Bst<i32> = IterativeBST::from_iter(vec![1, 2]);
+/// assert_eq!(bst.asc_order_vec(), other.asc_order_vec());
+/// ```
+#[derive(Debug)]
+pub struct Bst<T: Ord, S: Strategy = Iterative> {
+    pub(crate) root: HeapNode<T>,
+    pub(crate) size: usize,
+    pub(crate) rebalance: Option<AutoRebalance>,
+    pub(crate) max_depth: Option<usize>,
+    strategy: PhantomData<S>,
+}
+
+impl<T: Ord, S: Strategy> Bst<T, S> {
+    /// Creates an empty `Bst<T, S>`
+    ///
+    /// No nodes are allocated on the heap yet, which makes this a `const fn` usable to
+    /// initialize `static` items.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, RecursiveBST};
+    ///
+    /// // Empty trees are created
+    /// let mut iterative_bst: IterativeBST<i32> = IterativeBST::new();
+    /// assert!(iterative_bst.is_empty());
+    ///
+    /// let mut recursive_bst: RecursiveBST<i32> = RecursiveBST::new();
+    /// assert!(recursive_bst.is_empty());
+    /// ```
+    ///
+    /// ```rust
+    /// use std::sync::Mutex;
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// // Global registry, populated lazily
+    /// static REGISTRY: Mutex<IterativeBST<u32>> = Mutex::new(IterativeBST::new());
+    ///
+    /// REGISTRY.lock().unwrap().insert(7);
+    /// assert!(REGISTRY.lock().unwrap().contains(&7));
+    /// ```
+    pub const fn new() -> Bst<T, S> {
+        Bst {
+            root: None,
+            size: 0,
+            rebalance: None,
+            max_depth: None,
+            strategy: PhantomData,
+        }
+    }
+
+    /// Creates an empty `Bst<T, S>` which rebuilds itself into a perfectly balanced tree whenever
+    /// its height exceeds `factor · log2(size)`.
+    ///
+    /// Protects against the O(n) behavior of degenerate trees when the insertion order can't be
+    /// controlled, e.g. for already sorted input. The height is tracked from the depth of every
+    /// inserted node, so the check adds no extra traversal to insertions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `factor` is smaller than `1.0`, as no tree could satisfy the bound.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, RecursiveBST};
+    ///
+    /// let mut iterative_bst = IterativeBST::with_rebalance_factor(2.0);
+    /// let mut recursive_bst = RecursiveBST::with_rebalance_factor(2.0);
+    /// for value in 1..=1024 {
+    ///     iterative_bst.insert(value);
+    ///     recursive_bst.insert(value);
+    /// }
+    ///
+    /// assert!(iterative_bst.height().unwrap() <= 20);
+    /// assert!(recursive_bst.height().unwrap() <= 20);
+    /// ```
+    pub fn with_rebalance_factor(factor: f64) -> Bst<T, S> {
+        Bst {
+            rebalance: Some(AutoRebalance::new(factor)),
+            ..Bst::new()
+        }
+    }
+
+    /// Consumes the tree and returns an iterator over its elements grouped **level by level**,
+    /// starting with the root.
+    ///
+    /// Each level is moved out of the tree only when it is requested, so pipelines can process
+    /// breadth-first batches as they come without collecting the whole
+    /// [into_level_order_iter()](BinarySearchTree::into_level_order_iter()) first.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, RecursiveBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![4, 2, 6, 1, 3, 7]);
+    /// let mut levels = bst.into_levels();
+    ///
+    /// assert_eq!(levels.next(), Some(vec![4]));
+    /// assert_eq!(levels.next(), Some(vec![2, 6]));
+    /// assert_eq!(levels.next(), Some(vec![1, 3, 7]));
+    /// assert_eq!(levels.next(), None);
+    ///
+    /// assert_eq!(RecursiveBST::<i32>::new().into_levels().next(), None);
+    /// ```
+    #[cfg(feature = "traversals-extra")]
+    pub fn into_levels(self) -> impl Iterator<Item = Vec<T>> {
+        IntoLevels::new(self.root, self.size)
+    }
+
+    /// Returns references to the elements seen when looking at the tree **from above**, from
+    /// left to right.
+    ///
+    /// Every node is given a horizontal distance from the root, going one step left for a left
+    /// child and one step right for a right child. For each distance, the element closest to
+    /// the root is kept, elements on the same level being resolved from left to right.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //        40
+    ///  //       /  \
+    ///  //     20    60
+    ///  //       \
+    ///  //        30
+    ///  //          \
+    ///  //           35
+    /// let bst = IterativeBST::from_iter(vec![40, 20, 60, 30, 35]);
+    ///
+    /// // 30 is hidden below 40 and 35 below 60
+    /// assert_eq!(bst.top_view(), vec![&20, &40, &60]);
+    /// ```
+    #[cfg(feature = "traversals-extra")]
+    pub fn top_view(&self) -> Vec<&T> {
+        Node::horizontal_view(&self.root, false)
+    }
+
+    /// Returns references to the elements seen when looking at the tree **from below**, from
+    /// left to right.
+    ///
+    /// Uses the same horizontal distances as [Bst::top_view()], but keeps the element furthest
+    /// from the root for each distance. Of two elements on the same level and at the same
+    /// distance, the rightmost one is kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from_iter(vec![40, 20, 60, 30, 35]);
+    ///
+    /// assert_eq!(bst.bottom_view(), vec![&20, &30, &35]);
+    /// assert!(RecursiveBST::<i32>::new().bottom_view().is_empty());
+    /// ```
+    #[cfg(feature = "traversals-extra")]
+    pub fn bottom_view(&self) -> Vec<&T> {
+        Node::horizontal_view(&self.root, true)
+    }
+
+    /// Returns an iterator over the elements of the tree in the order of a **diagonal
+    /// traversal.**
+    ///
+    /// The first diagonal starts at the root and follows right children. The left child of
+    /// every element of a diagonal starts a diagonal of the next group, which is walked in the
+    /// same way once the current group is done.
+    ///
+    /// The iterator is lazy, it only holds the starts of the diagonals which have not been
+    /// walked yet.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         8
+    ///  //       /  \
+    ///  //      3    10
+    ///  //     / \     \
+    ///  //    1   6     14
+    ///  //       /     /
+    ///  //      4     13
+    /// let bst = IterativeBST::from_iter(vec![8, 3, 10, 1, 6, 14, 4, 13]);
+    ///
+    /// // The diagonals are [8, 10, 14], then [3, 6] and [13], then [1] and [4]
+    /// assert_eq!(
+    ///     bst.diagonal_order_iter().collect::<Vec<&i32>>(),
+    ///     vec![&8, &10, &14, &3, &6, &13, &1, &4]
+    /// );
+    /// ```
+    #[cfg(feature = "traversals-extra")]
+    pub fn diagonal_order_iter(&self) -> impl Iterator<Item = &T> {
+        DiagonalIter::new(&self.root)
+    }
+
+    /// Splits the tree at `value` into two ranges which can be mutated **at the same time**:
+    /// the elements less than `value` and the elements greater than or equal to it, both in
+    /// ascending order.
+    ///
+    /// Only the search path for `value` is walked to set up the ranges, which lets algorithms
+    /// move data between both halves in a single pass. Changes must keep every element
+    /// comparing the same way to all others, see [RangeMut].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::from_iter(vec![40, 20, 60, 10, 30, 50, 70]);
+    ///
+    /// // Spread both halves further apart from each other
+    /// let (lower, upper) = bst.split_at_mut(&45);
+    /// lower.for_each(|value| *value -= 5);
+    /// upper.for_each(|value| *value += 5);
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&5, &15, &25, &35, &55, &65, &75]);
+    /// assert!(bst.contains(&35));
+    /// ```
+    pub fn split_at_mut(&mut self, value: &T) -> (RangeMut<'_, T>, RangeMut<'_, T>) {
+        RangeMut::split(&mut self.root, value)
+    }
+
+    /// Calls `f` with a mutable reference to every element in ascending order, keeping only the
+    /// elements for which it returns `true`, and returns how many elements were removed.
+    ///
+    /// Mirrors [Vec::retain_mut()], so a single sweep can refresh the payload of the elements
+    /// which stay and expire the others, e.g. to maintain a cache. Like with
+    /// [retrieve_as_mut()](BinarySearchTree::retrieve_as_mut()), `f` must not change how the
+    /// elements compare to each other. The tree is left as it is when every element is kept,
+    /// otherwise it is rebuilt balanced out of the remaining elements in `O(n)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// // Cache entries ordered by key, along with the number of sweeps they have been idle for
+    /// #[derive(Debug)]
+    /// struct Entry(&'static str, u32);
+    ///
+    /// impl PartialEq for Entry {
+    ///     fn eq(&self, other: &Self) -> bool {
+    ///         self.0 == other.0
+    ///     }
+    /// }
+    /// impl Eq for Entry {}
+    /// impl PartialOrd for Entry {
+    ///     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    ///         Some(self.cmp(other))
+    ///     }
+    /// }
+    /// impl Ord for Entry {
+    ///     fn cmp(&self, other: &Self) -> Ordering {
+    ///         self.0.cmp(other.0)
+    ///     }
+    /// }
+    ///
+    /// let mut cache = IterativeBST::from_iter(vec![Entry("b", 0), Entry("a", 2), Entry("c", 1)]);
+    ///
+    /// let expired = cache.retain_mut(|entry| {
+    ///     entry.1 += 1;
+    ///     entry.1 < 3
+    /// });
+    ///
+    /// assert_eq!(expired, 1);
+    /// assert_eq!(
+    ///     cache.asc_order_iter().map(|entry| (entry.0, entry.1)).collect::<Vec<_>>(),
+    ///     vec![("b", 1), ("c", 2)]
+    /// );
+    /// ```
+    pub fn retain_mut<F: FnMut(&mut T) -> bool>(&mut self, mut f: F) -> usize {
+        let keep: Vec<bool> = RangeMut::ascending(&mut self.root).map(&mut f).collect();
+        let removed = keep.iter().filter(|kept| !**kept).count();
+        if removed == 0 {
+            instrument::remove_many(0, self.size);
+            return 0;
+        }
+
+        let mut keep = keep.into_iter();
+        let mut elements = Node::iterative_consume_in_order_vec(self.root.take());
+        elements.retain(|_| keep.next().unwrap());
+        self.size = elements.len();
+        self.root = Node::build_balanced(elements);
+        if let Some(rebalance) = &mut self.rebalance {
+            rebalance.rebuilt(self.size);
+        }
+        instrument::remove_many(removed, self.size);
+        removed
+    }
+
+    /// Returns an iterator over the elements between `start` and `end`, walking **from `start`
+    /// towards `end`**, with `inclusivity` deciding which of the bounds themselves belong to the
+    /// range.
+    ///
+    /// The elements come in ascending order if `start` is not greater than `end` and in
+    /// descending order otherwise, so the same call covers both directions without building
+    /// [RangeBounds](std::ops::RangeBounds) out of bound tuples. Only the search path for
+    /// `start` is walked to find the first element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, Inclusivity, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![50, 20, 80, 10, 30, 70, 90]);
+    ///
+    /// let inclusive: Vec<_> = bst.between(&20, &70, Inclusivity::Inclusive).collect();
+    /// assert_eq!(inclusive, vec![&20, &30, &50, &70]);
+    ///
+    /// let half_open: Vec<_> = bst.between(&20, &70, Inclusivity::IncludeStart).collect();
+    /// assert_eq!(half_open, vec![&20, &30, &50]);
+    ///
+    /// // Reversed bounds walk the range in descending order
+    /// let reversed: Vec<_> = bst.between(&70, &20, Inclusivity::IncludeEnd).collect();
+    /// assert_eq!(reversed, vec![&50, &30, &20]);
+    /// ```
+    pub fn between<'a>(
+        &'a self,
+        start: &'a T,
+        end: &'a T,
+        inclusivity: Inclusivity,
+    ) -> impl Iterator<Item = &'a T> {
+        let (include_start, include_end) = inclusivity.includes();
+        let descending = start > end;
+        // Compares elements in the order in which they are walked
+        let walked = move |first: &T, second: &T| {
+            if descending {
+                second.cmp(first)
+            } else {
+                first.cmp(second)
+            }
+        };
+
+        SortedIter::seek(&self.root, descending, move |current| {
+            match walked(current, start) {
+                Ordering::Less => true,
+                Ordering::Equal => !include_start,
+                Ordering::Greater => false,
+            }
+        })
+        .take_while(move |current| match walked(current, end) {
+            Ordering::Less => true,
+            Ordering::Equal => include_end,
+            Ordering::Greater => false,
+        })
+    }
+
+    /// Returns a reference to the element stored at the **root** of the tree, or `None` if the
+    /// tree is empty.
+    ///
+    /// The root is the pivot splitting the tree into its smaller and larger elements, which
+    /// partition-based algorithms can use directly, and lets tests assert the expected shape
+    /// without going through a traversal.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, RecursiveBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![4, 2, 6]);
+    /// assert_eq!(bst.root(), Some(&4));
+    ///
+    /// // Balanced trees are rooted at their median
+    /// let chain = RecursiveBST::from_iter(vec![1, 2, 3, 4, 5]);
+    /// assert_eq!(chain.root(), Some(&1));
+    /// assert_eq!(chain.merge_balanced(RecursiveBST::new()).root(), Some(&3));
+    /// assert_eq!(RecursiveBST::<i32>::new().root(), None);
+    /// ```
+    pub fn root(&self) -> Option<&T> {
+        self.root.as_ref().map(|node| &node.value)
+    }
+
+    /// Returns `true` if the tree contains the given value, or a [TimedOut] error if the search
+    /// is still running at `deadline`.
+    ///
+    /// See [Bst::retrieve_deadline()] for how the deadline is checked.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![3, 1, 2]);
+    /// let deadline = Instant::now() + Duration::from_millis(100);
+    ///
+    /// assert_eq!(bst.contains_deadline(&2, deadline), Ok(true));
+    /// assert_eq!(bst.contains_deadline(&4, deadline), Ok(false));
+    /// ```
+    pub fn contains_deadline(&self, value: &T, deadline: Instant) -> Result<bool, TimedOut> {
+        self.retrieve_deadline(value, deadline)
+            .map(|found| found.is_some())
+    }
+
+    /// Returns a reference to the element equal to the given value or `None` if the tree does
+    /// not contain it, or a [TimedOut] error if the search is still running at `deadline`.
+    ///
+    /// Meant for soft real-time loops in which a degenerate tree must not stall a frame: the
+    /// search is given up instead of walking a long chain of nodes to the end. The clock is
+    /// read before the first node and then every 32 nodes, so a search passes its deadline by
+    /// at most that many comparisons. Searches are
+    /// iterative whatever the [Strategy] of the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from_iter(vec![3, 1, 2]);
+    /// let deadline = Instant::now() + Duration::from_millis(100);
+    /// assert_eq!(bst.retrieve_deadline(&1, deadline), Ok(Some(&1)));
+    ///
+    /// // A deadline which has already passed times out straight away
+    /// let error = bst.retrieve_deadline(&1, Instant::now()).unwrap_err();
+    /// assert_eq!(error.visited(), 0);
+    /// ```
+    pub fn retrieve_deadline(&self, value: &T, deadline: Instant) -> Result<Option<&T>, TimedOut> {
+        let mut root = &self.root;
+        let mut visited = 0;
+        while let Some(current) = root {
+            if visited % DEADLINE_INTERVAL == 0 && Instant::now() >= deadline {
+                return Err(TimedOut::new(visited));
+            }
+            visited += 1;
+
+            match value.cmp(&current.value) {
+                Ordering::Equal => return Ok(Some(&current.value)),
+                Ordering::Less => root = &current.left,
+                Ordering::Greater => root = &current.right,
+            }
+        }
+
+        Ok(None)
+    }
+
+    /// Returns an iterator over the elements of the tree in **ascending order**, paired with
+    /// their sorted index (rank).
+    ///
+    /// The rank is computed lazily during the walk, so only the elements which are actually
+    /// consumed are ever visited.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::new();
+    /// bst.insert(20);
+    /// bst.insert(10);
+    /// bst.insert(30);
+    ///
+    /// let mut enumerate_order_iter = bst.enumerate_order_iter();
+    ///
+    /// assert_eq!(enumerate_order_iter.next(), Some((0, &10)));
+    /// assert_eq!(enumerate_order_iter.next(), Some((1, &20)));
+    /// assert_eq!(enumerate_order_iter.next(), Some((2, &30)));
+    /// assert_eq!(enumerate_order_iter.next(), None);
+    /// ```
+    pub fn enumerate_order_iter(&self) -> impl Iterator<Item = (usize, &T)> {
+        SortedIter::ascending(&self.root).enumerate()
+    }
+
+    /// Returns an iterator over the `k` smallest elements of the tree in **ascending order.**
+    ///
+    /// Only `O(k + h)` nodes are visited, where `h` is the height of the tree, as opposed to
+    /// collecting the entire [asc_order_vec](BinarySearchTree::asc_order_vec()) and truncating it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::new();
+    /// bst.insert(3);
+    /// bst.insert(1);
+    /// bst.insert(5);
+    /// bst.insert(2);
+    ///
+    /// assert_eq!(bst.k_smallest(2).collect::<Vec<&i32>>(), vec![&1, &2]);
+    /// assert_eq!(bst.k_smallest(10).collect::<Vec<&i32>>(), vec![&1, &2, &3, &5]);
+    /// ```
+    pub fn k_smallest(&self, k: usize) -> impl Iterator<Item = &T> {
+        SortedIter::ascending(&self.root).take(k)
+    }
+
+    /// Returns an iterator over the `k` largest elements of the tree in **descending order.**
+    ///
+    /// Only `O(k + h)` nodes are visited, where `h` is the height of the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::new();
+    /// bst.insert(3);
+    /// bst.insert(1);
+    /// bst.insert(5);
+    /// bst.insert(2);
+    ///
+    /// assert_eq!(bst.k_largest(2).collect::<Vec<&i32>>(), vec![&5, &3]);
+    /// assert_eq!(bst.k_largest(0).next(), None);
+    /// ```
+    pub fn k_largest(&self, k: usize) -> impl Iterator<Item = &T> {
+        SortedIter::descending(&self.root).take(k)
+    }
+
+    /// Returns an iterator over every pair of **adjacent elements** in ascending order.
+    ///
+    /// A tree of `n` elements yields `n - 1` pairs, each element but the minimum appearing as
+    /// the second half of a pair with its predecessor.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![3, 1, 7]);
+    ///
+    /// let mut pairs = bst.pairs();
+    /// assert_eq!(pairs.next(), Some((&1, &3)));
+    /// assert_eq!(pairs.next(), Some((&3, &7)));
+    /// assert_eq!(pairs.next(), None);
+    /// ```
+    pub fn pairs(&self) -> impl Iterator<Item = (&T, &T)> {
+        SortedIter::ascending(&self.root).zip(SortedIter::ascending(&self.root).skip(1))
+    }
+
+    /// Removes and returns the `n` smallest elements of the tree in **ascending order.**
+    ///
+    /// Fewer than `n` elements are returned if the tree does not contain enough of them. The
+    /// elements are stripped in one pass, rather than descending from the root for every
+    /// [remove_min](BinarySearchTree::remove_min()).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::new();
+    /// bst.insert(3);
+    /// bst.insert(1);
+    /// bst.insert(5);
+    /// bst.insert(2);
+    ///
+    /// assert_eq!(bst.remove_min_n(2), vec![1, 2]);
+    /// assert_eq!(bst.asc_order_vec(), vec![&3, &5]);
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    pub fn remove_min_n(&mut self, n: usize) -> Vec<T> {
+        let mut remaining = n;
+        let removed = Node::iterative_remove_min_while(&mut self.root, |_| {
+            if remaining == 0 {
+                return false;
+            }
+            remaining -= 1;
+            true
+        });
+        self.size -= removed.len();
+        instrument::remove_many(removed.len(), self.size);
+        removed
+    }
+
+    /// Removes and returns the `n` largest elements of the tree in **descending order.**
+    ///
+    /// Fewer than `n` elements are returned if the tree does not contain enough of them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::new();
+    /// bst.insert(3);
+    /// bst.insert(1);
+    /// bst.insert(5);
+    /// bst.insert(2);
+    ///
+    /// assert_eq!(bst.remove_max_n(3), vec![5, 3, 2]);
+    /// assert_eq!(bst.asc_order_vec(), vec![&1]);
+    /// assert_eq!(bst.size(), 1);
+    /// ```
+    pub fn remove_max_n(&mut self, n: usize) -> Vec<T> {
+        let mut remaining = n;
+        let removed = Node::iterative_remove_max_while(&mut self.root, |_| {
+            if remaining == 0 {
+                return false;
+            }
+            remaining -= 1;
+            true
+        });
+        self.size -= removed.len();
+        instrument::remove_many(removed.len(), self.size);
+        removed
+    }
+
+    /// Removes and returns elements in **ascending order** for as long as `f` returns `true`.
+    ///
+    /// Removal stops at the first element for which `f` returns `false`, which is kept in the
+    /// tree along with every element greater than it. This makes it straightforward to expire
+    /// a prefix of the sorted order, e.g. every timestamp below a cut-off.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::new();
+    /// bst.insert(10);
+    /// bst.insert(20);
+    /// bst.insert(30);
+    /// bst.insert(40);
+    ///
+    /// assert_eq!(bst.remove_while(|value| *value < 25), vec![10, 20]);
+    /// assert_eq!(bst.asc_order_vec(), vec![&30, &40]);
+    /// ```
+    pub fn remove_while<F: FnMut(&T) -> bool>(&mut self, f: F) -> Vec<T> {
+        let removed = Node::iterative_remove_min_while(&mut self.root, f);
+        self.size -= removed.len();
+        instrument::remove_many(removed.len(), self.size);
+        removed
+    }
+
+    /// Removes and returns the minimum element only if `f` returns `true` for it, or returns
+    /// `None` if it doesn't or the tree is empty.
+    ///
+    /// The minimum is tested and unlinked in a single descent, where peeking with
+    /// [min](BinarySearchTree::min()) before calling [remove_min](BinarySearchTree::remove_min())
+    /// walks the tree twice. Looping over it pops every expired entry of a timer queue.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// // Deadlines of pending timers
+    /// let mut timers = IterativeBST::from_iter(vec![30, 10, 50, 20]);
+    /// let now = 25;
+    ///
+    /// let mut expired = Vec::new();
+    /// while let Some(deadline) = timers.remove_min_if(|deadline| *deadline <= now) {
+    ///     expired.push(deadline);
+    /// }
+    ///
+    /// assert_eq!(expired, vec![10, 20]);
+    /// assert_eq!(timers.asc_order_vec(), vec![&30, &50]);
+    /// ```
+    pub fn remove_min_if<F: FnOnce(&T) -> bool>(&mut self, f: F) -> Option<T> {
+        let removed_min = Node::iterative_remove_min_if(&mut self.root, f);
+        if removed_min.is_some() {
+            self.size -= 1;
+        }
+        instrument::remove_many(removed_min.is_some() as usize, self.size);
+        removed_min
+    }
+
+    /// Removes and returns the maximum element only if `f` returns `true` for it, or returns
+    /// `None` if it doesn't or the tree is empty.
+    ///
+    /// Mirror image of [Bst::remove_min_if()], in a single descent as well.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::from_iter(vec![2, 9, 4]);
+    ///
+    /// assert_eq!(bst.remove_max_if(|max| max % 2 == 0), None);
+    /// assert_eq!(bst.remove_max_if(|max| *max > 5), Some(9));
+    /// assert_eq!(bst.remove_max_if(|max| max % 2 == 0), Some(4));
+    /// assert_eq!(bst.size(), 1);
+    /// ```
+    pub fn remove_max_if<F: FnOnce(&T) -> bool>(&mut self, f: F) -> Option<T> {
+        let removed_max = Node::iterative_remove_max_if(&mut self.root, f);
+        if removed_max.is_some() {
+            self.size -= 1;
+        }
+        instrument::remove_many(removed_max.is_some() as usize, self.size);
+        removed_max
+    }
+
+    /// Keeps only the elements **greater than** `value`, returning how many were dropped.
+    ///
+    /// Rather than removing the smaller elements one by one, every node along the search path
+    /// for `value` which is not greater than it is unlinked along with its whole left subtree.
+    /// Re-linking takes `O(h)`, on top of dropping the detached elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::from_iter(vec![50, 30, 70, 20, 40, 60, 80]);
+    ///
+    /// assert_eq!(bst.retain_greater(&40), 3);
+    /// assert_eq!(bst.asc_order_vec(), vec![&50, &60, &70, &80]);
+    /// assert_eq!(bst.size(), 4);
+    /// ```
+    pub fn retain_greater(&mut self, value: &T) -> usize {
+        let removed = Node::iterative_retain_greater(&mut self.root, value);
+        self.size -= removed;
+        instrument::remove_many(removed, self.size);
+        removed
+    }
+
+    /// Keeps only the elements **less than** `value`, returning how many were dropped.
+    ///
+    /// Mirror image of [Bst::retain_greater()], unlinking right subtrees in `O(h)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::from_iter(vec![50, 30, 70, 20, 40, 60, 80]);
+    ///
+    /// assert_eq!(bst.retain_less(&55), 3);
+    /// assert_eq!(bst.asc_order_vec(), vec![&20, &30, &40, &50]);
+    /// ```
+    pub fn retain_less(&mut self, value: &T) -> usize {
+        let removed = Node::iterative_retain_less(&mut self.root, value);
+        self.size -= removed;
+        instrument::remove_many(removed, self.size);
+        removed
+    }
+
+    /// Rebuilds the tree into a **perfectly balanced** one, consuming it.
+    ///
+    /// The elements are collected in order and bulk-built in `O(n)`, which is a one-call remedy
+    /// for trees which degenerated because of the order of their insertions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(1..=7);
+    /// assert_eq!(bst.height(), Some(6));
+    ///
+    /// let bst = bst.into_balanced();
+    /// assert_eq!(bst.height(), Some(2));
+    /// assert_eq!(bst.pre_order_vec(), vec![&4, &2, &1, &3, &6, &5, &7]);
+    /// ```
+    pub fn into_balanced(self) -> Bst<T, S> {
+        let elements = Node::iterative_consume_in_order_vec(self.root);
+
+        Bst::from_sorted(elements, self.rebalance, self.max_depth)
+    }
+
+    /// Consumes the tree into a [Vec] of its elements in **ascending order**.
+    ///
+    /// The elements are moved out in a single in-order walk, which takes `O(n)` and never
+    /// compares elements. Unlike collecting
+    /// [into_asc_order_iter()](BinarySearchTree::into_asc_order_iter()), this hands out the
+    /// vector directly for downstream stages which expect one, e.g. to
+    /// [binary search](slice::binary_search()) it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![3, 1, 2]);
+    /// let sorted = bst.into_sorted_vec();
+    ///
+    /// assert_eq!(sorted, vec![1, 2, 3]);
+    /// assert_eq!(sorted.binary_search(&2), Ok(1));
+    /// ```
+    pub fn into_sorted_vec(self) -> Vec<T> {
+        Node::iterative_consume_in_order_vec(self.root)
+    }
+
+    /// Consumes the tree into a max-heap [BinaryHeap] of its elements.
+    ///
+    /// The heap is built out of [Bst::into_sorted_vec()] in `O(n)`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![3, 1, 2]);
+    /// let mut heap = bst.into_binary_heap();
+    ///
+    /// assert_eq!(heap.pop(), Some(3));
+    /// assert_eq!(heap.into_sorted_vec(), vec![1, 2]);
+    /// ```
+    pub fn into_binary_heap(self) -> BinaryHeap<T> {
+        BinaryHeap::from(self.into_sorted_vec())
+    }
+
+    /// Merges the elements of both trees into a new, **balanced** tree, consuming both.
+    ///
+    /// The two sorted sequences are merged and the result is bulk-built in `O(n + m)`, instead of
+    /// inserting one tree's elements into the other at `O(m log(n + m))` while degrading its
+    /// shape. Elements present in both trees are only kept once.
+    ///
+    /// The merged tree keeps the rebalance setting and the
+    /// [max depth](crate::RecursiveBST::with_max_depth()) of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` has a max depth which the balanced tree of all elements would exceed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut first = IterativeBST::new();
+    /// first.insert(1);
+    /// first.insert(2);
+    /// first.insert(3);
+    ///
+    /// let mut second = IterativeBST::new();
+    /// second.insert(3);
+    /// second.insert(4);
+    /// second.insert(5);
+    ///
+    /// let merged = first.merge_balanced(second);
+    ///
+    /// assert_eq!(merged.size(), 5);
+    /// assert_eq!(merged.height(), Some(2));
+    /// assert_eq!(merged.asc_order_vec(), vec![&1, &2, &3, &4, &5]);
+    /// ```
+    pub fn merge_balanced(self, other: Bst<T, S>) -> Bst<T, S> {
+        let elements = Node::merge_sorted(
+            Node::iterative_consume_in_order_vec(self.root),
+            Node::iterative_consume_in_order_vec(other.root),
+        );
+
+        Bst::from_sorted(elements, self.rebalance, self.max_depth)
+    }
+
+    /// Splits the tree into the elements for which `pred` returns `true` and those for which it
+    /// returns `false`, consuming it.
+    ///
+    /// Both sorted sequences are collected in a single in-order pass and bulk-built into
+    /// **balanced** trees, which keep the rebalance setting and the
+    /// [max depth](crate::RecursiveBST::with_max_depth()) of the original tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(1..=7);
+    ///
+    /// let (even, odd) = bst.partition(|value| value % 2 == 0);
+    ///
+    /// assert_eq!(even.asc_order_vec(), vec![&2, &4, &6]);
+    /// assert_eq!(odd.asc_order_vec(), vec![&1, &3, &5, &7]);
+    /// assert_eq!(odd.height(), Some(2));
+    /// ```
+    pub fn partition<F>(self, pred: F) -> (Bst<T, S>, Bst<T, S>)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let elements = Node::iterative_consume_in_order_vec(self.root);
+        let (matching, rest): (Vec<T>, Vec<T>) = elements.into_iter().partition(pred);
+
+        (
+            Bst::from_sorted(matching, self.rebalance, self.max_depth),
+            Bst::from_sorted(rest, self.rebalance, self.max_depth),
+        )
+    }
+
+    /// Returns an iterator over the elements of both trees in **ascending order.**
+    ///
+    /// The union is produced lazily without building a third tree, `policy` decides what is
+    /// yielded when both trees contain an equal element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DuplicatePolicy, IterativeBST};
+    ///
+    /// let mut first = IterativeBST::new();
+    /// first.insert(1);
+    /// first.insert(3);
+    ///
+    /// let mut second = IterativeBST::new();
+    /// second.insert(2);
+    /// second.insert(3);
+    ///
+    /// let union: Vec<&i32> = first.merge_iter(&second, DuplicatePolicy::KeepFirst).collect();
+    /// assert_eq!(union, vec![&1, &2, &3]);
+    ///
+    /// let all: Vec<&i32> = first.merge_iter(&second, DuplicatePolicy::KeepBoth).collect();
+    /// assert_eq!(all, vec![&1, &2, &3, &3]);
+    /// ```
+    pub fn merge_iter<'a>(
+        &'a self,
+        other: &'a Bst<T, S>,
+        policy: DuplicatePolicy,
+    ) -> impl Iterator<Item = &'a T> {
+        MergeIter::new(&self.root, &other.root, policy)
+    }
+
+    /// Returns `true` if the binary search tree contains an element equal to every given value.
+    ///
+    /// The probes are sorted first and then looked up in a single coordinated traversal, so big
+    /// batches don't pay for a separate root-to-leaf descent per value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![4, 2, 6, 1, 3]);
+    ///
+    /// assert!(bst.contains_all(&[3, 1, 6]));
+    /// assert!(!bst.contains_all(&[3, 5]));
+    /// ```
+    pub fn contains_all<'a, I: IntoIterator<Item = &'a T>>(&self, values: I) -> bool
+    where
+        T: 'a,
+    {
+        Node::iterative_contains_all(&self.root, &Node::sorted_probes(values))
+    }
+
+    /// Returns a reference to the element equal to each probe, or `None` for probes which are
+    /// not present, in the order of the probes.
+    ///
+    /// Like [Bst::contains_all()], the probes are sorted and answered in a single coordinated
+    /// traversal, which touches every node at most once and walks the tree in order instead of
+    /// jumping around it for every [retrieve()](BinarySearchTree::retrieve()).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![4, 2, 6, 1, 3]);
+    ///
+    /// assert_eq!(bst.get_many(&[6, 5, 1, 6]), vec![Some(&6), None, Some(&1), Some(&6)]);
+    /// ```
+    pub fn get_many<'a>(&'a self, probes: &[T]) -> Vec<Option<&'a T>> {
+        Node::get_many(probes, |sorted, found| {
+            Node::iterative_get_many(&self.root, sorted, found)
+        })
+    }
+
+    /// Walks down the tree guided by `f` and returns the element for which it returns
+    /// [Ordering::Equal], or `None` if the walk runs off the tree.
+    ///
+    /// `f` returns how the element it is given compares to the one being searched for, like in
+    /// [slice::binary_search_by()]. This allows searching by partial keys or by any other
+    /// ordering consistent with the one of the tree, without building an equal probe value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![(2, "b"), (1, "a"), (3, "c")]);
+    ///
+    /// // Search by the first half of the tuple only
+    /// assert_eq!(bst.descend_with(|(id, _)| id.cmp(&3)), Some(&(3, "c")));
+    /// assert_eq!(bst.descend_with(|(id, _)| id.cmp(&4)), None);
+    /// ```
+    pub fn descend_with<F>(&self, f: F) -> Option<&T>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        Node::iterative_retrieve_by(&self.root, f)
+    }
+
+    /// Removes every element equal to one of the given values, returning how many were removed.
+    ///
+    /// Like [Bst::contains_all()], the probes are sorted and handled in a single coordinated
+    /// traversal. Values which are not present are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::from_iter(vec![4, 2, 6, 1, 3]);
+    ///
+    /// assert_eq!(bst.remove_all(&[6, 2, 7, 2]), 2);
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &3, &4]);
+    /// assert_eq!(bst.size(), 3);
+    /// ```
+    pub fn remove_all<'a, I: IntoIterator<Item = &'a T>>(&mut self, values: I) -> usize
+    where
+        T: 'a,
+    {
+        let removed = Node::iterative_remove_all(&mut self.root, &Node::sorted_probes(values));
+        self.size -= removed;
+        instrument::remove_many(removed, self.size);
+        removed
+    }
+
+    /// Returns an iterator which removes and yields the elements in **ascending order**, one at
+    /// a time.
+    ///
+    /// Every step is an amortized O(1) delete-min. The iterator can be dropped early, in which
+    /// case every element which was not yielded stays in the binary search tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::from_iter(vec![3, 1, 4, 2, 5]);
+    ///
+    /// let smallest: Vec<i32> = bst.drain_sorted().take(2).collect();
+    ///
+    /// assert_eq!(smallest, vec![1, 2]);
+    /// assert_eq!(bst.asc_order_vec(), vec![&3, &4, &5]);
+    /// assert_eq!(bst.size(), 3);
+    /// ```
+    pub fn drain_sorted(&mut self) -> impl Iterator<Item = T> + '_ {
+        DrainSorted::new(&mut self.root, &mut self.size)
+    }
+
+    /// Returns the **number of nodes at each depth**, where index `0` is the root.
+    ///
+    /// Together with [Bst::average_depth()] this quantifies how degenerate the tree has
+    /// become: a balanced tree doubles its count per level, a degenerate one has a single node
+    /// per level.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![4, 2, 6, 1, 3, 7]);
+    ///
+    /// assert_eq!(bst.depth_histogram(), vec![1, 2, 3]);
+    /// assert!(IterativeBST::<i32>::new().depth_histogram().is_empty());
+    /// ```
+    pub fn depth_histogram(&self) -> Vec<usize> {
+        Node::iterative_depth_histogram(&self.root)
+    }
+
+    /// Returns the average depth of all nodes or `None` if the tree is empty.
+    ///
+    /// This is the expected number of steps below the root to reach a random element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![2, 1, 3, 4]);
+    ///
+    /// assert_eq!(bst.average_depth(), Some(1.0));
+    /// assert_eq!(IterativeBST::<i32>::new().average_depth(), None);
+    /// ```
+    pub fn average_depth(&self) -> Option<f64> {
+        if self.size == 0 {
+            return None;
+        }
+
+        let total_depth: usize = self
+            .depth_histogram()
+            .into_iter()
+            .enumerate()
+            .map(|(depth, count)| depth * count)
+            .sum();
+
+        Some(total_depth as f64 / self.size as f64)
+    }
+
+    /// Returns an iterator over every element paired with its **balance factor**, the height of
+    /// its left subtree minus the height of its right subtree.
+    ///
+    /// All factors are computed in a single post-order pass and yielded in post-order. Positive
+    /// factors point to left-heavy regions, negative ones to right-heavy regions.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![3, 1, 2, 4]);
+    ///
+    /// let mut balance_factors = bst.balance_factors();
+    /// assert_eq!(balance_factors.next(), Some((&2, 0)));
+    /// assert_eq!(balance_factors.next(), Some((&1, -1)));
+    /// assert_eq!(balance_factors.next(), Some((&4, 0)));
+    /// assert_eq!(balance_factors.next(), Some((&3, 1)));
+    /// assert_eq!(balance_factors.next(), None);
+    /// ```
+    pub fn balance_factors(&self) -> IntoIter<(&T, isize)> {
+        Node::iterative_balance_factors(&self.root).into_iter()
+    }
+
+    /// Rotates the subtree rooted at the given value to the left, so that its right child takes
+    /// its place.
+    ///
+    /// Returns `false` and leaves the tree untouched if the value does not exist or has no right
+    /// child, or if the rotation would push a node deeper than the
+    /// [max depth](crate::RecursiveBST::with_max_depth()) of the tree. The ordering of the
+    /// elements is always preserved.
+    ///
+    /// Only available with the `unstable-internals` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::from_iter(vec![1, 2, 3]);
+    ///
+    /// assert!(bst.rotate_left_at(&1));
+    /// assert_eq!(bst.pre_order_vec(), vec![&2, &1, &3]);
+    /// assert!(!bst.rotate_left_at(&1));
+    /// ```
+    #[cfg(feature = "unstable-internals")]
+    pub fn rotate_left_at(&mut self, value: &T) -> bool {
+        self.rotate_at(value, Node::rotate_left, Node::rotate_right)
+    }
+
+    /// Rotates the subtree rooted at the given value to the right, so that its left child takes
+    /// its place (see [Bst::rotate_left_at()]).
+    ///
+    /// Only available with the `unstable-internals` feature.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::from_iter(vec![3, 2, 1]);
+    ///
+    /// assert!(bst.rotate_right_at(&3));
+    /// assert_eq!(bst.pre_order_vec(), vec![&2, &1, &3]);
+    /// assert!(!bst.rotate_right_at(&4));
+    /// ```
+    #[cfg(feature = "unstable-internals")]
+    pub fn rotate_right_at(&mut self, value: &T) -> bool {
+        self.rotate_at(value, Node::rotate_right, Node::rotate_left)
+    }
+
+    /// Applies `rotate` to the subtree rooted at the given value, reverting it with `undo` if it
+    /// pushed a node deeper than the max depth.
+    #[cfg(feature = "unstable-internals")]
+    fn rotate_at<R, U>(&mut self, value: &T, rotate: R, undo: U) -> bool
+    where
+        R: FnOnce(&mut HeapNode<T>) -> bool,
+        U: FnOnce(&mut HeapNode<T>) -> bool,
+    {
+        let depth = self
+            .max_depth
+            .map(|max_depth| (Node::search_depth(&self.root, value).0, max_depth));
+        let slot = Node::iterative_slot_by(&mut self.root, |current| current.cmp(value));
+        if !rotate(slot) {
+            return false;
+        }
+        if let Some((depth, max_depth)) = depth {
+            if depth + Node::iterative_height(slot) as usize > max_depth {
+                undo(slot);
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Bulk-builds a balanced tree out of elements sorted in ascending order, carrying over the
+    /// rebalance setting and the max depth of the tree the elements come from.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the balanced tree has nodes deeper than `max_depth`, which can only happen when
+    /// merging elements of several trees.
+    pub(crate) fn from_sorted(
+        elements: Vec<T>,
+        rebalance: Option<AutoRebalance>,
+        max_depth: Option<usize>,
+    ) -> Bst<T, S> {
+        let size = elements.len();
+        let depth = size.checked_ilog2().unwrap_or(0) as usize;
+        if let Some(max_depth) = max_depth.filter(|max_depth| depth > *max_depth) {
+            panic!(
+                "balanced tree of {} elements has nodes at depth {}, exceeding the max depth of {}",
+                size, depth, max_depth
+            );
+        }
+        Bst {
+            root: Node::build_balanced(elements),
+            size,
+            rebalance: rebalance.map(|mut rebalance| {
+                rebalance.rebuilt(size);
+                rebalance
+            }),
+            max_depth,
+            strategy: PhantomData,
+        }
+    }
+}
+
+impl<T: Ord + Hash, S: Strategy> Bst<T, S> {
+    /// Returns an iterator over **running checksums** of the elements in ascending order.
+    ///
+    /// The n-th checksum covers the first n elements, so two processes holding the same
+    /// elements produce the same sequence no matter the shape of their trees. Streaming the
+    /// checksums to each other, or only every few of them, compares trees without shipping
+    /// their contents, and the first checksum which differs points at the first element that
+    /// does.
+    ///
+    /// Checksums are 64-bit FNV-1a over the [Hash] implementation of the elements, the same as
+    /// the hashes of [MerkleBST](crate::MerkleBST). They detect accidental divergence but are
+    /// **not** collision resistant against an adversary, and elements should hash identically
+    /// on every platform involved, which rules out types such as `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, RecursiveBST};
+    ///
+    /// let local = IterativeBST::from_iter(vec![3_u32, 1, 4, 5, 9]);
+    /// let remote = RecursiveBST::from_iter(vec![9_u32, 5, 4, 3, 1]);
+    /// assert!(local.checksum_iter().eq(remote.checksum_iter()));
+    ///
+    /// let stale = RecursiveBST::from_iter(vec![1_u32, 3, 4, 6, 9]);
+    /// let diverged = local
+    ///     .checksum_iter()
+    ///     .zip(stale.checksum_iter())
+    ///     .position(|(local, remote)| local != remote);
+    /// assert_eq!(diverged, Some(3));
+    /// ```
+    pub fn checksum_iter(&self) -> impl Iterator<Item = u64> + '_ {
+        let mut hasher = Fnv64::new();
+        SortedIter::ascending(&self.root).map(move |value| {
+            hasher.write(&hash_value(value).to_le_bytes());
+            hasher.finish()
+        })
+    }
+}
+
+impl<T: Ord + Copy + Sub<Output = T>, S: Strategy> Bst<T, S> {
+    /// Returns an iterator over the differences between **adjacent elements** in ascending
+    /// order, see [Bst::pairs()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![10, 2, 5, 6]);
+    ///
+    /// assert_eq!(bst.gaps().collect::<Vec<i32>>(), vec![3, 1, 4]);
+    /// ```
+    pub fn gaps(&self) -> impl Iterator<Item = T> + '_ {
+        self.pairs().map(|(smaller, larger)| *larger - *smaller)
+    }
+
+    /// Returns the smallest difference between two adjacent elements or `None` if the tree has
+    /// fewer than two elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::new();
+    /// bst.insert(0u64);
+    /// assert_eq!(bst.min_gap(), None);
+    ///
+    /// bst.insert(64);
+    /// bst.insert(80);
+    /// assert_eq!(bst.min_gap(), Some(16));
+    /// ```
+    pub fn min_gap(&self) -> Option<T> {
+        self.gaps().min()
+    }
+}
+
+impl<K: PrefixKey, S: Strategy> Bst<K, S> {
+    /// Returns `true` if the binary search tree contains a key with the given bytes.
+    ///
+    /// Probes are borrowed `&str` or `&[u8]` slices, so the dominant case of looking up [String]
+    /// keys needs no allocation. Most comparisons are settled on the first byte, the rest of the
+    /// keys being compared in bulk.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![String::from("pear"), String::from("apple")]);
+    ///
+    /// assert!(bst.contains_key_bytes("apple"));
+    /// assert!(!bst.contains_key_bytes(b"app"));
+    /// ```
+    pub fn contains_key_bytes(&self, key: impl AsRef<[u8]>) -> bool {
+        self.retrieve_key_bytes(key).is_some()
+    }
+
+    /// Returns a reference to the key with the given bytes or `None` if it does not exist, see
+    /// [Bst::contains_key_bytes()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![b"\x01\x02".to_vec(), b"\x01".to_vec()]);
+    ///
+    /// assert_eq!(bst.retrieve_key_bytes([1]), Some(&vec![1]));
+    /// assert_eq!(bst.retrieve_key_bytes([2]), None);
+    /// ```
+    pub fn retrieve_key_bytes(&self, key: impl AsRef<[u8]>) -> Option<&K> {
+        let probe = key.as_ref();
+        self.descend_with(|current| compare_bytes(current.as_bytes(), probe))
+    }
+}
+
+impl<T: Step, S: Strategy> Bst<T, S> {
+    /// Returns an iterator over the runs of **contiguous values** stored in the tree, each run
+    /// merged into a single range, in ascending order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![7, 2, 1, 8, 3, 10]);
+    ///
+    /// assert_eq!(
+    ///     bst.coalesced_ranges().collect::<Vec<_>>(),
+    ///     vec![1..=3, 7..=8, 10..=10]
+    /// );
+    /// ```
+    pub fn coalesced_ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        CoalescedRanges::new(SortedIter::ascending(&self.root))
+    }
+}
+
+impl<T: Ord, S: Strategy> Bst<T, S>
+where
+    Bst<T, S>: BinarySearchTree<T>,
+{
+    /// Returns an iterator over the elements in the given `order`, picked at runtime.
+    ///
+    /// Lets generic code such as visualizations or tests select the order dynamically instead
+    /// of matching over each of the traversal methods of [BinarySearchTree].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, TraversalOrder};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![2, 1, 3]);
+    ///
+    /// for (order, expected) in [
+    ///     (TraversalOrder::Pre, vec![&2, &1, &3]),
+    ///     (TraversalOrder::In, vec![&1, &2, &3]),
+    ///     (TraversalOrder::Post, vec![&1, &3, &2]),
+    ///     (TraversalOrder::Level, vec![&2, &1, &3]),
+    ///     (TraversalOrder::Desc, vec![&3, &2, &1]),
+    /// ] {
+    ///     assert_eq!(bst.traverse(order).collect::<Vec<&i32>>(), expected);
+    /// }
+    /// ```
+    pub fn traverse(&self, order: TraversalOrder) -> impl Iterator<Item = &T> {
+        match order {
+            #[cfg(feature = "traversals-extra")]
+            TraversalOrder::Pre => self.pre_order_iter(),
+            TraversalOrder::In => self.in_order_iter(),
+            #[cfg(feature = "traversals-extra")]
+            TraversalOrder::Post => self.post_order_iter(),
+            #[cfg(feature = "traversals-extra")]
+            TraversalOrder::Level => self.level_order_iter(),
+            TraversalOrder::Desc => {
+                let mut elements = self.in_order_vec();
+                elements.reverse();
+                elements.into_iter()
+            }
+        }
+    }
+
+    /// Consumes the tree, returning an iterator over its elements in the given `order`, see
+    /// [Bst::traverse()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST, TraversalOrder};
+    ///
+    /// let bst = RecursiveBST::from_iter(vec![2, 1, 3]);
+    ///
+    /// assert_eq!(
+    ///     bst.into_traverse(TraversalOrder::Desc).collect::<Vec<i32>>(),
+    ///     vec![3, 2, 1]
+    /// );
+    /// ```
+    pub fn into_traverse(self, order: TraversalOrder) -> impl Iterator<Item = T> {
+        match order {
+            #[cfg(feature = "traversals-extra")]
+            TraversalOrder::Pre => self.into_pre_order_iter(),
+            TraversalOrder::In => self.into_in_order_iter(),
+            #[cfg(feature = "traversals-extra")]
+            TraversalOrder::Post => self.into_post_order_iter(),
+            #[cfg(feature = "traversals-extra")]
+            TraversalOrder::Level => self.into_level_order_iter(),
+            TraversalOrder::Desc => {
+                let mut elements: Vec<T> = self.into_in_order_iter().collect();
+                elements.reverse();
+                elements.into_iter()
+            }
+        }
+    }
+
+    /// Applies `f` to the element equal to `value`, then moves the element if it no longer fits
+    /// between its neighbours. Nothing happens if there is no such element.
+    ///
+    /// Unlike changing an element through
+    /// [retrieve_as_mut()](BinarySearchTree::retrieve_as_mut()), this keeps the tree searchable
+    /// no matter how the element changes. Elements which still fit stay where they are,
+    /// otherwise the element is taken out and inserted again, dropping it if an equal element
+    /// exists.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, RecursiveBST};
+    ///
+    /// let mut bst = IterativeBST::from_iter(vec![20, 10, 30]);
+    ///
+    /// bst.modify(&10, |value| *value = 25);
+    /// assert_eq!(bst.asc_order_vec(), vec![&20, &25, &30]);
+    /// assert!(bst.contains(&25));
+    ///
+    /// let mut bst = RecursiveBST::from_iter(vec![20, 10, 30]);
+    ///
+    /// // 30 already exists, so the modified element is dropped
+    /// bst.modify(&10, |value| *value = 30);
+    /// assert_eq!(bst.asc_order_vec(), vec![&20, &30]);
+    /// ```
+    pub fn modify<F: FnOnce(&mut T)>(&mut self, value: &T, f: F) {
+        let path = match Node::iterative_path(&self.root, value) {
+            None => return,
+            Some(path) => path,
+        };
+
+        let slot = Node::slot_at_path(&mut self.root, &path);
+        f(&mut slot.as_mut().unwrap().value);
+
+        if !Node::fits_at_path(&self.root, &path) {
+            let slot = Node::slot_at_path(&mut self.root, &path);
+            let element = Node::iterative_take(slot).unwrap();
+            self.size -= 1;
+            self.insert(element);
+        }
+    }
+
+    /// Inserts every element of the iterator, reporting how many were inserted and how many
+    /// were rejected as duplicates.
+    ///
+    /// Behaves like [Extend::extend()] otherwise, so bulk loads can detect records that were
+    /// silently dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, InsertReport, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::new();
+    /// bst.insert(1);
+    ///
+    /// let report = bst.insert_all(vec![1, 2, 3, 2]);
+    ///
+    /// assert_eq!(report, InsertReport { inserted: 2, duplicates: 2 });
+    /// assert_eq!(bst.size(), 3);
+    /// ```
+    pub fn insert_all<I: IntoIterator<Item = T>>(&mut self, iter: I) -> InsertReport {
+        let mut report = InsertReport::default();
+        for value in iter.into_iter() {
+            let size = self.size;
+            self.insert(value);
+            if self.size > size {
+                report.inserted += 1;
+            } else {
+                report.duplicates += 1;
+            }
+        }
+        report
+    }
+
+    /// Inserts every element of the iterator like [Bst::insert_all()] does, calling
+    /// `progress` with the number of elements taken so far after every `every` of them.
+    ///
+    /// Makes long bulk loads observable, e.g. to drive a progress bar. Returning `false` from
+    /// `progress` cancels the load, keeping the elements inserted so far and leaving the rest
+    /// of the iterator untouched, so passing it by reference with [Iterator::by_ref()] allows
+    /// resuming later on.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `every` is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, InsertReport, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::new();
+    /// let mut records = (0..10).chain(0..10);
+    /// let mut reported = Vec::new();
+    ///
+    /// let report = bst.load_from(records.by_ref(), 4, |taken| {
+    ///     reported.push(taken);
+    ///     taken < 12
+    /// });
+    ///
+    /// assert_eq!(reported, vec![4, 8, 12]);
+    /// assert_eq!(report, InsertReport { inserted: 10, duplicates: 2 });
+    ///
+    /// // Resume where the load was cancelled
+    /// let report = bst.load_from(records, 4, |_| true);
+    /// assert_eq!(report, InsertReport { inserted: 0, duplicates: 8 });
+    /// ```
+    pub fn load_from<I, F>(&mut self, iter: I, every: usize, mut progress: F) -> InsertReport
+    where
+        I: IntoIterator<Item = T>,
+        F: FnMut(usize) -> bool,
+    {
+        assert!(every > 0, "progress interval must be at least 1");
+        let mut report = InsertReport::default();
+        for value in iter.into_iter() {
+            let size = self.size;
+            self.insert(value);
+            if self.size > size {
+                report.inserted += 1;
+            } else {
+                report.duplicates += 1;
+            }
+
+            let taken = report.inserted + report.duplicates;
+            if taken % every == 0 && !progress(taken) {
+                break;
+            }
+        }
+        report
+    }
+
+    /// Creates a new `IterativeBST<T>` from an iterator like [FromIterator::from_iter()] does,
+    /// additionally returning how many elements were skipped as duplicates.
+    ///
+    /// Lets bulk loads warn about data quality issues instead of silently ending up with fewer
+    /// elements than they were given.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let (bst, duplicates) = IterativeBST::from_iter_report(vec![3, 1, 3, 2, 1]);
+    ///
+    /// assert_eq!(duplicates, 2);
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3]);
+    /// ```
+    pub fn from_iter_report<I: IntoIterator<Item = T>>(iter: I) -> (Bst<T, S>, usize) {
+        let mut bst = Bst::new();
+        let report = bst.insert_all(iter);
+        (bst, report.duplicates)
+    }
+
+    /// Creates a new `IterativeBST<T>` from a vector which must not contain duplicates.
+    ///
+    /// Elements are inserted in order like [From::from()] does, but instead of silently
+    /// dropping a duplicate a [DuplicateError] holding it and its position is returned.
+    ///
+    /// This is not a [TryFrom] implementation, as that would conflict with the one the standard
+    /// library derives from `From<Vec<T>>`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::try_from_vec(vec![2, 1, 3]).unwrap();
+    /// assert_eq!(bst.size(), 3);
+    ///
+    /// let error = IterativeBST::try_from_vec(vec![2, 1, 3, 1]).unwrap_err();
+    /// assert_eq!(error.index(), 3);
+    /// assert_eq!(error.value(), &1);
+    /// ```
+    pub fn try_from_vec(vec: Vec<T>) -> Result<Bst<T, S>, DuplicateError<T>> {
+        let mut bst = Bst::new();
+        for (index, value) in vec.into_iter().enumerate() {
+            if bst.contains(&value) {
+                return Err(DuplicateError::new(index, value));
+            }
+            bst.insert(value);
+        }
+        Ok(bst)
+    }
+
+    /// Applies a batch of insertions and removals **atomically**.
+    ///
+    /// Either every operation is applied in order or, if any of them would fail, none is and the
+    /// tree is left untouched. An insertion fails if the value is already present and a removal
+    /// fails if the value is missing, taking earlier operations of the batch into account.
+    ///
+    /// The batch is validated up front with a single lookup per distinct value, so rejecting it
+    /// never requires a rollback.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BatchError, BinarySearchTree, Op, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::from_iter(vec![1, 2]);
+    ///
+    /// assert_eq!(bst.apply(vec![Op::Remove(1), Op::Insert(3), Op::Insert(1)]), Ok(()));
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3]);
+    ///
+    /// let result = bst.apply(vec![Op::Insert(4), Op::Remove(5)]);
+    ///
+    /// assert_eq!(result, Err(BatchError::NotPresent { index: 1 }));
+    /// assert!(!bst.contains(&4));
+    /// ```
+    pub fn apply<I: IntoIterator<Item = Op<T>>>(&mut self, batch: I) -> Result<(), BatchError> {
+        let ops: Vec<Op<T>> = batch.into_iter().collect();
+        batch::validate(&ops, |value| self.contains(value))?;
+
+        for op in ops {
+            match op {
+                Op::Insert(value) => self.insert(value),
+                Op::Remove(value) => self.remove(&value),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Ord + Copy, S: Strategy> Bst<T, S>
+where
+    Bst<T, S>: BinarySearchTree<T>,
+{
+    /// Returns copies of the elements of the tree in **ascending order**, without consuming
+    /// the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![3, 1, 2]);
+    ///
+    /// assert_eq!(bst.asc_order_copied(), vec![1, 2, 3]);
+    /// assert_eq!(bst.size(), 3);
+    /// ```
+    pub fn asc_order_copied(&self) -> Vec<T> {
+        let mut elements = Vec::with_capacity(self.size);
+        elements.extend(SortedIter::ascending(&self.root).copied());
+        elements
+    }
+
+    /// Returns copies of the elements of the tree in the order of a **pre-order traversal**,
+    /// without consuming the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from_iter(vec![3, 1, 2, 4]);
+    ///
+    /// assert_eq!(bst.pre_order_copied(), vec![3, 1, 2, 4]);
+    /// ```
+    #[cfg(feature = "traversals-extra")]
+    pub fn pre_order_copied(&self) -> Vec<T> {
+        self.pre_order_iter().copied().collect()
+    }
+
+    /// Returns copies of the elements of the tree in the order of an **in-order traversal**,
+    /// without consuming the tree (see [Bst::asc_order_copied()]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from_iter(vec![3, 1, 2, 4]);
+    ///
+    /// assert_eq!(bst.in_order_copied(), vec![1, 2, 3, 4]);
+    /// ```
+    pub fn in_order_copied(&self) -> Vec<T> {
+        self.asc_order_copied()
+    }
+
+    /// Returns copies of the elements of the tree in the order of a **post-order traversal**,
+    /// without consuming the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![3, 1, 2, 4]);
+    ///
+    /// assert_eq!(bst.post_order_copied(), vec![2, 1, 4, 3]);
+    /// ```
+    #[cfg(feature = "traversals-extra")]
+    pub fn post_order_copied(&self) -> Vec<T> {
+        self.post_order_iter().copied().collect()
+    }
+
+    /// Returns copies of the elements of the tree in the order of a **level-order traversal**,
+    /// without consuming the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![3, 1, 2, 4]);
+    ///
+    /// assert_eq!(bst.level_order_copied(), vec![3, 1, 4, 2]);
+    /// ```
+    #[cfg(feature = "traversals-extra")]
+    pub fn level_order_copied(&self) -> Vec<T> {
+        self.level_order_iter().copied().collect()
+    }
+}
+
+#[cfg(feature = "debug-checks")]
+impl<T: Ord + Clone + Debug, S: Strategy> Bst<T, S>
+where
+    Bst<T, S>: BinarySearchTree<T>,
+{
+    /// Checks that every traversal of the tree is consistent with the others, panicking with
+    /// the name of the first traversal which is not.
+    ///
+    /// The ascending and in-order traversals must hold the elements in strictly ascending
+    /// order and as many of them as [size()](BinarySearchTree::size()), while the pre-order,
+    /// post-order and level-order ones must hold the same elements. For each traversal the
+    /// vector, the borrowing iterator and the consuming iterator of a copy of the tree with
+    /// the same structure must hand out the elements in the same order.
+    ///
+    /// Meant for fuzzing harnesses and tests of code changing the traversals, notably the
+    /// unsafe consuming ones. This takes `O(n)` time and clones every element once per
+    /// traversal.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the traversals disagree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, RecursiveBST};
+    ///
+    /// let mut bst = IterativeBST::from_iter(vec![5, 2, 8, 1, 9, 3]);
+    /// bst.remove(&2);
+    /// bst.check_iterators();
+    ///
+    /// RecursiveBST::from_iter(0..100).check_iterators();
+    /// ```
+    pub fn check_iterators(&self) {
+        let ascending = self.asc_order_vec();
+        assert_eq!(
+            ascending.len(),
+            self.size(),
+            "asc order holds the wrong number of elements"
+        );
+        assert!(
+            ascending.windows(2).all(|pair| pair[0] < pair[1]),
+            "asc order is not strictly ascending"
+        );
+        self.check_family(
+            "asc order",
+            ascending.clone(),
+            self.asc_order_iter(),
+            |bst| bst.into_asc_order_iter(),
+        );
+        self.check_family("in order", ascending.clone(), self.in_order_iter(), |bst| {
+            bst.into_in_order_iter()
+        });
+        assert_eq!(
+            self.in_order_vec(),
+            ascending,
+            "in order differs from asc order"
+        );
+
+        for (name, elements) in [
+            ("pre order", self.pre_order_vec()),
+            ("post order", self.post_order_vec()),
+            ("level order", self.level_order_vec()),
+        ] {
+            let mut sorted = elements.clone();
+            sorted.sort();
+            assert_eq!(
+                sorted, ascending,
+                "{name} holds other elements than asc order"
+            );
+        }
+        self.check_family(
+            "pre order",
+            self.pre_order_vec(),
+            self.pre_order_iter(),
+            |bst| bst.into_pre_order_iter(),
+        );
+        self.check_family(
+            "post order",
+            self.post_order_vec(),
+            self.post_order_iter(),
+            |bst| bst.into_post_order_iter(),
+        );
+        self.check_family(
+            "level order",
+            self.level_order_vec(),
+            self.level_order_iter(),
+            |bst| bst.into_level_order_iter(),
+        );
+    }
+
+    /// Checks that the borrowing iterator and the consuming iterator of a copy of the tree hand
+    /// out the elements of `expected` in the same order.
+    fn check_family<'a, F, I>(&'a self, name: &str, expected: Vec<&'a T>, iter: I, consume: F)
+    where
+        I: Iterator<Item = &'a T>,
+        F: FnOnce(Bst<T, S>) -> std::vec::IntoIter<T>,
+    {
+        assert_eq!(
+            iter.collect::<Vec<&T>>(),
+            expected,
+            "{name} iterator differs from vector"
+        );
+
+        let mut copy = Bst::new();
+        copy.root = Node::clone_tree(&self.root);
+        copy.size = self.size;
+        let consumed = consume(copy).collect::<Vec<T>>();
+        assert_eq!(
+            consumed.iter().collect::<Vec<&T>>(),
+            expected,
+            "consuming {name} iterator differs from vector"
+        );
+    }
+}
+
+impl<T: Ord, S: Strategy> Default for Bst<T, S> {
+    /// Creates an empty `Bst<T, S>`
+    fn default() -> Bst<T, S> {
+        Bst::new()
+    }
+}
+
+impl<T: Ord, S: Strategy> PartialEq for Bst<T, S>
+where
+    Bst<T, S>: BinarySearchTree<T>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.asc_order_vec() == other.asc_order_vec()
+    }
+}
+
+impl<T: Ord, S: Strategy> Extend<T> for Bst<T, S>
+where
+    Bst<T, S>: BinarySearchTree<T>,
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter.into_iter() {
+            self.insert(value)
+        }
+    }
+}
+
+impl<'a, T: Ord + Clone + 'a, S: Strategy> Extend<&'a T> for Bst<T, S>
+where
+    Bst<T, S>: BinarySearchTree<T>,
+{
+    /// Clones the elements into the tree, like the standard library collections do.
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        for value in iter.into_iter() {
+            self.insert(value.clone())
+        }
+    }
+}
+
+impl<T: Ord, S: Strategy> FromIterator<T> for Bst<T, S>
+where
+    Bst<T, S>: BinarySearchTree<T>,
+{
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bst = Bst::new();
+        bst.extend(iter);
+        bst
+    }
+}
+
+impl<T: Ord, S: Strategy> From<Vec<T>> for Bst<T, S>
+where
+    Bst<T, S>: BinarySearchTree<T>,
+{
+    fn from(vec: Vec<T>) -> Self {
+        let mut bst = Bst::new();
+        for value in vec.into_iter() {
+            bst.insert(value);
+        }
+        bst
+    }
+}
+
+impl<T: Ord + Clone, S: Strategy> From<&[T]> for Bst<T, S>
+where
+    Bst<T, S>: BinarySearchTree<T>,
+{
+    fn from(slice: &[T]) -> Self {
+        let mut bst = Bst::new();
+        for value in slice {
+            bst.insert((*value).clone());
+        }
+        bst
+    }
+}
+
+impl<T: Ord + Clone, S: Strategy> Clone for Bst<T, S> {
+    /// Copies the tree node for node, so the clone has exactly the same shape.
+    fn clone(&self) -> Self {
+        Bst {
+            root: Node::clone_tree(&self.root),
+            size: self.size,
+            rebalance: self.rebalance,
+            max_depth: self.max_depth,
+            strategy: PhantomData,
+        }
+    }
+}
+
+impl<T: Ord + Debug, S: Strategy> Display for Bst<T, S>
+where
+    Bst<T, S>: BinarySearchTree<T>,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.asc_order_vec())
+    }
+}
+
+#[cfg(all(test, feature = "iterative", feature = "recursive"))]
+mod tests {
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    #[cfg(feature = "traversals-extra")]
+    use crate::TraversalOrder;
+    use crate::{
+        BinarySearchTree, Bst, Inclusivity, Iterative, IterativeBST, Recursive, RecursiveBST,
+        TimedOut,
+    };
+
+    #[test]
+    fn successfully_use_aliases_and_generic_type_interchangeably() {
+        let iterative: Bst<i32, Iterative> = IterativeBST::from_iter(vec![2, 1, 3]);
+        let recursive: Bst<i32, Recursive> = RecursiveBST::from(vec![2, 1, 3]);
+        let default: Bst<i32> = iterative.clone();
+
+        assert_eq!(default, iterative);
+        assert_eq!(iterative.pre_order_vec(), recursive.pre_order_vec());
+        assert_eq!(iterative.to_string(), recursive.to_string());
+    }
+
+    #[test]
+    fn successfully_create_trees_in_const_context() {
+        const EMPTY: RecursiveBST<i32> = RecursiveBST::new();
+        static SHARED: Mutex<IterativeBST<i32>> = Mutex::new(IterativeBST::new());
+
+        let mut tree = EMPTY;
+        tree.insert(1);
+        assert_eq!(tree.size(), 1);
+        assert!(EMPTY.is_empty());
+
+        SHARED.lock().unwrap().extend(vec![2, 1]);
+        assert_eq!(SHARED.lock().unwrap().asc_order_vec(), vec![&1, &2]);
+    }
+
+    #[test]
+    fn successfully_share_impls_between_strategies() {
+        fn build<S: crate::Strategy>() -> Bst<i32, S>
+        where
+            Bst<i32, S>: BinarySearchTree<i32>,
+        {
+            let mut bst = Bst::default();
+            bst.extend(vec![5, 3, 8]);
+            bst
+        }
+
+        assert_eq!(build::<Iterative>().asc_order_vec(), vec![&3, &5, &8]);
+        assert_eq!(build::<Recursive>().asc_order_vec(), vec![&3, &5, &8]);
+    }
+
+    #[test]
+    fn successfully_modify_and_reposition_elements() {
+        let mut bst = IterativeBST::from_iter(vec![40, 20, 60, 10, 30, 50, 70]);
+
+        bst.modify(&30, |value| *value = 35);
+        assert_eq!(bst.pre_order_vec(), vec![&40, &20, &10, &35, &60, &50, &70]);
+
+        bst.modify(&20, |value| *value = 55);
+        assert_eq!(bst.asc_order_vec(), vec![&10, &35, &40, &50, &55, &60, &70]);
+        assert!(bst.contains(&55));
+        assert!(!bst.contains(&20));
+
+        bst.modify(&40, |value| *value = 70);
+        bst.modify(&99, |_| unreachable!());
+        assert_eq!(bst.size(), 6);
+        assert_eq!(bst.asc_order_vec(), vec![&10, &35, &50, &55, &60, &70]);
+
+        let mut recursive = RecursiveBST::from_iter(vec![2, 1, 3]);
+        recursive.modify(&2, |value| *value = 0);
+        assert_eq!(recursive.asc_order_vec(), vec![&0, &1, &3]);
+        assert!(recursive.contains(&0));
+    }
+
+    #[test]
+    #[cfg(feature = "traversals-extra")]
+    fn successfully_consume_bst_level_by_level() {
+        let bst = RecursiveBST::from_iter(vec![
+            String::from("d"),
+            String::from("b"),
+            String::from("f"),
+            String::from("a"),
+            String::from("g"),
+        ]);
+        let expected: Vec<String> = bst.level_order_iter().cloned().collect();
+
+        let mut levels = bst.into_levels();
+        assert_eq!(levels.size_hint(), (1, Some(5)));
+        assert_eq!(levels.next(), Some(vec![String::from("d")]));
+        assert_eq!(levels.size_hint(), (1, Some(4)));
+
+        let rest: Vec<Vec<String>> = levels.collect();
+        assert_eq!(rest, vec![vec!["b", "f"], vec!["a", "g"]]);
+        assert_eq!(
+            std::iter::once(String::from("d"))
+                .chain(rest.into_iter().flatten())
+                .collect::<Vec<String>>(),
+            expected
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "traversals-extra")]
+    fn successfully_view_bst_from_above_and_below() {
+        //            50
+        //          /    \
+        //        30      70
+        //       /  \    /  \
+        //     20   40  60   80
+        //            \
+        //             45
+        //               \
+        //                48
+        let iterative = IterativeBST::from_iter(vec![50, 30, 70, 20, 40, 60, 80, 45, 48]);
+        let recursive = RecursiveBST::from_iter(vec![50, 30, 70, 20, 40, 60, 80, 45, 48]);
+
+        assert_eq!(iterative.top_view(), vec![&20, &30, &50, &70, &80]);
+        // 40 and 60 share a level and a distance, the rightmost one wins
+        assert_eq!(iterative.bottom_view(), vec![&20, &30, &60, &45, &48]);
+        assert_eq!(recursive.top_view(), iterative.top_view());
+        assert_eq!(recursive.bottom_view(), iterative.bottom_view());
+
+        let single = IterativeBST::from_iter(vec![1]);
+        assert_eq!(single.top_view(), vec![&1]);
+        assert_eq!(single.bottom_view(), vec![&1]);
+    }
+
+    #[test]
+    #[cfg(feature = "traversals-extra")]
+    fn successfully_walk_bst_by_diagonals() {
+        let iterative = IterativeBST::from_iter(vec![50, 30, 70, 20, 40, 60, 80, 45]);
+        let recursive = RecursiveBST::from_iter(vec![50, 30, 70, 20, 40, 60, 80, 45]);
+
+        let mut diagonal_order_iter = iterative.diagonal_order_iter();
+        assert_eq!(diagonal_order_iter.next(), Some(&50));
+        assert_eq!(diagonal_order_iter.next(), Some(&70));
+        assert_eq!(diagonal_order_iter.next(), Some(&80));
+        assert_eq!(
+            diagonal_order_iter.collect::<Vec<&i32>>(),
+            vec![&30, &40, &45, &60, &20]
+        );
+        assert!(recursive
+            .diagonal_order_iter()
+            .eq(iterative.diagonal_order_iter()));
+        assert_eq!(
+            RecursiveBST::<i32>::new().diagonal_order_iter().next(),
+            None
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "traversals-extra")]
+    fn successfully_traverse_bst_in_runtime_order() {
+        let values = vec![8, 4, 12, 2, 6, 10, 14, 1];
+        let iterative = IterativeBST::from_iter(values.clone());
+        let recursive = RecursiveBST::from_iter(values.clone());
+
+        let orders = [
+            (TraversalOrder::Pre, iterative.pre_order_vec()),
+            (TraversalOrder::In, iterative.in_order_vec()),
+            (TraversalOrder::Post, iterative.post_order_vec()),
+            (TraversalOrder::Level, iterative.level_order_vec()),
+            (
+                TraversalOrder::Desc,
+                iterative.asc_order_iter().rev().collect(),
+            ),
+        ];
+        for (order, expected) in orders {
+            assert_eq!(iterative.traverse(order).collect::<Vec<&i32>>(), expected);
+            assert!(recursive.traverse(order).eq(expected.iter().copied()));
+            assert!(RecursiveBST::from_iter(values.clone())
+                .into_traverse(order)
+                .eq(expected.into_iter().copied()));
+        }
+        assert_eq!(
+            IterativeBST::<i32>::new()
+                .traverse(TraversalOrder::Desc)
+                .next(),
+            None
+        );
+    }
+
+    #[test]
+    fn successfully_extend_bst_with_borrowed_elements() {
+        fn extend_generic<E: Extend<i32> + for<'a> Extend<&'a i32>>(target: &mut E) {
+            target.extend(&[3, 1]);
+            target.extend(vec![2]);
+        }
+
+        let names = vec![String::from("b"), String::from("a")];
+        let mut iterative: IterativeBST<String> = IterativeBST::new();
+        iterative.extend(names.iter());
+        iterative.extend(&names);
+
+        let mut recursive = RecursiveBST::new();
+        extend_generic(&mut recursive);
+
+        assert_eq!(iterative.asc_order_vec(), vec!["a", "b"]);
+        assert_eq!(recursive.asc_order_vec(), vec![&1, &2, &3]);
+        assert_eq!(names.len(), 2);
+    }
+
+    #[test]
+    fn successfully_walk_elements_between_bounds() {
+        let bst = RecursiveBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14, 0, 16]);
+        let inclusivities = [
+            Inclusivity::Inclusive,
+            Inclusivity::Exclusive,
+            Inclusivity::IncludeStart,
+            Inclusivity::IncludeEnd,
+        ];
+
+        for start in -1..=17 {
+            for end in -1..=17 {
+                for inclusivity in inclusivities {
+                    let (include_start, include_end) = inclusivity.includes();
+                    let (low, high) = (start.min(end), start.max(end));
+                    let mut expected: Vec<&i32> = bst
+                        .asc_order_iter()
+                        .filter(|value| {
+                            (low..=high).contains(*value)
+                                && (**value != start || include_start)
+                                && (**value != end || include_end)
+                        })
+                        .collect();
+                    if start > end {
+                        expected.reverse();
+                    }
+
+                    let walked: Vec<&i32> = bst.between(&start, &end, inclusivity).collect();
+                    assert_eq!(walked, expected, "{}..{} {:?}", start, end, inclusivity);
+                }
+            }
+        }
+
+        let empty = IterativeBST::<i32>::new();
+        assert_eq!(empty.between(&0, &1, Inclusivity::Inclusive).next(), None);
+    }
+
+    #[test]
+    fn successfully_split_bst_into_mutable_ranges() {
+        let values = vec![8, 4, 12, 2, 6, 10, 14, 1, 3, 5, 7, 9, 11, 13, 15];
+        let mut bst = RecursiveBST::from_iter(values);
+
+        for split in 0..=16 {
+            let (lower, upper) = bst.split_at_mut(&split);
+            assert!(lower.map(|value| *value).eq(1..split.clamp(1, 16)));
+            assert!(upper.map(|value| *value).eq(split.clamp(1, 16)..=15));
+        }
+
+        let mut empty = IterativeBST::<i32>::new();
+        let (mut lower, mut upper) = empty.split_at_mut(&0);
+        assert_eq!(lower.next(), None);
+        assert_eq!(upper.next(), None);
+    }
+
+    #[test]
+    fn successfully_move_data_between_mutable_ranges() {
+        #[derive(Debug)]
+        struct Bucket(i32, Vec<char>);
+
+        impl PartialEq for Bucket {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl Eq for Bucket {}
+
+        impl PartialOrd for Bucket {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Bucket {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let mut bst = IterativeBST::new();
+        for (key, items) in [(2, "ab"), (1, "c"), (4, ""), (3, "de"), (5, "")] {
+            bst.insert(Bucket(key, items.chars().collect()));
+        }
+
+        let (lower, upper) = bst.split_at_mut(&Bucket(3, Vec::new()));
+        for (low, high) in lower.zip(upper) {
+            high.1.append(&mut low.1);
+        }
+
+        let items: Vec<(i32, String)> = bst
+            .asc_order_iter()
+            .map(|bucket| (bucket.0, bucket.1.iter().collect()))
+            .collect();
+        assert_eq!(
+            items,
+            vec![
+                (1, String::new()),
+                (2, String::new()),
+                (3, "dec".to_string()),
+                (4, "ab".to_string()),
+                (5, String::new())
+            ]
+        );
+        assert!(bst.contains(&Bucket(4, Vec::new())));
+    }
+
+    #[test]
+    #[cfg(feature = "debug-checks")]
+    fn successfully_check_iterators_of_assorted_shapes() {
+        let values = vec![50, 20, 80, 10, 30, 70, 90, 25, 35, 85];
+        let mut iterative = IterativeBST::from_iter(values.clone());
+        let mut recursive = RecursiveBST::from_iter(values);
+        for removed in [20, 50, 90, 12] {
+            iterative.check_iterators();
+            recursive.check_iterators();
+            iterative.remove(&removed);
+            recursive.remove(&removed);
+        }
+
+        IterativeBST::<i32>::new().check_iterators();
+        IterativeBST::from_iter(0..2_000).check_iterators();
+        RecursiveBST::from_iter((0..200).rev()).check_iterators();
+    }
+
+    #[test]
+    fn successfully_retain_and_update_elements_in_one_sweep() {
+        let mut iterative = IterativeBST::from_iter(vec![50, 20, 80, 10, 30, 70, 90]);
+        let mut recursive = RecursiveBST::from_iter(vec![50, 20, 80, 10, 30, 70, 90]);
+        let shape = iterative
+            .pre_order_vec()
+            .into_iter()
+            .copied()
+            .collect::<Vec<i32>>();
+
+        // Nothing removed leaves the shape alone
+        let mut visited = Vec::new();
+        assert_eq!(
+            iterative.retain_mut(|value| {
+                visited.push(*value);
+                true
+            }),
+            0
+        );
+        assert_eq!(visited, vec![10, 20, 30, 50, 70, 80, 90]);
+        assert_eq!(
+            iterative
+                .pre_order_vec()
+                .into_iter()
+                .copied()
+                .collect::<Vec<i32>>(),
+            shape
+        );
+
+        assert_eq!(iterative.retain_mut(|value| *value % 20 != 0), 2);
+        assert_eq!(recursive.retain_mut(|value| *value % 20 != 0), 2);
+        assert_eq!(iterative.asc_order_vec(), vec![&10, &30, &50, &70, &90]);
+        assert_eq!(iterative.size(), 5);
+        assert_eq!(iterative.height(), Some(2));
+        assert_eq!(iterative.pre_order_vec(), recursive.pre_order_vec());
+
+        assert_eq!(recursive.retain_mut(|_| false), 5);
+        assert!(recursive.is_empty());
+        assert_eq!(recursive.retain_mut(|_| false), 0);
+    }
+
+    #[test]
+    fn successfully_compare_trees_by_running_checksums() {
+        let balanced = IterativeBST::from_iter(vec![4, 2, 6, 1, 3, 5, 7]);
+        let chain = RecursiveBST::from_iter(1..=7);
+        assert_eq!(
+            balanced.checksum_iter().collect::<Vec<u64>>(),
+            chain.checksum_iter().collect::<Vec<u64>>()
+        );
+
+        let checksums: Vec<u64> = balanced.checksum_iter().collect();
+        let mut shorter = balanced.clone();
+        shorter.remove(&7);
+        assert_eq!(
+            shorter.checksum_iter().collect::<Vec<u64>>(),
+            checksums[..6]
+        );
+
+        let mut changed = balanced.clone();
+        changed.remove(&2);
+        changed.insert(0);
+        let diverged = changed
+            .checksum_iter()
+            .zip(checksums.iter())
+            .position(|(a, b)| a != *b);
+        assert_eq!(diverged, Some(0));
+
+        assert_eq!(IterativeBST::<i32>::new().checksum_iter().next(), None);
+    }
+
+    #[test]
+    fn successfully_search_with_deadline() {
+        let chain = IterativeBST::from_iter(0..1_000);
+        let later = Instant::now() + Duration::from_secs(60);
+
+        assert_eq!(chain.retrieve_deadline(&999, later), Ok(Some(&999)));
+        assert_eq!(chain.contains_deadline(&1_000, later), Ok(false));
+        assert_eq!(
+            chain.contains_deadline(&999, Instant::now()),
+            Err(TimedOut::new(0))
+        );
+
+        let empty = RecursiveBST::<i32>::new();
+        assert_eq!(empty.retrieve_deadline(&1, Instant::now()), Ok(None));
+        assert_eq!(
+            TimedOut::new(64).to_string(),
+            "search passed its deadline after visiting 64 nodes"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "traversals-extra")]
+    fn successfully_copy_elements_without_consuming_bst() {
+        let iterative = IterativeBST::from_iter(vec![5, 3, 8, 1, 4, 9]);
+        let recursive = RecursiveBST::from_iter(vec![5, 3, 8, 1, 4, 9]);
+
+        for copied in [iterative.asc_order_copied(), recursive.in_order_copied()] {
+            assert_eq!(copied, vec![1, 3, 4, 5, 8, 9]);
+        }
+        assert_eq!(iterative.pre_order_copied(), vec![5, 3, 1, 4, 8, 9]);
+        assert_eq!(recursive.post_order_copied(), vec![1, 4, 3, 9, 8, 5]);
+        assert_eq!(recursive.level_order_copied(), vec![5, 3, 8, 1, 4, 9]);
+        assert_eq!(iterative.size(), 6);
+        let consumed: Vec<i32> = recursive.into_pre_order_iter().collect();
+        assert_eq!(iterative.pre_order_copied(), consumed);
+
+        assert!(IterativeBST::<u8>::new().asc_order_copied().is_empty());
+    }
+
+    #[test]
+    fn successfully_inspect_root_of_bst() {
+        let mut iterative = IterativeBST::from_iter(vec![40, 20, 60]);
+        let mut recursive = RecursiveBST::from_iter(vec![40, 20, 60]);
+        assert_eq!(iterative.root(), Some(&40));
+        assert_eq!(recursive.root(), iterative.pre_order_vec().first().copied());
+
+        iterative.remove(&40);
+        recursive.remove(&40);
+        assert_eq!(iterative.root(), Some(&60));
+        assert_eq!(recursive.root(), Some(&60));
+
+        assert_eq!(IterativeBST::<i32>::new().root(), None);
+    }
+}
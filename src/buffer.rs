@@ -0,0 +1,228 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::bst::{Bst, Strategy};
+use crate::iter::SortedIter;
+use crate::node::Node;
+
+/// Reusable stack for the lazy traversals of a [Bst], see [Bst::asc_order_iter_with()].
+///
+/// Lazy iterators keep the path from the current element back up to the root on a stack, which
+/// is allocated every time a traversal starts. Hot loops restarting traversals over and over
+/// can keep a buffer around instead, so the allocation is only made once and grows to the
+/// largest height seen.
+///
+/// The buffer holds no elements between traversals and can be used with any tree of the same
+/// element type which lives for `'a`, the lifetime of the nodes it stacks up while walking.
+/// As it holds references to the elements, the buffer can only be sent to another thread if
+/// they can be shared between threads:
+///
+/// ```compile_fail
+/// use std::rc::Rc;
+/// use bst_rs::TraversalBuffer;
+///
+/// fn send<T: Send>(_: T) {}
+/// send(TraversalBuffer::<Rc<i32>>::new());
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{BinarySearchTree, IterativeBST, TraversalBuffer};
+///
+/// let bst = IterativeBST::from_iter(vec![4, 2, 6, 1, 3, 5, 7]);
+/// let mut buffer = TraversalBuffer::new();
+///
+/// for k in 1..=3 {
+///     let smallest: Vec<&i32> = bst.asc_order_iter_with(&mut buffer).take(k).collect();
+///     assert_eq!(smallest.len(), k);
+/// }
+/// assert!(buffer.capacity() >= 3);
+/// ```
+pub struct TraversalBuffer<'a, T: Ord> {
+    /// Always empty outside of a traversal, only its allocation is kept.
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T: Ord> TraversalBuffer<'a, T> {
+    /// Creates an empty `TraversalBuffer<T>`, nothing is allocated until it is first used.
+    pub const fn new() -> TraversalBuffer<'a, T> {
+        TraversalBuffer { stack: Vec::new() }
+    }
+
+    /// Creates a buffer able to walk trees up to a height of `capacity - 1` without allocating.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::TraversalBuffer;
+    ///
+    /// let buffer: TraversalBuffer<i32> = TraversalBuffer::with_capacity(32);
+    /// assert!(buffer.capacity() >= 32);
+    /// ```
+    pub fn with_capacity(capacity: usize) -> TraversalBuffer<'a, T> {
+        TraversalBuffer {
+            stack: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of nodes the buffer can hold without allocating.
+    pub fn capacity(&self) -> usize {
+        self.stack.capacity()
+    }
+
+    /// Lends the allocation out to a traversal, leaving an unallocated stack behind.
+    fn lend(&mut self) -> Vec<&'a Node<T>> {
+        std::mem::take(&mut self.stack)
+    }
+
+    /// Takes back an allocation handed out by [TraversalBuffer::lend()], forgetting the nodes
+    /// left on it.
+    fn restore(&mut self, mut stack: Vec<&'a Node<T>>) {
+        stack.clear();
+        self.stack = stack;
+    }
+}
+
+impl<'a, T: Ord> Default for TraversalBuffer<'a, T> {
+    /// Creates an empty `TraversalBuffer<T>`
+    fn default() -> TraversalBuffer<'a, T> {
+        TraversalBuffer::new()
+    }
+}
+
+impl<'a, T: Ord> Debug for TraversalBuffer<'a, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TraversalBuffer")
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
+
+/// [SortedIter] borrowing its stack from a [TraversalBuffer], giving it back when dropped.
+struct BufferedIter<'a, 'b, T: Ord> {
+    iter: SortedIter<'a, T>,
+    buffer: &'b mut TraversalBuffer<'a, T>,
+}
+
+impl<'a, 'b, T: Ord> BufferedIter<'a, 'b, T> {
+    fn new<S: Strategy>(
+        bst: &'a Bst<T, S>,
+        buffer: &'b mut TraversalBuffer<'a, T>,
+        descending: bool,
+    ) -> BufferedIter<'a, 'b, T> {
+        BufferedIter {
+            iter: SortedIter::with_stack(&bst.root, descending, buffer.lend()),
+            buffer,
+        }
+    }
+}
+
+impl<'a, 'b, T: Ord> Iterator for BufferedIter<'a, 'b, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+}
+
+impl<'a, 'b, T: Ord> Drop for BufferedIter<'a, 'b, T> {
+    fn drop(&mut self) {
+        self.buffer.restore(self.iter.take_stack());
+    }
+}
+
+impl<T: Ord, S: Strategy> Bst<T, S> {
+    /// Returns a lazy iterator over the elements in **ascending order** which keeps its stack in
+    /// `buffer` instead of allocating a new one.
+    ///
+    /// The buffer can be used again once the iterator has been dropped, while the elements
+    /// returned remain borrowed from the tree only.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST, TraversalBuffer};
+    ///
+    /// let bst = RecursiveBST::from_iter(vec![2, 1, 3]);
+    /// let mut buffer = TraversalBuffer::new();
+    ///
+    /// let first: Vec<&i32> = bst.asc_order_iter_with(&mut buffer).collect();
+    /// let second: Vec<&i32> = bst.asc_order_iter_with(&mut buffer).collect();
+    ///
+    /// assert_eq!(first, vec![&1, &2, &3]);
+    /// assert_eq!(first, second);
+    /// ```
+    pub fn asc_order_iter_with<'a: 'b, 'b>(
+        &'a self,
+        buffer: &'b mut TraversalBuffer<'a, T>,
+    ) -> impl Iterator<Item = &'a T> + 'b {
+        BufferedIter::new(self, buffer, false)
+    }
+
+    /// Returns a lazy iterator over the elements in **descending order** which keeps its stack
+    /// in `buffer`, see [Bst::asc_order_iter_with()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, TraversalBuffer};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![2, 1, 3]);
+    /// let mut buffer = TraversalBuffer::new();
+    ///
+    /// let mut desc_order_iter = bst.desc_order_iter_with(&mut buffer);
+    /// assert_eq!(desc_order_iter.next(), Some(&3));
+    /// assert_eq!(desc_order_iter.next(), Some(&2));
+    /// assert_eq!(desc_order_iter.next(), Some(&1));
+    /// assert_eq!(desc_order_iter.next(), None);
+    /// ```
+    pub fn desc_order_iter_with<'a: 'b, 'b>(
+        &'a self,
+        buffer: &'b mut TraversalBuffer<'a, T>,
+    ) -> impl Iterator<Item = &'a T> + 'b {
+        BufferedIter::new(self, buffer, true)
+    }
+}
+
+#[cfg(all(test, feature = "iterative", feature = "recursive"))]
+mod tests {
+    use crate::{BinarySearchTree, IterativeBST, RecursiveBST, TraversalBuffer};
+
+    #[test]
+    fn successfully_reuse_buffer_across_traversals() {
+        let iterative = IterativeBST::from_iter(1..=64);
+        let recursive = RecursiveBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14]);
+        let mut buffer = TraversalBuffer::new();
+
+        // The whole right spine is pushed when walking the degenerate tree in descending order
+        let largest: Vec<&i32> = iterative
+            .desc_order_iter_with(&mut buffer)
+            .take(2)
+            .collect();
+        let capacity = buffer.capacity();
+        assert!(capacity >= 64);
+
+        let smallest: Vec<&i32> = iterative.asc_order_iter_with(&mut buffer).take(3).collect();
+        assert!(recursive
+            .asc_order_iter_with(&mut buffer)
+            .eq(recursive.asc_order_iter()));
+        assert_eq!(buffer.capacity(), capacity);
+
+        // Elements outlive the iterators which borrowed the buffer
+        assert_eq!(smallest, vec![&1, &2, &3]);
+        assert_eq!(largest, vec![&64, &63]);
+    }
+
+    #[test]
+    fn successfully_walk_empty_bst_with_buffer() {
+        let empty = IterativeBST::<i32>::new();
+        let mut buffer = TraversalBuffer::with_capacity(4);
+
+        assert_eq!(empty.asc_order_iter_with(&mut buffer).next(), None);
+        assert!(buffer.capacity() >= 4);
+        assert_eq!(
+            format!("{:?}", TraversalBuffer::<i32>::new()),
+            "TraversalBuffer { capacity: 0 }"
+        );
+    }
+}
@@ -0,0 +1,1049 @@
+use std::cell::{OnceCell, RefCell};
+use std::collections::VecDeque;
+use std::fmt::{Debug, Display, Formatter};
+use std::vec::IntoIter;
+
+use crate::{BinarySearchTree, IterativeBST};
+
+/// [IterativeBST] with a small least-recently-used cache in front of
+/// [contains](BinarySearchTree::contains()) and [retrieve](BinarySearchTree::retrieve()).
+///
+/// The cache keeps clones of recently found elements, so lookups of those are answered without
+/// descending the tree, comparing the probe against a handful of elements instead of one element
+/// per level of the tree. This pays off for access patterns hitting the same few elements over
+/// and over again, more so with expensive comparisons. Misses are not cached.
+///
+/// Lookups hand out references to the cached clones, so they can only add elements while the
+/// cache has room left, never replace one. Removals and
+/// [retrieve_as_mut](BinarySearchTree::retrieve_as_mut()) drop the element they affect from the
+/// cache, insertions keep it as it is, and [CachedBST::clear_cache()] makes room for a new set
+/// of elements.
+///
+/// Lookups update the cache through `&self`, so unlike [IterativeBST] the tree can be moved to
+/// another thread but not shared between threads:
+///
+/// ```compile_fail
+/// use bst_rs::CachedBST;
+///
+/// fn share<T: Sync>(_: &T) {}
+/// share(&CachedBST::<i32>::new());
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{BinarySearchTree, CachedBST};
+///
+/// let mut bst = CachedBST::with_cache_capacity(2);
+/// bst.extend(1..=1000);
+///
+/// for _ in 0..10_000 {
+///     assert!(bst.contains(&500));
+///     assert_eq!(bst.retrieve(&42), Some(&42));
+/// }
+/// ```
+pub struct CachedBST<T: Ord> {
+    bst: IterativeBST<T>,
+    /// Clones of cached elements of `bst`.
+    ///
+    /// Slots are only filled through `&self` and only emptied through `&mut self`, so lookups can
+    /// lend the cached elements for as long as the tree is borrowed.
+    slots: Box<[OnceCell<T>]>,
+    /// Indices of the filled `slots`, most recently used first.
+    recent: RefCell<VecDeque<usize>>,
+}
+
+impl<T: Ord> CachedBST<T> {
+    /// Creates an empty `CachedBST<T>` caching a single element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let bst: CachedBST<i32> = CachedBST::new();
+    /// assert!(bst.is_empty());
+    /// assert_eq!(bst.cache_capacity(), 1);
+    /// ```
+    pub fn new() -> CachedBST<T> {
+        CachedBST::with_cache_capacity(1)
+    }
+
+    /// Creates an empty `CachedBST<T>` caching up to `capacity` elements.
+    ///
+    /// A capacity of `0` disables caching.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::CachedBST;
+    ///
+    /// let bst: CachedBST<i32> = CachedBST::with_cache_capacity(8);
+    /// assert_eq!(bst.cache_capacity(), 8);
+    /// ```
+    pub fn with_cache_capacity(capacity: usize) -> CachedBST<T> {
+        CachedBST {
+            bst: IterativeBST::new(),
+            slots: (0..capacity).map(|_| OnceCell::new()).collect(),
+            recent: RefCell::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    /// Returns the maximum number of cached elements.
+    pub fn cache_capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Drops all cached elements, making room for the ones looked up next.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::from_iter(vec![2, 1, 3]);
+    /// assert!(bst.contains(&1));
+    ///
+    /// bst.clear_cache();
+    /// assert!(bst.contains(&3));
+    /// ```
+    pub fn clear_cache(&mut self) {
+        self.slots.iter_mut().for_each(|slot| drop(slot.take()));
+        self.recent.get_mut().clear();
+    }
+
+    /// Returns the underlying [IterativeBST], dropping the cache.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let bst = CachedBST::from_iter(vec![2, 1]);
+    ///
+    /// assert_eq!(bst.into_inner().asc_order_vec(), vec![&1, &2]);
+    /// ```
+    pub fn into_inner(self) -> IterativeBST<T> {
+        self.bst
+    }
+
+    fn cached(&self, value: &T) -> Option<&T> {
+        let mut recent = self.recent.borrow_mut();
+        let position = recent
+            .iter()
+            .position(|&slot| self.slots[slot].get() == Some(value))?;
+        let slot = recent.remove(position).unwrap();
+        recent.push_front(slot);
+
+        self.slots[slot].get()
+    }
+
+    /// Drops the cached clone of `value`, if any.
+    fn forget(&mut self, value: &T) {
+        let slots = &mut self.slots;
+        self.recent.get_mut().retain(|&slot| {
+            if slots[slot].get() != Some(value) {
+                return true;
+            }
+            slots[slot].take();
+            false
+        });
+    }
+}
+
+impl<T: Ord + Clone> CachedBST<T> {
+    /// Caches a clone of `element` if there is room left, returning the clone if so and
+    /// `element` otherwise.
+    fn remember<'a>(&'a self, element: &'a T) -> &'a T {
+        let Some(slot) = self.slots.iter().position(|slot| slot.get().is_none()) else {
+            return element;
+        };
+        self.recent.borrow_mut().push_front(slot);
+        self.slots[slot].get_or_init(|| element.clone())
+    }
+}
+
+impl<T: Ord> Default for CachedBST<T> {
+    /// Creates an empty `CachedBST<T>`
+    fn default() -> CachedBST<T> {
+        CachedBST::new()
+    }
+}
+
+impl<T: Ord + Debug> Debug for CachedBST<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CachedBST")
+            .field("bst", &self.bst)
+            .field("capacity", &self.cache_capacity())
+            .finish()
+    }
+}
+
+impl<T: Ord> PartialEq for CachedBST<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bst == other.bst
+    }
+}
+
+impl<T: Ord + Clone> Extend<T> for CachedBST<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter.into_iter() {
+            self.insert(value)
+        }
+    }
+}
+
+impl<T: Ord + Clone> FromIterator<T> for CachedBST<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bst = CachedBST::new();
+        bst.extend(iter);
+        bst
+    }
+}
+
+impl<T: Ord> From<IterativeBST<T>> for CachedBST<T> {
+    fn from(bst: IterativeBST<T>) -> Self {
+        CachedBST {
+            bst,
+            ..CachedBST::new()
+        }
+    }
+}
+
+impl<T: Ord + Clone> Clone for CachedBST<T> {
+    fn clone(&self) -> Self {
+        CachedBST {
+            bst: self.bst.clone(),
+            ..CachedBST::with_cache_capacity(self.cache_capacity())
+        }
+    }
+}
+
+impl<T: Ord + Debug> Display for CachedBST<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.bst)
+    }
+}
+
+impl<T: Ord + Clone> BinarySearchTree<T> for CachedBST<T> {
+    /// Returns the total **number of nodes** within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(5);
+    /// bst.insert(10);
+    /// bst.insert(3);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// ```
+    fn size(&self) -> usize {
+        self.bst.size()
+    }
+
+    /// Returns `true` if the binary search tree contains no nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst: CachedBST<i32> = CachedBST::new();
+    /// assert!(bst.is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        self.bst.is_empty()
+    }
+
+    /// Returns `true` if the binary search tree contains one or more nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// assert!(bst.is_empty());
+    ///
+    /// bst.insert(2);
+    ///
+    /// assert!(bst.is_not_empty());
+    /// ```
+    fn is_not_empty(&self) -> bool {
+        self.bst.is_not_empty()
+    }
+
+    /// Inserts given value as a node.
+    ///
+    /// **Duplicate values are _not allowed_**.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    ///
+    /// bst.insert(10);
+    /// bst.insert(10);   // Element is not inserted
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(15);
+    /// bst.insert(25);
+    ///
+    /// assert_eq!(bst.size(), 5);
+    /// ```
+    fn insert(&mut self, value: T) {
+        self.bst.insert(value)
+    }
+
+    /// Returns `true` if the binary search tree contains an element with the given value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert!(bst.contains(&5));
+    /// assert!(!bst.contains(&10));
+    /// ```
+    fn contains(&self, value: &T) -> bool {
+        self.retrieve(value).is_some()
+    }
+
+    /// Removes the given value.
+    ///
+    /// Tree will not be modified if trying to remove element that does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// assert_eq!(bst.size(), 3);
+    ///
+    /// bst.remove(&5);
+    /// bst.remove(&10); // Element is not removed
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove(&mut self, value: &T) {
+        self.forget(value);
+        self.bst.remove(value)
+    }
+
+    /// Returns a reference to the element or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert_eq!(bst.retrieve(&5), Some(&5));
+    /// assert_eq!(bst.retrieve(&10), None);
+    /// ```
+    fn retrieve(&self, value: &T) -> Option<&T> {
+        if let Some(cached) = self.cached(value) {
+            return Some(cached);
+        }
+        Some(self.remember(self.bst.retrieve(value)?))
+    }
+
+    /// Returns a mutable reference to the element (see [CachedBST::retrieve()])
+    /// or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(10);
+    /// bst.insert(5);
+    ///
+    /// let optional_retrieved_value_as_mut = bst.retrieve_as_mut(&5);
+    /// assert_eq!(optional_retrieved_value_as_mut, Some(&mut 5));
+    ///
+    /// let mut retrieved_value = optional_retrieved_value_as_mut.unwrap();
+    /// *retrieved_value = 2; // Change value inside tree to '2'
+    ///
+    /// assert_eq!(bst.retrieve_as_mut(&5), None); // 5 does not exist anymore
+    /// assert_eq!(bst.retrieve_as_mut(&2), Some(&mut 2));
+    /// ```
+    fn retrieve_as_mut(&mut self, value: &T) -> Option<&mut T> {
+        self.forget(value);
+        self.bst.retrieve_as_mut(value)
+    }
+
+    /// Returns the **height** or `None` if tree is empty.
+    ///
+    /// The height is the number of edges between the root and it's furthest leaf node.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = CachedBST::new();
+    /// assert_eq!(bst.height(), None);
+    ///
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The height is 2.
+    /// assert_eq!(bst.height(), Some(2));
+    /// ```
+    fn height(&self) -> Option<isize> {
+        self.bst.height()
+    }
+
+    /// Returns a reference to the minimum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// assert_eq!(bst.min(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.min(), Some(&2));
+    /// ```
+    fn min(&self) -> Option<&T> {
+        self.bst.min()
+    }
+
+    /// Returns a reference to the maximum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// assert_eq!(bst.max(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.max(), Some(&10));
+    /// ```
+    fn max(&self) -> Option<&T> {
+        self.bst.max()
+    }
+
+    /// Removes and returns the minimum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// assert_eq!(bst.remove_min(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_min(), Some(2));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_min(&mut self) -> Option<T> {
+        let removed = self.bst.remove_min()?;
+        self.forget(&removed);
+        Some(removed)
+    }
+
+    /// Removes and returns the maximum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// assert_eq!(bst.remove_max(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_max(), Some(10));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_max(&mut self) -> Option<T> {
+        let removed = self.bst.remove_max()?;
+        self.forget(&removed);
+        Some(removed)
+    }
+
+    /// Returns references to the elements of the tree in **ascending order.**`
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [CachedBST::in_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn asc_order_vec(&self) -> Vec<&T> {
+        self.bst.asc_order_vec()
+    }
+
+    /// Returns references to the elements of the tree in the order of a **pre-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The pre_order_vec is: [&4, &2, &1, &3, &6, &5, &7]
+    /// assert_eq!(bst.pre_order_vec(), vec![&4, &2, &1, &3, &6, &5, &7]);
+    /// ```
+    fn pre_order_vec(&self) -> Vec<&T> {
+        self.bst.pre_order_vec()
+    }
+
+    /// Returns references to the elements of the tree in the order of an **in-order traversal.**
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [CachedBST::asc_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The in_order_vec is: [&1, &2, &3, &4, &5, &6, &7]
+    /// assert_eq!(bst.in_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn in_order_vec(&self) -> Vec<&T> {
+        self.bst.in_order_vec()
+    }
+
+    /// Returns references to the elements of the tree in the order of a **post-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The post_order_vec is: [&1, &3, &2, &5, &7, &6, &4]
+    /// assert_eq!(bst.post_order_vec(), vec![&1, &3, &2, &5, &7, &6, &4]);
+    /// ```
+    fn post_order_vec(&self) -> Vec<&T> {
+        self.bst.post_order_vec()
+    }
+
+    /// Returns references to the elements of the tree in the order of a **level-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The level_order_vec is: [&4, &2, &6, &1, &3, &5, &7]
+    /// assert_eq!(bst.level_order_vec(), vec![&4, &2, &6, &1, &3, &5, &7]);
+    /// ```
+    fn level_order_vec(&self) -> Vec<&T> {
+        self.bst.level_order_vec()
+    }
+
+    /// Returns an iterator over [CachedBST::asc_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [CachedBST::in_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut asc_order_iter = bst.asc_order_iter();
+    ///
+    /// assert_eq!(asc_order_iter.next(), Some(&1));
+    /// assert_eq!(asc_order_iter.next(), Some(&2));
+    /// assert_eq!(asc_order_iter.next(), Some(&3));
+    /// assert_eq!(asc_order_iter.next(), Some(&4));
+    /// assert_eq!(asc_order_iter.next(), Some(&5));
+    /// assert_eq!(asc_order_iter.next(), None);
+    /// ```
+    fn asc_order_iter(&self) -> IntoIter<&T> {
+        self.bst.asc_order_iter()
+    }
+
+    /// Returns an iterator over [CachedBST::pre_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut pre_order_iter = bst.pre_order_iter();
+    ///
+    /// assert_eq!(pre_order_iter.next(), Some(&3));
+    /// assert_eq!(pre_order_iter.next(), Some(&1));
+    /// assert_eq!(pre_order_iter.next(), Some(&2));
+    /// assert_eq!(pre_order_iter.next(), Some(&4));
+    /// assert_eq!(pre_order_iter.next(), Some(&5));
+    /// assert_eq!(pre_order_iter.next(), None);
+    /// ```
+    fn pre_order_iter(&self) -> IntoIter<&T> {
+        self.bst.pre_order_iter()
+    }
+
+    /// Returns an iterator over [CachedBST::in_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [CachedBST::asc_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut in_order_iter = bst.in_order_iter();
+    ///
+    /// assert_eq!(in_order_iter.next(), Some(&1));
+    /// assert_eq!(in_order_iter.next(), Some(&2));
+    /// assert_eq!(in_order_iter.next(), Some(&3));
+    /// assert_eq!(in_order_iter.next(), Some(&4));
+    /// assert_eq!(in_order_iter.next(), Some(&5));
+    /// assert_eq!(in_order_iter.next(), None);
+    /// ```
+    fn in_order_iter(&self) -> IntoIter<&T> {
+        self.bst.in_order_iter()
+    }
+
+    /// Returns an iterator over [CachedBST::post_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut post_order_iter = bst.post_order_iter();
+    ///
+    /// assert_eq!(post_order_iter.next(), Some(&2));
+    /// assert_eq!(post_order_iter.next(), Some(&1));
+    /// assert_eq!(post_order_iter.next(), Some(&5));
+    /// assert_eq!(post_order_iter.next(), Some(&4));
+    /// assert_eq!(post_order_iter.next(), Some(&3));
+    /// assert_eq!(post_order_iter.next(), None);
+    /// ```
+    fn post_order_iter(&self) -> IntoIter<&T> {
+        self.bst.post_order_iter()
+    }
+
+    /// Returns an iterator over [CachedBST::level_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut level_order_iter = bst.level_order_iter();
+    ///
+    /// assert_eq!(level_order_iter.next(), Some(&3));
+    /// assert_eq!(level_order_iter.next(), Some(&1));
+    /// assert_eq!(level_order_iter.next(), Some(&4));
+    /// assert_eq!(level_order_iter.next(), Some(&2));
+    /// assert_eq!(level_order_iter.next(), Some(&5));
+    /// assert_eq!(level_order_iter.next(), None);
+    /// ```
+    fn level_order_iter(&self) -> IntoIter<&T> {
+        self.bst.level_order_iter()
+    }
+
+    /// Returns [CachedBST::asc_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [CachedBST::into_in_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_asc_order_iter = bst.into_asc_order_iter();
+    ///
+    /// assert_eq!(into_asc_order_iter.next(), Some(1));
+    /// assert_eq!(into_asc_order_iter.next(), Some(2));
+    /// assert_eq!(into_asc_order_iter.next(), Some(3));
+    /// assert_eq!(into_asc_order_iter.next(), Some(4));
+    /// assert_eq!(into_asc_order_iter.next(), Some(5));
+    /// assert_eq!(into_asc_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_asc_order_iter(self) -> IntoIter<T> {
+        self.bst.into_asc_order_iter()
+    }
+
+    /// Returns [CachedBST::pre_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_pre_order_iter = bst.into_pre_order_iter();
+    ///
+    /// assert_eq!(into_pre_order_iter.next(), Some(3));
+    /// assert_eq!(into_pre_order_iter.next(), Some(1));
+    /// assert_eq!(into_pre_order_iter.next(), Some(2));
+    /// assert_eq!(into_pre_order_iter.next(), Some(4));
+    /// assert_eq!(into_pre_order_iter.next(), Some(5));
+    /// assert_eq!(into_pre_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_pre_order_iter(self) -> IntoIter<T> {
+        self.bst.into_pre_order_iter()
+    }
+
+    /// Returns [CachedBST::in_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [CachedBST::asc_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_in_order_iter = bst.into_in_order_iter();
+    ///
+    /// assert_eq!(into_in_order_iter.next(), Some(1));
+    /// assert_eq!(into_in_order_iter.next(), Some(2));
+    /// assert_eq!(into_in_order_iter.next(), Some(3));
+    /// assert_eq!(into_in_order_iter.next(), Some(4));
+    /// assert_eq!(into_in_order_iter.next(), Some(5));
+    /// assert_eq!(into_in_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_in_order_iter(self) -> IntoIter<T> {
+        self.bst.into_in_order_iter()
+    }
+
+    /// Returns [CachedBST::post_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_post_order_iter = bst.into_post_order_iter();
+    ///
+    /// assert_eq!(into_post_order_iter.next(), Some(2));
+    /// assert_eq!(into_post_order_iter.next(), Some(1));
+    /// assert_eq!(into_post_order_iter.next(), Some(5));
+    /// assert_eq!(into_post_order_iter.next(), Some(4));
+    /// assert_eq!(into_post_order_iter.next(), Some(3));
+    /// assert_eq!(into_post_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_post_order_iter(self) -> IntoIter<T> {
+        self.bst.into_post_order_iter()
+    }
+
+    /// Returns [CachedBST::level_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, CachedBST};
+    ///
+    /// let mut bst = CachedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_level_order_iter = bst.into_level_order_iter();
+    ///
+    /// assert_eq!(into_level_order_iter.next(), Some(3));
+    /// assert_eq!(into_level_order_iter.next(), Some(1));
+    /// assert_eq!(into_level_order_iter.next(), Some(4));
+    /// assert_eq!(into_level_order_iter.next(), Some(2));
+    /// assert_eq!(into_level_order_iter.next(), Some(5));
+    /// assert_eq!(into_level_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_level_order_iter(self) -> IntoIter<T> {
+        self.bst.into_level_order_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BinarySearchTree, CachedBST, IterativeBST};
+
+    fn cached(bst: &CachedBST<i32>) -> Vec<i32> {
+        bst.recent
+            .borrow()
+            .iter()
+            .map(|&slot| *bst.slots[slot].get().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn successfully_answer_repeated_lookups_from_cache() {
+        let mut bst = CachedBST::with_cache_capacity(2);
+        bst.extend(vec![5, 3, 8, 1]);
+
+        assert_eq!(bst.retrieve(&1), Some(&1));
+        assert_eq!(bst.retrieve(&8), Some(&8));
+        assert_eq!(cached(&bst), vec![8, 1]);
+
+        assert!(bst.contains(&1));
+        assert_eq!(bst.retrieve(&5), Some(&5));
+        assert_eq!(cached(&bst), vec![1, 8]);
+
+        let retrieved = bst.retrieve(&8).unwrap();
+        assert!(std::ptr::eq(retrieved, bst.slots[1].get().unwrap()));
+    }
+
+    #[test]
+    fn successfully_not_cache_misses() {
+        let bst = CachedBST::from_iter(vec![2, 1]);
+
+        assert!(!bst.contains(&3));
+        assert!(cached(&bst).is_empty());
+    }
+
+    #[test]
+    fn successfully_keep_cache_on_insert() {
+        let mut bst = CachedBST::with_cache_capacity(2);
+        bst.extend(vec![4, 2, 6]);
+        assert!(bst.contains(&2));
+
+        bst.extend(vec![1, 3, 5, 7]);
+
+        assert_eq!(cached(&bst), vec![2]);
+        assert!(bst.contains(&7));
+        assert_eq!(cached(&bst), vec![7, 2]);
+    }
+
+    #[test]
+    fn successfully_forget_removed_and_mutated_elements() {
+        let mut bst = CachedBST::with_cache_capacity(4);
+        bst.extend(vec![4, 2, 6, 5, 7]);
+        for value in [2, 4, 5, 6] {
+            assert!(bst.contains(&value));
+        }
+
+        bst.remove(&4);
+        assert_eq!(cached(&bst), vec![6, 5, 2]);
+        assert!(!bst.contains(&4));
+
+        assert_eq!(bst.remove_min(), Some(2));
+        assert!(!bst.contains(&2));
+        assert_eq!(cached(&bst), vec![6, 5]);
+
+        *bst.retrieve_as_mut(&5).unwrap() = 3;
+        assert_eq!(cached(&bst), vec![6]);
+        assert!(!bst.contains(&5));
+        assert!(bst.contains(&3));
+
+        assert_eq!(bst.remove_max(), Some(7));
+        bst.clear_cache();
+        assert!(cached(&bst).is_empty());
+        assert_eq!(bst.into_inner(), IterativeBST::from_iter(vec![3, 6]));
+    }
+
+    #[test]
+    fn successfully_disable_cache_with_zero_capacity() {
+        let mut bst = CachedBST::with_cache_capacity(0);
+        bst.insert(1);
+
+        assert!(bst.contains(&1));
+        assert!(cached(&bst).is_empty());
+    }
+}
@@ -0,0 +1,1202 @@
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::fmt::{Debug, Display, Formatter};
+use std::vec::IntoIter;
+
+use crate::BinarySearchTree;
+
+/// Minimum degree of the tree, every node but the root holds between `MIN_DEGREE - 1` and
+/// `2 * MIN_DEGREE - 1` elements.
+const MIN_DEGREE: usize = 6;
+const MAX_ELEMENTS: usize = 2 * MIN_DEGREE - 1;
+
+#[derive(Debug, Clone)]
+struct Chunk<T> {
+    elements: Vec<T>,
+    children: Vec<Chunk<T>>,
+}
+
+impl<T: Ord> Chunk<T> {
+    fn leaf(elements: Vec<T>) -> Chunk<T> {
+        Chunk {
+            elements,
+            children: Vec::new(),
+        }
+    }
+
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    fn is_full(&self) -> bool {
+        self.elements.len() == MAX_ELEMENTS
+    }
+
+    /// Splits the full child at `index` around its median, which moves up into `self`.
+    fn split_child(&mut self, index: usize) {
+        let child = &mut self.children[index];
+        let right = Chunk {
+            elements: child.elements.split_off(MIN_DEGREE),
+            children: if child.is_leaf() {
+                Vec::new()
+            } else {
+                child.children.split_off(MIN_DEGREE)
+            },
+        };
+        let median = child.elements.pop().unwrap();
+
+        self.elements.insert(index, median);
+        self.children.insert(index + 1, right);
+    }
+
+    /// Merges the child at `index + 1` and the separating element into the child at `index`.
+    fn merge_children(&mut self, index: usize) {
+        let right = self.children.remove(index + 1);
+        let separator = self.elements.remove(index);
+        let left = &mut self.children[index];
+
+        left.elements.push(separator);
+        left.elements.extend(right.elements);
+        left.children.extend(right.children);
+    }
+
+    /// Makes sure the child at `index` can lose an element by borrowing from or merging with a
+    /// sibling, returning the index of the child which now covers the original one.
+    fn fill_child(&mut self, index: usize) -> usize {
+        if self.children[index].elements.len() >= MIN_DEGREE {
+            return index;
+        }
+
+        if index > 0 && self.children[index - 1].elements.len() >= MIN_DEGREE {
+            let (left, right) = self.children.split_at_mut(index);
+            let (sibling, child) = (&mut left[index - 1], &mut right[0]);
+            let separator = std::mem::replace(
+                &mut self.elements[index - 1],
+                sibling.elements.pop().unwrap(),
+            );
+            child.elements.insert(0, separator);
+            if let Some(grandchild) = sibling.children.pop() {
+                child.children.insert(0, grandchild);
+            }
+            index
+        } else if index + 1 < self.children.len()
+            && self.children[index + 1].elements.len() >= MIN_DEGREE
+        {
+            let (left, right) = self.children.split_at_mut(index + 1);
+            let (child, sibling) = (&mut left[index], &mut right[0]);
+            let separator =
+                std::mem::replace(&mut self.elements[index], sibling.elements.remove(0));
+            child.elements.push(separator);
+            if !sibling.is_leaf() {
+                child.children.push(sibling.children.remove(0));
+            }
+            index
+        } else if index + 1 < self.children.len() {
+            self.merge_children(index);
+            index
+        } else {
+            self.merge_children(index - 1);
+            index - 1
+        }
+    }
+
+    /// Removes the minimum of a subtree whose root can lose an element.
+    fn remove_min(mut chunk: &mut Chunk<T>) -> T {
+        while !chunk.is_leaf() {
+            let index = chunk.fill_child(0);
+            chunk = &mut chunk.children[index];
+        }
+        chunk.elements.remove(0)
+    }
+
+    /// Removes the maximum of a subtree whose root can lose an element.
+    fn remove_max(mut chunk: &mut Chunk<T>) -> T {
+        while !chunk.is_leaf() {
+            let index = chunk.fill_child(chunk.children.len() - 1);
+            chunk = &mut chunk.children[index];
+        }
+        chunk.elements.pop().unwrap()
+    }
+
+    fn remove(mut chunk: &mut Chunk<T>, value: &T) -> Option<T> {
+        loop {
+            match chunk.elements.binary_search(value) {
+                Err(_) if chunk.is_leaf() => return None,
+                Err(index) => {
+                    let index = chunk.fill_child(index);
+                    chunk = &mut chunk.children[index];
+                }
+                Ok(index) if chunk.is_leaf() => return Some(chunk.elements.remove(index)),
+                Ok(index) => {
+                    if chunk.children[index].elements.len() >= MIN_DEGREE {
+                        let predecessor = Chunk::remove_max(&mut chunk.children[index]);
+                        return Some(std::mem::replace(&mut chunk.elements[index], predecessor));
+                    }
+                    if chunk.children[index + 1].elements.len() >= MIN_DEGREE {
+                        let successor = Chunk::remove_min(&mut chunk.children[index + 1]);
+                        return Some(std::mem::replace(&mut chunk.elements[index], successor));
+                    }
+                    chunk.merge_children(index);
+                    chunk = &mut chunk.children[index];
+                }
+            }
+        }
+    }
+}
+
+/// Binary Search Tree variant storing small sorted arrays of elements in every node, in the
+/// manner of a B-tree.
+///
+/// Every node holds up to 11 elements and has one more child than elements, which keeps the
+/// tree perfectly balanced and much shallower than a binary tree. Lookups mostly scan
+/// contiguous memory instead of chasing a pointer per comparison.
+///
+/// # Important
+///
+/// Traversals visit whole nodes: pre-order yields the elements of a node before those of its
+/// children, post-order after them and level-order yields the nodes level by level. In-order
+/// traversal is still ascending. The height counts node levels below the root.
+#[derive(Debug)]
+pub struct ChunkedBST<T: Ord> {
+    root: Option<Chunk<T>>,
+    size: usize,
+}
+
+impl<T: Ord> ChunkedBST<T> {
+    /// Creates an empty `ChunkedBST<T>`
+    ///
+    /// No nodes are allocated on the heap yet
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// // Empty tree is created
+    /// let mut bst: ChunkedBST<i32> = ChunkedBST::new();
+    /// assert!(bst.is_empty())
+    /// ```
+    pub const fn new() -> ChunkedBST<T> {
+        ChunkedBST {
+            root: None,
+            size: 0,
+        }
+    }
+
+    /// Replaces a root which lost its last element by its only child, if any.
+    fn shrink_root(&mut self) {
+        if let Some(root) = &mut self.root {
+            if root.elements.is_empty() {
+                self.root = root.children.pop();
+            }
+        }
+    }
+
+    fn chunks_pre_order(&self) -> Vec<&Chunk<T>> {
+        let mut chunks = Vec::new();
+        let mut stack: Vec<&Chunk<T>> = self.root.iter().collect();
+        while let Some(chunk) = stack.pop() {
+            chunks.push(chunk);
+            stack.extend(chunk.children.iter().rev());
+        }
+        chunks
+    }
+
+    fn chunks_post_order(&self) -> Vec<&Chunk<T>> {
+        let mut chunks = Vec::new();
+        let mut stack: Vec<&Chunk<T>> = self.root.iter().collect();
+        while let Some(chunk) = stack.pop() {
+            chunks.push(chunk);
+            stack.extend(chunk.children.iter());
+        }
+        chunks.reverse();
+        chunks
+    }
+
+    fn chunks_level_order(&self) -> Vec<&Chunk<T>> {
+        let mut chunks = Vec::new();
+        let mut queue: VecDeque<&Chunk<T>> = self.root.iter().collect();
+        while let Some(chunk) = queue.pop_front() {
+            chunks.push(chunk);
+            queue.extend(chunk.children.iter());
+        }
+        chunks
+    }
+
+    fn sorted_elements(&self) -> Vec<&T> {
+        let mut elements = Vec::with_capacity(self.size);
+        let mut stack = Vec::new();
+        if let Some(root) = &self.root {
+            stack.push((root.elements.iter(), root.children.iter(), true));
+        }
+
+        while let Some((chunk_elements, children, descend)) = stack.last_mut() {
+            if *descend {
+                *descend = false;
+                if let Some(child) = children.next() {
+                    stack.push((child.elements.iter(), child.children.iter(), true));
+                }
+            } else if let Some(element) = chunk_elements.next() {
+                elements.push(element);
+                *descend = true;
+            } else {
+                stack.pop();
+            }
+        }
+
+        elements
+    }
+
+    fn into_in_order_vec(self) -> Vec<T> {
+        let mut elements = Vec::with_capacity(self.size);
+        let mut stack = Vec::new();
+        if let Some(root) = self.root {
+            stack.push((root.elements.into_iter(), root.children.into_iter(), true));
+        }
+
+        while let Some((chunk_elements, children, descend)) = stack.last_mut() {
+            if *descend {
+                *descend = false;
+                if let Some(child) = children.next() {
+                    stack.push((child.elements.into_iter(), child.children.into_iter(), true));
+                }
+            } else if let Some(element) = chunk_elements.next() {
+                elements.push(element);
+                *descend = true;
+            } else {
+                stack.pop();
+            }
+        }
+
+        elements
+    }
+
+    fn into_pre_order_vec(self) -> Vec<T> {
+        let mut elements = Vec::with_capacity(self.size);
+        let mut stack: Vec<Chunk<T>> = self.root.into_iter().collect();
+        while let Some(chunk) = stack.pop() {
+            stack.extend(chunk.children.into_iter().rev());
+            elements.extend(chunk.elements);
+        }
+        elements
+    }
+
+    fn into_post_order_vec(self) -> Vec<T> {
+        let mut chunks = Vec::new();
+        let mut stack: Vec<Chunk<T>> = self.root.into_iter().collect();
+        while let Some(chunk) = stack.pop() {
+            stack.extend(chunk.children);
+            chunks.push(chunk.elements);
+        }
+        chunks.into_iter().rev().flatten().collect()
+    }
+
+    fn into_level_order_vec(self) -> Vec<T> {
+        let mut elements = Vec::with_capacity(self.size);
+        let mut queue: VecDeque<Chunk<T>> = self.root.into_iter().collect();
+        while let Some(chunk) = queue.pop_front() {
+            queue.extend(chunk.children);
+            elements.extend(chunk.elements);
+        }
+        elements
+    }
+}
+
+fn elements<T>(chunks: Vec<&Chunk<T>>) -> Vec<&T> {
+    chunks
+        .into_iter()
+        .flat_map(|chunk| &chunk.elements)
+        .collect()
+}
+
+impl<T: Ord> Default for ChunkedBST<T> {
+    /// Creates an empty `ChunkedBST<T>`
+    fn default() -> ChunkedBST<T> {
+        ChunkedBST::new()
+    }
+}
+
+impl<T: Ord> PartialEq for ChunkedBST<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.asc_order_vec() == other.asc_order_vec()
+    }
+}
+
+impl<T: Ord> Extend<T> for ChunkedBST<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter.into_iter() {
+            self.insert(value)
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for ChunkedBST<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bst = ChunkedBST::new();
+        bst.extend(iter);
+        bst
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for ChunkedBST<T> {
+    fn from(vec: Vec<T>) -> Self {
+        let mut bst = ChunkedBST::new();
+        for value in vec.into_iter() {
+            bst.insert(value);
+        }
+        bst
+    }
+}
+
+impl<T: Ord + Clone> From<&[T]> for ChunkedBST<T> {
+    fn from(slice: &[T]) -> Self {
+        let mut bst = ChunkedBST::new();
+        for value in slice {
+            bst.insert((*value).clone());
+        }
+        bst
+    }
+}
+
+impl<T: Ord + Clone> Clone for ChunkedBST<T> {
+    fn clone(&self) -> Self {
+        ChunkedBST {
+            root: self.root.clone(),
+            size: self.size,
+        }
+    }
+}
+
+impl<T: Ord + Debug> Display for ChunkedBST<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.asc_order_vec())
+    }
+}
+
+impl<T: Ord> BinarySearchTree<T> for ChunkedBST<T> {
+    /// Returns the total **number of elements** within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// let mut bst = ChunkedBST::new();
+    /// bst.insert(5);
+    /// bst.insert(10);
+    /// bst.insert(3);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// ```
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the binary search tree contains no nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// let mut bst: ChunkedBST<i32> = ChunkedBST::new();
+    /// assert!(bst.is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns `true` if the binary search tree contains one or more nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// let mut bst = ChunkedBST::new();
+    /// assert!(bst.is_empty());
+    ///
+    /// bst.insert(2);
+    ///
+    /// assert!(bst.is_not_empty());
+    /// ```
+    fn is_not_empty(&self) -> bool {
+        self.size != 0
+    }
+
+    /// Inserts given value as a node.
+    ///
+    /// **Duplicate values are _not allowed_**.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// let mut bst = ChunkedBST::new();
+    ///
+    /// bst.insert(10);
+    /// bst.insert(10);   // Element is not inserted
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(15);
+    /// bst.insert(25);
+    ///
+    /// assert_eq!(bst.size(), 5);
+    /// ```
+    fn insert(&mut self, value: T) {
+        let root = match &mut self.root {
+            None => {
+                self.root = Some(Chunk::leaf(vec![value]));
+                self.size += 1;
+                return;
+            }
+            Some(root) => root,
+        };
+
+        if root.is_full() {
+            let old_root = std::mem::replace(root, Chunk::leaf(Vec::new()));
+            root.children.push(old_root);
+            root.split_child(0);
+        }
+
+        let mut chunk = root;
+        loop {
+            let mut index = match chunk.elements.binary_search(&value) {
+                Ok(_) => return,
+                Err(index) => index,
+            };
+            if chunk.is_leaf() {
+                chunk.elements.insert(index, value);
+                self.size += 1;
+                return;
+            }
+            if chunk.children[index].is_full() {
+                chunk.split_child(index);
+                match value.cmp(&chunk.elements[index]) {
+                    Ordering::Equal => return,
+                    Ordering::Greater => index += 1,
+                    Ordering::Less => {}
+                }
+            }
+            chunk = &mut chunk.children[index];
+        }
+    }
+
+    /// Returns `true` if the binary search tree contains an element with the given value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// let mut bst = ChunkedBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert!(bst.contains(&5));
+    /// assert!(!bst.contains(&10));
+    /// ```
+    fn contains(&self, value: &T) -> bool {
+        self.retrieve(value).is_some()
+    }
+
+    /// Removes the given value.
+    ///
+    /// Tree will not be modified if trying to remove element that does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// let mut bst = ChunkedBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// assert_eq!(bst.size(), 3);
+    ///
+    /// bst.remove(&5);
+    /// bst.remove(&10); // Element is not removed
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove(&mut self, value: &T) {
+        if let Some(root) = &mut self.root {
+            if Chunk::remove(root, value).is_some() {
+                self.size -= 1;
+            }
+        }
+        self.shrink_root();
+    }
+
+    /// Returns a reference to the element or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// let mut bst = ChunkedBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert_eq!(bst.retrieve(&5), Some(&5));
+    /// assert_eq!(bst.retrieve(&10), None);
+    /// ```
+    fn retrieve(&self, value: &T) -> Option<&T> {
+        let mut chunk = self.root.as_ref()?;
+        loop {
+            match chunk.elements.binary_search(value) {
+                Ok(index) => return Some(&chunk.elements[index]),
+                Err(index) => chunk = chunk.children.get(index)?,
+            }
+        }
+    }
+
+    /// Returns a mutable reference to the element (see [ChunkedBST::retrieve()])
+    /// or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// let mut bst = ChunkedBST::new();
+    /// bst.insert(10);
+    /// bst.insert(5);
+    ///
+    /// let optional_retrieved_value_as_mut = bst.retrieve_as_mut(&5);
+    /// assert_eq!(optional_retrieved_value_as_mut, Some(&mut 5));
+    ///
+    /// let mut retrieved_value = optional_retrieved_value_as_mut.unwrap();
+    /// *retrieved_value = 2; // Change value inside tree to '2'
+    ///
+    /// assert_eq!(bst.retrieve_as_mut(&5), None); // 5 does not exist anymore
+    /// assert_eq!(bst.retrieve_as_mut(&2), Some(&mut 2));
+    /// ```
+    fn retrieve_as_mut(&mut self, value: &T) -> Option<&mut T> {
+        let mut chunk = self.root.as_mut()?;
+        loop {
+            match chunk.elements.binary_search(value) {
+                Ok(index) => return Some(&mut chunk.elements[index]),
+                Err(index) => chunk = chunk.children.get_mut(index)?,
+            }
+        }
+    }
+
+    /// Returns the **height** or `None` if tree is empty.
+    ///
+    /// The height is the number of edges between the root and it's furthest leaf node.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// let mut bst = ChunkedBST::new();
+    /// assert_eq!(bst.height(), None);
+    ///
+    /// // A single node holds up to 11 elements
+    /// bst.extend(0..11);
+    /// assert_eq!(bst.height(), Some(0));
+    ///
+    /// // The 12th element splits it into a root and two leaves
+    /// bst.insert(11);
+    /// assert_eq!(bst.height(), Some(1));
+    /// ```
+    fn height(&self) -> Option<isize> {
+        let mut chunk = self.root.as_ref()?;
+        let mut height = 0;
+        while let Some(child) = chunk.children.first() {
+            chunk = child;
+            height += 1;
+        }
+        Some(height)
+    }
+
+    /// Returns a reference to the minimum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// let mut bst = ChunkedBST::new();
+    /// assert_eq!(bst.min(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.min(), Some(&2));
+    /// ```
+    fn min(&self) -> Option<&T> {
+        let mut chunk = self.root.as_ref()?;
+        while let Some(child) = chunk.children.first() {
+            chunk = child;
+        }
+        chunk.elements.first()
+    }
+
+    /// Returns a reference to the maximum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// let mut bst = ChunkedBST::new();
+    /// assert_eq!(bst.max(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.max(), Some(&10));
+    /// ```
+    fn max(&self) -> Option<&T> {
+        let mut chunk = self.root.as_ref()?;
+        while let Some(child) = chunk.children.last() {
+            chunk = child;
+        }
+        chunk.elements.last()
+    }
+
+    /// Removes and returns the minimum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// let mut bst = ChunkedBST::new();
+    /// assert_eq!(bst.remove_min(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_min(), Some(2));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_min(&mut self) -> Option<T> {
+        let removed_min = Chunk::remove_min(self.root.as_mut()?);
+        self.size -= 1;
+        self.shrink_root();
+        Some(removed_min)
+    }
+
+    /// Removes and returns the maximum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// let mut bst = ChunkedBST::new();
+    /// assert_eq!(bst.remove_max(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_max(), Some(10));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_max(&mut self) -> Option<T> {
+        let removed_max = Chunk::remove_max(self.root.as_mut()?);
+        self.size -= 1;
+        self.shrink_root();
+        Some(removed_max)
+    }
+
+    /// Returns references to the elements of the tree in **ascending order.**`
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ChunkedBST::in_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// let mut bst = ChunkedBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn asc_order_vec(&self) -> Vec<&T> {
+        self.in_order_vec()
+    }
+
+    /// Returns references to the elements of the tree in the order of a **pre-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// // Given a tree whose root holds 5 and whose two leaves hold 0..=4 and 6..=11
+    /// let bst: ChunkedBST<i32> = (0..12).collect();
+    ///
+    /// assert_eq!(bst.pre_order_vec(), vec![&5, &0, &1, &2, &3, &4, &6, &7, &8, &9, &10, &11]);
+    /// ```
+    fn pre_order_vec(&self) -> Vec<&T> {
+        elements(self.chunks_pre_order())
+    }
+
+    /// Returns references to the elements of the tree in the order of an **in-order traversal.**
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ChunkedBST::asc_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// let mut bst = ChunkedBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The in_order_vec is: [&1, &2, &3, &4, &5, &6, &7]
+    /// assert_eq!(bst.in_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn in_order_vec(&self) -> Vec<&T> {
+        self.sorted_elements()
+    }
+
+    /// Returns references to the elements of the tree in the order of a **post-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// // Given a tree whose root holds 5 and whose two leaves hold 0..=4 and 6..=11
+    /// let bst: ChunkedBST<i32> = (0..12).collect();
+    ///
+    /// assert_eq!(bst.post_order_vec(), vec![&0, &1, &2, &3, &4, &6, &7, &8, &9, &10, &11, &5]);
+    /// ```
+    fn post_order_vec(&self) -> Vec<&T> {
+        elements(self.chunks_post_order())
+    }
+
+    /// Returns references to the elements of the tree in the order of a **level-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// // Given a tree whose root holds 5 and whose two leaves hold 0..=4 and 6..=11
+    /// let bst: ChunkedBST<i32> = (0..12).collect();
+    ///
+    /// assert_eq!(bst.level_order_vec(), vec![&5, &0, &1, &2, &3, &4, &6, &7, &8, &9, &10, &11]);
+    /// ```
+    fn level_order_vec(&self) -> Vec<&T> {
+        elements(self.chunks_level_order())
+    }
+
+    /// Returns an iterator over [ChunkedBST::asc_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ChunkedBST::in_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// let mut bst = ChunkedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut asc_order_iter = bst.asc_order_iter();
+    ///
+    /// assert_eq!(asc_order_iter.next(), Some(&1));
+    /// assert_eq!(asc_order_iter.next(), Some(&2));
+    /// assert_eq!(asc_order_iter.next(), Some(&3));
+    /// assert_eq!(asc_order_iter.next(), Some(&4));
+    /// assert_eq!(asc_order_iter.next(), Some(&5));
+    /// assert_eq!(asc_order_iter.next(), None);
+    /// ```
+    fn asc_order_iter(&self) -> IntoIter<&T> {
+        self.in_order_iter()
+    }
+
+    /// Returns an iterator over [ChunkedBST::pre_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// // Given a tree whose root holds 5 and whose two leaves hold 0..=4 and 6..=11
+    /// let bst: ChunkedBST<i32> = (0..12).collect();
+    ///
+    /// let pre_order: Vec<&i32> = bst.pre_order_iter().collect();
+    /// assert_eq!(pre_order, vec![&5, &0, &1, &2, &3, &4, &6, &7, &8, &9, &10, &11]);
+    /// ```
+    fn pre_order_iter(&self) -> IntoIter<&T> {
+        self.pre_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [ChunkedBST::in_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ChunkedBST::asc_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// let mut bst = ChunkedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut in_order_iter = bst.in_order_iter();
+    ///
+    /// assert_eq!(in_order_iter.next(), Some(&1));
+    /// assert_eq!(in_order_iter.next(), Some(&2));
+    /// assert_eq!(in_order_iter.next(), Some(&3));
+    /// assert_eq!(in_order_iter.next(), Some(&4));
+    /// assert_eq!(in_order_iter.next(), Some(&5));
+    /// assert_eq!(in_order_iter.next(), None);
+    /// ```
+    fn in_order_iter(&self) -> IntoIter<&T> {
+        self.in_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [ChunkedBST::post_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// // Given a tree whose root holds 5 and whose two leaves hold 0..=4 and 6..=11
+    /// let bst: ChunkedBST<i32> = (0..12).collect();
+    ///
+    /// let post_order: Vec<&i32> = bst.post_order_iter().collect();
+    /// assert_eq!(post_order, vec![&0, &1, &2, &3, &4, &6, &7, &8, &9, &10, &11, &5]);
+    /// ```
+    fn post_order_iter(&self) -> IntoIter<&T> {
+        self.post_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [ChunkedBST::level_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// // Given a tree whose root holds 5 and whose two leaves hold 0..=4 and 6..=11
+    /// let bst: ChunkedBST<i32> = (0..12).collect();
+    ///
+    /// let level_order: Vec<&i32> = bst.level_order_iter().collect();
+    /// assert_eq!(level_order, vec![&5, &0, &1, &2, &3, &4, &6, &7, &8, &9, &10, &11]);
+    /// ```
+    fn level_order_iter(&self) -> IntoIter<&T> {
+        self.level_order_vec().into_iter()
+    }
+
+    /// Returns [ChunkedBST::asc_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ChunkedBST::into_in_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// let mut bst = ChunkedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_asc_order_iter = bst.into_asc_order_iter();
+    ///
+    /// assert_eq!(into_asc_order_iter.next(), Some(1));
+    /// assert_eq!(into_asc_order_iter.next(), Some(2));
+    /// assert_eq!(into_asc_order_iter.next(), Some(3));
+    /// assert_eq!(into_asc_order_iter.next(), Some(4));
+    /// assert_eq!(into_asc_order_iter.next(), Some(5));
+    /// assert_eq!(into_asc_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_asc_order_iter(self) -> IntoIter<T> {
+        self.into_in_order_iter()
+    }
+
+    /// Returns [ChunkedBST::pre_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// // Given a tree whose root holds 5 and whose two leaves hold 0..=4 and 6..=11
+    /// let bst: ChunkedBST<i32> = (0..12).collect();
+    ///
+    /// let pre_order: Vec<i32> = bst.into_pre_order_iter().collect();
+    /// assert_eq!(pre_order, vec![5, 0, 1, 2, 3, 4, 6, 7, 8, 9, 10, 11]);
+    /// ```
+    fn into_pre_order_iter(self) -> IntoIter<T> {
+        self.into_pre_order_vec().into_iter()
+    }
+
+    /// Returns [ChunkedBST::in_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ChunkedBST::asc_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// let mut bst = ChunkedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_in_order_iter = bst.into_in_order_iter();
+    ///
+    /// assert_eq!(into_in_order_iter.next(), Some(1));
+    /// assert_eq!(into_in_order_iter.next(), Some(2));
+    /// assert_eq!(into_in_order_iter.next(), Some(3));
+    /// assert_eq!(into_in_order_iter.next(), Some(4));
+    /// assert_eq!(into_in_order_iter.next(), Some(5));
+    /// assert_eq!(into_in_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_in_order_iter(self) -> IntoIter<T> {
+        self.into_in_order_vec().into_iter()
+    }
+
+    /// Returns [ChunkedBST::post_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// // Given a tree whose root holds 5 and whose two leaves hold 0..=4 and 6..=11
+    /// let bst: ChunkedBST<i32> = (0..12).collect();
+    ///
+    /// let post_order: Vec<i32> = bst.into_post_order_iter().collect();
+    /// assert_eq!(post_order, vec![0, 1, 2, 3, 4, 6, 7, 8, 9, 10, 11, 5]);
+    /// ```
+    fn into_post_order_iter(self) -> IntoIter<T> {
+        self.into_post_order_vec().into_iter()
+    }
+
+    /// Returns [ChunkedBST::level_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ChunkedBST};
+    ///
+    /// // Given a tree whose root holds 5 and whose two leaves hold 0..=4 and 6..=11
+    /// let bst: ChunkedBST<i32> = (0..12).collect();
+    ///
+    /// let level_order: Vec<i32> = bst.into_level_order_iter().collect();
+    /// assert_eq!(level_order, vec![5, 0, 1, 2, 3, 4, 6, 7, 8, 9, 10, 11]);
+    /// ```
+    fn into_level_order_iter(self) -> IntoIter<T> {
+        self.into_level_order_vec().into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Chunk, MAX_ELEMENTS, MIN_DEGREE};
+    #[cfg(feature = "iterative")]
+    use crate::IterativeBST;
+    use crate::{BinarySearchTree, ChunkedBST};
+
+    /// Deterministic sequence of pseudo random values for comparisons with [IterativeBST].
+    #[cfg(feature = "iterative")]
+    fn pseudo_random_values(count: usize) -> Vec<u32> {
+        let mut state: u32 = 0x9e37_79b9;
+        (0..count)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state % 2000
+            })
+            .collect()
+    }
+
+    /// Checks the element bounds of every chunk and that all leaves are at the same depth.
+    fn assert_invariants<T: Ord>(bst: &ChunkedBST<T>) {
+        let mut leaf_depths = Vec::new();
+        let mut stack: Vec<(&Chunk<T>, usize)> = bst.root.iter().map(|root| (root, 0)).collect();
+        while let Some((chunk, depth)) = stack.pop() {
+            assert!(chunk.elements.len() <= MAX_ELEMENTS);
+            if depth > 0 {
+                assert!(chunk.elements.len() >= MIN_DEGREE - 1);
+            }
+            if chunk.is_leaf() {
+                leaf_depths.push(depth);
+            } else {
+                assert_eq!(chunk.children.len(), chunk.elements.len() + 1);
+                stack.extend(chunk.children.iter().map(|child| (child, depth + 1)));
+            }
+        }
+        leaf_depths.dedup();
+        assert!(leaf_depths.len() <= 1);
+        assert!(bst.asc_order_vec().windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn successfully_insert_elements_into_bst() {
+        let mut bst = ChunkedBST::new();
+        for value in (0..100).rev() {
+            bst.insert(value);
+        }
+        bst.insert(50);
+
+        assert_eq!(bst.size(), 100);
+        assert!(bst.contains(&0));
+        assert!(bst.contains(&99));
+        assert!(!bst.contains(&100));
+        assert!(bst.height().unwrap() <= 2);
+        assert_invariants(&bst);
+    }
+
+    #[test]
+    fn successfully_remove_elements_from_bst() {
+        let mut bst = ChunkedBST::from_iter(0..200);
+
+        for value in (0..200).step_by(2) {
+            bst.remove(&value);
+        }
+        bst.remove(&1000);
+
+        assert_eq!(bst.size(), 100);
+        assert_eq!(bst.min(), Some(&1));
+        assert_eq!(bst.max(), Some(&199));
+        assert_invariants(&bst);
+    }
+
+    #[test]
+    fn successfully_remove_min_and_max_until_empty() {
+        let mut bst = ChunkedBST::from_iter(0..100);
+
+        for value in 0..50 {
+            assert_eq!(bst.remove_min(), Some(value));
+            assert_eq!(bst.remove_max(), Some(99 - value));
+            assert_invariants(&bst);
+        }
+
+        assert!(bst.is_empty());
+        assert_eq!(bst.height(), None);
+        assert_eq!(bst.remove_min(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "iterative")]
+    fn successfully_behave_like_iterative_bst() {
+        let values = pseudo_random_values(3000);
+        let mut chunked = ChunkedBST::new();
+        let mut iterative = IterativeBST::new();
+
+        for (index, value) in values.iter().enumerate() {
+            if index % 3 == 2 {
+                chunked.remove(value);
+                iterative.remove(value);
+            } else {
+                chunked.insert(*value);
+                iterative.insert(*value);
+            }
+        }
+
+        assert_eq!(chunked.size(), iterative.size());
+        assert_eq!(chunked.asc_order_vec(), iterative.asc_order_vec());
+        assert_invariants(&chunked);
+    }
+
+    #[test]
+    fn successfully_traverse_whole_chunks() {
+        let bst = ChunkedBST::from_iter(0..12);
+
+        assert_eq!(bst.height(), Some(1));
+        assert_eq!(
+            bst.pre_order_vec(),
+            vec![&5, &0, &1, &2, &3, &4, &6, &7, &8, &9, &10, &11]
+        );
+        assert_eq!(
+            bst.post_order_vec(),
+            vec![&0, &1, &2, &3, &4, &6, &7, &8, &9, &10, &11, &5]
+        );
+        assert_eq!(bst.level_order_vec(), bst.pre_order_vec());
+        assert_eq!(
+            bst.clone().into_post_order_iter().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4, 6, 7, 8, 9, 10, 11, 5]
+        );
+        assert_eq!(
+            bst.clone().into_in_order_iter().collect::<Vec<_>>(),
+            (0..12).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            bst.into_level_order_iter().collect::<Vec<_>>(),
+            vec![5, 0, 1, 2, 3, 4, 6, 7, 8, 9, 10, 11]
+        );
+    }
+
+    #[test]
+    fn successfully_mutate_retrieved_element() {
+        let mut bst = ChunkedBST::from_iter((0..30).map(|value| (value, 'a')));
+
+        bst.retrieve_as_mut(&(20, 'a')).unwrap().1 = 'b';
+
+        assert_eq!(bst.retrieve(&(20, 'b')), Some(&(20, 'b')));
+        assert_eq!(bst.retrieve(&(31, 'a')), None);
+    }
+}
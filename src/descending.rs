@@ -0,0 +1,1043 @@
+use std::cmp::Reverse;
+use std::fmt::{Debug, Display, Formatter};
+use std::vec::IntoIter;
+
+use crate::{BinarySearchTree, IterativeBST};
+
+/// [IterativeBST] ordering its elements from the largest to the smallest.
+///
+/// The whole API works on the reversed ordering: [min](BinarySearchTree::min()) and
+/// [remove_min](BinarySearchTree::remove_min()) refer to the largest element and
+/// [asc_order_vec](BinarySearchTree::asc_order_vec()) lists the elements in descending order.
+/// Elements are stored in [Reverse] internally, but are inserted, looked up and handed out
+/// as they are, so nothing needs to be wrapped or unwrapped by the caller.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{BinarySearchTree, DescendingBST, IterativeBST};
+///
+/// let mut scores = IterativeBST::descending();
+/// scores.extend(vec![70, 95, 80]);
+///
+/// assert_eq!(scores.min(), Some(&95));
+/// assert_eq!(scores.asc_order_vec(), vec![&95, &80, &70]);
+/// assert_eq!(scores.remove_min(), Some(95));
+///
+/// let same: DescendingBST<i32> = DescendingBST::from_iter(vec![80, 70]);
+/// assert_eq!(scores, same);
+/// ```
+pub struct DescendingBST<T: Ord> {
+    bst: IterativeBST<Reverse<T>>,
+}
+
+/// Views `value` as the element stored for it.
+fn reversed<T>(value: &T) -> &Reverse<T> {
+    // SAFETY: `Reverse` is `#[repr(transparent)]` over `T`
+    unsafe { &*(value as *const T).cast::<Reverse<T>>() }
+}
+
+fn unwrap_refs<T>(values: Vec<&Reverse<T>>) -> Vec<&T> {
+    values.into_iter().map(|Reverse(value)| value).collect()
+}
+
+fn unwrap_values<T>(values: IntoIter<Reverse<T>>) -> IntoIter<T> {
+    values
+        .map(|Reverse(value)| value)
+        .collect::<Vec<T>>()
+        .into_iter()
+}
+
+impl<T: Ord> DescendingBST<T> {
+    /// Creates an empty `DescendingBST<T>`
+    ///
+    /// No nodes are allocated on the heap yet
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// let bst: DescendingBST<i32> = DescendingBST::new();
+    /// assert!(bst.is_empty());
+    /// ```
+    pub const fn new() -> DescendingBST<T> {
+        DescendingBST {
+            bst: IterativeBST::new(),
+        }
+    }
+
+    /// Consumes the tree, returning the underlying [IterativeBST] of [Reverse] elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::cmp::Reverse;
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// let bst = DescendingBST::from_iter(vec![1, 2]);
+    ///
+    /// assert_eq!(bst.into_inner().min(), Some(&Reverse(2)));
+    /// ```
+    pub fn into_inner(self) -> IterativeBST<Reverse<T>> {
+        self.bst
+    }
+}
+
+impl<T: Ord> IterativeBST<T> {
+    /// Creates an empty [DescendingBST], whose entire API works on the reversed ordering of
+    /// `T`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::descending();
+    /// bst.insert("a");
+    /// bst.insert("c");
+    /// bst.insert("b");
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&"c", &"b", &"a"]);
+    /// assert_eq!(bst.max(), Some(&"a"));
+    /// ```
+    pub const fn descending() -> DescendingBST<T> {
+        DescendingBST::new()
+    }
+}
+
+impl<T: Ord> Default for DescendingBST<T> {
+    /// Creates an empty `DescendingBST<T>`
+    fn default() -> DescendingBST<T> {
+        DescendingBST::new()
+    }
+}
+
+impl<T: Ord + Debug> Debug for DescendingBST<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DescendingBST")
+            .field("bst", &self.bst)
+            .finish()
+    }
+}
+
+impl<T: Ord> PartialEq for DescendingBST<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bst == other.bst
+    }
+}
+
+impl<T: Ord> Extend<T> for DescendingBST<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter.into_iter() {
+            self.insert(value)
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for DescendingBST<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bst = DescendingBST::new();
+        bst.extend(iter);
+        bst
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for DescendingBST<T> {
+    fn from(vec: Vec<T>) -> Self {
+        let mut bst = DescendingBST::new();
+        for value in vec.into_iter() {
+            bst.insert(value);
+        }
+        bst
+    }
+}
+
+impl<T: Ord + Clone> Clone for DescendingBST<T> {
+    fn clone(&self) -> Self {
+        DescendingBST {
+            bst: self.bst.clone(),
+        }
+    }
+}
+
+impl<T: Ord + Debug> Display for DescendingBST<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.asc_order_vec())
+    }
+}
+
+impl<T: Ord> BinarySearchTree<T> for DescendingBST<T> {
+    /// Returns the total **number of nodes** within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(5);
+    /// bst.insert(10);
+    /// bst.insert(3);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// ```
+    fn size(&self) -> usize {
+        self.bst.size()
+    }
+
+    /// Returns `true` if the binary search tree contains no nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// let mut bst: DescendingBST<i32> = DescendingBST::new();
+    /// assert!(bst.is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        self.bst.is_empty()
+    }
+
+    /// Returns `true` if the binary search tree contains one or more nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// let mut bst = DescendingBST::new();
+    /// assert!(bst.is_empty());
+    ///
+    /// bst.insert(2);
+    ///
+    /// assert!(bst.is_not_empty());
+    /// ```
+    fn is_not_empty(&self) -> bool {
+        self.bst.is_not_empty()
+    }
+
+    /// Inserts given value as a node.
+    ///
+    /// **Duplicate values are _not allowed_**.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// let mut bst = DescendingBST::new();
+    ///
+    /// bst.insert(10);
+    /// bst.insert(10);   // Element is not inserted
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(15);
+    /// bst.insert(25);
+    ///
+    /// assert_eq!(bst.size(), 5);
+    /// ```
+    fn insert(&mut self, value: T) {
+        self.bst.insert(Reverse(value))
+    }
+
+    /// Returns `true` if the binary search tree contains an element with the given value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert!(bst.contains(&5));
+    /// assert!(!bst.contains(&10));
+    /// ```
+    fn contains(&self, value: &T) -> bool {
+        self.bst.contains(reversed(value))
+    }
+
+    /// Removes the given value.
+    ///
+    /// Tree will not be modified if trying to remove element that does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// assert_eq!(bst.size(), 3);
+    ///
+    /// bst.remove(&5);
+    /// bst.remove(&10); // Element is not removed
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove(&mut self, value: &T) {
+        self.bst.remove(reversed(value))
+    }
+
+    /// Returns a reference to the element or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert_eq!(bst.retrieve(&5), Some(&5));
+    /// assert_eq!(bst.retrieve(&10), None);
+    /// ```
+    fn retrieve(&self, value: &T) -> Option<&T> {
+        self.bst
+            .retrieve(reversed(value))
+            .map(|Reverse(value)| value)
+    }
+
+    /// Returns a mutable reference to the element (see [DescendingBST::retrieve()])
+    /// or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(10);
+    /// bst.insert(5);
+    ///
+    /// let optional_retrieved_value_as_mut = bst.retrieve_as_mut(&5);
+    /// assert_eq!(optional_retrieved_value_as_mut, Some(&mut 5));
+    ///
+    /// let mut retrieved_value = optional_retrieved_value_as_mut.unwrap();
+    /// *retrieved_value = 2; // Change value inside tree to '2'
+    ///
+    /// assert_eq!(bst.retrieve_as_mut(&5), None); // 5 does not exist anymore
+    /// assert_eq!(bst.retrieve_as_mut(&2), Some(&mut 2));
+    /// ```
+    fn retrieve_as_mut(&mut self, value: &T) -> Option<&mut T> {
+        self.bst
+            .retrieve_as_mut(reversed(value))
+            .map(|Reverse(value)| value)
+    }
+
+    /// Returns the **height** or `None` if tree is empty.
+    ///
+    /// The height is the number of edges between the root and it's furthest leaf node.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      6    2
+    ///  //     / \  / \
+    ///  //    7  5 3   1
+    /// let mut bst = DescendingBST::new();
+    /// assert_eq!(bst.height(), None);
+    ///
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The height is 2.
+    /// assert_eq!(bst.height(), Some(2));
+    /// ```
+    fn height(&self) -> Option<isize> {
+        self.bst.height()
+    }
+
+    /// Returns a reference to the minimum element of the tree in the reversed ordering, i.e. the
+    /// largest element, or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// let mut bst = DescendingBST::new();
+    /// assert_eq!(bst.min(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.min(), Some(&10));
+    /// ```
+    fn min(&self) -> Option<&T> {
+        self.bst.min().map(|Reverse(value)| value)
+    }
+
+    /// Returns a reference to the maximum element of the tree in the reversed ordering, i.e. the
+    /// smallest element, or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// let mut bst = DescendingBST::new();
+    /// assert_eq!(bst.max(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.max(), Some(&2));
+    /// ```
+    fn max(&self) -> Option<&T> {
+        self.bst.max().map(|Reverse(value)| value)
+    }
+
+    /// Removes and returns the minimum element in the reversed ordering, i.e. the largest
+    /// element, from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// let mut bst = DescendingBST::new();
+    /// assert_eq!(bst.remove_min(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_min(), Some(10));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_min(&mut self) -> Option<T> {
+        self.bst.remove_min().map(|Reverse(value)| value)
+    }
+
+    /// Removes and returns the maximum element in the reversed ordering, i.e. the smallest
+    /// element, from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// let mut bst = DescendingBST::new();
+    /// assert_eq!(bst.remove_max(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_max(), Some(2));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_max(&mut self) -> Option<T> {
+        self.bst.remove_max().map(|Reverse(value)| value)
+    }
+
+    /// Returns references to the elements of the tree in **ascending order** of the reversed ordering, i.e. from the largest to the smallest element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      6    2
+    ///  //     / \  / \
+    ///  //    7  5 3   1
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&7, &6, &5, &4, &3, &2, &1]);
+    /// ```
+    fn asc_order_vec(&self) -> Vec<&T> {
+        unwrap_refs(self.bst.asc_order_vec())
+    }
+
+    /// Returns references to the elements of the tree in the order of a **pre-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      6    2
+    ///  //     / \  / \
+    ///  //    7  5 3   1
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// assert_eq!(bst.pre_order_vec(), vec![&4, &6, &7, &5, &2, &3, &1]);
+    /// ```
+    fn pre_order_vec(&self) -> Vec<&T> {
+        unwrap_refs(self.bst.pre_order_vec())
+    }
+
+    /// Returns references to the elements of the tree in the order of an **in-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      6    2
+    ///  //     / \  / \
+    ///  //    7  5 3   1
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// assert_eq!(bst.in_order_vec(), vec![&7, &6, &5, &4, &3, &2, &1]);
+    /// ```
+    fn in_order_vec(&self) -> Vec<&T> {
+        unwrap_refs(self.bst.in_order_vec())
+    }
+
+    /// Returns references to the elements of the tree in the order of a **post-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      6    2
+    ///  //     / \  / \
+    ///  //    7  5 3   1
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// assert_eq!(bst.post_order_vec(), vec![&7, &5, &6, &3, &1, &2, &4]);
+    /// ```
+    fn post_order_vec(&self) -> Vec<&T> {
+        unwrap_refs(self.bst.post_order_vec())
+    }
+
+    /// Returns references to the elements of the tree in the order of a **level-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      6    2
+    ///  //     / \  / \
+    ///  //    7  5 3   1
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// assert_eq!(bst.level_order_vec(), vec![&4, &6, &2, &7, &5, &3, &1]);
+    /// ```
+    fn level_order_vec(&self) -> Vec<&T> {
+        unwrap_refs(self.bst.level_order_vec())
+    }
+
+    /// Returns an iterator over the elements of the tree in **ascending order** of the reversed ordering, i.e. from the largest to the smallest element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      6    2
+    ///  //     / \  / \
+    ///  //    7  5 3   1
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// let mut asc_order_iter = bst.asc_order_iter();
+    /// assert_eq!(asc_order_iter.next(), Some(&7));
+    /// assert_eq!(asc_order_iter.next(), Some(&6));
+    /// assert_eq!(asc_order_iter.next(), Some(&5));
+    /// assert_eq!(asc_order_iter.next(), Some(&4));
+    /// assert_eq!(asc_order_iter.next(), Some(&3));
+    /// assert_eq!(asc_order_iter.next(), Some(&2));
+    /// assert_eq!(asc_order_iter.next(), Some(&1));
+    /// assert_eq!(asc_order_iter.next(), None);
+    /// ```
+    fn asc_order_iter(&self) -> IntoIter<&T> {
+        self.asc_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over the elements of the tree in the order of a **pre-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      6    2
+    ///  //     / \  / \
+    ///  //    7  5 3   1
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// let mut pre_order_iter = bst.pre_order_iter();
+    /// assert_eq!(pre_order_iter.next(), Some(&4));
+    /// assert_eq!(pre_order_iter.next(), Some(&6));
+    /// assert_eq!(pre_order_iter.next(), Some(&7));
+    /// assert_eq!(pre_order_iter.next(), Some(&5));
+    /// assert_eq!(pre_order_iter.next(), Some(&2));
+    /// assert_eq!(pre_order_iter.next(), Some(&3));
+    /// assert_eq!(pre_order_iter.next(), Some(&1));
+    /// assert_eq!(pre_order_iter.next(), None);
+    /// ```
+    fn pre_order_iter(&self) -> IntoIter<&T> {
+        self.pre_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over the elements of the tree in the order of an **in-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      6    2
+    ///  //     / \  / \
+    ///  //    7  5 3   1
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// let mut in_order_iter = bst.in_order_iter();
+    /// assert_eq!(in_order_iter.next(), Some(&7));
+    /// assert_eq!(in_order_iter.next(), Some(&6));
+    /// assert_eq!(in_order_iter.next(), Some(&5));
+    /// assert_eq!(in_order_iter.next(), Some(&4));
+    /// assert_eq!(in_order_iter.next(), Some(&3));
+    /// assert_eq!(in_order_iter.next(), Some(&2));
+    /// assert_eq!(in_order_iter.next(), Some(&1));
+    /// assert_eq!(in_order_iter.next(), None);
+    /// ```
+    fn in_order_iter(&self) -> IntoIter<&T> {
+        self.in_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over the elements of the tree in the order of a **post-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      6    2
+    ///  //     / \  / \
+    ///  //    7  5 3   1
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// let mut post_order_iter = bst.post_order_iter();
+    /// assert_eq!(post_order_iter.next(), Some(&7));
+    /// assert_eq!(post_order_iter.next(), Some(&5));
+    /// assert_eq!(post_order_iter.next(), Some(&6));
+    /// assert_eq!(post_order_iter.next(), Some(&3));
+    /// assert_eq!(post_order_iter.next(), Some(&1));
+    /// assert_eq!(post_order_iter.next(), Some(&2));
+    /// assert_eq!(post_order_iter.next(), Some(&4));
+    /// assert_eq!(post_order_iter.next(), None);
+    /// ```
+    fn post_order_iter(&self) -> IntoIter<&T> {
+        self.post_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over the elements of the tree in the order of a **level-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      6    2
+    ///  //     / \  / \
+    ///  //    7  5 3   1
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// let mut level_order_iter = bst.level_order_iter();
+    /// assert_eq!(level_order_iter.next(), Some(&4));
+    /// assert_eq!(level_order_iter.next(), Some(&6));
+    /// assert_eq!(level_order_iter.next(), Some(&2));
+    /// assert_eq!(level_order_iter.next(), Some(&7));
+    /// assert_eq!(level_order_iter.next(), Some(&5));
+    /// assert_eq!(level_order_iter.next(), Some(&3));
+    /// assert_eq!(level_order_iter.next(), Some(&1));
+    /// assert_eq!(level_order_iter.next(), None);
+    /// ```
+    fn level_order_iter(&self) -> IntoIter<&T> {
+        self.level_order_vec().into_iter()
+    }
+
+    /// Consumes the tree, returning an iterator over its elements in **ascending order** of the reversed ordering, i.e. from the largest to the smallest element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      6    2
+    ///  //     / \  / \
+    ///  //    7  5 3   1
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// let mut into_asc_order_iter = bst.into_asc_order_iter();
+    /// assert_eq!(into_asc_order_iter.next(), Some(7));
+    /// assert_eq!(into_asc_order_iter.next(), Some(6));
+    /// assert_eq!(into_asc_order_iter.next(), Some(5));
+    /// assert_eq!(into_asc_order_iter.next(), Some(4));
+    /// assert_eq!(into_asc_order_iter.next(), Some(3));
+    /// assert_eq!(into_asc_order_iter.next(), Some(2));
+    /// assert_eq!(into_asc_order_iter.next(), Some(1));
+    /// assert_eq!(into_asc_order_iter.next(), None);
+    /// ```
+    fn into_asc_order_iter(self) -> IntoIter<T> {
+        unwrap_values(self.bst.into_asc_order_iter())
+    }
+
+    /// Consumes the tree, returning an iterator over its elements in the order of a **pre-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      6    2
+    ///  //     / \  / \
+    ///  //    7  5 3   1
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// let mut into_pre_order_iter = bst.into_pre_order_iter();
+    /// assert_eq!(into_pre_order_iter.next(), Some(4));
+    /// assert_eq!(into_pre_order_iter.next(), Some(6));
+    /// assert_eq!(into_pre_order_iter.next(), Some(7));
+    /// assert_eq!(into_pre_order_iter.next(), Some(5));
+    /// assert_eq!(into_pre_order_iter.next(), Some(2));
+    /// assert_eq!(into_pre_order_iter.next(), Some(3));
+    /// assert_eq!(into_pre_order_iter.next(), Some(1));
+    /// assert_eq!(into_pre_order_iter.next(), None);
+    /// ```
+    fn into_pre_order_iter(self) -> IntoIter<T> {
+        unwrap_values(self.bst.into_pre_order_iter())
+    }
+
+    /// Consumes the tree, returning an iterator over its elements in the order of an **in-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      6    2
+    ///  //     / \  / \
+    ///  //    7  5 3   1
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// let mut into_in_order_iter = bst.into_in_order_iter();
+    /// assert_eq!(into_in_order_iter.next(), Some(7));
+    /// assert_eq!(into_in_order_iter.next(), Some(6));
+    /// assert_eq!(into_in_order_iter.next(), Some(5));
+    /// assert_eq!(into_in_order_iter.next(), Some(4));
+    /// assert_eq!(into_in_order_iter.next(), Some(3));
+    /// assert_eq!(into_in_order_iter.next(), Some(2));
+    /// assert_eq!(into_in_order_iter.next(), Some(1));
+    /// assert_eq!(into_in_order_iter.next(), None);
+    /// ```
+    fn into_in_order_iter(self) -> IntoIter<T> {
+        unwrap_values(self.bst.into_in_order_iter())
+    }
+
+    /// Consumes the tree, returning an iterator over its elements in the order of a **post-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      6    2
+    ///  //     / \  / \
+    ///  //    7  5 3   1
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// let mut into_post_order_iter = bst.into_post_order_iter();
+    /// assert_eq!(into_post_order_iter.next(), Some(7));
+    /// assert_eq!(into_post_order_iter.next(), Some(5));
+    /// assert_eq!(into_post_order_iter.next(), Some(6));
+    /// assert_eq!(into_post_order_iter.next(), Some(3));
+    /// assert_eq!(into_post_order_iter.next(), Some(1));
+    /// assert_eq!(into_post_order_iter.next(), Some(2));
+    /// assert_eq!(into_post_order_iter.next(), Some(4));
+    /// assert_eq!(into_post_order_iter.next(), None);
+    /// ```
+    fn into_post_order_iter(self) -> IntoIter<T> {
+        unwrap_values(self.bst.into_post_order_iter())
+    }
+
+    /// Consumes the tree, returning an iterator over its elements in the order of a **level-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DescendingBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      6    2
+    ///  //     / \  / \
+    ///  //    7  5 3   1
+    /// let mut bst = DescendingBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// let mut into_level_order_iter = bst.into_level_order_iter();
+    /// assert_eq!(into_level_order_iter.next(), Some(4));
+    /// assert_eq!(into_level_order_iter.next(), Some(6));
+    /// assert_eq!(into_level_order_iter.next(), Some(2));
+    /// assert_eq!(into_level_order_iter.next(), Some(7));
+    /// assert_eq!(into_level_order_iter.next(), Some(5));
+    /// assert_eq!(into_level_order_iter.next(), Some(3));
+    /// assert_eq!(into_level_order_iter.next(), Some(1));
+    /// assert_eq!(into_level_order_iter.next(), None);
+    /// ```
+    fn into_level_order_iter(self) -> IntoIter<T> {
+        unwrap_values(self.bst.into_level_order_iter())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BinarySearchTree, DescendingBST, IterativeBST};
+
+    #[test]
+    fn successfully_behave_like_mirrored_iterative_bst() {
+        let values = vec![50, 20, 80, 10, 30, 70, 90, 25, 85];
+        let mut descending = DescendingBST::from(values.clone());
+        let mut iterative = IterativeBST::from_iter(values.iter().map(|value| -value));
+        let negated =
+            |values: Vec<&i32>| values.into_iter().map(|value| -value).collect::<Vec<_>>();
+
+        assert_eq!(
+            negated(descending.asc_order_vec()),
+            iterative
+                .asc_order_vec()
+                .into_iter()
+                .copied()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            negated(descending.pre_order_vec()),
+            iterative
+                .pre_order_vec()
+                .into_iter()
+                .copied()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            negated(descending.post_order_vec()),
+            iterative
+                .post_order_vec()
+                .into_iter()
+                .copied()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            negated(descending.level_order_vec()),
+            iterative
+                .level_order_vec()
+                .into_iter()
+                .copied()
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(descending.height(), iterative.height());
+
+        for value in [30, 85, 50, 5] {
+            descending.remove(&value);
+            iterative.remove(&-value);
+            assert_eq!(descending.contains(&value), iterative.contains(&-value));
+        }
+        assert_eq!(
+            descending.remove_min(),
+            iterative.remove_min().map(|value| -value)
+        );
+        assert_eq!(
+            descending.remove_max(),
+            iterative.remove_max().map(|value| -value)
+        );
+        assert_eq!(descending.size(), iterative.size());
+        assert_eq!(
+            descending.into_asc_order_iter().collect::<Vec<_>>(),
+            iterative
+                .into_asc_order_iter()
+                .map(|value| -value)
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn successfully_look_up_and_modify_without_wrapping() {
+        let mut bst = IterativeBST::descending();
+        bst.extend(vec![
+            String::from("b"),
+            String::from("a"),
+            String::from("c"),
+        ]);
+
+        assert_eq!(bst.retrieve(&String::from("a")), Some(&String::from("a")));
+        bst.retrieve_as_mut(&String::from("c")).unwrap().push('c');
+        assert!(bst.contains(&String::from("cc")));
+        assert!(!bst.contains(&String::from("c")));
+        assert_eq!(bst.to_string(), "[\"cc\", \"b\", \"a\"]");
+        assert_eq!(bst.clone(), bst);
+        assert_eq!(DescendingBST::<String>::default().min(), None);
+    }
+}
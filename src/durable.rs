@@ -0,0 +1,620 @@
+use std::fs::{self, File, OpenOptions};
+use std::hash::Hasher;
+use std::io::{self, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+
+use crate::merkle::Fnv64;
+use crate::{BinarySearchTree, IterativeBST, StreamElement, StreamFormat};
+
+const SNAPSHOT: &str = "snapshot.bin";
+const SNAPSHOT_TMP: &str = "snapshot.tmp";
+const WAL: &str = "wal.log";
+
+const INSERT: u8 = 1;
+const REMOVE: u8 = 2;
+
+/// Number of logged operations after which the log is compacted by default.
+const DEFAULT_COMPACTION_THRESHOLD: usize = 4096;
+
+fn checksum(op: u8, payload: &[u8]) -> u64 {
+    let mut hasher = Fnv64::new();
+    hasher.write_u8(op);
+    hasher.write(payload);
+    hasher.finish()
+}
+
+/// Reads one log record, returning `None` at the end of the log or at a record which was
+/// only partially written or is corrupt.
+fn read_record<R: Read>(reader: &mut R) -> io::Result<Option<(u8, Vec<u8>)>> {
+    let mut header = [0; 13];
+    match reader.read_exact(&mut header) {
+        Err(error) if error.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        result => result?,
+    }
+    let op = header[0];
+    let len = u32::from_le_bytes(header[1..5].try_into().unwrap()) as u64;
+    let expected = u64::from_le_bytes(header[5..13].try_into().unwrap());
+
+    let mut payload = Vec::new();
+    reader.take(len).read_to_end(&mut payload)?;
+    if payload.len() as u64 != len || checksum(op, &payload) != expected {
+        return Ok(None);
+    }
+    Ok(Some((op, payload)))
+}
+
+/// [IterativeBST] persisted in a directory, which survives crashes of the process using it.
+///
+/// Every modification is appended to a **write-ahead log** before it is applied, and
+/// [DurableBST::recover()] replays the log on top of the last snapshot. Once the log holds
+/// enough operations (see [DurableBST::set_compaction_threshold()]), the tree is written as a
+/// new snapshot in the [Binary](StreamFormat::Binary) format and the log starts over.
+///
+/// A record which was only partially written when the process died is detected by its
+/// checksum and dropped, along with everything after it. A record which failed to be written
+/// while the process keeps running is cut off right away.
+///
+/// Compaction happens after the operation reaching the threshold has been applied, so if it
+/// fails, the operation still succeeds and the error is kept for
+/// [DurableBST::take_compaction_error()]. The log then keeps growing and every further
+/// operation retries the compaction.
+///
+/// # Important
+///
+/// Operations are handed to the operating system before returning, which protects them
+/// against crashes of the process. Protecting them against power loss as well requires
+/// calling [DurableBST::sync()].
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::DurableBST;
+///
+/// let dir = std::env::temp_dir().join(format!("bst-rs-durable-doc-{}", std::process::id()));
+///
+/// let mut bst = DurableBST::recover(&dir).unwrap();
+/// bst.insert(3u32).unwrap();
+/// bst.insert(1).unwrap();
+/// bst.remove(&3).unwrap();
+/// drop(bst);
+///
+/// let bst = DurableBST::<u32>::recover(&dir).unwrap();
+/// assert!(bst.contains(&1));
+/// assert!(!bst.contains(&3));
+/// # std::fs::remove_dir_all(&dir).unwrap();
+/// ```
+#[derive(Debug)]
+pub struct DurableBST<T: Ord + StreamElement> {
+    tree: IterativeBST<T>,
+    dir: PathBuf,
+    wal: File,
+    /// Length of the intact records in `wal`.
+    wal_len: u64,
+    /// Whether a failed write left part of a record behind `wal_len`, which has to be cut off
+    /// before the next record is appended.
+    torn: bool,
+    logged: usize,
+    compaction_threshold: usize,
+    compaction_error: Option<io::Error>,
+}
+
+impl<T: Ord + StreamElement> DurableBST<T> {
+    /// Opens the tree persisted in `dir`, replaying the write-ahead log on top of the last
+    /// snapshot. The directory is created if it does not exist, starting an empty tree.
+    ///
+    /// # Errors
+    ///
+    /// Returns the I/O errors of accessing the directory, and an error of kind
+    /// [InvalidData](io::ErrorKind::InvalidData) if the snapshot or an intact log record can't
+    /// be decoded as `T`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::DurableBST;
+    ///
+    /// let dir = std::env::temp_dir().join(format!("bst-rs-recover-doc-{}", std::process::id()));
+    ///
+    /// let bst = DurableBST::<i64>::recover(&dir).unwrap();
+    /// assert!(bst.is_empty());
+    /// # std::fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn recover<P: AsRef<Path>>(dir: P) -> io::Result<DurableBST<T>> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+
+        let mut tree = match File::open(dir.join(SNAPSHOT)) {
+            Ok(snapshot) => IterativeBST::read_from(snapshot, StreamFormat::Binary)?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => IterativeBST::new(),
+            Err(error) => return Err(error),
+        };
+
+        let mut wal = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(dir.join(WAL))?;
+
+        let mut logged = 0;
+        let mut intact = 0;
+        let mut reader = BufReader::new(&mut wal);
+        while let Some((op, payload)) = read_record(&mut reader)? {
+            let mut bytes = payload.as_slice();
+            let value = T::read_binary(&mut bytes)?;
+            if !bytes.is_empty() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "trailing bytes in log record",
+                ));
+            }
+            match op {
+                INSERT => tree.insert(value),
+                REMOVE => tree.remove(&value),
+                _ => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "unknown log operation",
+                    ))
+                }
+            }
+            logged += 1;
+            intact += 13 + payload.len() as u64;
+        }
+        // Drops a torn record left behind by a crash, so that new ones are not appended to it
+        wal.set_len(intact)?;
+
+        Ok(DurableBST {
+            tree,
+            dir,
+            wal,
+            wal_len: intact,
+            torn: false,
+            logged,
+            compaction_threshold: DEFAULT_COMPACTION_THRESHOLD,
+            compaction_error: None,
+        })
+    }
+
+    /// Sets the number of logged operations after which the log is compacted into a new
+    /// snapshot, 4096 by default. Compaction happens during the operation reaching it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threshold` is `0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::DurableBST;
+    ///
+    /// let dir = std::env::temp_dir().join(format!("bst-rs-threshold-doc-{}", std::process::id()));
+    ///
+    /// let mut bst = DurableBST::recover(&dir).unwrap();
+    /// bst.set_compaction_threshold(2);
+    /// bst.insert(1u8).unwrap();
+    /// assert_eq!(bst.logged(), 1);
+    /// bst.insert(2).unwrap();
+    /// assert_eq!(bst.logged(), 0);
+    /// # std::fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn set_compaction_threshold(&mut self, threshold: usize) {
+        assert!(threshold > 0, "compaction threshold must be at least 1");
+        self.compaction_threshold = threshold;
+    }
+
+    /// Returns the number of operations in the write-ahead log since the last snapshot.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::DurableBST;
+    ///
+    /// let dir = std::env::temp_dir().join(format!("bst-rs-logged-doc-{}", std::process::id()));
+    ///
+    /// let mut bst = DurableBST::recover(&dir).unwrap();
+    /// bst.insert(1u8).unwrap();
+    /// bst.insert(1).unwrap();
+    ///
+    /// // Operations which don't change the tree are not logged
+    /// assert_eq!(bst.logged(), 1);
+    /// # std::fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn logged(&self) -> usize {
+        self.logged
+    }
+
+    /// Returns the error of the last automatic compaction which failed, clearing it, or `None`
+    /// if none failed since the last call.
+    ///
+    /// The operation which triggered the compaction was applied and logged nonetheless, so it
+    /// must not be retried. An explicit [DurableBST::compact()] returns its error directly.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::DurableBST;
+    ///
+    /// let dir = std::env::temp_dir().join(format!("bst-rs-compaction-doc-{}", std::process::id()));
+    ///
+    /// let mut bst = DurableBST::recover(&dir).unwrap();
+    /// bst.set_compaction_threshold(1);
+    /// bst.insert(1u8).unwrap();
+    ///
+    /// assert!(bst.take_compaction_error().is_none());
+    /// # std::fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn take_compaction_error(&mut self) -> Option<io::Error> {
+        self.compaction_error.take()
+    }
+
+    /// Returns the in-memory tree, for every read-only operation of [BinarySearchTree].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, DurableBST};
+    ///
+    /// let dir = std::env::temp_dir().join(format!("bst-rs-tree-doc-{}", std::process::id()));
+    ///
+    /// let mut bst = DurableBST::recover(&dir).unwrap();
+    /// bst.insert(2u16).unwrap();
+    /// bst.insert(1).unwrap();
+    ///
+    /// assert_eq!(bst.tree().asc_order_vec(), vec![&1, &2]);
+    /// # std::fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn tree(&self) -> &IterativeBST<T> {
+        &self.tree
+    }
+
+    /// Returns the total **number of elements** within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::DurableBST;
+    ///
+    /// let dir = std::env::temp_dir().join(format!("bst-rs-len-doc-{}", std::process::id()));
+    ///
+    /// let mut bst = DurableBST::recover(&dir).unwrap();
+    /// bst.insert(1u8).unwrap();
+    ///
+    /// assert_eq!(bst.len(), 1);
+    /// # std::fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn len(&self) -> usize {
+        self.tree.size()
+    }
+
+    /// Returns `true` if the tree contains no elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::DurableBST;
+    ///
+    /// let dir = std::env::temp_dir().join(format!("bst-rs-empty-doc-{}", std::process::id()));
+    ///
+    /// let bst = DurableBST::<u8>::recover(&dir).unwrap();
+    /// assert!(bst.is_empty());
+    /// # std::fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+
+    /// Returns `true` if the tree contains an element with the given value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::DurableBST;
+    ///
+    /// let dir = std::env::temp_dir().join(format!("bst-rs-contains-doc-{}", std::process::id()));
+    ///
+    /// let mut bst = DurableBST::recover(&dir).unwrap();
+    /// bst.insert(String::from("key")).unwrap();
+    ///
+    /// assert!(bst.contains(&String::from("key")));
+    /// # std::fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        self.tree.contains(value)
+    }
+
+    /// Inserts the given value, returning `false` if it was already present.
+    ///
+    /// The insertion is logged before it is applied. If logging fails, the tree is left
+    /// unchanged. Errors of the compaction which may follow are not returned here, see
+    /// [DurableBST::take_compaction_error()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::DurableBST;
+    ///
+    /// let dir = std::env::temp_dir().join(format!("bst-rs-insert-doc-{}", std::process::id()));
+    ///
+    /// let mut bst = DurableBST::recover(&dir).unwrap();
+    /// assert!(bst.insert(1u8).unwrap());
+    /// assert!(!bst.insert(1).unwrap());
+    /// # std::fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn insert(&mut self, value: T) -> io::Result<bool> {
+        if self.tree.contains(&value) {
+            return Ok(false);
+        }
+        self.log(INSERT, &value)?;
+        self.tree.insert(value);
+        self.compact_if_needed();
+        Ok(true)
+    }
+
+    /// Removes the given value, returning `false` if it was not present.
+    ///
+    /// The removal is logged before it is applied. If logging fails, the tree is left
+    /// unchanged. Errors of the compaction which may follow are not returned here, see
+    /// [DurableBST::take_compaction_error()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::DurableBST;
+    ///
+    /// let dir = std::env::temp_dir().join(format!("bst-rs-remove-doc-{}", std::process::id()));
+    ///
+    /// let mut bst = DurableBST::recover(&dir).unwrap();
+    /// bst.insert(1u8).unwrap();
+    ///
+    /// assert!(bst.remove(&1).unwrap());
+    /// assert!(!bst.remove(&1).unwrap());
+    /// # std::fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn remove(&mut self, value: &T) -> io::Result<bool> {
+        if !self.tree.contains(value) {
+            return Ok(false);
+        }
+        self.log(REMOVE, value)?;
+        self.tree.remove(value);
+        self.compact_if_needed();
+        Ok(true)
+    }
+
+    /// Writes the whole tree as a new snapshot and empties the write-ahead log.
+    ///
+    /// The snapshot is written to a temporary file which then replaces the previous snapshot,
+    /// so a crash during compaction leaves either the old or the new snapshot in place. The
+    /// directory is synced after the replacement, so the new snapshot survives power loss before
+    /// the log is emptied. A crash before the log is emptied only replays operations which are
+    /// already part of the new snapshot, which leaves the tree unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::DurableBST;
+    ///
+    /// let dir = std::env::temp_dir().join(format!("bst-rs-compact-doc-{}", std::process::id()));
+    ///
+    /// let mut bst = DurableBST::recover(&dir).unwrap();
+    /// bst.insert(1u8).unwrap();
+    /// bst.compact().unwrap();
+    ///
+    /// assert_eq!(bst.logged(), 0);
+    /// assert!(DurableBST::<u8>::recover(&dir).unwrap().contains(&1));
+    /// # std::fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn compact(&mut self) -> io::Result<()> {
+        let tmp = self.dir.join(SNAPSHOT_TMP);
+        let mut snapshot = File::create(&tmp)?;
+        self.tree.write_to(&mut snapshot, StreamFormat::Binary)?;
+        snapshot.sync_all()?;
+        fs::rename(&tmp, self.dir.join(SNAPSHOT))?;
+        // The rename only lives in the directory entry, which has to be synced on its own
+        #[cfg(unix)]
+        File::open(&self.dir)?.sync_all()?;
+
+        self.wal.set_len(0)?;
+        self.wal.sync_all()?;
+        self.wal_len = 0;
+        self.torn = false;
+        self.logged = 0;
+        Ok(())
+    }
+
+    /// Flushes the write-ahead log to the storage device, making every operation so far
+    /// durable across power loss.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::DurableBST;
+    ///
+    /// let dir = std::env::temp_dir().join(format!("bst-rs-sync-doc-{}", std::process::id()));
+    ///
+    /// let mut bst = DurableBST::recover(&dir).unwrap();
+    /// bst.insert(1u8).unwrap();
+    /// bst.sync().unwrap();
+    /// # std::fs::remove_dir_all(&dir).unwrap();
+    /// ```
+    pub fn sync(&self) -> io::Result<()> {
+        self.wal.sync_data()
+    }
+
+    fn log(&mut self, op: u8, value: &T) -> io::Result<()> {
+        let mut payload = Vec::new();
+        value.write_binary(&mut payload)?;
+        let len = u32::try_from(payload.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "element too large"))?;
+
+        let mut record = Vec::with_capacity(13 + payload.len());
+        record.push(op);
+        record.extend(len.to_le_bytes());
+        record.extend(checksum(op, &payload).to_le_bytes());
+        record.extend(payload);
+
+        if self.torn {
+            self.wal.set_len(self.wal_len)?;
+            self.torn = false;
+        }
+        // A single write, so that a crash can at most leave a torn record at the end
+        if let Err(error) = self.wal.write_all(&record) {
+            // Cuts off whatever part of the record was written, or leaves that to the next record
+            self.torn = self.wal.set_len(self.wal_len).is_err();
+            return Err(error);
+        }
+        self.wal_len += record.len() as u64;
+        self.logged += 1;
+        Ok(())
+    }
+
+    fn compact_if_needed(&mut self) {
+        if self.logged >= self.compaction_threshold {
+            if let Err(error) = self.compact() {
+                self.compaction_error = Some(error);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::{self, OpenOptions};
+    use std::io::Write;
+    use std::path::PathBuf;
+
+    use crate::{BinarySearchTree, DurableBST};
+
+    /// Fresh directory per test, removed when dropped.
+    struct TempDir(PathBuf);
+
+    impl TempDir {
+        fn new(name: &str) -> TempDir {
+            let dir = std::env::temp_dir().join(format!(
+                "bst-rs-durable-{}-{}",
+                name,
+                std::process::id()
+            ));
+            let _ = fs::remove_dir_all(&dir);
+            TempDir(dir)
+        }
+    }
+
+    impl Drop for TempDir {
+        fn drop(&mut self) {
+            let _ = fs::remove_dir_all(&self.0);
+        }
+    }
+
+    #[test]
+    fn successfully_recover_from_log_and_snapshot() {
+        let dir = TempDir::new("recover");
+
+        let mut bst = DurableBST::recover(&dir.0).unwrap();
+        bst.set_compaction_threshold(5);
+        for value in [5, 3, 8, 1, 4, 7] {
+            bst.insert(value).unwrap();
+        }
+        bst.remove(&3).unwrap();
+        // 5 operations went into the snapshot, 2 are still in the log
+        assert_eq!(bst.logged(), 2);
+        drop(bst);
+
+        let bst = DurableBST::<i32>::recover(&dir.0).unwrap();
+        assert_eq!(bst.tree().asc_order_vec(), vec![&1, &4, &5, &7, &8]);
+        assert_eq!(bst.logged(), 2);
+    }
+
+    #[test]
+    fn successfully_drop_torn_record_after_crash() {
+        let dir = TempDir::new("torn");
+
+        let mut bst = DurableBST::recover(&dir.0).unwrap();
+        bst.insert(String::from("kept")).unwrap();
+        drop(bst);
+
+        // Simulates a crash in the middle of writing a record
+        let mut wal = OpenOptions::new()
+            .append(true)
+            .open(dir.0.join("wal.log"))
+            .unwrap();
+        wal.write_all(&[1, 200, 0, 0, 0, 1, 2, 3]).unwrap();
+        drop(wal);
+
+        let mut bst = DurableBST::<String>::recover(&dir.0).unwrap();
+        assert_eq!(bst.len(), 1);
+        bst.insert(String::from("after")).unwrap();
+        drop(bst);
+
+        let bst = DurableBST::<String>::recover(&dir.0).unwrap();
+        assert_eq!(
+            bst.tree().asc_order_vec(),
+            vec![&String::from("after"), &String::from("kept")]
+        );
+    }
+
+    #[test]
+    fn successfully_ignore_record_with_bad_checksum() {
+        let dir = TempDir::new("checksum");
+
+        let mut bst = DurableBST::recover(&dir.0).unwrap();
+        bst.insert(1u64).unwrap();
+        bst.insert(2).unwrap();
+        drop(bst);
+
+        let path = dir.0.join("wal.log");
+        let mut bytes = fs::read(&path).unwrap();
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0xff;
+        fs::write(&path, bytes).unwrap();
+
+        let bst = DurableBST::<u64>::recover(&dir.0).unwrap();
+        assert_eq!(bst.tree().asc_order_vec(), vec![&1]);
+        assert_eq!(bst.logged(), 1);
+    }
+
+    #[test]
+    fn successfully_compact_into_snapshot() {
+        let dir = TempDir::new("compact");
+
+        let mut bst = DurableBST::recover(&dir.0).unwrap();
+        for value in 0..100u32 {
+            bst.insert(value).unwrap();
+        }
+        bst.compact().unwrap();
+
+        assert_eq!(fs::metadata(dir.0.join("wal.log")).unwrap().len(), 0);
+        assert!(!dir.0.join("snapshot.tmp").exists());
+        drop(bst);
+
+        let bst = DurableBST::<u32>::recover(&dir.0).unwrap();
+        assert_eq!(bst.len(), 100);
+        // Snapshots are read back balanced
+        assert_eq!(bst.tree().height(), Some(6));
+    }
+
+    #[test]
+    fn successfully_apply_operation_when_compaction_fails() {
+        let dir = TempDir::new("compaction-error");
+
+        let mut bst = DurableBST::recover(&dir.0).unwrap();
+        bst.set_compaction_threshold(1);
+        // Snapshots can't be written while a directory is in the way
+        fs::create_dir(dir.0.join("snapshot.tmp")).unwrap();
+
+        assert!(bst.insert(1u8).unwrap());
+        assert!(bst.take_compaction_error().is_some());
+        assert!(bst.take_compaction_error().is_none());
+        assert!(bst.remove(&1).unwrap());
+        assert!(bst.take_compaction_error().is_some());
+        assert_eq!(bst.logged(), 2);
+
+        fs::remove_dir(dir.0.join("snapshot.tmp")).unwrap();
+        assert!(bst.insert(2).unwrap());
+        assert!(bst.take_compaction_error().is_none());
+        assert_eq!(bst.logged(), 0);
+        drop(bst);
+
+        let bst = DurableBST::<u8>::recover(&dir.0).unwrap();
+        assert_eq!(bst.tree().asc_order_vec(), vec![&2]);
+    }
+}
@@ -0,0 +1,344 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+
+/// Reason why a batch passed to [IterativeBST::apply()](crate::IterativeBST::apply()) was
+/// rejected.
+///
+/// `index` is the position of the first offending operation within the batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BatchError {
+    /// The operation inserts a value which is already present at that point of the batch.
+    AlreadyPresent { index: usize },
+    /// The operation removes a value which is not present at that point of the batch.
+    NotPresent { index: usize },
+}
+
+impl BatchError {
+    /// Returns the position of the offending operation within the batch.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, BatchError, IterativeBST, Op};
+    ///
+    /// let mut bst = IterativeBST::from_iter(vec![1]);
+    /// let error = bst.apply(vec![Op::Insert(2), Op::Remove(3)]).unwrap_err();
+    ///
+    /// assert_eq!(error.index(), 1);
+    /// ```
+    pub fn index(&self) -> usize {
+        match self {
+            BatchError::AlreadyPresent { index } | BatchError::NotPresent { index } => *index,
+        }
+    }
+}
+
+impl Display for BatchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BatchError::AlreadyPresent { index } => {
+                write!(
+                    f,
+                    "operation {} inserts a value which is already present",
+                    index
+                )
+            }
+            BatchError::NotPresent { index } => {
+                write!(
+                    f,
+                    "operation {} removes a value which is not present",
+                    index
+                )
+            }
+        }
+    }
+}
+
+impl Error for BatchError {}
+
+/// Error returned by [IterativeBST::try_from_vec()](crate::IterativeBST::try_from_vec()) when
+/// the input contains the same element more than once.
+///
+/// Holds the first element which would have been dropped as a duplicate along with its position
+/// within the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateError<T> {
+    index: usize,
+    value: T,
+}
+
+impl<T> DuplicateError<T> {
+    pub(crate) fn new(index: usize, value: T) -> DuplicateError<T> {
+        DuplicateError { index, value }
+    }
+
+    /// Returns the position of the duplicate within the input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::IterativeBST;
+    ///
+    /// let error = IterativeBST::try_from_vec(vec![1, 2, 1]).unwrap_err();
+    ///
+    /// assert_eq!(error.index(), 2);
+    /// ```
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns a reference to the duplicate element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::IterativeBST;
+    ///
+    /// let error = IterativeBST::try_from_vec(vec![1, 2, 1]).unwrap_err();
+    ///
+    /// assert_eq!(error.value(), &1);
+    /// ```
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Consumes the error, returning the duplicate element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::IterativeBST;
+    ///
+    /// let error = IterativeBST::try_from_vec(vec!["a", "a"]).unwrap_err();
+    ///
+    /// assert_eq!(error.into_value(), "a");
+    /// ```
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Display for DuplicateError<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "element {} is a duplicate of an earlier element",
+            self.index
+        )
+    }
+}
+
+impl<T: Debug> Error for DuplicateError<T> {}
+
+/// Error returned by [IterativeBST::insert_bounded()](crate::IterativeBST::insert_bounded())
+/// when the new node would end up deeper than the given height budget.
+///
+/// Holds the rejected element, so it can be inserted again once the tree has been rebuilt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeightExceeded<T> {
+    depth: usize,
+    value: T,
+}
+
+impl<T> HeightExceeded<T> {
+    pub(crate) fn new(depth: usize, value: T) -> HeightExceeded<T> {
+        HeightExceeded { depth, value }
+    }
+
+    /// Returns the depth the rejected element would have been inserted at.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::from_iter(vec![1, 2, 3]);
+    /// let error = bst.insert_bounded(4, 2).unwrap_err();
+    ///
+    /// assert_eq!(error.depth(), 3);
+    /// ```
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    /// Returns a reference to the rejected element.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Consumes the error, returning the rejected element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::from_iter(vec!["a", "b"]);
+    /// let error = bst.insert_bounded("c", 1).unwrap_err();
+    ///
+    /// assert_eq!(error.into_value(), "c");
+    /// ```
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Display for HeightExceeded<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "element would be inserted at depth {}, exceeding the height budget",
+            self.depth
+        )
+    }
+}
+
+impl<T: Debug> Error for HeightExceeded<T> {}
+
+/// Error returned by [IterativeBST::push_max()](crate::IterativeBST::push_max()) when the
+/// element is not greater than the maximum of the tree.
+///
+/// Holds the rejected element, so it can still be inserted the regular way.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NotGreater<T> {
+    value: T,
+}
+
+impl<T> NotGreater<T> {
+    pub(crate) fn new(value: T) -> NotGreater<T> {
+        NotGreater { value }
+    }
+
+    /// Returns a reference to the rejected element.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Consumes the error, returning the rejected element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::from_iter(vec![1, 3]);
+    /// let error = bst.push_max(2).unwrap_err();
+    ///
+    /// bst.insert(error.into_value());
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3]);
+    /// ```
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+impl<T> Display for NotGreater<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "element is not greater than the maximum of the tree")
+    }
+}
+
+impl<T: Debug> Error for NotGreater<T> {}
+
+/// Error returned by [Bst::retrieve_deadline()](crate::Bst::retrieve_deadline()) and
+/// [Bst::contains_deadline()](crate::Bst::contains_deadline()) when the search passes its
+/// deadline before finishing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimedOut {
+    visited: usize,
+}
+
+impl TimedOut {
+    pub(crate) fn new(visited: usize) -> TimedOut {
+        TimedOut { visited }
+    }
+
+    /// Returns the number of nodes the search had visited when it gave up.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Instant;
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![1, 2, 3]);
+    /// let error = bst.contains_deadline(&3, Instant::now()).unwrap_err();
+    ///
+    /// assert_eq!(error.visited(), 0);
+    /// ```
+    pub fn visited(&self) -> usize {
+        self.visited
+    }
+}
+
+impl Display for TimedOut {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "search passed its deadline after visiting {} nodes",
+            self.visited
+        )
+    }
+}
+
+impl Error for TimedOut {}
+
+/// Reason why [Bst::from_shape_string()](crate::Bst::from_shape_string()) rejected a shape.
+///
+/// `position` is the byte offset within the shape at which the problem was found.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShapeError {
+    /// A character which is not allowed at that point of the shape.
+    UnexpectedChar { position: usize },
+    /// The shape ends before every subtree is closed.
+    UnexpectedEnd { position: usize },
+    /// The text of the value starting there can't be parsed.
+    InvalidValue { position: usize },
+    /// The value starting there is not greater than the value preceding it in order, so the
+    /// shape is not a binary search tree.
+    Unordered { position: usize },
+}
+
+impl ShapeError {
+    /// Returns the byte offset within the shape at which the problem was found.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{IterativeBST, ShapeError};
+    ///
+    /// let error = IterativeBST::<i32>::from_shape_string("2(3,)").unwrap_err();
+    ///
+    /// assert_eq!(error, ShapeError::Unordered { position: 0 });
+    /// assert_eq!(error.position(), 0);
+    /// ```
+    pub fn position(&self) -> usize {
+        match self {
+            ShapeError::UnexpectedChar { position }
+            | ShapeError::UnexpectedEnd { position }
+            | ShapeError::InvalidValue { position }
+            | ShapeError::Unordered { position } => *position,
+        }
+    }
+}
+
+impl Display for ShapeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ShapeError::UnexpectedChar { position } => {
+                write!(f, "unexpected character at {}", position)
+            }
+            ShapeError::UnexpectedEnd { position } => {
+                write!(f, "shape ends unexpectedly at {}", position)
+            }
+            ShapeError::InvalidValue { position } => {
+                write!(f, "value at {} can't be parsed", position)
+            }
+            ShapeError::Unordered { position } => {
+                write!(f, "value at {} is out of order", position)
+            }
+        }
+    }
+}
+
+impl Error for ShapeError {}
@@ -0,0 +1,1159 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::vec::IntoIter;
+
+use crate::node::{HeapNode, Node};
+use crate::{BinarySearchTree, IterativeBST};
+
+/// [IterativeBST] caching clones of its minimum and maximum, so [min](BinarySearchTree::min())
+/// and [max](BinarySearchTree::max()) are answered without descending the tree.
+///
+/// [remove_min](BinarySearchTree::remove_min()) and [remove_max](BinarySearchTree::remove_max())
+/// pick up the next end on their way, either at the end of the subtree left behind by the
+/// removed element or at its parent, so keeping the cache up to date takes no additional walk.
+/// This pays off when the tree is used as a priority queue.
+///
+/// Insertions compare the new element against the cached ends, removals of any other element
+/// leave them as they are. [retrieve_as_mut](BinarySearchTree::retrieve_as_mut()) of an end
+/// drops its clone, as the element may change, and the next modification looks it up again.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{BinarySearchTree, ExtremaBST};
+///
+/// let mut bst = ExtremaBST::from_iter(vec![5, 3, 8, 1, 4]);
+/// assert_eq!(bst.min(), Some(&1));
+/// assert_eq!(bst.max(), Some(&8));
+///
+/// assert_eq!(bst.remove_min(), Some(1));
+/// assert_eq!(bst.remove_max(), Some(8));
+/// assert_eq!(bst.min(), Some(&3));
+/// assert_eq!(bst.max(), Some(&5));
+/// ```
+pub struct ExtremaBST<T: Ord> {
+    bst: IterativeBST<T>,
+    /// Clone of the minimum of `bst`, or `None` if the tree is empty or the clone was dropped
+    /// by [retrieve_as_mut](BinarySearchTree::retrieve_as_mut()).
+    min: Option<T>,
+    /// Clone of the maximum of `bst`, like `min`.
+    max: Option<T>,
+}
+
+/// End of the tree a cached element belongs to.
+#[derive(Clone, Copy)]
+enum End {
+    Min,
+    Max,
+}
+
+impl End {
+    /// Returns the child of `node` towards this end.
+    fn child<T: Ord>(self, node: &Node<T>) -> &HeapNode<T> {
+        match self {
+            End::Min => &node.left,
+            End::Max => &node.right,
+        }
+    }
+
+    fn child_mut<T: Ord>(self, node: &mut Node<T>) -> &mut HeapNode<T> {
+        match self {
+            End::Min => &mut node.left,
+            End::Max => &mut node.right,
+        }
+    }
+
+    /// Returns the child of `node` away from this end.
+    fn other_child_mut<T: Ord>(self, node: &mut Node<T>) -> &mut HeapNode<T> {
+        match self {
+            End::Min => &mut node.right,
+            End::Max => &mut node.left,
+        }
+    }
+}
+
+/// Returns the element at the end of the subtree in `slot`, or `None` if the slot is empty.
+fn spine_end<T: Ord>(slot: &HeapNode<T>, end: End) -> Option<&T> {
+    let mut node = slot.as_deref()?;
+    while let Some(child) = end.child(node) {
+        node = child;
+    }
+    Some(&node.value)
+}
+
+impl<T: Ord> ExtremaBST<T> {
+    /// Creates an empty `ExtremaBST<T>`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let bst: ExtremaBST<i32> = ExtremaBST::new();
+    /// assert!(bst.is_empty());
+    /// assert_eq!(bst.min(), None);
+    /// ```
+    pub const fn new() -> ExtremaBST<T> {
+        ExtremaBST {
+            bst: IterativeBST::new(),
+            min: None,
+            max: None,
+        }
+    }
+
+    /// Returns the underlying [IterativeBST], dropping the cached ends.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let bst = ExtremaBST::from_iter(vec![2, 1]);
+    ///
+    /// assert_eq!(bst.into_inner().asc_order_vec(), vec![&1, &2]);
+    /// ```
+    pub fn into_inner(self) -> IterativeBST<T> {
+        self.bst
+    }
+}
+
+impl<T: Ord + Clone> ExtremaBST<T> {
+    /// Removes and returns both the minimum and the maximum element, or `None` for the ones
+    /// which don't exist.
+    ///
+    /// A tree holding a single element yields it as the minimum.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::from_iter(vec![5, 3, 8]);
+    ///
+    /// assert_eq!(bst.pop_both(), (Some(3), Some(8)));
+    /// assert_eq!(bst.pop_both(), (Some(5), None));
+    /// assert_eq!(bst.pop_both(), (None, None));
+    /// ```
+    pub fn pop_both(&mut self) -> (Option<T>, Option<T>) {
+        (self.remove_end(End::Min), self.remove_end(End::Max))
+    }
+
+    /// Returns an iterator which removes and yields the minimum and the maximum element in
+    /// turns, starting with the minimum, until the tree is empty.
+    ///
+    /// Every step is a removal at one end like [remove_min](BinarySearchTree::remove_min()) and
+    /// [remove_max](BinarySearchTree::remove_max()). The iterator can be dropped early, in which
+    /// case every element which was not yielded stays in the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::from_iter(vec![3, 1, 4, 2, 5]);
+    ///
+    /// let ends: Vec<i32> = bst.swap_remove_extremes().take(3).collect();
+    ///
+    /// assert_eq!(ends, vec![1, 5, 2]);
+    /// assert_eq!(bst.asc_order_vec(), vec![&3, &4]);
+    /// ```
+    pub fn swap_remove_extremes(&mut self) -> impl Iterator<Item = T> + '_ {
+        let mut end = End::Min;
+        std::iter::from_fn(move || {
+            let removed = self.remove_end(end);
+            end = match end {
+                End::Min => End::Max,
+                End::Max => End::Min,
+            };
+            removed
+        })
+    }
+
+    /// Clones the ends which are not cached from the tree.
+    fn refill(&mut self) {
+        if self.min.is_none() {
+            self.min = self.bst.min().cloned();
+        }
+        if self.max.is_none() {
+            self.max = self.bst.max().cloned();
+        }
+    }
+
+    fn remove_end(&mut self, end: End) -> Option<T> {
+        let at_root = end.child(self.bst.root.as_ref()?).is_none();
+        let (removed, next) = if at_root {
+            let mut node = self.bst.root.take().unwrap();
+            self.bst.root = end.other_child_mut(&mut node).take();
+            (node.value, spine_end(&self.bst.root, end).cloned())
+        } else {
+            let mut parent = self.bst.root.as_mut().unwrap();
+            while end.child(end.child(parent).as_ref().unwrap()).is_some() {
+                parent = end.child_mut(parent).as_mut().unwrap();
+            }
+            let slot = end.child_mut(parent);
+            let mut node = slot.take().unwrap();
+            // The end has no child towards itself, only the other subtree takes its place
+            *slot = end.other_child_mut(&mut node).take();
+            let next = spine_end(end.child(parent), end).unwrap_or(&parent.value);
+            (node.value, Some(next.clone()))
+        };
+        self.bst.size -= 1;
+
+        match end {
+            End::Min => self.min = next,
+            End::Max => self.max = next,
+        }
+        if self.bst.is_empty() {
+            self.min = None;
+            self.max = None;
+        }
+        Some(removed)
+    }
+}
+
+impl<T: Ord> Default for ExtremaBST<T> {
+    /// Creates an empty `ExtremaBST<T>`
+    fn default() -> ExtremaBST<T> {
+        ExtremaBST::new()
+    }
+}
+
+impl<T: Ord + Debug> Debug for ExtremaBST<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExtremaBST")
+            .field("bst", &self.bst)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Ord> PartialEq for ExtremaBST<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bst == other.bst
+    }
+}
+
+impl<T: Ord + Clone> Extend<T> for ExtremaBST<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter.into_iter() {
+            self.insert(value)
+        }
+    }
+}
+
+impl<T: Ord + Clone> FromIterator<T> for ExtremaBST<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bst = ExtremaBST::new();
+        bst.extend(iter);
+        bst
+    }
+}
+
+impl<T: Ord + Clone> From<IterativeBST<T>> for ExtremaBST<T> {
+    fn from(bst: IterativeBST<T>) -> Self {
+        let mut bst = ExtremaBST {
+            bst,
+            ..ExtremaBST::new()
+        };
+        bst.refill();
+        bst
+    }
+}
+
+impl<T: Ord + Clone> Clone for ExtremaBST<T> {
+    fn clone(&self) -> Self {
+        ExtremaBST::from(self.bst.clone())
+    }
+}
+
+impl<T: Ord + Debug> Display for ExtremaBST<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.bst)
+    }
+}
+
+impl<T: Ord + Clone> BinarySearchTree<T> for ExtremaBST<T> {
+    /// Returns the total **number of nodes** within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// bst.insert(5);
+    /// bst.insert(10);
+    /// bst.insert(3);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// ```
+    fn size(&self) -> usize {
+        self.bst.size()
+    }
+
+    /// Returns `true` if the binary search tree contains no nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst: ExtremaBST<i32> = ExtremaBST::new();
+    /// assert!(bst.is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        self.bst.is_empty()
+    }
+
+    /// Returns `true` if the binary search tree contains one or more nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// assert!(bst.is_empty());
+    ///
+    /// bst.insert(2);
+    ///
+    /// assert!(bst.is_not_empty());
+    /// ```
+    fn is_not_empty(&self) -> bool {
+        self.bst.is_not_empty()
+    }
+
+    /// Inserts given value as a node.
+    ///
+    /// **Duplicate values are _not allowed_**.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    ///
+    /// bst.insert(10);
+    /// bst.insert(10);   // Element is not inserted
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(15);
+    /// bst.insert(25);
+    ///
+    /// assert_eq!(bst.size(), 5);
+    /// ```
+    fn insert(&mut self, value: T) {
+        self.refill();
+        let new_min = self.min.as_ref().is_none_or(|min| value < *min);
+        let new_max = self.max.as_ref().is_none_or(|max| value > *max);
+        // Only a value beyond one of the ends is cloned, which is never a duplicate
+        let copy = (new_min || new_max).then(|| value.clone());
+        self.bst.insert(value);
+
+        if let Some(copy) = copy {
+            if new_min && new_max {
+                self.max = Some(copy.clone());
+            } else if new_max {
+                self.max = Some(copy);
+                return;
+            }
+            self.min = Some(copy);
+        }
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        self.bst.contains(value)
+    }
+
+    /// Removes the given value.
+    ///
+    /// Tree will not be modified if trying to remove element that does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// assert_eq!(bst.size(), 3);
+    ///
+    /// bst.remove(&5);
+    /// bst.remove(&10); // Element is not removed
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove(&mut self, value: &T) {
+        if self.min.as_ref() == Some(value) {
+            self.remove_end(End::Min);
+        } else if self.max.as_ref() == Some(value) {
+            self.remove_end(End::Max);
+        } else {
+            // Any other element is neither end, unless the clone of that end was dropped
+            self.bst.remove(value);
+        }
+    }
+
+    /// Returns a reference to the element or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert_eq!(bst.retrieve(&5), Some(&5));
+    /// assert_eq!(bst.retrieve(&10), None);
+    /// ```
+    fn retrieve(&self, value: &T) -> Option<&T> {
+        self.bst.retrieve(value)
+    }
+
+    /// Returns a mutable reference to the element (see [ExtremaBST::retrieve()])
+    /// or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// bst.insert(10);
+    /// bst.insert(5);
+    ///
+    /// let optional_retrieved_value_as_mut = bst.retrieve_as_mut(&5);
+    /// assert_eq!(optional_retrieved_value_as_mut, Some(&mut 5));
+    ///
+    /// let mut retrieved_value = optional_retrieved_value_as_mut.unwrap();
+    /// *retrieved_value = 2; // Change value inside tree to '2'
+    ///
+    /// assert_eq!(bst.retrieve_as_mut(&5), None); // 5 does not exist anymore
+    /// assert_eq!(bst.retrieve_as_mut(&2), Some(&mut 2));
+    /// ```
+    fn retrieve_as_mut(&mut self, value: &T) -> Option<&mut T> {
+        if self.min.as_ref() == Some(value) {
+            self.min = None;
+        }
+        if self.max.as_ref() == Some(value) {
+            self.max = None;
+        }
+        self.bst.retrieve_as_mut(value)
+    }
+
+    /// Returns the **height** or `None` if tree is empty.
+    ///
+    /// The height is the number of edges between the root and it's furthest leaf node.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = ExtremaBST::new();
+    /// assert_eq!(bst.height(), None);
+    ///
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The height is 2.
+    /// assert_eq!(bst.height(), Some(2));
+    /// ```
+    fn height(&self) -> Option<isize> {
+        self.bst.height()
+    }
+
+    /// Returns a reference to the minimum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// assert_eq!(bst.min(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.min(), Some(&2));
+    /// ```
+    fn min(&self) -> Option<&T> {
+        self.min.as_ref().or_else(|| self.bst.min())
+    }
+
+    /// Returns a reference to the maximum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// assert_eq!(bst.max(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.max(), Some(&10));
+    /// ```
+    fn max(&self) -> Option<&T> {
+        self.max.as_ref().or_else(|| self.bst.max())
+    }
+
+    /// Removes and returns the minimum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// assert_eq!(bst.remove_min(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_min(), Some(2));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_min(&mut self) -> Option<T> {
+        self.remove_end(End::Min)
+    }
+
+    /// Removes and returns the maximum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// assert_eq!(bst.remove_max(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_max(), Some(10));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_max(&mut self) -> Option<T> {
+        self.remove_end(End::Max)
+    }
+
+    /// Returns references to the elements of the tree in **ascending order.**`
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ExtremaBST::in_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn asc_order_vec(&self) -> Vec<&T> {
+        self.bst.asc_order_vec()
+    }
+
+    /// Returns references to the elements of the tree in the order of a **pre-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = ExtremaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The pre_order_vec is: [&4, &2, &1, &3, &6, &5, &7]
+    /// assert_eq!(bst.pre_order_vec(), vec![&4, &2, &1, &3, &6, &5, &7]);
+    /// ```
+    fn pre_order_vec(&self) -> Vec<&T> {
+        self.bst.pre_order_vec()
+    }
+
+    /// Returns references to the elements of the tree in the order of an **in-order traversal.**
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ExtremaBST::asc_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = ExtremaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The in_order_vec is: [&1, &2, &3, &4, &5, &6, &7]
+    /// assert_eq!(bst.in_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn in_order_vec(&self) -> Vec<&T> {
+        self.bst.in_order_vec()
+    }
+
+    /// Returns references to the elements of the tree in the order of a **post-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = ExtremaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The post_order_vec is: [&1, &3, &2, &5, &7, &6, &4]
+    /// assert_eq!(bst.post_order_vec(), vec![&1, &3, &2, &5, &7, &6, &4]);
+    /// ```
+    fn post_order_vec(&self) -> Vec<&T> {
+        self.bst.post_order_vec()
+    }
+
+    /// Returns references to the elements of the tree in the order of a **level-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = ExtremaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The level_order_vec is: [&4, &2, &6, &1, &3, &5, &7]
+    /// assert_eq!(bst.level_order_vec(), vec![&4, &2, &6, &1, &3, &5, &7]);
+    /// ```
+    fn level_order_vec(&self) -> Vec<&T> {
+        self.bst.level_order_vec()
+    }
+
+    /// Returns an iterator over [ExtremaBST::asc_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ExtremaBST::in_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut asc_order_iter = bst.asc_order_iter();
+    ///
+    /// assert_eq!(asc_order_iter.next(), Some(&1));
+    /// assert_eq!(asc_order_iter.next(), Some(&2));
+    /// assert_eq!(asc_order_iter.next(), Some(&3));
+    /// assert_eq!(asc_order_iter.next(), Some(&4));
+    /// assert_eq!(asc_order_iter.next(), Some(&5));
+    /// assert_eq!(asc_order_iter.next(), None);
+    /// ```
+    fn asc_order_iter(&self) -> IntoIter<&T> {
+        self.bst.asc_order_iter()
+    }
+
+    /// Returns an iterator over [ExtremaBST::pre_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut pre_order_iter = bst.pre_order_iter();
+    ///
+    /// assert_eq!(pre_order_iter.next(), Some(&3));
+    /// assert_eq!(pre_order_iter.next(), Some(&1));
+    /// assert_eq!(pre_order_iter.next(), Some(&2));
+    /// assert_eq!(pre_order_iter.next(), Some(&4));
+    /// assert_eq!(pre_order_iter.next(), Some(&5));
+    /// assert_eq!(pre_order_iter.next(), None);
+    /// ```
+    fn pre_order_iter(&self) -> IntoIter<&T> {
+        self.bst.pre_order_iter()
+    }
+
+    /// Returns an iterator over [ExtremaBST::in_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ExtremaBST::asc_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut in_order_iter = bst.in_order_iter();
+    ///
+    /// assert_eq!(in_order_iter.next(), Some(&1));
+    /// assert_eq!(in_order_iter.next(), Some(&2));
+    /// assert_eq!(in_order_iter.next(), Some(&3));
+    /// assert_eq!(in_order_iter.next(), Some(&4));
+    /// assert_eq!(in_order_iter.next(), Some(&5));
+    /// assert_eq!(in_order_iter.next(), None);
+    /// ```
+    fn in_order_iter(&self) -> IntoIter<&T> {
+        self.bst.in_order_iter()
+    }
+
+    /// Returns an iterator over [ExtremaBST::post_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut post_order_iter = bst.post_order_iter();
+    ///
+    /// assert_eq!(post_order_iter.next(), Some(&2));
+    /// assert_eq!(post_order_iter.next(), Some(&1));
+    /// assert_eq!(post_order_iter.next(), Some(&5));
+    /// assert_eq!(post_order_iter.next(), Some(&4));
+    /// assert_eq!(post_order_iter.next(), Some(&3));
+    /// assert_eq!(post_order_iter.next(), None);
+    /// ```
+    fn post_order_iter(&self) -> IntoIter<&T> {
+        self.bst.post_order_iter()
+    }
+
+    /// Returns an iterator over [ExtremaBST::level_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut level_order_iter = bst.level_order_iter();
+    ///
+    /// assert_eq!(level_order_iter.next(), Some(&3));
+    /// assert_eq!(level_order_iter.next(), Some(&1));
+    /// assert_eq!(level_order_iter.next(), Some(&4));
+    /// assert_eq!(level_order_iter.next(), Some(&2));
+    /// assert_eq!(level_order_iter.next(), Some(&5));
+    /// assert_eq!(level_order_iter.next(), None);
+    /// ```
+    fn level_order_iter(&self) -> IntoIter<&T> {
+        self.bst.level_order_iter()
+    }
+
+    /// Returns [ExtremaBST::asc_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ExtremaBST::into_in_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_asc_order_iter = bst.into_asc_order_iter();
+    ///
+    /// assert_eq!(into_asc_order_iter.next(), Some(1));
+    /// assert_eq!(into_asc_order_iter.next(), Some(2));
+    /// assert_eq!(into_asc_order_iter.next(), Some(3));
+    /// assert_eq!(into_asc_order_iter.next(), Some(4));
+    /// assert_eq!(into_asc_order_iter.next(), Some(5));
+    /// assert_eq!(into_asc_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_asc_order_iter(self) -> IntoIter<T> {
+        self.bst.into_asc_order_iter()
+    }
+
+    /// Returns [ExtremaBST::pre_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_pre_order_iter = bst.into_pre_order_iter();
+    ///
+    /// assert_eq!(into_pre_order_iter.next(), Some(3));
+    /// assert_eq!(into_pre_order_iter.next(), Some(1));
+    /// assert_eq!(into_pre_order_iter.next(), Some(2));
+    /// assert_eq!(into_pre_order_iter.next(), Some(4));
+    /// assert_eq!(into_pre_order_iter.next(), Some(5));
+    /// assert_eq!(into_pre_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_pre_order_iter(self) -> IntoIter<T> {
+        self.bst.into_pre_order_iter()
+    }
+
+    /// Returns [ExtremaBST::in_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ExtremaBST::asc_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_in_order_iter = bst.into_in_order_iter();
+    ///
+    /// assert_eq!(into_in_order_iter.next(), Some(1));
+    /// assert_eq!(into_in_order_iter.next(), Some(2));
+    /// assert_eq!(into_in_order_iter.next(), Some(3));
+    /// assert_eq!(into_in_order_iter.next(), Some(4));
+    /// assert_eq!(into_in_order_iter.next(), Some(5));
+    /// assert_eq!(into_in_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_in_order_iter(self) -> IntoIter<T> {
+        self.bst.into_in_order_iter()
+    }
+
+    /// Returns [ExtremaBST::post_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_post_order_iter = bst.into_post_order_iter();
+    ///
+    /// assert_eq!(into_post_order_iter.next(), Some(2));
+    /// assert_eq!(into_post_order_iter.next(), Some(1));
+    /// assert_eq!(into_post_order_iter.next(), Some(5));
+    /// assert_eq!(into_post_order_iter.next(), Some(4));
+    /// assert_eq!(into_post_order_iter.next(), Some(3));
+    /// assert_eq!(into_post_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_post_order_iter(self) -> IntoIter<T> {
+        self.bst.into_post_order_iter()
+    }
+
+    /// Returns [ExtremaBST::level_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ExtremaBST};
+    ///
+    /// let mut bst = ExtremaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_level_order_iter = bst.into_level_order_iter();
+    ///
+    /// assert_eq!(into_level_order_iter.next(), Some(3));
+    /// assert_eq!(into_level_order_iter.next(), Some(1));
+    /// assert_eq!(into_level_order_iter.next(), Some(4));
+    /// assert_eq!(into_level_order_iter.next(), Some(2));
+    /// assert_eq!(into_level_order_iter.next(), Some(5));
+    /// assert_eq!(into_level_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_level_order_iter(self) -> IntoIter<T> {
+        self.bst.into_level_order_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::{BinarySearchTree, ExtremaBST, IterativeBST};
+
+    fn assert_cached_extrema(bst: &ExtremaBST<u32>) {
+        assert_eq!(bst.min.as_ref(), bst.bst.min());
+        assert_eq!(bst.max.as_ref(), bst.bst.max());
+    }
+
+    #[test]
+    fn successfully_update_extrema_on_insert() {
+        let mut bst = ExtremaBST::new();
+        for value in [50, 30, 70, 20, 80, 40, 60, 10, 90, 90, 10] {
+            bst.insert(value);
+            assert_cached_extrema(&bst);
+        }
+
+        assert_eq!(bst.min, Some(10));
+        assert_eq!(bst.max, Some(90));
+        assert_eq!(bst.size(), 9);
+    }
+
+    #[test]
+    fn successfully_keep_extrema_after_every_kind_of_mutation() {
+        let mut bst = ExtremaBST::from_iter(vec![50, 30, 70, 20, 40, 60, 80, 10, 90]);
+        assert_cached_extrema(&bst);
+
+        bst.remove(&40);
+        assert_cached_extrema(&bst);
+        bst.remove(&50);
+        assert_cached_extrema(&bst);
+        bst.remove(&10);
+        assert_eq!(bst.min(), Some(&20));
+        assert_cached_extrema(&bst);
+        bst.remove(&90);
+        assert_eq!(bst.max(), Some(&80));
+        assert_cached_extrema(&bst);
+
+        assert_eq!(bst.remove_min(), Some(20));
+        assert_eq!(bst.min(), Some(&30));
+        assert_cached_extrema(&bst);
+        assert_eq!(bst.remove_max(), Some(80));
+        assert_eq!(bst.max(), Some(&70));
+        assert_cached_extrema(&bst);
+
+        *bst.retrieve_as_mut(&30).unwrap() = 25;
+        assert_eq!(bst.min, None);
+        assert_eq!(bst.min(), Some(&25));
+        bst.insert(65);
+        assert_cached_extrema(&bst);
+        *bst.retrieve_as_mut(&70).unwrap() = 75;
+        assert_eq!(bst.remove_max(), Some(75));
+        assert_eq!(bst.max(), Some(&65));
+        assert_cached_extrema(&bst);
+
+        assert_eq!(bst.pop_both(), (Some(25), Some(65)));
+        assert_cached_extrema(&bst);
+        assert_eq!(bst.asc_order_vec(), vec![&60]);
+        assert_eq!(bst.remove_max(), Some(60));
+        assert_eq!((bst.min(), bst.max()), (None, None));
+        assert_cached_extrema(&bst);
+    }
+
+    #[test]
+    fn successfully_drain_from_both_ends() {
+        let mut bst = ExtremaBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14, 1, 3, 5, 7, 9, 11, 13]);
+        let mut drained = Vec::new();
+
+        while let Some(min) = bst.remove_min() {
+            drained.push(min);
+            assert_cached_extrema(&bst);
+            if let Some(max) = bst.remove_max() {
+                drained.push(max);
+                assert_cached_extrema(&bst);
+            }
+        }
+
+        assert_eq!(drained, vec![1, 14, 2, 13, 3, 12, 4, 11, 5, 10, 6, 9, 7, 8]);
+        assert!(bst.is_empty());
+        assert_eq!(bst.into_inner(), IterativeBST::new());
+    }
+
+    #[test]
+    fn successfully_match_btreeset_on_random_operations() {
+        let mut bst = ExtremaBST::new();
+        let mut expected = BTreeSet::new();
+        let mut state = 11u32;
+
+        for _ in 0..2000 {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            let value = (state >> 8) % 64;
+            match (state >> 4) % 5 {
+                0 | 1 => {
+                    bst.insert(value);
+                    expected.insert(value);
+                }
+                2 => {
+                    bst.remove(&value);
+                    expected.remove(&value);
+                }
+                3 => assert_eq!(bst.remove_min(), expected.pop_first()),
+                _ => assert_eq!(bst.remove_max(), expected.pop_last()),
+            }
+            assert_cached_extrema(&bst);
+            assert_eq!(bst.min(), expected.first());
+            assert_eq!(bst.max(), expected.last());
+        }
+
+        assert_eq!(bst.size(), expected.len());
+        assert!(bst.asc_order_iter().eq(expected.iter()));
+    }
+
+    #[test]
+    fn successfully_keep_extrema_after_rebalance() {
+        let mut bst = ExtremaBST::from(IterativeBST::with_rebalance_factor(1.0));
+        bst.extend(1..=64);
+
+        assert_cached_extrema(&bst);
+        assert!(bst.height().unwrap() <= 6);
+        assert_eq!(bst.remove_max(), Some(64));
+        assert_eq!(bst.clone().remove_min(), Some(1));
+        assert_eq!(bst.max(), Some(&63));
+    }
+
+    #[test]
+    fn successfully_remove_both_ends_alternately() {
+        let mut bst = ExtremaBST::from_iter(vec![50, 20, 80, 10, 30, 70, 90, 60]);
+
+        assert_eq!(bst.pop_both(), (Some(10), Some(90)));
+        assert_cached_extrema(&bst);
+        assert_eq!(bst.size(), 6);
+
+        let ends: Vec<u32> = bst.swap_remove_extremes().take(3).collect();
+        assert_eq!(ends, vec![20, 80, 30]);
+        assert_cached_extrema(&bst);
+        assert_eq!(bst.asc_order_vec(), vec![&50, &60, &70]);
+
+        assert_eq!(
+            bst.swap_remove_extremes().collect::<Vec<u32>>(),
+            vec![50, 70, 60]
+        );
+        assert!(bst.is_empty());
+        assert_eq!(bst.pop_both(), (None, None));
+    }
+}
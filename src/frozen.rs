@@ -0,0 +1,539 @@
+use std::io::{self, BufWriter, Write};
+use std::marker::PhantomData;
+
+use crate::bst::{Bst, Strategy};
+use crate::iter::SortedIter;
+
+const MAGIC: &[u8; 4] = b"BSTF";
+const VERSION: u32 = 1;
+const HEADER_LEN: usize = 24;
+
+/// Element with a fixed-width encoding, which can be frozen into a [FrozenBST].
+///
+/// Implemented for the primitive integer types using their little-endian representation, except
+/// `usize` and `isize` whose size depends on the platform.
+pub trait FixedElement: Ord + Sized {
+    /// Number of bytes of every encoded element.
+    const SIZE: usize;
+
+    /// Encodes the element into `bytes`, which is exactly [FixedElement::SIZE] long.
+    fn write_fixed(&self, bytes: &mut [u8]);
+
+    /// Decodes an element from `bytes`, which is exactly [FixedElement::SIZE] long.
+    fn read_fixed(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_fixed_element {
+    ($($primitive:ty),*) => {
+        $(
+            impl FixedElement for $primitive {
+                const SIZE: usize = std::mem::size_of::<$primitive>();
+
+                fn write_fixed(&self, bytes: &mut [u8]) {
+                    bytes.copy_from_slice(&self.to_le_bytes());
+                }
+
+                fn read_fixed(bytes: &[u8]) -> $primitive {
+                    <$primitive>::from_le_bytes(bytes.try_into().unwrap())
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_element!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+/// Read-only tree queried in place over the bytes written by [Bst::freeze_to()].
+///
+/// The layout is stable: a 24 byte header (the magic `BSTF`, the format version `1` as a
+/// little-endian `u32`, then [FixedElement::SIZE] and the number of elements as little-endian
+/// `u64`s) followed by the encoded elements in ascending order. That sequence is the implicit
+/// perfectly balanced tree whose root is the middle element, so lookups walk O(log n) elements
+/// without any of them being copied into nodes first.
+///
+/// Nothing is read from the bytes up front, which makes memory-mapping the file (with any
+/// memory mapping crate) enough to query lookup tables far larger than RAM.
+///
+/// # Important
+///
+/// Only the header is validated by [FrozenBST::open()]. Bytes which were not written by
+/// [Bst::freeze_to()] can't cause undefined behavior, but queries over them return
+/// meaningless results.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{BinarySearchTree, FrozenBST, IterativeBST};
+///
+/// let bst = IterativeBST::from(vec![40u32, 10, 30, 20]);
+///
+/// let mut bytes = Vec::new();
+/// bst.freeze_to(&mut bytes).unwrap();
+///
+/// let frozen = FrozenBST::<u32>::open(&bytes).unwrap();
+/// assert_eq!(frozen.len(), 4);
+/// assert!(frozen.contains(&30));
+/// assert!(!frozen.contains(&35));
+/// assert_eq!(frozen.iter().collect::<Vec<_>>(), vec![10, 20, 30, 40]);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct FrozenBST<'a, T: FixedElement> {
+    elements: &'a [u8],
+    len: usize,
+    element: PhantomData<T>,
+}
+
+impl<'a, T: FixedElement> FrozenBST<'a, T> {
+    /// Opens the tree stored in `bytes`, checking the header and the total length only.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [InvalidData](io::ErrorKind::InvalidData) if the header is
+    /// missing or from another format version, the elements have another [FixedElement::SIZE]
+    /// than `T`, or `bytes` does not have the length announced by the header.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, FrozenBST, IterativeBST};
+    ///
+    /// let mut bytes = Vec::new();
+    /// IterativeBST::<u16>::from(vec![1, 2]).freeze_to(&mut bytes).unwrap();
+    ///
+    /// assert!(FrozenBST::<u16>::open(&bytes).is_ok());
+    /// assert!(FrozenBST::<u64>::open(&bytes).is_err());
+    /// assert!(FrozenBST::<u16>::open(&bytes[..bytes.len() - 1]).is_err());
+    /// ```
+    pub fn open(bytes: &'a [u8]) -> io::Result<FrozenBST<'a, T>> {
+        match FrozenBST::check(bytes) {
+            Ok(tree) => Ok(tree),
+            Err(message) => Err(invalid_data(message)),
+        }
+    }
+
+    /// Opens the tree stored in `bytes` like [FrozenBST::open()], in a `const` context.
+    ///
+    /// Together with [include_bytes!] this ships lookup tables inside the binary: the layout is
+    /// written at build time, e.g. by a build script calling [FrozenBST::freeze_slice_to()],
+    /// and the header is checked while compiling, so querying the table has no startup cost.
+    ///
+    /// ```rust,ignore
+    /// static PRIMES: FrozenBST<u32> =
+    ///     FrozenBST::open_const(include_bytes!(concat!(env!("OUT_DIR"), "/primes.bstf")));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics in the same cases as [FrozenBST::open()] returns an error, which fails the build
+    /// when evaluated for a `const` or `static` item.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::FrozenBST;
+    ///
+    /// static SMALL_PRIMES: FrozenBST<u8> = FrozenBST::open_const(
+    ///     b"BSTF\x01\0\0\0\x01\0\0\0\0\0\0\0\x04\0\0\0\0\0\0\0\x02\x03\x05\x07",
+    /// );
+    ///
+    /// assert!(SMALL_PRIMES.contains(&5));
+    /// assert!(!SMALL_PRIMES.contains(&4));
+    /// assert_eq!(SMALL_PRIMES.max(), Some(7));
+    /// ```
+    pub const fn open_const(bytes: &'a [u8]) -> FrozenBST<'a, T> {
+        match FrozenBST::check(bytes) {
+            Ok(tree) => tree,
+            Err(message) => panic!("{}", message),
+        }
+    }
+
+    /// Checks the header and the total length of `bytes`, in a way which can be evaluated at
+    /// compile time.
+    const fn check(bytes: &'a [u8]) -> Result<FrozenBST<'a, T>, &'static str> {
+        if bytes.len() < HEADER_LEN {
+            return Err("not a frozen tree");
+        }
+        let (header, elements) = bytes.split_at(HEADER_LEN);
+        let mut index = 0;
+        while index < MAGIC.len() {
+            if header[index] != MAGIC[index] {
+                return Err("not a frozen tree");
+            }
+            index += 1;
+        }
+        if read_header_field(header, 4, 4) != VERSION as u64 {
+            return Err("unsupported frozen tree version");
+        }
+        if read_header_field(header, 8, 8) != T::SIZE as u64 {
+            return Err("element size does not match");
+        }
+
+        let len = read_header_field(header, 16, 8);
+        if len > usize::MAX as u64 {
+            return Err("too many elements");
+        }
+        let len = len as usize;
+        match len.checked_mul(T::SIZE) {
+            Some(size) if size == elements.len() => Ok(FrozenBST {
+                elements,
+                len,
+                element: PhantomData,
+            }),
+            _ => Err("length does not match the header"),
+        }
+    }
+
+    /// Writes `values` in the stable layout of [FrozenBST] without building a tree first,
+    /// sorting them and writing duplicates once.
+    ///
+    /// Meant for build scripts producing lookup tables which are then embedded with
+    /// [FrozenBST::open_const()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::FrozenBST;
+    ///
+    /// let mut bytes = Vec::new();
+    /// FrozenBST::freeze_slice_to(&[30u16, 10, 20, 10], &mut bytes).unwrap();
+    ///
+    /// let frozen = FrozenBST::<u16>::open(&bytes).unwrap();
+    /// assert_eq!(frozen.iter().collect::<Vec<_>>(), vec![10, 20, 30]);
+    /// ```
+    pub fn freeze_slice_to<W: Write>(values: &[T], writer: W) -> io::Result<()> {
+        let mut sorted: Vec<&T> = values.iter().collect();
+        sorted.sort_unstable();
+        sorted.dedup();
+        write_layout(sorted.len(), sorted, writer)
+    }
+
+    /// Returns the total **number of elements** within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, FrozenBST, IterativeBST};
+    ///
+    /// let mut bytes = Vec::new();
+    /// IterativeBST::<i8>::from(vec![1, 2, 3]).freeze_to(&mut bytes).unwrap();
+    ///
+    /// assert_eq!(FrozenBST::<i8>::open(&bytes).unwrap().len(), 3);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the tree contains no elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{FrozenBST, IterativeBST};
+    ///
+    /// let mut bytes = Vec::new();
+    /// IterativeBST::<i8>::new().freeze_to(&mut bytes).unwrap();
+    ///
+    /// assert!(FrozenBST::<i8>::open(&bytes).unwrap().is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the element with the given rank, i.e. the `index`-th smallest element, or
+    /// `None` if `index` is out of bounds. O(1).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, FrozenBST, IterativeBST};
+    ///
+    /// let mut bytes = Vec::new();
+    /// IterativeBST::from(vec![7u64, 3, 5]).freeze_to(&mut bytes).unwrap();
+    /// let frozen = FrozenBST::<u64>::open(&bytes).unwrap();
+    ///
+    /// assert_eq!(frozen.get(0), Some(3));
+    /// assert_eq!(frozen.get(2), Some(7));
+    /// assert_eq!(frozen.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<T> {
+        (index < self.len)
+            .then(|| T::read_fixed(&self.elements[index * T::SIZE..(index + 1) * T::SIZE]))
+    }
+
+    /// Returns `true` if the tree contains an element with the given value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, FrozenBST, IterativeBST};
+    ///
+    /// let mut bytes = Vec::new();
+    /// IterativeBST::from(vec![-5i32, 5]).freeze_to(&mut bytes).unwrap();
+    /// let frozen = FrozenBST::<i32>::open(&bytes).unwrap();
+    ///
+    /// assert!(frozen.contains(&-5));
+    /// assert!(!frozen.contains(&0));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        self.rank(value).is_ok()
+    }
+
+    /// Returns `Ok` with the rank of the given value if it is present, otherwise `Err` with the
+    /// number of smaller elements, i.e. the position it would be inserted at.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, FrozenBST, IterativeBST};
+    ///
+    /// let mut bytes = Vec::new();
+    /// IterativeBST::from(vec![10u8, 20, 30]).freeze_to(&mut bytes).unwrap();
+    /// let frozen = FrozenBST::<u8>::open(&bytes).unwrap();
+    ///
+    /// assert_eq!(frozen.rank(&20), Ok(1));
+    /// assert_eq!(frozen.rank(&25), Err(2));
+    /// ```
+    pub fn rank(&self, value: &T) -> Result<usize, usize> {
+        // Descends the implicit balanced tree, `low..high` being the current subtree
+        let (mut low, mut high) = (0, self.len);
+        while low < high {
+            let middle = low + (high - low) / 2;
+            match self.get(middle).unwrap().cmp(value) {
+                std::cmp::Ordering::Equal => return Ok(middle),
+                std::cmp::Ordering::Less => low = middle + 1,
+                std::cmp::Ordering::Greater => high = middle,
+            }
+        }
+        Err(low)
+    }
+
+    /// Returns the minimum element of the tree or `None` if it is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, FrozenBST, IterativeBST};
+    ///
+    /// let mut bytes = Vec::new();
+    /// IterativeBST::from(vec![2u32, 1, 3]).freeze_to(&mut bytes).unwrap();
+    ///
+    /// assert_eq!(FrozenBST::<u32>::open(&bytes).unwrap().min(), Some(1));
+    /// ```
+    pub fn min(&self) -> Option<T> {
+        self.get(0)
+    }
+
+    /// Returns the maximum element of the tree or `None` if it is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, FrozenBST, IterativeBST};
+    ///
+    /// let mut bytes = Vec::new();
+    /// IterativeBST::from(vec![2u32, 1, 3]).freeze_to(&mut bytes).unwrap();
+    ///
+    /// assert_eq!(FrozenBST::<u32>::open(&bytes).unwrap().max(), Some(3));
+    /// ```
+    pub fn max(&self) -> Option<T> {
+        self.len.checked_sub(1).and_then(|last| self.get(last))
+    }
+
+    /// Returns an iterator decoding the elements in **ascending order.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, FrozenBST, IterativeBST};
+    ///
+    /// let mut bytes = Vec::new();
+    /// IterativeBST::from(vec![2i64, 1]).freeze_to(&mut bytes).unwrap();
+    /// let frozen = FrozenBST::<i64>::open(&bytes).unwrap();
+    ///
+    /// let mut iter = frozen.iter();
+    /// assert_eq!(iter.next(), Some(1));
+    /// assert_eq!(iter.next(), Some(2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = T> + 'a
+    where
+        T: 'a,
+    {
+        self.elements.chunks_exact(T::SIZE).map(T::read_fixed)
+    }
+}
+
+impl<T: FixedElement, S: Strategy> Bst<T, S> {
+    /// Writes the tree to `writer` in the stable layout of [FrozenBST], which can then query it
+    /// in place.
+    ///
+    /// Elements are encoded one at a time while walking the tree and the writer is buffered
+    /// internally.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, FrozenBST, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from(vec![1u8, 2]);
+    ///
+    /// let mut bytes = Vec::new();
+    /// bst.freeze_to(&mut bytes).unwrap();
+    ///
+    /// assert_eq!(&bytes[..4], b"BSTF");
+    /// assert_eq!(bytes.len(), 24 + 2);
+    /// ```
+    pub fn freeze_to<W: Write>(&self, writer: W) -> io::Result<()> {
+        write_layout(self.size, SortedIter::ascending(&self.root), writer)
+    }
+}
+
+/// Reads the little-endian field of `len` bytes at `offset` of the header.
+const fn read_header_field(header: &[u8], offset: usize, len: usize) -> u64 {
+    let mut field = 0;
+    let mut index = len;
+    while index > 0 {
+        index -= 1;
+        field = field << 8 | header[offset + index] as u64;
+    }
+    field
+}
+
+/// Writes the header followed by the `len` elements yielded in ascending order by `values`.
+fn write_layout<'a, T, I, W>(len: usize, values: I, writer: W) -> io::Result<()>
+where
+    T: FixedElement + 'a,
+    I: IntoIterator<Item = &'a T>,
+    W: Write,
+{
+    let mut writer = BufWriter::new(writer);
+
+    let mut header = [0; HEADER_LEN];
+    header[..4].copy_from_slice(MAGIC);
+    VERSION.write_fixed(&mut header[4..8]);
+    (T::SIZE as u64).write_fixed(&mut header[8..16]);
+    (len as u64).write_fixed(&mut header[16..24]);
+    writer.write_all(&header)?;
+
+    let mut bytes = vec![0; T::SIZE];
+    for value in values {
+        value.write_fixed(&mut bytes);
+        writer.write_all(&bytes)?;
+    }
+    writer.flush()
+}
+
+#[cfg(all(test, feature = "iterative", feature = "recursive"))]
+mod tests {
+    use std::io::ErrorKind;
+
+    use crate::{BinarySearchTree, FrozenBST, IterativeBST, RecursiveBST};
+
+    fn freeze<T: crate::FixedElement>(values: Vec<T>) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        IterativeBST::from(values).freeze_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn successfully_query_frozen_tree_like_original() {
+        let values: Vec<i32> = (0..500).map(|value| (value * 7919) % 1000 - 500).collect();
+        let bst = RecursiveBST::from(values);
+        let mut bytes = Vec::new();
+        bst.freeze_to(&mut bytes).unwrap();
+
+        let frozen = FrozenBST::<i32>::open(&bytes).unwrap();
+
+        assert_eq!(frozen.len(), bst.size());
+        assert_eq!(frozen.min(), bst.min().copied());
+        assert_eq!(frozen.max(), bst.max().copied());
+        assert_eq!(
+            frozen.iter().collect::<Vec<_>>(),
+            bst.asc_order_vec().into_iter().copied().collect::<Vec<_>>()
+        );
+        for value in -600..600 {
+            assert_eq!(frozen.contains(&value), bst.contains(&value));
+        }
+    }
+
+    #[test]
+    fn successfully_write_stable_layout() {
+        let bytes = freeze(vec![0x0201u16, 0x0403]);
+
+        assert_eq!(
+            bytes,
+            [
+                b"BSTF".as_slice(),
+                &1u32.to_le_bytes(),
+                &2u64.to_le_bytes(),
+                &2u64.to_le_bytes(),
+                &[0x01, 0x02, 0x03, 0x04],
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn successfully_query_empty_frozen_tree() {
+        let bytes = freeze(Vec::<u128>::new());
+        let frozen = FrozenBST::<u128>::open(&bytes).unwrap();
+
+        assert!(frozen.is_empty());
+        assert_eq!(frozen.min(), None);
+        assert_eq!(frozen.max(), None);
+        assert_eq!(frozen.rank(&1), Err(0));
+        assert_eq!(frozen.iter().next(), None);
+    }
+
+    #[test]
+    fn successfully_reject_invalid_headers() {
+        let bytes = freeze(vec![1u32, 2, 3]);
+        let open = |bytes: &[u8]| FrozenBST::<u32>::open(bytes).unwrap_err().kind();
+
+        assert_eq!(open(&bytes[..10]), ErrorKind::InvalidData);
+        assert_eq!(open(&[&bytes[..], &[0]].concat()), ErrorKind::InvalidData);
+
+        let mut other_magic = bytes.clone();
+        other_magic[0] = b'X';
+        assert_eq!(open(&other_magic), ErrorKind::InvalidData);
+
+        let mut other_version = bytes.clone();
+        other_version[4] = 2;
+        assert_eq!(open(&other_version), ErrorKind::InvalidData);
+
+        let mut huge_len = bytes;
+        huge_len[16..24].copy_from_slice(&u64::MAX.to_le_bytes());
+        assert_eq!(open(&huge_len), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn successfully_embed_layout_written_from_slice() {
+        const EMBEDDED: FrozenBST<u16> = FrozenBST::open_const(&[
+            b'B', b'S', b'T', b'F', 1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0,
+            0x01, 0x00, 0x00, 0x01, 0xff, 0xff,
+        ]);
+        let values: [u16; 4] = [0x0100, 0xffff, 1, 0x0100];
+        let mut bytes = Vec::new();
+        FrozenBST::freeze_slice_to(&values, &mut bytes).unwrap();
+
+        assert_eq!(bytes, freeze(values.to_vec()));
+        assert_eq!(EMBEDDED.iter().collect::<Vec<_>>(), vec![1, 0x0100, 0xffff]);
+        assert_eq!(
+            FrozenBST::<u16>::open_const(&bytes)
+                .iter()
+                .collect::<Vec<_>>(),
+            EMBEDDED.iter().collect::<Vec<_>>()
+        );
+        assert!(FrozenBST::<i64>::open_const(&freeze(Vec::<i64>::new())).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "element size does not match")]
+    fn panic_on_const_open_of_other_element_size() {
+        FrozenBST::<u64>::open_const(&freeze(vec![1u32]));
+    }
+}
@@ -0,0 +1,53 @@
+#[cfg(feature = "metrics")]
+use crate::metrics;
+#[cfg(feature = "tracing")]
+use crate::trace::{self, TraceEvent};
+
+/// Reports an insertion whose search ended at `depth` to whichever of the `tracing` and
+/// `metrics` features are enabled, `found` being whether an equal element was already there and
+/// `size` the number of elements afterwards.
+///
+/// The depth is taken from the walk of the insertion itself, so reporting never descends the
+/// tree on its own, and nothing at all is done with both features disabled.
+pub(crate) fn insert(depth: usize, found: bool, size: usize) {
+    #[cfg(feature = "tracing")]
+    trace::emit(|| TraceEvent::insert_at(depth, found));
+    #[cfg(feature = "metrics")]
+    metrics::insert(depth, size);
+    let _ = (depth, found, size);
+}
+
+/// Reports a removal whose search ended at `depth`, like [insert()].
+pub(crate) fn remove(depth: usize, found: bool, size: usize) {
+    #[cfg(feature = "tracing")]
+    trace::emit(|| TraceEvent::remove_at(depth, found));
+    #[cfg(feature = "metrics")]
+    metrics::remove(depth, size);
+    let _ = (depth, found, size);
+}
+
+/// Reports the removal of the minimum or maximum element from `depth`, or an attempt to remove
+/// one from an empty tree if `removed` is `false`.
+pub(crate) fn remove_end(depth: usize, removed: bool, size: usize) {
+    #[cfg(feature = "tracing")]
+    trace::emit(|| TraceEvent::remove_end(depth, removed));
+    #[cfg(feature = "metrics")]
+    metrics::remove(depth, size);
+    let _ = (depth, removed, size);
+}
+
+/// Reports a bulk removal of `removed` elements, leaving the tree with `size` elements.
+pub(crate) fn remove_many(removed: usize, size: usize) {
+    #[cfg(feature = "tracing")]
+    trace::emit(|| TraceEvent::RemoveMany { count: removed });
+    #[cfg(feature = "metrics")]
+    metrics::remove_many(removed, size);
+    let _ = (removed, size);
+}
+
+/// Reports a lookup whose search ended at `depth`.
+pub(crate) fn lookup(depth: usize) {
+    #[cfg(feature = "metrics")]
+    metrics::lookup(depth);
+    let _ = depth;
+}
@@ -0,0 +1,252 @@
+use std::borrow::Borrow;
+use std::fmt::{Debug, Formatter};
+use std::rc::Rc;
+
+use crate::node::Node;
+use crate::{BinarySearchTree, IterativeBST};
+
+/// Deduplicating storage handing out shared [Rc] handles to the canonical copy of each value,
+/// e.g. for symbol tables.
+///
+/// Every distinct value is stored once in an [IterativeBST]. Interning a value which is already
+/// present returns a handle to the stored copy instead of keeping another one, so handles of
+/// equal values can be compared cheaply with [Rc::ptr_eq()] and outlive the interner.
+///
+/// Like [get_or_insert()](IterativeBST::get_or_insert()), every lookup takes a single descent.
+///
+/// # Example
+///
+/// ```rust
+/// use std::rc::Rc;
+/// use bst_rs::InternBST;
+///
+/// let mut symbols = InternBST::new();
+///
+/// let first = symbols.intern(String::from("main"));
+/// let second = symbols.intern(String::from("main"));
+///
+/// assert!(Rc::ptr_eq(&first, &second));
+/// assert_eq!(symbols.size(), 1);
+/// ```
+pub struct InternBST<T: Ord> {
+    bst: IterativeBST<Rc<T>>,
+}
+
+impl<T: Ord> InternBST<T> {
+    /// Creates an empty `InternBST<T>`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::InternBST;
+    ///
+    /// let symbols: InternBST<String> = InternBST::new();
+    /// assert!(symbols.is_empty());
+    /// ```
+    pub fn new() -> InternBST<T> {
+        InternBST {
+            bst: IterativeBST::new(),
+        }
+    }
+
+    /// Returns the total **number of distinct values** stored.
+    pub fn size(&self) -> usize {
+        self.bst.size()
+    }
+
+    /// Returns `true` if no value has been interned yet.
+    pub fn is_empty(&self) -> bool {
+        self.bst.is_empty()
+    }
+
+    /// Returns a handle to the stored value equal to `value`, storing `value` first if there is
+    /// none.
+    ///
+    /// `value` is dropped if an equal value is already stored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use bst_rs::InternBST;
+    ///
+    /// let mut symbols = InternBST::new();
+    /// let main = symbols.intern(String::from("main"));
+    ///
+    /// assert_eq!(*main, "main");
+    /// assert_eq!(Rc::strong_count(&main), 2);
+    /// ```
+    pub fn intern(&mut self, value: T) -> Rc<T> {
+        let slot = Node::iterative_slot_by(&mut self.bst.root, |current| (**current).cmp(&value));
+        if slot.is_none() {
+            *slot = Some(Box::new(Node::new(Rc::new(value))));
+            self.bst.size += 1;
+        }
+        Rc::clone(&slot.as_ref().unwrap().value)
+    }
+
+    /// Returns a handle to the stored value equal to `probe`, storing an owned copy of `probe`
+    /// first if there is none.
+    ///
+    /// Borrowed forms such as `&str` for [String] values can be used as probes, the owned
+    /// value is only built when the probe is missing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::rc::Rc;
+    /// use bst_rs::InternBST;
+    ///
+    /// let mut symbols: InternBST<String> = InternBST::new();
+    ///
+    /// let first = symbols.intern_ref("main");
+    /// let second = symbols.intern_ref("main");
+    ///
+    /// assert!(Rc::ptr_eq(&first, &second));
+    /// assert_eq!(symbols.size(), 1);
+    /// ```
+    pub fn intern_ref<Q>(&mut self, probe: &Q) -> Rc<T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ToOwned<Owned = T> + ?Sized,
+    {
+        let slot = Node::iterative_slot_by(&mut self.bst.root, |current| {
+            (**current).borrow().cmp(probe)
+        });
+        if slot.is_none() {
+            *slot = Some(Box::new(Node::new(Rc::new(probe.to_owned()))));
+            self.bst.size += 1;
+        }
+        Rc::clone(&slot.as_ref().unwrap().value)
+    }
+
+    /// Returns a reference to the handle of the stored value equal to `probe` or `None` if it
+    /// has not been interned.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::InternBST;
+    ///
+    /// let mut symbols = InternBST::new();
+    /// symbols.intern(String::from("main"));
+    ///
+    /// assert_eq!(symbols.get("main").map(|symbol| symbol.as_str()), Some("main"));
+    /// assert!(symbols.get("exit").is_none());
+    /// ```
+    pub fn get<Q>(&self, probe: &Q) -> Option<&Rc<T>>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        Node::iterative_retrieve_by(&self.bst.root, |current| (**current).borrow().cmp(probe))
+    }
+
+    /// Returns `true` if a value equal to `probe` has been interned.
+    pub fn contains<Q>(&self, probe: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.get(probe).is_some()
+    }
+
+    /// Returns an iterator over the handles of the stored values in **ascending order.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::InternBST;
+    ///
+    /// let mut symbols = InternBST::new();
+    /// symbols.intern("b");
+    /// symbols.intern("a");
+    ///
+    /// assert_eq!(symbols.iter().map(|symbol| **symbol).collect::<Vec<&str>>(), vec!["a", "b"]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &Rc<T>> {
+        self.bst.asc_order_iter()
+    }
+
+    /// Drops the stored copies of values without any handle left outside of the interner,
+    /// returning how many were removed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::InternBST;
+    ///
+    /// let mut symbols = InternBST::new();
+    /// let kept = symbols.intern("main");
+    /// symbols.intern("exit");
+    ///
+    /// assert_eq!(symbols.purge_unused(), 1);
+    /// assert!(symbols.contains("main"));
+    /// assert!(!symbols.contains("exit"));
+    /// ```
+    pub fn purge_unused(&mut self) -> usize {
+        let (used, unused): (Vec<Rc<T>>, Vec<Rc<T>>) = std::mem::take(&mut self.bst)
+            .into_asc_order_iter()
+            .partition(|value| Rc::strong_count(value) > 1);
+        self.bst = IterativeBST::from_sorted(used, None, None);
+        unused.len()
+    }
+}
+
+impl<T: Ord> Default for InternBST<T> {
+    /// Creates an empty `InternBST<T>`
+    fn default() -> InternBST<T> {
+        InternBST::new()
+    }
+}
+
+impl<T: Ord + Debug> Debug for InternBST<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use crate::InternBST;
+
+    #[test]
+    fn successfully_share_canonical_values() {
+        let mut symbols = InternBST::new();
+        let names = ["let", "x", "let", "y", "x", "let"];
+
+        let handles: Vec<Rc<String>> = names
+            .iter()
+            .map(|name| symbols.intern(name.to_string()))
+            .collect();
+
+        assert_eq!(symbols.size(), 3);
+        assert!(Rc::ptr_eq(&handles[0], &handles[2]));
+        assert!(Rc::ptr_eq(&handles[0], &handles[5]));
+        assert!(Rc::ptr_eq(&handles[1], &handles[4]));
+        assert!(!Rc::ptr_eq(&handles[1], &handles[3]));
+        assert!(Rc::ptr_eq(&symbols.intern_ref("y"), &handles[3]));
+        assert!(Rc::ptr_eq(symbols.get("let").unwrap(), &handles[0]));
+        assert_eq!(format!("{:?}", symbols), r#"{"let", "x", "y"}"#);
+    }
+
+    #[test]
+    fn successfully_keep_handles_after_purging_and_dropping_interner() {
+        let mut symbols = InternBST::new();
+        let kept = symbols.intern_ref("kept");
+        for name in ["a", "b", "c"] {
+            symbols.intern_ref(name);
+        }
+
+        assert_eq!(symbols.purge_unused(), 3);
+        assert_eq!(symbols.size(), 1);
+        assert!(Rc::ptr_eq(&symbols.intern_ref("kept"), &kept));
+        assert_eq!(symbols.purge_unused(), 0);
+
+        drop(symbols);
+        assert_eq!(*kept, "kept");
+        assert_eq!(Rc::strong_count(&kept), 1);
+    }
+}
@@ -0,0 +1,580 @@
+use std::cmp::Ordering;
+#[cfg(all(
+    feature = "traversals-extra",
+    any(feature = "iterative", feature = "recursive")
+))]
+use std::collections::VecDeque;
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+use std::iter::Peekable;
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+use std::ops::RangeInclusive;
+
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+use crate::instrument;
+use crate::node::{HeapNode, Node};
+
+/// Lazy iterator over the elements of a tree in ascending or descending order.
+///
+/// Only the path from the current element back up to the root is ever held on the stack, so
+/// stopping early avoids walking the rest of the tree.
+pub(crate) struct SortedIter<'a, T: Ord> {
+    stack: Vec<&'a Node<T>>,
+    descending: bool,
+}
+
+impl<'a, T: Ord> SortedIter<'a, T> {
+    pub(crate) fn ascending(root: &'a HeapNode<T>) -> SortedIter<'a, T> {
+        SortedIter::new(root, false)
+    }
+
+    pub(crate) fn descending(root: &'a HeapNode<T>) -> SortedIter<'a, T> {
+        SortedIter::new(root, true)
+    }
+
+    /// Ascending iterator starting at the first element for which `f` does not return
+    /// [Ordering::Less], following the same convention as [Node::iterative_slot_by()].
+    pub(crate) fn lower_bound_by<F>(mut root: &'a HeapNode<T>, mut f: F) -> SortedIter<'a, T>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut iter = SortedIter {
+            stack: Vec::new(),
+            descending: false,
+        };
+        while let Some(current) = root {
+            if f(&current.value) == Ordering::Less {
+                root = &current.right;
+            } else {
+                iter.stack.push(current);
+                root = &current.left;
+            }
+        }
+        iter
+    }
+
+    /// Iterator starting at the first element in its direction for which `skip` returns
+    /// `false`, `skip` having to return `true` for a prefix of the elements in that direction.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn seek<F>(
+        mut root: &'a HeapNode<T>,
+        descending: bool,
+        mut skip: F,
+    ) -> SortedIter<'a, T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut iter = SortedIter {
+            stack: Vec::new(),
+            descending,
+        };
+        while let Some(current) = root {
+            let (before, after) = if descending {
+                (&current.right, &current.left)
+            } else {
+                (&current.left, &current.right)
+            };
+            if skip(&current.value) {
+                root = after;
+            } else {
+                iter.stack.push(current);
+                root = before;
+            }
+        }
+        iter
+    }
+
+    fn new(root: &'a HeapNode<T>, descending: bool) -> SortedIter<'a, T> {
+        SortedIter::with_stack(root, descending, Vec::new())
+    }
+
+    /// Creates an iterator keeping its stack in `stack`, which must be empty, so an existing
+    /// allocation can be reused.
+    pub(crate) fn with_stack(
+        root: &'a HeapNode<T>,
+        descending: bool,
+        stack: Vec<&'a Node<T>>,
+    ) -> SortedIter<'a, T> {
+        debug_assert!(stack.is_empty());
+        let mut iter = SortedIter { stack, descending };
+        iter.push_spine(root);
+        iter
+    }
+
+    /// Takes the stack out of the iterator, leaving it exhausted.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn take_stack(&mut self) -> Vec<&'a Node<T>> {
+        std::mem::take(&mut self.stack)
+    }
+
+    fn push_spine(&mut self, mut root: &'a HeapNode<T>) {
+        while let Some(current) = root {
+            self.stack.push(current);
+            root = if self.descending {
+                &current.right
+            } else {
+                &current.left
+            };
+        }
+    }
+}
+
+impl<'a, T: Ord> Iterator for SortedIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.stack.pop()?;
+        if self.descending {
+            self.push_spine(&current.left);
+        } else {
+            self.push_spine(&current.right);
+        }
+
+        Some(&current.value)
+    }
+}
+
+/// Lazy iterator handing out mutable references to the elements of one side of a tree in
+/// ascending order, see [Bst::split_at_mut()](crate::Bst::split_at_mut()).
+///
+/// Elements may be changed freely as long as they keep comparing the same way to every other
+/// element of the tree, e.g. by only updating data which is ignored by their [Ord]
+/// implementation. Otherwise, the tree is left in an unspecified but memory-safe state in which
+/// searches may miss elements.
+pub struct RangeMut<'a, T: Ord> {
+    /// Elements waiting to be handed out, each followed by the subtree of elements which come
+    /// directly after it.
+    stack: Vec<(&'a mut T, Option<&'a mut Node<T>>)>,
+}
+
+impl<'a, T: Ord> RangeMut<'a, T> {
+    /// Splits the tree into the elements less than `value` and the ones greater than or equal
+    /// to it.
+    ///
+    /// Only the search path for `value` is walked. Every node on it which is less than `value`
+    /// hands its whole left subtree to the lower range, every other node its whole right
+    /// subtree to the upper range.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn split(
+        mut root: &'a mut HeapNode<T>,
+        value: &T,
+    ) -> (RangeMut<'a, T>, RangeMut<'a, T>) {
+        let mut lower_path = Vec::new();
+        let mut upper = RangeMut { stack: Vec::new() };
+
+        while let Some(node) = root {
+            let Node {
+                value: current,
+                left,
+                right,
+            } = &mut **node;
+            if *current < *value {
+                lower_path.push((current, left.as_deref_mut()));
+                root = right;
+            } else {
+                upper.stack.push((current, right.as_deref_mut()));
+                root = left;
+            }
+        }
+
+        // The left subtree of a node on the path comes right after the previous node on the path
+        let mut lower = RangeMut { stack: Vec::new() };
+        let mut after = None;
+        for (current, left) in lower_path.into_iter().rev() {
+            lower.stack.push((current, after));
+            after = left;
+        }
+        lower.push_spine(after);
+
+        (lower, upper)
+    }
+
+    /// Walks the elements in ascending order starting at the first one for which `f` does not
+    /// return [Ordering::Less], see [SortedIter::lower_bound_by()].
+    pub(crate) fn lower_bound_by<F>(mut root: &'a mut HeapNode<T>, mut f: F) -> RangeMut<'a, T>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        let mut range = RangeMut { stack: Vec::new() };
+        while let Some(node) = root {
+            let Node { value, left, right } = &mut **node;
+            if f(value) == Ordering::Less {
+                root = right;
+            } else {
+                range.stack.push((value, right.as_deref_mut()));
+                root = left;
+            }
+        }
+        range
+    }
+
+    /// Walks every element of the tree in ascending order.
+    pub(crate) fn ascending(root: &'a mut HeapNode<T>) -> RangeMut<'a, T> {
+        let mut range = RangeMut { stack: Vec::new() };
+        range.push_spine(root.as_deref_mut());
+        range
+    }
+
+    fn push_spine(&mut self, mut root: Option<&'a mut Node<T>>) {
+        while let Some(node) = root {
+            let Node { value, left, right } = node;
+            self.stack.push((value, right.as_deref_mut()));
+            root = left.as_deref_mut();
+        }
+    }
+}
+
+impl<'a, T: Ord> Iterator for RangeMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (value, after) = self.stack.pop()?;
+        self.push_spine(after);
+
+        Some(value)
+    }
+}
+
+/// Decides which elements [merge_iter](crate::IterativeBST::merge_iter()) yields when both trees
+/// contain an equal element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    /// Yield the element of both trees, the one from the first tree coming first.
+    KeepBoth,
+    /// Yield only the element of the first tree.
+    KeepFirst,
+    /// Yield only the element of the second tree.
+    KeepSecond,
+}
+
+/// Order in which [traverse()](crate::Bst::traverse()) visits the elements of a tree, so the
+/// order can be picked at runtime.
+///
+/// Pre-order, post-order and level-order are only available with the `traversals-extra`
+/// feature, which is why matching on this enum requires a wildcard arm.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TraversalOrder {
+    /// Root, then left subtree, then right subtree.
+    #[cfg(feature = "traversals-extra")]
+    Pre,
+    /// Left subtree, then root, then right subtree, i.e. ascending order.
+    In,
+    /// Left subtree, then right subtree, then root.
+    #[cfg(feature = "traversals-extra")]
+    Post,
+    /// Level by level starting at the root, each level from left to right.
+    #[cfg(feature = "traversals-extra")]
+    Level,
+    /// Descending order.
+    Desc,
+}
+
+/// Which of the two bounds given to [between()](crate::Bst::between()) are part of the range.
+///
+/// `Start` and `End` refer to the order of the arguments, not to the smaller and larger bound,
+/// so they keep their meaning when the range is walked in descending order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Inclusivity {
+    /// Both bounds are included, like `start..=end`.
+    Inclusive,
+    /// Neither bound is included.
+    Exclusive,
+    /// Only the start is included, like `start..end`.
+    IncludeStart,
+    /// Only the end is included.
+    IncludeEnd,
+}
+
+impl Inclusivity {
+    /// Returns whether the start and the end are included.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn includes(self) -> (bool, bool) {
+        match self {
+            Inclusivity::Inclusive => (true, true),
+            Inclusivity::Exclusive => (false, false),
+            Inclusivity::IncludeStart => (true, false),
+            Inclusivity::IncludeEnd => (false, true),
+        }
+    }
+}
+
+/// Lazy iterator over the union of two trees in ascending order.
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+pub(crate) struct MergeIter<'a, T: Ord> {
+    first: Peekable<SortedIter<'a, T>>,
+    second: Peekable<SortedIter<'a, T>>,
+    policy: DuplicatePolicy,
+}
+
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+impl<'a, T: Ord> MergeIter<'a, T> {
+    pub(crate) fn new(
+        first: &'a HeapNode<T>,
+        second: &'a HeapNode<T>,
+        policy: DuplicatePolicy,
+    ) -> MergeIter<'a, T> {
+        MergeIter {
+            first: SortedIter::ascending(first).peekable(),
+            second: SortedIter::ascending(second).peekable(),
+            policy,
+        }
+    }
+}
+
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+impl<'a, T: Ord> Iterator for MergeIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ordering = match (self.first.peek(), self.second.peek()) {
+            (None, None) => return None,
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (Some(x), Some(y)) => x.cmp(y),
+        };
+
+        match (ordering, self.policy) {
+            (Ordering::Less, _) | (Ordering::Equal, DuplicatePolicy::KeepBoth) => self.first.next(),
+            (Ordering::Greater, _) => self.second.next(),
+            (Ordering::Equal, DuplicatePolicy::KeepFirst) => {
+                self.second.next();
+                self.first.next()
+            }
+            (Ordering::Equal, DuplicatePolicy::KeepSecond) => {
+                self.first.next();
+                self.second.next()
+            }
+        }
+    }
+}
+
+/// Removes and yields the elements of a tree in ascending order.
+///
+/// The left spine of the remaining tree is kept detached on the stack, so every step is an
+/// amortized O(1) delete-min. Whatever was not yielded is put back into the tree once the
+/// iterator is dropped.
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+pub(crate) struct DrainSorted<'a, T: Ord> {
+    root: &'a mut HeapNode<T>,
+    size: &'a mut usize,
+    stack: Vec<Box<Node<T>>>,
+    /// Number of elements yielded so far, reported as a single bulk removal once dropped.
+    drained: usize,
+}
+
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+impl<'a, T: Ord> DrainSorted<'a, T> {
+    pub(crate) fn new(root: &'a mut HeapNode<T>, size: &'a mut usize) -> DrainSorted<'a, T> {
+        let mut stack = Vec::new();
+        Node::detach_left_spine(root.take(), &mut stack);
+        DrainSorted {
+            root,
+            size,
+            stack,
+            drained: 0,
+        }
+    }
+}
+
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+impl<'a, T: Ord> Iterator for DrainSorted<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut node = self.stack.pop()?;
+        Node::detach_left_spine(node.right.take(), &mut self.stack);
+        *self.size -= 1;
+        self.drained += 1;
+
+        Some(node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (*self.size, Some(*self.size))
+    }
+}
+
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+impl<'a, T: Ord> Drop for DrainSorted<'a, T> {
+    fn drop(&mut self) {
+        *self.root = Node::reattach_left_spine(std::mem::take(&mut self.stack));
+        instrument::remove_many(self.drained, *self.size);
+    }
+}
+
+/// Consuming iterator yielding the elements of a tree one level at a time, see
+/// [into_levels](crate::Bst::into_levels()).
+///
+/// Only the nodes of the next level are held, each node being freed as soon as its element has
+/// been moved out of it.
+#[cfg(all(
+    feature = "traversals-extra",
+    any(feature = "iterative", feature = "recursive")
+))]
+pub(crate) struct IntoLevels<T: Ord> {
+    level: Vec<Box<Node<T>>>,
+    remaining: usize,
+}
+
+#[cfg(all(
+    feature = "traversals-extra",
+    any(feature = "iterative", feature = "recursive")
+))]
+impl<T: Ord> IntoLevels<T> {
+    pub(crate) fn new(root: HeapNode<T>, size: usize) -> IntoLevels<T> {
+        IntoLevels {
+            level: root.into_iter().collect(),
+            remaining: size,
+        }
+    }
+}
+
+#[cfg(all(
+    feature = "traversals-extra",
+    any(feature = "iterative", feature = "recursive")
+))]
+impl<T: Ord> Iterator for IntoLevels<T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.level.is_empty() {
+            return None;
+        }
+
+        let mut elements = Vec::with_capacity(self.level.len());
+        let mut next_level = Vec::with_capacity(self.level.len() * 2);
+        for node in self.level.drain(..) {
+            let Node { value, left, right } = *node;
+            elements.push(value);
+            next_level.extend(left);
+            next_level.extend(right);
+        }
+        self.level = next_level;
+        self.remaining -= elements.len();
+
+        Some(elements)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.level.len().min(1), Some(self.remaining))
+    }
+}
+
+/// Lazy iterator over the elements of a tree diagonal by diagonal, see
+/// [diagonal_order_iter](crate::Bst::diagonal_order_iter()).
+///
+/// Each diagonal is walked by following right children, while left children are queued as
+/// the starts of the next diagonal.
+#[cfg(all(
+    feature = "traversals-extra",
+    any(feature = "iterative", feature = "recursive")
+))]
+pub(crate) struct DiagonalIter<'a, T: Ord> {
+    current: Option<&'a Node<T>>,
+    starts: VecDeque<&'a Node<T>>,
+}
+
+#[cfg(all(
+    feature = "traversals-extra",
+    any(feature = "iterative", feature = "recursive")
+))]
+impl<'a, T: Ord> DiagonalIter<'a, T> {
+    pub(crate) fn new(root: &'a HeapNode<T>) -> DiagonalIter<'a, T> {
+        DiagonalIter {
+            current: root.as_deref(),
+            starts: VecDeque::new(),
+        }
+    }
+}
+
+#[cfg(all(
+    feature = "traversals-extra",
+    any(feature = "iterative", feature = "recursive")
+))]
+impl<'a, T: Ord> Iterator for DiagonalIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = match self.current {
+            Some(current) => current,
+            None => self.starts.pop_front()?,
+        };
+        if let Some(left) = current.left.as_deref() {
+            self.starts.push_back(left);
+        }
+        self.current = current.right.as_deref();
+
+        Some(&current.value)
+    }
+}
+
+/// Integer-like type whose values can be enumerated one after another, used to detect runs of
+/// contiguous values such as in [coalesced_ranges](crate::IterativeBST::coalesced_ranges()) or
+/// [RangeSetBST](crate::RangeSetBST).
+///
+/// Implemented for all primitive integer types.
+pub trait Step: Ord + Copy {
+    /// Returns the value directly following this one or `None` if this is the largest value.
+    fn successor(&self) -> Option<Self>;
+
+    /// Returns the value directly preceding this one or `None` if this is the smallest value.
+    fn predecessor(&self) -> Option<Self>;
+}
+
+macro_rules! impl_step {
+    ($($primitive:ty),*) => {
+        $(
+            impl Step for $primitive {
+                fn successor(&self) -> Option<$primitive> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(&self) -> Option<$primitive> {
+                    self.checked_sub(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_step!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Lazy iterator merging runs of contiguous values of an ascending iterator into ranges.
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+pub(crate) struct CoalescedRanges<T, I> {
+    elements: I,
+    next_start: Option<T>,
+}
+
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+impl<T, I> CoalescedRanges<T, I> {
+    pub(crate) fn new(elements: I) -> CoalescedRanges<T, I> {
+        CoalescedRanges {
+            elements,
+            next_start: None,
+        }
+    }
+}
+
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+impl<'a, T: Step + 'a, I: Iterator<Item = &'a T>> Iterator for CoalescedRanges<T, I> {
+    type Item = RangeInclusive<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = match self.next_start.take() {
+            Some(start) => start,
+            None => *self.elements.next()?,
+        };
+
+        let mut end = start;
+        for value in self.elements.by_ref() {
+            if end.successor() != Some(*value) {
+                self.next_start = Some(*value);
+                break;
+            }
+            end = *value;
+        }
+
+        Some(start..=end)
+    }
+}
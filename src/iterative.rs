@@ -1,107 +1,197 @@
-use std::fmt::{Debug, Display, Formatter};
 use std::vec::IntoIter;
 
-use crate::BinarySearchTree;
-use crate::Node;
-use crate::HeapNode;
+use crate::bst::{Bst, Iterative};
+use crate::instrument;
+use crate::node::{HeapNode, Node};
+#[cfg(feature = "tracing")]
+use crate::trace::{self, TraceEvent};
+use crate::{BinarySearchTree, HeightExceeded, NotGreater};
 
 /// Iterative Binary Search Tree implementation.
 ///
 /// # Important
 ///
-/// This should be preferred over [RecursiveBST] for reasons listed in crate level documentation.
-#[derive(Debug)]
-pub struct IterativeBST<T: Ord> {
-    root: HeapNode<T>,
-    size: usize,
-}
+/// This should be preferred over [RecursiveBST](crate::RecursiveBST) for reasons listed in crate level documentation.
+pub type IterativeBST<T> = Bst<T, Iterative>;
 
 impl<T: Ord> IterativeBST<T> {
-    /// Creates an empty `IterativeBST<T>`
+    /// Inserts given value as a node unless it would end up deeper than `max_height`, in which
+    /// case the tree is left untouched and the value is handed back in a [HeightExceeded].
     ///
-    /// No nodes are allocated on the heap yet
+    /// Keeps the worst-case search length bounded for callers which must guarantee lookup
+    /// latency, and lets them decide when to rebuild the tree instead. Like
+    /// [insert()](BinarySearchTree::insert()), a value which already exists is not inserted
+    /// again and counts as success.
     ///
-    /// # Examples
+    /// # Example
     ///
     /// ```rust
     /// use bst_rs::{BinarySearchTree, IterativeBST};
     ///
-    /// // Empty tree is created
-    /// let mut bst: IterativeBST<i32> = IterativeBST::new();
-    /// assert!(bst.is_empty())
+    /// let mut bst = IterativeBST::new();
+    /// assert!(bst.insert_bounded(1, 1).is_ok());
+    /// assert!(bst.insert_bounded(2, 1).is_ok());
+    ///
+    /// let error = bst.insert_bounded(3, 1).unwrap_err();
+    /// assert_eq!(error.into_value(), 3);
+    /// assert_eq!(bst.height(), Some(1));
+    ///
+    /// // Rebuild into a balanced tree to make room
+    /// let mut bst = IterativeBST::from_iter(vec![1, 2]).merge_balanced(IterativeBST::from_iter(vec![3]));
+    /// assert!(bst.insert_bounded(4, 2).is_ok());
     /// ```
-    pub fn new() -> IterativeBST<T> {
-        IterativeBST {
-            root: None,
-            size: 0,
+    pub fn insert_bounded(&mut self, value: T, max_height: usize) -> Result<(), HeightExceeded<T>> {
+        let (slot, depth) = Node::iterative_search_slot(&mut self.root, &value);
+        let found = slot.is_some();
+        if !found {
+            if depth > max_height {
+                return Err(HeightExceeded::new(depth, value));
+            }
+            *slot = Some(Box::new(Node::new(value)));
+            self.size += 1;
         }
-    }
-}
-
-impl<T: Ord> Default for IterativeBST<T> {
-    /// Creates an empty `IterativeBST<T>`
-    fn default() -> IterativeBST<T> {
-        IterativeBST::new()
-    }
-}
-
-impl<T: Ord> PartialEq for IterativeBST<T> {
-    fn eq(&self, other: &Self) -> bool {
-        self.asc_order_vec() == other.asc_order_vec()
-    }
-}
-
-impl<T: Ord> Extend<T> for IterativeBST<T> {
-    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        for value in iter.into_iter() {
-            self.insert(value)
+        instrument::insert(depth, found, self.size);
+        if !found {
+            self.rebalance_if_degenerate(depth);
         }
+        Ok(())
     }
-}
 
-impl<T: Ord> FromIterator<T> for IterativeBST<T> {
-    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let mut bst = IterativeBST::new();
-        bst.extend(iter);
-        bst
+    /// Appends `value` as the new maximum of the tree, or returns it in a [NotGreater] error if
+    /// it is not greater than the current maximum.
+    ///
+    /// Only the right spine is walked and no comparison is made against the rest of the tree,
+    /// which makes this the fast path for keys arriving in increasing order, such as
+    /// timestamps of a time series. Auto rebalancing still applies, so appending sorted keys to
+    /// a tree created with [IterativeBST::with_rebalance_factor()] keeps it shallow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut series = IterativeBST::new();
+    /// for timestamp in [100, 105, 110] {
+    ///     series.push_max(timestamp).unwrap();
+    /// }
+    ///
+    /// // Late arrivals are rejected and handed back
+    /// let late = series.push_max(103).unwrap_err();
+    /// series.insert(late.into_value());
+    ///
+    /// assert_eq!(series.asc_order_vec(), vec![&100, &103, &105, &110]);
+    /// assert!(series.push_max(110).is_err());
+    /// ```
+    pub fn push_max(&mut self, value: T) -> Result<(), NotGreater<T>> {
+        let depth = Node::iterative_push_max(&mut self.root, value).map_err(NotGreater::new)?;
+        self.size += 1;
+        instrument::insert(depth, false, self.size);
+        self.rebalance_if_degenerate(depth);
+        Ok(())
     }
-}
 
-impl<T: Ord> From<Vec<T>> for IterativeBST<T> {
-    fn from(vec: Vec<T>) -> Self {
-        let mut bst = IterativeBST::new();
-        for value in vec.into_iter() {
-            bst.insert(value);
+    /// Returns a reference to the element equal to `value`, inserting `value` first if there is
+    /// none.
+    ///
+    /// The element is looked up and inserted in a single descent, instead of a
+    /// [contains()](BinarySearchTree::contains()) followed by an
+    /// [insert()](BinarySearchTree::insert()) and a [retrieve()](BinarySearchTree::retrieve()).
+    /// `value` is dropped if an equal element is already present.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut bst = IterativeBST::from_iter(vec![2, 1]);
+    ///
+    /// assert_eq!(bst.get_or_insert(3), &3);
+    /// assert_eq!(bst.get_or_insert(1), &1);
+    /// assert_eq!(bst.size(), 3);
+    /// ```
+    pub fn get_or_insert(&mut self, value: T) -> &T {
+        let (slot, size) = self.insertion_slot(&value);
+        if slot.is_none() {
+            *slot = Some(Box::new(Node::new(value)));
+            *size += 1;
         }
-        bst
+        &slot.as_ref().unwrap().value
     }
-}
 
-impl<T: Ord + Clone> From<&[T]> for IterativeBST<T> {
-    fn from(slice: &[T]) -> Self {
-        let mut bst = IterativeBST::new();
-        for value in slice {
-            bst.insert((*value).clone());
+    /// Returns a reference to the element equal to `probe`, inserting the value returned by `f`
+    /// first if there is none.
+    ///
+    /// Like [IterativeBST::get_or_insert()] this takes a single descent, and `f` is only called
+    /// when the element is missing, so building an owned value can be skipped for elements
+    /// which are already present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value returned by `f` is not equal to `probe`, as it would end up at the
+    /// wrong position.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let mut symbols: IterativeBST<String> = IterativeBST::new();
+    ///
+    /// let probe = String::from("main");
+    /// let symbol = symbols.get_or_insert_with(&probe, || probe.clone());
+    /// assert_eq!(symbol, "main");
+    ///
+    /// // The element is present, so no value is built
+    /// symbols.get_or_insert_with(&probe, || unreachable!());
+    /// assert_eq!(symbols.size(), 1);
+    /// ```
+    pub fn get_or_insert_with<F: FnOnce() -> T>(&mut self, probe: &T, f: F) -> &T {
+        let (slot, size) = self.insertion_slot(probe);
+        if slot.is_none() {
+            let value = f();
+            assert!(
+                value == *probe,
+                "value returned by f must be equal to the probe"
+            );
+            *slot = Some(Box::new(Node::new(value)));
+            *size += 1;
         }
-        bst
-    }
-}
-
-impl<T: Ord + Clone> Clone for IterativeBST<T> {
-    fn clone(&self) -> Self {
-        let mut bst = IterativeBST::new();
-
-        for value in self.in_order_iter() {
-            bst.insert((*value).clone());
+        &slot.as_ref().unwrap().value
+    }
+
+    /// Returns the slot holding the element equal to `probe`, or the empty slot where it is about
+    /// to be inserted by the caller, along with the size of the tree which the caller increments
+    /// once the new node has been written into the slot.
+    ///
+    /// A rebuild moves every element, so if inserting `probe` would trigger one, it happens up
+    /// front and the slot is looked up in the rebuilt tree.
+    fn insertion_slot(&mut self, probe: &T) -> (&mut HeapNode<T>, &mut usize) {
+        if self.rebalance.is_some() {
+            let (depth, found) = Node::search_depth(&self.root, probe);
+            if !found {
+                // The rebuild is decided on the size the tree will have after the insertion
+                self.size += 1;
+                self.rebalance_if_degenerate(depth);
+                self.size -= 1;
+            }
         }
 
-        bst
-    }
-}
-
-impl<T: Ord + Debug> Display for IterativeBST<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self.asc_order_vec())
+        let (slot, depth) = Node::iterative_search_slot(&mut self.root, probe);
+        let found = slot.is_some();
+        instrument::insert(depth, found, self.size + !found as usize);
+        (slot, &mut self.size)
+    }
+
+    fn rebalance_if_degenerate(&mut self, depth: usize) {
+        if let Some(rebalance) = &mut self.rebalance {
+            if rebalance.should_rebuild(depth, self.size) {
+                let elements = Node::iterative_consume_in_order_vec(self.root.take());
+                self.root = Node::build_balanced(elements);
+                rebalance.rebuilt(self.size);
+                #[cfg(feature = "tracing")]
+                trace::emit(|| TraceEvent::Rebalance { size: self.size });
+            }
+        }
     }
 }
 
@@ -177,9 +267,15 @@ impl<T: Ord> BinarySearchTree<T> for IterativeBST<T> {
     /// assert_eq!(bst.size(), 5);
     /// ```
     fn insert(&mut self, value: T) {
-        if Node::iterative_insert(&mut self.root, value).is_ok() {
+        let inserted = Node::iterative_insert(&mut self.root, value);
+        let (Ok(depth) | Err(depth)) = inserted;
+        if inserted.is_ok() {
             self.size += 1;
         }
+        instrument::insert(depth, inserted.is_err(), self.size);
+        if inserted.is_ok() {
+            self.rebalance_if_degenerate(depth);
+        }
     }
 
     /// Returns `true` if the binary search tree contains an element with the given value.
@@ -198,7 +294,9 @@ impl<T: Ord> BinarySearchTree<T> for IterativeBST<T> {
     /// assert!(!bst.contains(&10));
     /// ```
     fn contains(&self, value: &T) -> bool {
-        Node::iterative_contains(&self.root, value)
+        let (depth, found) = Node::search_depth(&self.root, value);
+        instrument::lookup(depth);
+        found
     }
 
     /// Removes the given value.
@@ -221,9 +319,12 @@ impl<T: Ord> BinarySearchTree<T> for IterativeBST<T> {
     /// assert_eq!(bst.size(), 2);
     /// ```
     fn remove(&mut self, value: &T) {
-        if Node::iterative_remove(&mut self.root, value).is_ok() {
+        let removed = Node::iterative_remove(&mut self.root, value);
+        let (Ok(depth) | Err(depth)) = removed;
+        if removed.is_ok() {
             self.size -= 1;
         }
+        instrument::remove(depth, removed.is_ok(), self.size);
     }
 
     /// Returns a reference to the element or `None` if element does not exist.
@@ -364,10 +465,14 @@ impl<T: Ord> BinarySearchTree<T> for IterativeBST<T> {
     /// assert_eq!(bst.size(), 2);
     /// ```
     fn remove_min(&mut self) -> Option<T> {
-        let removed_min = Node::iterative_remove_min(&mut self.root);
+        let (removed_min, depth) = match Node::iterative_remove_min_at(&mut self.root) {
+            None => (None, 0),
+            Some((min, depth)) => (Some(min), depth),
+        };
         if removed_min.is_some() {
             self.size -= 1;
         }
+        instrument::remove_end(depth, removed_min.is_some(), self.size);
         removed_min
     }
 
@@ -390,10 +495,14 @@ impl<T: Ord> BinarySearchTree<T> for IterativeBST<T> {
     /// assert_eq!(bst.size(), 2);
     /// ```
     fn remove_max(&mut self) -> Option<T> {
-        let removed_max = Node::iterative_remove_max(&mut self.root);
+        let (removed_max, depth) = match Node::iterative_remove_max_at(&mut self.root) {
+            None => (None, 0),
+            Some((max, depth)) => (Some(max), depth),
+        };
         if removed_max.is_some() {
             self.size -= 1;
         }
+        instrument::remove_end(depth, removed_max.is_some(), self.size);
         removed_max
     }
 
@@ -848,9 +957,10 @@ impl<T: Ord> BinarySearchTree<T> for IterativeBST<T> {
 
 #[cfg(test)]
 mod tests {
+    use std::panic::{self, AssertUnwindSafe};
     use std::vec::IntoIter;
 
-    use crate::{BinarySearchTree, IterativeBST};
+    use crate::{BatchError, BinarySearchTree, DuplicatePolicy, InsertReport, IterativeBST, Op};
 
     #[test]
     fn successfully_insert_elements_into_bst() {
@@ -1534,7 +1644,7 @@ mod tests {
         actual_bst.insert(2);
         actual_bst.insert(5);
 
-        actual_bst.extend(vec.into_iter());
+        actual_bst.extend(vec);
 
         assert_eq!(actual_bst.size(), 6);
         assert_eq!(actual_bst, expected_bst);
@@ -1550,7 +1660,7 @@ mod tests {
         expected_bst.insert(1);
         expected_bst.insert(10);
 
-        let actual_bst = IterativeBST::from_iter(vec![3, 2, 5, 8, 1, 10].into_iter());
+        let actual_bst = IterativeBST::from_iter(vec![3, 2, 5, 8, 1, 10]);
 
         assert_eq!(actual_bst, expected_bst);
     }
@@ -1588,4 +1698,806 @@ mod tests {
 
         assert_eq!(actual_bst, expected_bst);
     }
+
+    #[test]
+    fn enumerate_order_iter() {
+        let mut bst = IterativeBST::new();
+        bst.insert(3);
+        bst.insert(4);
+        bst.insert(5);
+        bst.insert(1);
+        bst.insert(2);
+
+        let mut enumerate_order_iter = bst.enumerate_order_iter();
+
+        assert_eq!(enumerate_order_iter.next(), Some((0, &1)));
+        assert_eq!(enumerate_order_iter.next(), Some((1, &2)));
+        assert_eq!(enumerate_order_iter.next(), Some((2, &3)));
+        assert_eq!(enumerate_order_iter.next(), Some((3, &4)));
+        assert_eq!(enumerate_order_iter.next(), Some((4, &5)));
+        assert_eq!(enumerate_order_iter.next(), None);
+
+        let empty_bst: IterativeBST<i32> = IterativeBST::new();
+        assert_eq!(empty_bst.enumerate_order_iter().next(), None);
+    }
+
+    #[test]
+    fn successfully_get_k_smallest_elements() {
+        let mut bst = IterativeBST::new();
+        assert_eq!(bst.k_smallest(3).next(), None);
+
+        bst.insert(4);
+        bst.insert(2);
+        bst.insert(6);
+        bst.insert(1);
+        bst.insert(3);
+        bst.insert(5);
+        bst.insert(7);
+
+        assert_eq!(bst.k_smallest(0).next(), None);
+        assert_eq!(bst.k_smallest(3).collect::<Vec<&i32>>(), vec![&1, &2, &3]);
+        assert_eq!(
+            bst.k_smallest(7).collect::<Vec<&i32>>(),
+            bst.asc_order_vec()
+        );
+        assert_eq!(bst.k_smallest(100).count(), 7);
+    }
+
+    #[test]
+    fn successfully_get_k_largest_elements() {
+        let mut bst = IterativeBST::new();
+        assert_eq!(bst.k_largest(3).next(), None);
+
+        bst.insert(4);
+        bst.insert(2);
+        bst.insert(6);
+        bst.insert(1);
+        bst.insert(3);
+        bst.insert(5);
+        bst.insert(7);
+
+        assert_eq!(bst.k_largest(0).next(), None);
+        assert_eq!(bst.k_largest(3).collect::<Vec<&i32>>(), vec![&7, &6, &5]);
+        assert_eq!(
+            bst.k_largest(100).collect::<Vec<&i32>>(),
+            vec![&7, &6, &5, &4, &3, &2, &1]
+        );
+    }
+
+    #[test]
+    fn successfully_remove_min_n_from_bst() {
+        let mut bst = IterativeBST::new();
+        assert_eq!(bst.remove_min_n(2), vec![]);
+
+        bst.insert(5);
+        bst.insert(3);
+        bst.insert(8);
+        bst.insert(1);
+        bst.insert(4);
+        bst.insert(7);
+        bst.insert(9);
+
+        assert_eq!(bst.remove_min_n(0), vec![]);
+        assert_eq!(bst.remove_min_n(4), vec![1, 3, 4, 5]);
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.asc_order_vec(), vec![&7, &8, &9]);
+
+        assert_eq!(bst.remove_min_n(10), vec![7, 8, 9]);
+        assert!(bst.is_empty());
+    }
+
+    #[test]
+    fn successfully_remove_max_n_from_bst() {
+        let mut bst = IterativeBST::new();
+        assert_eq!(bst.remove_max_n(2), vec![]);
+
+        bst.insert(5);
+        bst.insert(3);
+        bst.insert(8);
+        bst.insert(1);
+        bst.insert(4);
+        bst.insert(7);
+        bst.insert(9);
+
+        assert_eq!(bst.remove_max_n(4), vec![9, 8, 7, 5]);
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.asc_order_vec(), vec![&1, &3, &4]);
+        assert_eq!(bst.pre_order_vec(), vec![&3, &1, &4]);
+
+        assert_eq!(bst.remove_max_n(10), vec![4, 3, 1]);
+        assert!(bst.is_empty());
+    }
+
+    #[test]
+    fn successfully_remove_while_predicate_holds() {
+        let mut bst = IterativeBST::new();
+        assert_eq!(bst.remove_while(|_| true), vec![]);
+
+        bst.insert(5);
+        bst.insert(3);
+        bst.insert(8);
+        bst.insert(1);
+        bst.insert(4);
+        bst.insert(7);
+        bst.insert(9);
+
+        assert_eq!(bst.remove_while(|value| *value < 0), vec![]);
+        assert_eq!(bst.remove_while(|value| *value < 6), vec![1, 3, 4, 5]);
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.asc_order_vec(), vec![&7, &8, &9]);
+        assert!(bst.contains(&8));
+        assert_eq!(bst.min(), Some(&7));
+    }
+
+    #[test]
+    fn successfully_rebuild_degenerate_bst_into_balanced_bst() {
+        let mut bst = IterativeBST::with_rebalance_factor(100.0);
+        for value in (1..=15).rev() {
+            bst.insert(value);
+        }
+        assert_eq!(bst.height(), Some(14));
+
+        let mut bst = bst.into_balanced();
+        assert_eq!(bst.size(), 15);
+        assert_eq!(bst.height(), Some(3));
+        assert_eq!(bst.level_order_vec()[..3], [&8, &4, &12]);
+
+        bst.insert(16);
+        assert_eq!(bst.height(), Some(4));
+        assert!(IterativeBST::<i32>::new().into_balanced().is_empty());
+    }
+
+    #[test]
+    fn successfully_retain_one_side_of_bst() {
+        let values = vec![8, 4, 12, 2, 6, 10, 14, 1, 3, 5, 7, 9, 11, 13, 15];
+
+        for split in 0..=16 {
+            let mut greater = IterativeBST::from_iter(values.clone());
+            let mut less = IterativeBST::from_iter(values.clone());
+
+            let dropped = greater.retain_greater(&split);
+            assert_eq!(dropped, split.min(15) as usize);
+            assert!(greater.into_asc_order_iter().eq(split + 1..=15));
+
+            let dropped = less.retain_less(&split);
+            assert_eq!(less.size(), (split - 1).clamp(0, 15) as usize);
+            assert_eq!(dropped + less.size(), 15);
+            assert!(less.asc_order_iter().all(|value| *value < split));
+        }
+
+        let mut bst = IterativeBST::from_iter(vec![3, 1, 2]);
+        assert_eq!(bst.retain_greater(&0), 0);
+        assert_eq!(bst.retain_less(&0), 3);
+        assert!(bst.is_empty());
+        assert_eq!(bst.retain_greater(&0), 0);
+    }
+
+    #[test]
+    fn successfully_look_up_keys_by_borrowed_bytes() {
+        let words = vec!["kiwi", "fig", "banana", "cherry", "fi", "figs", ""];
+        let bst = IterativeBST::from_iter(words.iter().map(|word| word.to_string()));
+
+        for word in &words {
+            assert_eq!(bst.retrieve_key_bytes(word), Some(&word.to_string()));
+        }
+        assert!(!bst.contains_key_bytes("f"));
+        assert!(!bst.contains_key_bytes("figz"));
+        assert!(!bst.contains_key_bytes([0xff]));
+
+        let bytes = IterativeBST::from_iter(vec![vec![1, 2], vec![1], vec![0, 9]]);
+        assert!(bytes.contains_key_bytes([1u8, 2]));
+        assert!(!bytes.contains_key_bytes([1u8, 2, 0]));
+    }
+
+    #[test]
+    fn successfully_remove_extremes_only_when_predicate_holds() {
+        let mut bst = IterativeBST::from_iter(vec![5, 3, 8, 1, 4, 9, 7]);
+        let mut tested = Vec::new();
+
+        assert_eq!(
+            bst.remove_min_if(|min| {
+                tested.push(*min);
+                *min > 1
+            }),
+            None
+        );
+        assert_eq!(bst.remove_min_if(|min| *min == 1), Some(1));
+        assert_eq!(bst.remove_min_if(|min| *min == 3), Some(3));
+        assert_eq!(bst.remove_max_if(|max| *max < 9), None);
+        assert_eq!(bst.remove_max_if(|max| *max == 9), Some(9));
+        assert_eq!(bst.remove_max_if(|max| *max == 8), Some(8));
+
+        assert_eq!(tested, vec![1]);
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.pre_order_vec(), vec![&5, &4, &7]);
+
+        let mut empty = IterativeBST::<i32>::new();
+        assert_eq!(empty.remove_min_if(|_| true), None);
+        assert_eq!(empty.remove_max_if(|_| true), None);
+    }
+
+    #[test]
+    fn successfully_push_increasing_keys_as_new_maximum() {
+        let mut bst = IterativeBST::from_iter(vec![50, 30, 70, 60]);
+
+        assert_eq!(bst.push_max(80), Ok(()));
+        assert_eq!(bst.push_max(75).unwrap_err().into_value(), 75);
+        assert_eq!(bst.push_max(80).unwrap_err().value(), &80);
+        assert_eq!(bst.push_max(90), Ok(()));
+        assert_eq!(bst.size(), 6);
+        assert_eq!(bst.pre_order_vec(), vec![&50, &30, &70, &60, &80, &90]);
+
+        let mut empty = IterativeBST::new();
+        assert_eq!(empty.push_max(1), Ok(()));
+        assert_eq!(empty.max(), Some(&1));
+
+        let mut balanced = IterativeBST::with_rebalance_factor(2.0);
+        for value in 0..1024 {
+            balanced.push_max(value).unwrap();
+        }
+        assert_eq!(balanced.size(), 1024);
+        assert!(balanced.height().unwrap() <= 20);
+        assert!(balanced.asc_order_iter().copied().eq(0..1024));
+    }
+
+    #[test]
+    fn successfully_report_progress_of_load_and_cancel() {
+        let mut bst = IterativeBST::new();
+        let mut calls = Vec::new();
+
+        let report = bst.load_from(vec![5, 3, 8, 3, 1, 9, 7], 3, |taken| {
+            calls.push(taken);
+            true
+        });
+        assert_eq!(calls, vec![3, 6]);
+        assert_eq!(
+            report,
+            InsertReport {
+                inserted: 6,
+                duplicates: 1
+            }
+        );
+        assert_eq!(bst.asc_order_vec(), vec![&1, &3, &5, &7, &8, &9]);
+
+        let mut values = 10..20;
+        let report = bst.load_from(values.by_ref(), 1, |taken| taken < 2);
+        assert_eq!(report.inserted, 2);
+        assert_eq!(values.next(), Some(12));
+        assert_eq!(bst.size(), 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "progress interval must be at least 1")]
+    fn panic_on_load_without_progress_interval() {
+        IterativeBST::new().load_from(vec![1], 0, |_| true);
+    }
+
+    #[test]
+    fn successfully_export_bst_into_std_containers() {
+        let bst = IterativeBST::from_iter(vec![5, 3, 8, 1, 4, 7, 9]);
+
+        assert_eq!(bst.clone().into_sorted_vec(), vec![1, 3, 4, 5, 7, 8, 9]);
+        assert_eq!(
+            bst.into_binary_heap().into_sorted_vec(),
+            vec![1, 3, 4, 5, 7, 8, 9]
+        );
+        assert!(IterativeBST::<i32>::new().into_sorted_vec().is_empty());
+        assert!(IterativeBST::<i32>::new().into_binary_heap().is_empty());
+    }
+
+    #[test]
+    fn successfully_merge_bsts_into_balanced_bst() {
+        let mut first = IterativeBST::new();
+        for value in 1..=4 {
+            first.insert(value);
+        }
+        let mut second = IterativeBST::new();
+        for value in 3..=7 {
+            second.insert(value);
+        }
+
+        let merged = first.merge_balanced(second);
+
+        assert_eq!(merged.size(), 7);
+        assert_eq!(merged.height(), Some(2));
+        assert_eq!(merged.pre_order_vec(), vec![&4, &2, &1, &3, &6, &5, &7]);
+    }
+
+    #[test]
+    fn successfully_merge_bst_with_empty_bst() {
+        let mut bst = IterativeBST::new();
+        bst.insert(2);
+        bst.insert(1);
+
+        let merged = bst.merge_balanced(IterativeBST::new());
+        assert_eq!(merged.asc_order_vec(), vec![&1, &2]);
+        assert_eq!(merged.size(), 2);
+
+        let empty: IterativeBST<i32> = IterativeBST::new().merge_balanced(IterativeBST::new());
+        assert!(empty.is_empty());
+        assert_eq!(empty.height(), None);
+    }
+
+    #[test]
+    fn successfully_merge_iter_over_two_bsts() {
+        let mut first = IterativeBST::new();
+        first.insert((2, 'a'));
+        first.insert((1, 'a'));
+        first.insert((4, 'a'));
+        let mut second = IterativeBST::new();
+        second.insert((3, 'b'));
+        second.insert((2, 'a'));
+        second.insert((5, 'b'));
+
+        assert_eq!(
+            first
+                .merge_iter(&second, DuplicatePolicy::KeepBoth)
+                .collect::<Vec<_>>(),
+            vec![
+                &(1, 'a'),
+                &(2, 'a'),
+                &(2, 'a'),
+                &(3, 'b'),
+                &(4, 'a'),
+                &(5, 'b')
+            ]
+        );
+        assert_eq!(
+            first
+                .merge_iter(&second, DuplicatePolicy::KeepFirst)
+                .count(),
+            5
+        );
+        assert_eq!(
+            first
+                .merge_iter(&second, DuplicatePolicy::KeepSecond)
+                .count(),
+            5
+        );
+    }
+
+    #[test]
+    fn successfully_merge_iter_keeps_element_of_chosen_bst() {
+        let mut first = IterativeBST::new();
+        first.insert(1);
+        let mut second = IterativeBST::new();
+        second.insert(1);
+
+        let kept_first = first.merge_iter(&second, DuplicatePolicy::KeepFirst).next();
+        let kept_second = first
+            .merge_iter(&second, DuplicatePolicy::KeepSecond)
+            .next();
+
+        assert!(std::ptr::eq(kept_first.unwrap(), first.min().unwrap()));
+        assert!(std::ptr::eq(kept_second.unwrap(), second.min().unwrap()));
+
+        let empty = IterativeBST::<i32>::new();
+        assert_eq!(
+            empty.merge_iter(&empty, DuplicatePolicy::KeepBoth).next(),
+            None
+        );
+    }
+
+    #[test]
+    fn successfully_report_duplicates_of_insert_all() {
+        let mut bst = IterativeBST::from_iter(vec![5, 3]);
+
+        let report = bst.insert_all(vec![3, 8, 1, 8, 5, 9]);
+
+        assert_eq!(
+            report,
+            InsertReport {
+                inserted: 3,
+                duplicates: 3
+            }
+        );
+        assert_eq!(bst.size(), 5);
+        assert_eq!(bst.asc_order_vec(), vec![&1, &3, &5, &8, &9]);
+        assert_eq!(bst.insert_all(Vec::new()), InsertReport::default());
+    }
+
+    #[test]
+    fn successfully_report_duplicates_skipped_while_collecting() {
+        let (bst, duplicates) = IterativeBST::from_iter_report(vec![5, 3, 5, 8, 3, 5]);
+
+        assert_eq!(duplicates, 3);
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst, IterativeBST::from_iter(vec![5, 3, 8]));
+
+        let (empty, duplicates) = IterativeBST::<i32>::from_iter_report(Vec::new());
+        assert!(empty.is_empty());
+        assert_eq!(duplicates, 0);
+    }
+
+    #[test]
+    fn successfully_get_or_insert_elements() {
+        let mut bst = IterativeBST::from_iter(vec![5, 3, 8]);
+
+        assert_eq!(bst.get_or_insert(3), &3);
+        assert_eq!(bst.get_or_insert(4), &4);
+        assert_eq!(bst.get_or_insert_with(&9, || 9), &9);
+        assert_eq!(bst.get_or_insert_with(&8, || unreachable!()), &8);
+
+        assert_eq!(bst.size(), 5);
+        assert_eq!(bst.pre_order_vec(), vec![&5, &3, &4, &8, &9]);
+    }
+
+    #[test]
+    fn successfully_get_or_insert_into_rebalancing_bst() {
+        let mut bst = IterativeBST::with_rebalance_factor(1.0);
+        for value in 1..=64 {
+            assert_eq!(bst.get_or_insert(value), &value);
+        }
+
+        assert_eq!(bst.size(), 64);
+        assert!(bst.height().unwrap() <= 6);
+        assert_eq!(
+            bst.asc_order_iter().copied().collect::<Vec<i32>>(),
+            (1..=64).collect::<Vec<i32>>()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "must be equal to the probe")]
+    fn panic_on_get_or_insert_with_unequal_value() {
+        let mut bst = IterativeBST::from_iter(vec![2]);
+        bst.get_or_insert_with(&1, || 3);
+    }
+
+    #[test]
+    fn verify_size_is_kept_when_get_or_insert_with_panics() {
+        let mut bst = IterativeBST::from_iter(vec![2]);
+        let mut rebalanced = IterativeBST::with_rebalance_factor(1.0);
+        rebalanced.extend(vec![1, 2, 3]);
+
+        for tree in [&mut bst, &mut rebalanced] {
+            let size = tree.size();
+            let panicking = panic::catch_unwind(AssertUnwindSafe(|| {
+                tree.get_or_insert_with(&10, || panic!("failed to build value"));
+            }));
+            let unequal = panic::catch_unwind(AssertUnwindSafe(|| {
+                tree.get_or_insert_with(&10, || 11);
+            }));
+
+            assert!(panicking.is_err() && unequal.is_err());
+            assert_eq!(tree.size(), size);
+            assert_eq!(tree.asc_order_vec().len(), size);
+            assert!(!tree.contains(&10));
+            assert_eq!(tree.get_or_insert_with(&10, || 10), &10);
+            assert_eq!(tree.size(), size + 1);
+        }
+    }
+
+    #[test]
+    fn successfully_reject_insertions_exceeding_height_budget() {
+        let mut bst = IterativeBST::from_iter(vec![4, 2, 6]);
+
+        assert_eq!(bst.insert_bounded(1, 2), Ok(()));
+        assert_eq!(bst.insert_bounded(4, 0), Ok(()));
+
+        let error = bst.insert_bounded(0, 2).unwrap_err();
+        assert_eq!(error.depth(), 3);
+        assert_eq!(error.value(), &0);
+        assert_eq!(
+            error.to_string(),
+            "element would be inserted at depth 3, exceeding the height budget"
+        );
+
+        assert_eq!(bst.size(), 4);
+        assert_eq!(bst.height(), Some(2));
+        assert!(!bst.contains(&0));
+    }
+
+    #[test]
+    fn successfully_check_contains_all() {
+        let bst = IterativeBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14, 1, 3]);
+
+        assert!(bst.contains_all(&[14, 1, 8, 6, 1]));
+        assert!(bst.contains_all(&[]));
+        assert!(!bst.contains_all(&[1, 2, 5]));
+        assert!(!bst.contains_all(&[15]));
+        assert!(!IterativeBST::new().contains_all(&[1]));
+    }
+
+    #[test]
+    fn successfully_get_many_in_probe_order() {
+        let bst = IterativeBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14, 1, 3]);
+
+        let found = bst.get_many(&[14, 5, 1, 8, 14, 0]);
+        assert_eq!(
+            found,
+            vec![Some(&14), None, Some(&1), Some(&8), Some(&14), None]
+        );
+        assert!(std::ptr::eq(found[0].unwrap(), bst.max().unwrap()));
+        assert_eq!(bst.get_many(&[]), Vec::<Option<&i32>>::new());
+        assert_eq!(IterativeBST::new().get_many(&[1]), vec![None]);
+    }
+
+    #[test]
+    fn successfully_descend_with_custom_comparison() {
+        let bst = IterativeBST::from_iter(vec![40, 20, 60, 10, 30, 50, 70]);
+
+        let mut visited = Vec::new();
+        let found = bst.descend_with(|current| {
+            visited.push(*current);
+            (current / 10).cmp(&5)
+        });
+
+        assert_eq!(found, Some(&50));
+        assert_eq!(visited, vec![40, 60, 50]);
+        assert_eq!(bst.descend_with(|current| current.cmp(&35)), None);
+        assert_eq!(
+            IterativeBST::<i32>::new().descend_with(|_| unreachable!()),
+            None
+        );
+    }
+
+    #[test]
+    fn successfully_remove_all_given_elements() {
+        let mut bst = IterativeBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14, 1, 3]);
+
+        assert_eq!(bst.remove_all(&[8, 2, 14, 2, 11, 1]), 4);
+
+        assert_eq!(bst.size(), 5);
+        assert_eq!(bst.asc_order_vec(), vec![&3, &4, &6, &10, &12]);
+        assert_eq!(bst.remove_all(&[]), 0);
+        assert_eq!(bst.remove_all(&[3, 4, 6, 10, 12]), 5);
+        assert!(bst.is_empty());
+    }
+
+    #[test]
+    fn successfully_drain_sorted_completely() {
+        let mut bst = IterativeBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14, 1, 3]);
+
+        let drained: Vec<i32> = bst.drain_sorted().collect();
+
+        assert_eq!(drained, vec![1, 2, 3, 4, 6, 8, 10, 12, 14]);
+        assert!(bst.is_empty());
+        assert_eq!(bst, IterativeBST::new());
+    }
+
+    #[test]
+    fn successfully_keep_rest_of_bst_after_stopping_drain_sorted_early() {
+        let mut bst = IterativeBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14, 1, 3]);
+
+        let mut drain_sorted = bst.drain_sorted();
+        assert_eq!(drain_sorted.size_hint(), (9, Some(9)));
+        assert_eq!(drain_sorted.next(), Some(1));
+        assert_eq!(drain_sorted.next(), Some(2));
+        assert_eq!(drain_sorted.next(), Some(3));
+        assert_eq!(drain_sorted.next(), Some(4));
+        drop(drain_sorted);
+
+        assert_eq!(bst.size(), 5);
+        assert_eq!(bst.asc_order_vec(), vec![&6, &8, &10, &12, &14]);
+        assert_eq!(bst.pre_order_vec(), vec![&8, &6, &12, &10, &14]);
+        bst.insert(5);
+        assert_eq!(bst.min(), Some(&5));
+    }
+
+    #[test]
+    fn successfully_rebalance_degenerate_bst_automatically() {
+        let mut bst = IterativeBST::with_rebalance_factor(1.5);
+        for value in 0..500 {
+            bst.insert(value);
+        }
+
+        assert_eq!(bst.size(), 500);
+        assert!(bst.height().unwrap() as f64 <= 1.5 * 500f64.log2());
+        assert!(bst.asc_order_iter().copied().eq(0..500));
+        assert!(bst.clone().rebalance.is_some());
+    }
+
+    #[test]
+    fn successfully_keep_degenerate_bst_without_rebalance_factor() {
+        let mut bst = IterativeBST::new();
+        for value in 0..50 {
+            bst.insert(value);
+        }
+
+        assert_eq!(bst.height(), Some(49));
+    }
+
+    #[test]
+    #[should_panic(expected = "rebalance factor must be at least 1.0")]
+    fn panic_on_rebalance_factor_below_one() {
+        let _: IterativeBST<i32> = IterativeBST::with_rebalance_factor(0.5);
+    }
+
+    #[test]
+    fn successfully_get_depth_histogram() {
+        let balanced = IterativeBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14, 1]);
+        let degenerate = IterativeBST::from_iter(1..=4);
+
+        assert_eq!(balanced.depth_histogram(), vec![1, 2, 4, 1]);
+        assert_eq!(degenerate.depth_histogram(), vec![1, 1, 1, 1]);
+        assert_eq!(
+            balanced.depth_histogram().iter().sum::<usize>(),
+            balanced.size()
+        );
+    }
+
+    #[test]
+    fn successfully_get_average_depth() {
+        let balanced = IterativeBST::from_iter(vec![2, 1, 3]);
+        let degenerate = IterativeBST::from_iter(1..=4);
+
+        assert_eq!(balanced.average_depth(), Some(2.0 / 3.0));
+        assert_eq!(degenerate.average_depth(), Some(1.5));
+        assert_eq!(IterativeBST::from_iter(vec![1]).average_depth(), Some(0.0));
+    }
+
+    #[test]
+    fn successfully_get_balance_factors_in_post_order() {
+        let bst = IterativeBST::from_iter(vec![8, 4, 12, 2, 1, 10, 9]);
+
+        let balance_factors: Vec<(&i32, isize)> = bst.balance_factors().collect();
+
+        assert_eq!(
+            balance_factors,
+            vec![
+                (&1, 0),
+                (&2, 1),
+                (&4, 2),
+                (&9, 0),
+                (&10, 1),
+                (&12, 2),
+                (&8, 0)
+            ]
+        );
+        assert_eq!(
+            bst.balance_factors()
+                .map(|(value, _)| value)
+                .collect::<Vec<_>>(),
+            bst.post_order_vec()
+        );
+        assert_eq!(IterativeBST::<i32>::new().balance_factors().next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "unstable-internals")]
+    fn successfully_rotate_inner_subtrees() {
+        let mut bst = IterativeBST::from_iter(vec![8, 4, 12, 2, 6, 5, 7]);
+
+        assert!(bst.rotate_left_at(&4));
+        assert_eq!(bst.pre_order_vec(), vec![&8, &6, &4, &2, &5, &7, &12]);
+
+        assert!(bst.rotate_right_at(&6));
+        assert_eq!(bst.pre_order_vec(), vec![&8, &4, &2, &6, &5, &7, &12]);
+
+        assert!(!bst.rotate_right_at(&12));
+        assert!(!bst.rotate_left_at(&3));
+        assert_eq!(bst.size(), 7);
+        assert_eq!(bst.asc_order_vec(), vec![&2, &4, &5, &6, &7, &8, &12]);
+    }
+
+    #[test]
+    fn successfully_apply_batch_of_operations() {
+        let mut bst = IterativeBST::from_iter(vec![5, 3, 8]);
+
+        let result = bst.apply(vec![
+            Op::Insert(1),
+            Op::Remove(3),
+            Op::Insert(3),
+            Op::Remove(3),
+            Op::Remove(8),
+        ]);
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(bst.size(), 2);
+        assert_eq!(bst.asc_order_vec(), vec![&1, &5]);
+        assert_eq!(bst.apply(Vec::new()), Ok(()));
+    }
+
+    #[test]
+    fn successfully_reject_whole_batch_on_first_failing_operation() {
+        let mut bst = IterativeBST::from_iter(vec![5, 3, 8]);
+
+        let result = bst.apply(vec![
+            Op::Insert(1),
+            Op::Remove(9),
+            Op::Remove(5),
+            Op::Remove(5),
+            Op::Insert(3),
+        ]);
+
+        assert_eq!(result, Err(BatchError::NotPresent { index: 1 }));
+        assert_eq!(
+            bst.apply(vec![Op::Remove(8), Op::Insert(8), Op::Insert(8)]),
+            Err(BatchError::AlreadyPresent { index: 2 })
+        );
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.asc_order_vec(), vec![&3, &5, &8]);
+    }
+
+    #[test]
+    fn successfully_partition_bst_by_predicate() {
+        let bst = IterativeBST::from_iter(vec![8, 3, 10, 1, 6, 14, 4, 7, 13]);
+
+        let (small, large) = bst.partition(|value| *value < 7);
+
+        assert_eq!(small.size(), 4);
+        assert_eq!(small.asc_order_vec(), vec![&1, &3, &4, &6]);
+        assert_eq!(large.size(), 5);
+        assert_eq!(large.asc_order_vec(), vec![&7, &8, &10, &13, &14]);
+        assert_eq!(large.height(), Some(2));
+    }
+
+    #[test]
+    fn successfully_partition_bst_into_empty_side() {
+        let bst = IterativeBST::from_iter(vec![2, 1, 3]);
+
+        let (all, none) = bst.partition(|_| true);
+
+        assert_eq!(all.size(), 3);
+        assert!(none.is_empty());
+        assert_eq!(none.height(), None);
+    }
+
+    #[test]
+    fn successfully_get_adjacent_pairs_of_bst() {
+        let bst = IterativeBST::from_iter(vec![4, 2, 6, 1]);
+
+        assert_eq!(
+            bst.pairs().collect::<Vec<_>>(),
+            vec![(&1, &2), (&2, &4), (&4, &6)]
+        );
+        assert_eq!(IterativeBST::from_iter(vec![1]).pairs().next(), None);
+        assert_eq!(IterativeBST::<i32>::new().pairs().next(), None);
+    }
+
+    #[test]
+    fn successfully_get_gaps_between_offsets() {
+        let mut offsets = IterativeBST::from_iter(vec![0usize, 4096, 8192, 9216, 16384]);
+
+        assert_eq!(
+            offsets.gaps().collect::<Vec<_>>(),
+            vec![4096, 4096, 1024, 7168]
+        );
+        assert_eq!(offsets.min_gap(), Some(1024));
+
+        offsets.remove(&9216);
+        assert_eq!(offsets.min_gap(), Some(4096));
+    }
+
+    #[test]
+    fn successfully_create_bst_from_vec_without_duplicates() {
+        let bst = IterativeBST::try_from_vec(vec![5, 3, 8, 1]).unwrap();
+
+        assert_eq!(bst, IterativeBST::from(vec![5, 3, 8, 1]));
+        assert_eq!(bst.pre_order_vec(), vec![&5, &3, &1, &8]);
+    }
+
+    #[test]
+    fn successfully_reject_vec_with_duplicates() {
+        let error = IterativeBST::try_from_vec(vec![5, 3, 8, 3, 5]).unwrap_err();
+
+        assert_eq!(error.index(), 3);
+        assert_eq!(error.into_value(), 3);
+        assert_eq!(
+            IterativeBST::try_from_vec(vec!["a", "a"])
+                .unwrap_err()
+                .to_string(),
+            "element 1 is a duplicate of an earlier element"
+        );
+    }
+
+    #[test]
+    fn successfully_coalesce_contiguous_ranges() {
+        let mut ids = IterativeBST::from_iter(vec![-2i8, -1, 0, 5, 126, 127]);
+
+        assert_eq!(
+            ids.coalesced_ranges().collect::<Vec<_>>(),
+            vec![-2..=0, 5..=5, 126..=127]
+        );
+
+        ids.insert(-3);
+        ids.remove(&-1);
+        assert_eq!(
+            ids.coalesced_ranges().collect::<Vec<_>>(),
+            vec![-3..=-2, 0..=0, 5..=5, 126..=127]
+        );
+
+        assert_eq!(IterativeBST::<u8>::new().coalesced_ranges().next(), None);
+    }
 }
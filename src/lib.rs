@@ -2,6 +2,9 @@
 //!
 //! All elements within the Binary Search Trees _must_ implement the [Ord] trait.
 //!
+//! Both are aliases of the generic [Bst] type, whose [Strategy] parameter decides whether
+//! operations walk the tree iteratively or recursively.
+//!
 //! It is also important to note that [RecursiveBST] is more likely to `blow the stack.`
 //! For more information on why that is the case, please have a look at
 //! [The Story of Tail Call Optimizations in Rust.](https://seanchen1991.github.io/posts/tco-story/)
@@ -21,6 +24,51 @@
 //!
 //! I'm more than happy to accept (and encourage) contributions if anyone is kind enough to do so.
 //!
+//! # Feature Flags
+//!
+//! The following features are enabled by default and can be turned off to compile out code
+//! which is not needed, e.g. for firmware targets:
+//!
+//! - `iterative`: [IterativeBST] along with [CachedBST], [DescendingBST], [ExtremaBST],
+//!   [DurableBST], [InternBST], [ShardedBST] and the [bst!] macro which are built on top of it.
+//! - `recursive`: [RecursiveBST].
+//! - `traversals-extra`: the pre-order, post-order and level-order variants of [TraversalOrder]
+//!   along with [Bst::diagonal_order_iter()], [Bst::into_levels()] and the views of
+//!   [Bst::top_view()] and [Bst::bottom_view()].
+//!   The traversals of [BinarySearchTree] are always available, so turning the feature on never
+//!   changes what implementors of the trait have to provide.
+//!
+//! The unit tests build with any combination of features, the doc examples expect the default
+//! features to be enabled.
+//!
+//! Opt-in features:
+//!
+//! - `unstable-internals`: exposes low-level restructuring operations such as `rotate_left_at()`
+//!   and `rotate_right_at()`. These are **not** covered by semver guarantees.
+//! - `tracing`: emits insertions, removals and rebalances of [IterativeBST] and
+//!   [RecursiveBST] as `TRACE` level events of the [tracing](https://docs.rs/tracing) crate,
+//!   with the message `insert`, `remove`, `remove_many` or `rebalance`. Insertions and removals
+//!   carry the `depth` at which the search ended, the number of `comparisons` and whether the
+//!   value was `inserted` or `removed`. Bulk removals such as [Bst::retain_greater()] emit a
+//!   single `remove_many` event carrying the `count` of removed elements, rebalances carry the
+//!   `size` of the tree.
+//! - `metrics`: records operation counters, a search depth histogram and a size gauge of
+//!   [IterativeBST] and [RecursiveBST] through the [metrics](https://docs.rs/metrics) facade,
+//!   under the names of [INSERTS_METRIC], [REMOVES_METRIC], [LOOKUPS_METRIC],
+//!   [SEARCH_DEPTH_METRIC] and [SIZE_METRIC]. Metrics carry no labels, so the size gauge
+//!   reflects whichever tree was modified last.
+//! - `frozen`: [FrozenBST], a read-only tree queried in place over a stable on-disk layout,
+//!   e.g. over a memory-mapped file or over a lookup table embedded in the binary.
+//! - `parallel`: join-based set operations such as [Bst::par_union()] which combine large
+//!   trees on all available cores through the [rayon](https://docs.rs/rayon) thread pool.
+//! - `test-util`: the [test_util] module generating trees of known shapes for reproducible
+//!   tests and benchmarks, and checking implementations of [BinarySearchTree] against the
+//!   contract of the trait.
+//! - `shadow`: [ShadowBST], a differential testing wrapper checking every operation of a tree
+//!   against a [BTreeSet](std::collections::BTreeSet).
+//! - `debug-checks`: [Bst::check_iterators()], asserting that all traversals of a tree agree
+//!   with each other, for fuzzing harnesses and tests of the traversals.
+//!
 //! # Quick Start
 //!
 //! ```rust
@@ -83,14 +131,143 @@
 //! assert_ne!(recursive_bst, RecursiveBST::new());
 //! ```
 
-use crate::node::{HeapNode, Node};
 use std::vec::IntoIter;
 
-mod node;
+mod aa;
+mod arena;
+mod augmented;
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+mod batch;
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+mod bst;
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+mod buffer;
+#[cfg(feature = "iterative")]
+mod cached;
+mod chunked;
+#[cfg(feature = "iterative")]
+mod descending;
+#[cfg(feature = "iterative")]
+mod durable;
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+mod error;
+#[cfg(feature = "iterative")]
+mod extrema;
+#[cfg(all(feature = "frozen", any(feature = "iterative", feature = "recursive")))]
+mod frozen;
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+mod instrument;
+#[cfg(feature = "iterative")]
+mod intern;
+mod iter;
+#[cfg(feature = "iterative")]
 mod iterative;
+mod llrb;
+mod map;
+mod merkle;
+mod meta;
+#[cfg(all(feature = "metrics", any(feature = "iterative", feature = "recursive")))]
+mod metrics;
+mod multimap;
+mod multiset;
+mod node;
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+mod node_ref;
+#[cfg(all(
+    feature = "parallel",
+    any(feature = "iterative", feature = "recursive")
+))]
+mod parallel;
+mod prefix;
+mod priority;
+mod range_set;
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+mod rebalance;
+#[cfg(feature = "recursive")]
 mod recursive;
-pub use recursive::RecursiveBST;
+#[cfg(feature = "shadow")]
+mod shadow;
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+mod shape;
+#[cfg(feature = "iterative")]
+mod sharded;
+mod sort;
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+mod stream;
+#[cfg(all(
+    feature = "test-util",
+    any(feature = "iterative", feature = "recursive")
+))]
+pub mod test_util;
+mod threaded;
+mod tombstone;
+#[cfg(all(feature = "tracing", any(feature = "iterative", feature = "recursive")))]
+mod trace;
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+mod visitor;
+mod weighted;
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+mod zipper;
+pub use aa::AaBST;
+pub use arena::{ArenaBST, ArenaCursor, ArenaObserver, ElementId, OrderHandle, StaleCursor};
+pub use augmented::{Augment, AugmentedBST};
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+pub use batch::Op;
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+pub use bst::{Bst, Iterative, Recursive, Strategy};
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+pub use buffer::TraversalBuffer;
+#[cfg(feature = "iterative")]
+pub use cached::CachedBST;
+pub use chunked::ChunkedBST;
+#[cfg(feature = "iterative")]
+pub use descending::DescendingBST;
+#[cfg(feature = "iterative")]
+pub use durable::DurableBST;
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+pub use error::{BatchError, DuplicateError, HeightExceeded, NotGreater, ShapeError, TimedOut};
+#[cfg(feature = "iterative")]
+pub use extrema::ExtremaBST;
+#[cfg(all(feature = "frozen", any(feature = "iterative", feature = "recursive")))]
+pub use frozen::{FixedElement, FrozenBST};
+#[cfg(feature = "iterative")]
+pub use intern::InternBST;
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+pub use iter::RangeMut;
+pub use iter::{DuplicatePolicy, Inclusivity, Step, TraversalOrder};
+#[cfg(feature = "iterative")]
 pub use iterative::IterativeBST;
+pub use llrb::LlrbBST;
+pub use map::BSTMap;
+pub use merkle::{MembershipProof, Merkle, MerkleBST};
+pub use meta::MetaBST;
+#[cfg(all(feature = "metrics", any(feature = "iterative", feature = "recursive")))]
+pub use metrics::{
+    INSERTS_METRIC, LOOKUPS_METRIC, REMOVES_METRIC, SEARCH_DEPTH_METRIC, SIZE_METRIC,
+};
+pub use multimap::BSTMultiMap;
+pub use multiset::BSTMultiSet;
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+pub use node_ref::{validate, NodeRef};
+pub use prefix::{PrefixBST, PrefixKey};
+pub use priority::{MinPriority, PriorityBST};
+pub use range_set::RangeSetBST;
+#[cfg(feature = "recursive")]
+pub use recursive::RecursiveBST;
+#[cfg(feature = "shadow")]
+pub use shadow::ShadowBST;
+#[cfg(feature = "iterative")]
+pub use sharded::ShardedBST;
+pub use sort::{bst_sort, bst_sort_dedup};
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+pub use stream::{StreamElement, StreamFormat};
+pub use threaded::ThreadedBST;
+pub use tombstone::TombstoneBST;
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+pub use visitor::TreeVisitor;
+pub use weighted::{Weighted, WeightedBST};
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+pub use zipper::Zipper;
 
 /// Creates a [`IterativeBST`] containing the arguments.
 ///
@@ -116,6 +293,7 @@ pub use iterative::IterativeBST;
 /// ```
 ///
 /// [`IterativeBST`]: crate::IterativeBST
+#[cfg(feature = "iterative")]
 #[macro_export]
 macro_rules! bst {
     () => (
@@ -126,25 +304,13 @@ macro_rules! bst {
     );
 }
 
-#[cfg(test)]
-mod tests {
-    use super::{bst, BinarySearchTree, IterativeBST};
-
-    #[test]
-    fn successfully_construct_bst_from_macro() {
-        let mut actual_bst = IterativeBST::new();
-        actual_bst.insert(3);
-        actual_bst.insert(2);
-        let expected_bst = bst![3, 2];
-        assert_eq!(actual_bst, expected_bst);
-    }
-
-    #[test]
-    fn verify_permutations_produce_same_tree() {
-        let actual_bst = bst![2, 3];
-        let expected_bst = bst![3, 2];
-        assert_eq!(actual_bst, expected_bst);
-    }
+/// Outcome of a bulk insertion such as [IterativeBST::insert_all()].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct InsertReport {
+    /// Number of elements that were added to the tree.
+    pub inserted: usize,
+    /// Number of elements that were rejected because an equal element was already present.
+    pub duplicates: usize,
 }
 
 /// A trait containing all the common operations of Binary Search Trees.
@@ -413,3 +579,58 @@ pub trait BinarySearchTree<T: Ord> {
     /// Returns [level_order_iter](Self::level_order_iter()) **AND** consumes the tree.
     fn into_level_order_iter(self) -> IntoIter<T>;
 }
+
+#[cfg(all(test, feature = "iterative"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn successfully_construct_bst_from_macro() {
+        let mut actual_bst = IterativeBST::new();
+        actual_bst.insert(3);
+        actual_bst.insert(2);
+        let expected_bst = bst![3, 2];
+        assert_eq!(actual_bst, expected_bst);
+    }
+
+    #[test]
+    fn verify_permutations_produce_same_tree() {
+        let actual_bst = bst![2, 3];
+        let expected_bst = bst![3, 2];
+        assert_eq!(actual_bst, expected_bst);
+    }
+
+    #[test]
+    #[cfg(feature = "recursive")]
+    fn verify_trees_are_send_and_sync() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        fn assert_send<T: Send>() {}
+
+        assert_send_sync::<IterativeBST<i32>>();
+        assert_send_sync::<RecursiveBST<i32>>();
+        assert_send_sync::<AaBST<i32>>();
+        assert_send_sync::<LlrbBST<i32>>();
+        assert_send_sync::<ArenaBST<i32>>();
+        assert_send_sync::<BSTMap<i32, String>>();
+        assert_send_sync::<BSTMultiMap<i32, String>>();
+        assert_send_sync::<BSTMultiSet<i32>>();
+        assert_send_sync::<ChunkedBST<i32>>();
+        assert_send_sync::<DurableBST<i32>>();
+        assert_send_sync::<DescendingBST<i32>>();
+        assert_send_sync::<ExtremaBST<i32>>();
+        assert_send_sync::<MerkleBST<i32>>();
+        assert_send_sync::<MetaBST<i32, String>>();
+        assert_send_sync::<PrefixBST<String>>();
+        assert_send_sync::<PriorityBST<i32, fn(&i32) -> u8>>();
+        assert_send_sync::<RangeSetBST<i32>>();
+        assert_send_sync::<ShardedBST<i32>>();
+        assert_send_sync::<ThreadedBST<i32>>();
+        assert_send_sync::<TombstoneBST<i32>>();
+        assert_send_sync::<TraversalBuffer<i32>>();
+        assert_send_sync::<WeightedBST<i32, fn(&i32) -> u64>>();
+        assert_send_sync::<Zipper<i32, Recursive>>();
+
+        // Lookups update the cache through `&self`
+        assert_send::<CachedBST<i32>>();
+    }
+}
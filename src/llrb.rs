@@ -0,0 +1,1321 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::vec::IntoIter;
+
+use crate::node::{HeapNode, Node};
+use crate::BinarySearchTree;
+
+/// Element stored inside the nodes of an [LlrbBST] along with the color of the link from its
+/// parent, ordered by the element **only**.
+#[derive(Debug, Clone)]
+struct Colored<T> {
+    value: T,
+    red: bool,
+}
+
+impl<T> Colored<T> {
+    fn values(elements: Vec<&Colored<T>>) -> Vec<&T> {
+        elements.into_iter().map(|current| &current.value).collect()
+    }
+
+    fn into_values(elements: Vec<Colored<T>>) -> IntoIter<T> {
+        elements
+            .into_iter()
+            .map(|current| current.value)
+            .collect::<Vec<T>>()
+            .into_iter()
+    }
+}
+
+impl<T: Ord> PartialEq for Colored<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Ord> Eq for Colored<T> {}
+
+impl<T: Ord> PartialOrd for Colored<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for Colored<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+type LlrbNode<T> = Box<Node<Colored<T>>>;
+
+/// Whether the link to the given subtree is red, links to empty subtrees being black.
+fn is_red<T: Ord>(node: &HeapNode<Colored<T>>) -> bool {
+    node.as_ref().is_some_and(|node| node.value.red)
+}
+
+/// Whether the link to the left child of the given subtree is red.
+fn is_left_red<T: Ord>(node: &HeapNode<Colored<T>>) -> bool {
+    node.as_ref().is_some_and(|node| is_red(&node.left))
+}
+
+/// Turns a red right link into a red left link, so the right child becomes the parent.
+///
+/// ```text
+///   T => R           T <= R
+///  /    / \    =>   / \    \
+/// A    B   C       A   B    C
+/// ```
+fn rotate_left<T: Ord>(mut node: LlrbNode<T>) -> LlrbNode<T> {
+    let mut right = node.right.take().unwrap();
+    node.right = right.left.take();
+    right.value.red = node.value.red;
+    node.value.red = true;
+    right.left = Some(node);
+    right
+}
+
+/// Turns a red left link into a red right link, so the left child becomes the parent.
+///
+/// ```text
+///     L <= T         L => T
+///    / \    \   =>  /    / \
+///   A   B    C      A    B   C
+/// ```
+fn rotate_right<T: Ord>(mut node: LlrbNode<T>) -> LlrbNode<T> {
+    let mut left = node.left.take().unwrap();
+    node.left = left.right.take();
+    left.value.red = node.value.red;
+    node.value.red = true;
+    left.right = Some(node);
+    left
+}
+
+/// Flips the colors of a node and both of its children, which splits a temporary 4-node when
+/// inserting and merges the node with its children into one when removing.
+fn flip_colors<T: Ord>(node: &mut LlrbNode<T>) {
+    node.value.red = !node.value.red;
+    for child in [&mut node.left, &mut node.right].into_iter().flatten() {
+        child.value.red = !child.value.red;
+    }
+}
+
+/// Restores the invariants of a node whose subtrees are valid again, on the way back up from an
+/// insertion or a removal.
+///
+/// These are the only three cases of the left-leaning variant: a red right link is rotated to
+/// the left, two red links in a row are rotated into a node with two red links, which is then
+/// split by flipping the colors.
+fn balance<T: Ord>(mut node: LlrbNode<T>) -> LlrbNode<T> {
+    if is_red(&node.right) && !is_red(&node.left) {
+        node = rotate_left(node);
+    }
+    if is_red(&node.left) && is_left_red(&node.left) {
+        node = rotate_right(node);
+    }
+    if is_red(&node.left) && is_red(&node.right) {
+        flip_colors(&mut node);
+    }
+    node
+}
+
+/// Makes the left child of a node or one of its children red, so that removing from the left
+/// subtree never removes a black node.
+fn move_red_left<T: Ord>(mut node: LlrbNode<T>) -> LlrbNode<T> {
+    flip_colors(&mut node);
+    if is_left_red(&node.right) {
+        node.right = node.right.take().map(rotate_right);
+        node = rotate_left(node);
+        flip_colors(&mut node);
+    }
+    node
+}
+
+/// Mirror image of [move_red_left()] for removals from the right subtree.
+fn move_red_right<T: Ord>(mut node: LlrbNode<T>) -> LlrbNode<T> {
+    flip_colors(&mut node);
+    if is_left_red(&node.left) {
+        node = rotate_right(node);
+        flip_colors(&mut node);
+    }
+    node
+}
+
+/// Inserts `value` below `node` as a red leaf, restoring the invariants on the way back up.
+///
+/// Returns the new root of the subtree and whether the value was inserted.
+fn insert<T: Ord>(node: HeapNode<Colored<T>>, value: T) -> (LlrbNode<T>, bool) {
+    let mut node = match node {
+        None => return (Box::new(Node::new(Colored { value, red: true })), true),
+        Some(node) => node,
+    };
+
+    let inserted = match value.cmp(&node.value.value) {
+        Ordering::Equal => false,
+        Ordering::Less => {
+            let (left, inserted) = insert(node.left.take(), value);
+            node.left = Some(left);
+            inserted
+        }
+        Ordering::Greater => {
+            let (right, inserted) = insert(node.right.take(), value);
+            node.right = Some(right);
+            inserted
+        }
+    };
+
+    (balance(node), inserted)
+}
+
+/// Runs a removal starting at the root, which is painted red first if both of its children are
+/// black, as the removal expects the current node or one of its children to be red, and black
+/// again afterwards.
+fn remove_from_root<T: Ord, F>(
+    root: HeapNode<Colored<T>>,
+    remove: F,
+) -> (HeapNode<Colored<T>>, Option<T>)
+where
+    F: FnOnce(LlrbNode<T>) -> (HeapNode<Colored<T>>, Option<T>),
+{
+    let mut root = match root {
+        None => return (None, None),
+        Some(root) => root,
+    };
+    if !is_red(&root.left) && !is_red(&root.right) {
+        root.value.red = true;
+    }
+
+    let (mut root, removed) = remove(root);
+    if let Some(root) = &mut root {
+        root.value.red = false;
+    }
+    (root, removed)
+}
+
+/// Removes the element equal to `value` below `node`, which must contain it, pushing a red link
+/// down the search path and restoring the invariants on the way back up.
+///
+/// Returns the new root of the subtree and the removed element.
+fn remove<T: Ord>(mut node: LlrbNode<T>, value: &T) -> (HeapNode<Colored<T>>, Option<T>) {
+    let removed = if *value < node.value.value {
+        if !is_red(&node.left) && !is_left_red(&node.left) {
+            node = move_red_left(node);
+        }
+        let (left, removed) = remove(node.left.take().unwrap(), value);
+        node.left = left;
+        removed
+    } else {
+        if is_red(&node.left) {
+            node = rotate_right(node);
+        }
+        // Without a right child there is no left child either, as it would have been red
+        if *value == node.value.value && node.right.is_none() {
+            return (node.left.take(), Some(node.value.value));
+        }
+        if !is_red(&node.right) && !is_left_red(&node.right) {
+            node = move_red_right(node);
+        }
+        if *value == node.value.value {
+            let (right, successor) = remove_min(node.right.take().unwrap());
+            node.right = right;
+            Some(std::mem::replace(&mut node.value.value, successor.unwrap()))
+        } else {
+            let (right, removed) = remove(node.right.take().unwrap(), value);
+            node.right = right;
+            removed
+        }
+    };
+
+    (Some(balance(node)), removed)
+}
+
+/// Removes the minimum element below `node`, see [remove()].
+fn remove_min<T: Ord>(mut node: LlrbNode<T>) -> (HeapNode<Colored<T>>, Option<T>) {
+    if node.left.is_none() {
+        return (node.right.take(), Some(node.value.value));
+    }
+    if !is_red(&node.left) && !is_left_red(&node.left) {
+        node = move_red_left(node);
+    }
+
+    let (left, removed) = remove_min(node.left.take().unwrap());
+    node.left = left;
+    (Some(balance(node)), removed)
+}
+
+/// Removes the maximum element below `node`, see [remove()].
+fn remove_max<T: Ord>(mut node: LlrbNode<T>) -> (HeapNode<Colored<T>>, Option<T>) {
+    if is_red(&node.left) {
+        node = rotate_right(node);
+    }
+    if node.right.is_none() {
+        return (node.left.take(), Some(node.value.value));
+    }
+    if !is_red(&node.right) && !is_left_red(&node.right) {
+        node = move_red_right(node);
+    }
+
+    let (right, removed) = remove_max(node.right.take().unwrap());
+    node.right = right;
+    (Some(balance(node)), removed)
+}
+
+/// Copies a subtree node by node, keeping its shape and colors.
+fn clone_subtree<T: Ord + Clone>(node: &HeapNode<Colored<T>>) -> HeapNode<Colored<T>> {
+    node.as_ref().map(|node| {
+        Box::new(Node {
+            value: node.value.clone(),
+            left: clone_subtree(&node.left),
+            right: clone_subtree(&node.right),
+        })
+    })
+}
+
+/// Left-leaning red-black tree, a self-balancing Binary Search Tree guaranteeing `O(log n)`
+/// insertions, removals and lookups with a much shorter implementation than a classic
+/// red-black tree.
+///
+/// Every link between a node and its parent is either red or black. Red links always lean to
+/// the left and never follow each other, and every path from the root to an empty subtree
+/// crosses the same number of black links, the [black height](LlrbBST::black_height()). Each
+/// node along with a red left child stands for a node of a 2-3 tree, which keeps the height
+/// below `2 * log2(n + 1)`.
+///
+/// Restricting red links to the left leaves only three cases to restore after an insertion and
+/// lets removals share them, where a classic red-black tree distinguishes several symmetric
+/// cases for both. Together with the [AaBST], which restricts them to the right instead, this
+/// makes the tree a readable reference for how red-black trees work.
+///
+/// Insertions and removals are recursive, which is safe since the height is logarithmic.
+///
+/// [AaBST]: crate::AaBST
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{BinarySearchTree, LlrbBST};
+///
+/// // Ascending insertions which degenerate a plain binary search tree into a list
+/// let mut bst = LlrbBST::from_iter(1..=1023);
+/// assert!(bst.height().unwrap() < 20);
+///
+/// bst.remove(&512);
+/// assert_eq!(bst.size(), 1022);
+/// assert!(bst.black_height() <= 10);
+/// ```
+#[derive(Debug)]
+pub struct LlrbBST<T: Ord> {
+    root: HeapNode<Colored<T>>,
+    size: usize,
+}
+
+impl<T: Ord> LlrbBST<T> {
+    /// Creates an empty `LlrbBST<T>`
+    ///
+    /// No nodes are allocated on the heap yet
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, LlrbBST};
+    ///
+    /// // Empty tree is created
+    /// let mut bst: LlrbBST<i32> = LlrbBST::new();
+    /// assert!(bst.is_empty())
+    /// ```
+    pub const fn new() -> LlrbBST<T> {
+        LlrbBST {
+            root: None,
+            size: 0,
+        }
+    }
+
+    /// Returns `true` if the link from the parent of the node holding the given element is red,
+    /// or `None` if the element does not exist.
+    ///
+    /// The root is always black.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::LlrbBST;
+    ///
+    /// let bst = LlrbBST::from_iter(vec![2, 3]);
+    ///
+    /// // 2 leans to the left of 3
+    /// assert_eq!(bst.is_red(&2), Some(true));
+    /// assert_eq!(bst.is_red(&3), Some(false));
+    /// assert_eq!(bst.is_red(&4), None);
+    /// ```
+    pub fn is_red(&self, value: &T) -> Option<bool> {
+        Node::iterative_retrieve_by(&self.root, |current| current.value.cmp(value))
+            .map(|current| current.red)
+    }
+
+    /// Returns the number of black links on every path from the root to an empty subtree,
+    /// counting the root itself, or `0` if the tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::LlrbBST;
+    ///
+    /// assert_eq!(LlrbBST::<i32>::new().black_height(), 0);
+    /// assert_eq!(LlrbBST::from_iter(vec![2, 3]).black_height(), 1);
+    /// assert_eq!(LlrbBST::from_iter(1..=7).black_height(), 3);
+    /// ```
+    pub fn black_height(&self) -> usize {
+        let mut black_height = 0;
+        let mut current = &self.root;
+        while let Some(node) = current {
+            if !node.value.red {
+                black_height += 1;
+            }
+            current = &node.left;
+        }
+        black_height
+    }
+}
+
+impl<T: Ord> Default for LlrbBST<T> {
+    /// Creates an empty `LlrbBST<T>`
+    fn default() -> LlrbBST<T> {
+        LlrbBST::new()
+    }
+}
+
+impl<T: Ord> PartialEq for LlrbBST<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && self.asc_order_iter().eq(other.asc_order_iter())
+    }
+}
+
+impl<T: Ord> Extend<T> for LlrbBST<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter.into_iter() {
+            self.insert(value)
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for LlrbBST<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bst = LlrbBST::new();
+        bst.extend(iter);
+        bst
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for LlrbBST<T> {
+    fn from(vec: Vec<T>) -> Self {
+        let mut bst = LlrbBST::new();
+        for value in vec.into_iter() {
+            bst.insert(value);
+        }
+        bst
+    }
+}
+
+impl<T: Ord + Clone> From<&[T]> for LlrbBST<T> {
+    fn from(slice: &[T]) -> Self {
+        let mut bst = LlrbBST::new();
+        for value in slice {
+            bst.insert((*value).clone());
+        }
+        bst
+    }
+}
+
+impl<T: Ord + Clone> Clone for LlrbBST<T> {
+    fn clone(&self) -> Self {
+        LlrbBST {
+            root: clone_subtree(&self.root),
+            size: self.size,
+        }
+    }
+}
+
+impl<T: Ord + Debug> Display for LlrbBST<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.asc_order_vec())
+    }
+}
+
+impl<T: Ord> BinarySearchTree<T> for LlrbBST<T> {
+    /// Returns the total **number of nodes** within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(5);
+    /// bst.insert(10);
+    /// bst.insert(3);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// ```
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the binary search tree contains no nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst: LlrbBST<i32> = LlrbBST::new();
+    /// assert!(bst.is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns `true` if the binary search tree contains one or more nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// assert!(bst.is_empty());
+    ///
+    /// bst.insert(2);
+    ///
+    /// assert!(bst.is_not_empty());
+    /// ```
+    fn is_not_empty(&self) -> bool {
+        self.size != 0
+    }
+
+    /// Inserts given value as a node.
+    ///
+    /// **Duplicate values are _not allowed_**.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    ///
+    /// bst.insert(10);
+    /// bst.insert(10);   // Element is not inserted
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(15);
+    /// bst.insert(25);
+    ///
+    /// assert_eq!(bst.size(), 5);
+    /// ```
+    fn insert(&mut self, value: T) {
+        let (mut root, inserted) = insert(self.root.take(), value);
+        root.value.red = false;
+        self.root = Some(root);
+        if inserted {
+            self.size += 1;
+        }
+    }
+
+    /// Returns `true` if the binary search tree contains an element with the given value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert!(bst.contains(&5));
+    /// assert!(!bst.contains(&10));
+    /// ```
+    fn contains(&self, value: &T) -> bool {
+        self.retrieve(value).is_some()
+    }
+
+    /// Removes the given value.
+    ///
+    /// Tree will not be modified if trying to remove element that does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// assert_eq!(bst.size(), 3);
+    ///
+    /// bst.remove(&5);
+    /// bst.remove(&10); // Element is not removed
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove(&mut self, value: &T) {
+        if !self.contains(value) {
+            return;
+        }
+        let (root, removed) = remove_from_root(self.root.take(), |root| remove(root, value));
+        self.root = root;
+        if removed.is_some() {
+            self.size -= 1;
+        }
+    }
+
+    /// Returns a reference to the element or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert_eq!(bst.retrieve(&5), Some(&5));
+    /// assert_eq!(bst.retrieve(&10), None);
+    /// ```
+    fn retrieve(&self, value: &T) -> Option<&T> {
+        Node::iterative_retrieve_by(&self.root, |current| current.value.cmp(value))
+            .map(|current| &current.value)
+    }
+
+    /// Returns a mutable reference to the element (see [LlrbBST::retrieve()])
+    /// or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(10);
+    /// bst.insert(5);
+    ///
+    /// let optional_retrieved_value_as_mut = bst.retrieve_as_mut(&5);
+    /// assert_eq!(optional_retrieved_value_as_mut, Some(&mut 5));
+    ///
+    /// let mut retrieved_value = optional_retrieved_value_as_mut.unwrap();
+    /// *retrieved_value = 2; // Change value inside tree to '2'
+    ///
+    /// assert_eq!(bst.retrieve_as_mut(&5), None); // 5 does not exist anymore
+    /// assert_eq!(bst.retrieve_as_mut(&2), Some(&mut 2));
+    /// ```
+    fn retrieve_as_mut(&mut self, value: &T) -> Option<&mut T> {
+        Node::iterative_slot_by(&mut self.root, |current| current.value.cmp(value))
+            .as_mut()
+            .map(|node| &mut node.value.value)
+    }
+
+    /// Returns the **height** or `None` if tree is empty.
+    ///
+    /// The height is the number of edges between the root and it's furthest leaf node.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = LlrbBST::new();
+    /// assert_eq!(bst.height(), None);
+    ///
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The height is 2.
+    /// assert_eq!(bst.height(), Some(2));
+    /// ```
+    fn height(&self) -> Option<isize> {
+        self.root
+            .as_ref()
+            .map(|_| Node::iterative_height(&self.root))
+    }
+
+    /// Returns a reference to the minimum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// assert_eq!(bst.min(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.min(), Some(&2));
+    /// ```
+    fn min(&self) -> Option<&T> {
+        Node::iterative_min(&self.root).map(|current| &current.value)
+    }
+
+    /// Returns a reference to the maximum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// assert_eq!(bst.max(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.max(), Some(&10));
+    /// ```
+    fn max(&self) -> Option<&T> {
+        Node::iterative_max(&self.root).map(|current| &current.value)
+    }
+
+    /// Removes and returns the minimum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// assert_eq!(bst.remove_min(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_min(), Some(2));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_min(&mut self) -> Option<T> {
+        let (root, removed_min) = remove_from_root(self.root.take(), remove_min);
+        self.root = root;
+        let removed_min = removed_min?;
+        self.size -= 1;
+        Some(removed_min)
+    }
+
+    /// Removes and returns the maximum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// assert_eq!(bst.remove_max(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_max(), Some(10));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_max(&mut self) -> Option<T> {
+        let (root, removed_max) = remove_from_root(self.root.take(), remove_max);
+        self.root = root;
+        let removed_max = removed_max?;
+        self.size -= 1;
+        Some(removed_max)
+    }
+
+    /// Returns references to the elements of the tree in **ascending order.**`
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [LlrbBST::in_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn asc_order_vec(&self) -> Vec<&T> {
+        self.in_order_vec()
+    }
+
+    /// Returns references to the elements of the tree in the order of a **pre-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The pre_order_vec is: [&4, &2, &1, &3, &6, &5, &7]
+    /// assert_eq!(bst.pre_order_vec(), vec![&4, &2, &1, &3, &6, &5, &7]);
+    /// ```
+    fn pre_order_vec(&self) -> Vec<&T> {
+        Colored::values(Node::iterative_pre_order_vec(&self.root))
+    }
+
+    /// Returns references to the elements of the tree in the order of an **in-order traversal.**
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [LlrbBST::asc_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The in_order_vec is: [&1, &2, &3, &4, &5, &6, &7]
+    /// assert_eq!(bst.in_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn in_order_vec(&self) -> Vec<&T> {
+        Colored::values(Node::iterative_in_order_vec(&self.root))
+    }
+
+    /// Returns references to the elements of the tree in the order of a **post-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The post_order_vec is: [&1, &3, &2, &5, &7, &6, &4]
+    /// assert_eq!(bst.post_order_vec(), vec![&1, &3, &2, &5, &7, &6, &4]);
+    /// ```
+    fn post_order_vec(&self) -> Vec<&T> {
+        Colored::values(Node::iterative_post_order_vec(&self.root))
+    }
+
+    /// Returns references to the elements of the tree in the order of a **level-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The level_order_vec is: [&4, &2, &6, &1, &3, &5, &7]
+    /// assert_eq!(bst.level_order_vec(), vec![&4, &2, &6, &1, &3, &5, &7]);
+    /// ```
+    fn level_order_vec(&self) -> Vec<&T> {
+        Colored::values(Node::iterative_level_order_vec(&self.root))
+    }
+
+    /// Returns an iterator over [LlrbBST::asc_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [LlrbBST::in_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut asc_order_iter = bst.asc_order_iter();
+    ///
+    /// assert_eq!(asc_order_iter.next(), Some(&1));
+    /// assert_eq!(asc_order_iter.next(), Some(&2));
+    /// assert_eq!(asc_order_iter.next(), Some(&3));
+    /// assert_eq!(asc_order_iter.next(), Some(&4));
+    /// assert_eq!(asc_order_iter.next(), Some(&5));
+    /// assert_eq!(asc_order_iter.next(), None);
+    /// ```
+    fn asc_order_iter(&self) -> IntoIter<&T> {
+        self.in_order_iter()
+    }
+
+    /// Returns an iterator over [LlrbBST::pre_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut pre_order_iter = bst.pre_order_iter();
+    ///
+    /// assert_eq!(pre_order_iter.next(), Some(&4));
+    /// assert_eq!(pre_order_iter.next(), Some(&2));
+    /// assert_eq!(pre_order_iter.next(), Some(&1));
+    /// assert_eq!(pre_order_iter.next(), Some(&3));
+    /// assert_eq!(pre_order_iter.next(), Some(&5));
+    /// assert_eq!(pre_order_iter.next(), None);
+    /// ```
+    fn pre_order_iter(&self) -> IntoIter<&T> {
+        self.pre_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [LlrbBST::in_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [LlrbBST::asc_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut in_order_iter = bst.in_order_iter();
+    ///
+    /// assert_eq!(in_order_iter.next(), Some(&1));
+    /// assert_eq!(in_order_iter.next(), Some(&2));
+    /// assert_eq!(in_order_iter.next(), Some(&3));
+    /// assert_eq!(in_order_iter.next(), Some(&4));
+    /// assert_eq!(in_order_iter.next(), Some(&5));
+    /// assert_eq!(in_order_iter.next(), None);
+    /// ```
+    fn in_order_iter(&self) -> IntoIter<&T> {
+        self.in_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [LlrbBST::post_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut post_order_iter = bst.post_order_iter();
+    ///
+    /// assert_eq!(post_order_iter.next(), Some(&1));
+    /// assert_eq!(post_order_iter.next(), Some(&3));
+    /// assert_eq!(post_order_iter.next(), Some(&2));
+    /// assert_eq!(post_order_iter.next(), Some(&5));
+    /// assert_eq!(post_order_iter.next(), Some(&4));
+    /// assert_eq!(post_order_iter.next(), None);
+    /// ```
+    fn post_order_iter(&self) -> IntoIter<&T> {
+        self.post_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [LlrbBST::level_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut level_order_iter = bst.level_order_iter();
+    ///
+    /// assert_eq!(level_order_iter.next(), Some(&4));
+    /// assert_eq!(level_order_iter.next(), Some(&2));
+    /// assert_eq!(level_order_iter.next(), Some(&5));
+    /// assert_eq!(level_order_iter.next(), Some(&1));
+    /// assert_eq!(level_order_iter.next(), Some(&3));
+    /// assert_eq!(level_order_iter.next(), None);
+    /// ```
+    fn level_order_iter(&self) -> IntoIter<&T> {
+        self.level_order_vec().into_iter()
+    }
+
+    /// Returns [LlrbBST::asc_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [LlrbBST::into_in_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_asc_order_iter = bst.into_asc_order_iter();
+    ///
+    /// assert_eq!(into_asc_order_iter.next(), Some(1));
+    /// assert_eq!(into_asc_order_iter.next(), Some(2));
+    /// assert_eq!(into_asc_order_iter.next(), Some(3));
+    /// assert_eq!(into_asc_order_iter.next(), Some(4));
+    /// assert_eq!(into_asc_order_iter.next(), Some(5));
+    /// assert_eq!(into_asc_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_asc_order_iter(self) -> IntoIter<T> {
+        self.into_in_order_iter()
+    }
+
+    /// Returns [LlrbBST::pre_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_pre_order_iter = bst.into_pre_order_iter();
+    ///
+    /// assert_eq!(into_pre_order_iter.next(), Some(4));
+    /// assert_eq!(into_pre_order_iter.next(), Some(2));
+    /// assert_eq!(into_pre_order_iter.next(), Some(1));
+    /// assert_eq!(into_pre_order_iter.next(), Some(3));
+    /// assert_eq!(into_pre_order_iter.next(), Some(5));
+    /// assert_eq!(into_pre_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_pre_order_iter(self) -> IntoIter<T> {
+        Colored::into_values(Node::iterative_consume_pre_order_vec(self.root))
+    }
+
+    /// Returns [LlrbBST::in_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [LlrbBST::asc_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_in_order_iter = bst.into_in_order_iter();
+    ///
+    /// assert_eq!(into_in_order_iter.next(), Some(1));
+    /// assert_eq!(into_in_order_iter.next(), Some(2));
+    /// assert_eq!(into_in_order_iter.next(), Some(3));
+    /// assert_eq!(into_in_order_iter.next(), Some(4));
+    /// assert_eq!(into_in_order_iter.next(), Some(5));
+    /// assert_eq!(into_in_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_in_order_iter(self) -> IntoIter<T> {
+        Colored::into_values(Node::iterative_consume_in_order_vec(self.root))
+    }
+
+    /// Returns [LlrbBST::post_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_post_order_iter = bst.into_post_order_iter();
+    ///
+    /// assert_eq!(into_post_order_iter.next(), Some(1));
+    /// assert_eq!(into_post_order_iter.next(), Some(3));
+    /// assert_eq!(into_post_order_iter.next(), Some(2));
+    /// assert_eq!(into_post_order_iter.next(), Some(5));
+    /// assert_eq!(into_post_order_iter.next(), Some(4));
+    /// assert_eq!(into_post_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_post_order_iter(self) -> IntoIter<T> {
+        Colored::into_values(Node::iterative_consume_post_order_vec(self.root))
+    }
+
+    /// Returns [LlrbBST::level_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{LlrbBST, BinarySearchTree};
+    ///
+    /// let mut bst = LlrbBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_level_order_iter = bst.into_level_order_iter();
+    ///
+    /// assert_eq!(into_level_order_iter.next(), Some(4));
+    /// assert_eq!(into_level_order_iter.next(), Some(2));
+    /// assert_eq!(into_level_order_iter.next(), Some(5));
+    /// assert_eq!(into_level_order_iter.next(), Some(1));
+    /// assert_eq!(into_level_order_iter.next(), Some(3));
+    /// assert_eq!(into_level_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_level_order_iter(self) -> IntoIter<T> {
+        Colored::into_values(Node::iterative_consume_level_order_vec(self.root))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_red, Colored};
+    use crate::node::HeapNode;
+    #[cfg(feature = "iterative")]
+    use crate::IterativeBST;
+    use crate::{BinarySearchTree, LlrbBST};
+
+    /// Deterministic sequence of pseudo random values for comparisons with [IterativeBST].
+    #[cfg(feature = "iterative")]
+    fn pseudo_random_values(count: usize) -> Vec<u32> {
+        let mut state: u32 = 0x2545_f491;
+        (0..count)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state % 500
+            })
+            .collect()
+    }
+
+    /// Panics unless every node below `node` satisfies the invariants of a left-leaning
+    /// red-black tree, returning the black height of the subtree.
+    fn assert_invariants<T: Ord>(node: &HeapNode<Colored<T>>) -> usize {
+        let node = match node {
+            None => return 0,
+            Some(node) => node,
+        };
+
+        assert!(!is_red(&node.right));
+        if node.value.red {
+            assert!(!is_red(&node.left));
+        }
+        let black_height = assert_invariants(&node.left);
+        assert_eq!(assert_invariants(&node.right), black_height);
+
+        black_height + usize::from(!node.value.red)
+    }
+
+    fn assert_tree_invariants<T: Ord>(bst: &LlrbBST<T>) {
+        assert!(!is_red(&bst.root));
+        assert_eq!(assert_invariants(&bst.root), bst.black_height());
+    }
+
+    #[test]
+    fn successfully_keep_ascending_insertions_balanced() {
+        let bst = LlrbBST::from_iter(0..4096);
+
+        assert_tree_invariants(&bst);
+        assert_eq!(bst.size(), 4096);
+        assert!(bst.height().unwrap() <= 24);
+        assert_eq!(bst.min(), Some(&0));
+        assert_eq!(bst.max(), Some(&4095));
+    }
+
+    #[test]
+    fn successfully_ignore_duplicates() {
+        let mut bst = LlrbBST::from_iter(vec![3, 1, 2]);
+        bst.insert(2);
+        bst.remove(&4);
+
+        assert_tree_invariants(&bst);
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.pre_order_vec(), vec![&2, &1, &3]);
+    }
+
+    #[test]
+    fn successfully_remove_while_keeping_invariants() {
+        let mut bst = LlrbBST::from_iter(0..256);
+
+        for value in (0..256).step_by(3) {
+            bst.remove(&value);
+            assert_tree_invariants(&bst);
+        }
+        while bst.size() > 100 {
+            bst.remove_min();
+            assert_tree_invariants(&bst);
+            bst.remove_max();
+            assert_tree_invariants(&bst);
+        }
+
+        assert_eq!(bst.size(), 100);
+        assert!(!bst.contains(&0));
+        assert!(bst.height().unwrap() <= 14);
+    }
+
+    #[test]
+    #[cfg(feature = "iterative")]
+    fn successfully_match_iterative_bst() {
+        let values = pseudo_random_values(2000);
+        let mut llrb = LlrbBST::new();
+        let mut expected = IterativeBST::new();
+
+        for (index, value) in values.into_iter().enumerate() {
+            if index % 3 == 2 {
+                llrb.remove(&value);
+                expected.remove(&value);
+            } else {
+                llrb.insert(value);
+                expected.insert(value);
+            }
+            assert_eq!(llrb.size(), expected.size());
+            assert_tree_invariants(&llrb);
+        }
+
+        assert_eq!(llrb.asc_order_vec(), expected.asc_order_vec());
+        assert_eq!(llrb.remove_min(), expected.remove_min());
+        assert_eq!(llrb.remove_max(), expected.remove_max());
+        while llrb.remove_min().is_some() {
+            assert_tree_invariants(&llrb);
+        }
+        assert!(llrb.is_empty());
+    }
+
+    #[test]
+    fn successfully_clone_shape_and_colors() {
+        let bst = LlrbBST::from_iter(1..=10);
+        let clone = bst.clone();
+
+        assert_eq!(clone, bst);
+        assert_eq!(clone.level_order_vec(), bst.level_order_vec());
+        assert_eq!(clone.is_red(&4), bst.is_red(&4));
+        assert_eq!(clone.to_string(), "[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]");
+    }
+}
@@ -0,0 +1,627 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Formatter};
+use std::ops::{Bound, RangeBounds};
+
+use crate::iter::{RangeMut, SortedIter};
+use crate::node::{HeapNode, Node};
+
+/// Key-value pair stored inside the nodes of a [BSTMap].
+///
+/// Entries are ordered by their key **only**, the value never takes part in comparisons.
+pub(crate) struct Entry<K, V> {
+    pub(crate) key: K,
+    pub(crate) value: V,
+}
+
+impl<K: Ord, V> PartialEq for Entry<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl<K: Ord, V> Eq for Entry<K, V> {}
+
+impl<K: Ord, V> PartialOrd for Entry<K, V> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<K: Ord, V> Ord for Entry<K, V> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+}
+
+/// Compares `key` with the start of `range`, being [Ordering::Less] for keys before it.
+fn cmp_start<K: Ord, R: RangeBounds<K>>(range: &R, key: &K) -> Ordering {
+    let above = match range.start_bound() {
+        Bound::Included(start) => key >= start,
+        Bound::Excluded(start) => key > start,
+        Bound::Unbounded => true,
+    };
+    if above {
+        Ordering::Greater
+    } else {
+        Ordering::Less
+    }
+}
+
+fn below_end<K: Ord, R: RangeBounds<K>>(range: &R, key: &K) -> bool {
+    match range.end_bound() {
+        Bound::Included(end) => key <= end,
+        Bound::Excluded(end) => key < end,
+        Bound::Unbounded => true,
+    }
+}
+
+/// Ordered map built on top of the iterative node operations.
+///
+/// Keys _must_ implement the [Ord] trait and are unique, inserting an existing key replaces
+/// its value.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::BSTMap;
+///
+/// let mut map = BSTMap::new();
+/// map.insert(2, "two");
+/// map.insert(1, "one");
+///
+/// assert_eq!(map.get(&1), Some(&"one"));
+/// assert_eq!(map.insert(2, "deux"), Some("two"));
+/// assert_eq!(map.len(), 2);
+/// ```
+pub struct BSTMap<K: Ord, V> {
+    root: HeapNode<Entry<K, V>>,
+    size: usize,
+}
+
+impl<K: Ord, V> BSTMap<K, V> {
+    /// Creates an empty `BSTMap<K, V>`
+    ///
+    /// No nodes are allocated on the heap yet
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// let map: BSTMap<i32, &str> = BSTMap::new();
+    /// assert!(map.is_empty());
+    /// ```
+    pub const fn new() -> BSTMap<K, V> {
+        BSTMap {
+            root: None,
+            size: 0,
+        }
+    }
+
+    /// Returns the total **number of entries** within the map.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// let mut map = BSTMap::new();
+    /// map.insert(1, "one");
+    /// map.insert(1, "uno");
+    ///
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the map contains no entries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// let mut map = BSTMap::new();
+    /// assert!(map.is_empty());
+    ///
+    /// map.insert(1, "one");
+    /// assert!(!map.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Inserts the given key-value pair.
+    ///
+    /// Returns the previous value if the key was already present, in which case the value is
+    /// replaced but the stored key is kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// let mut map = BSTMap::new();
+    /// assert_eq!(map.insert(1, "one"), None);
+    /// assert_eq!(map.insert(1, "uno"), Some("one"));
+    /// assert_eq!(map.get(&1), Some(&"uno"));
+    /// ```
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let slot = Node::iterative_slot_by(&mut self.root, |entry| entry.key.cmp(&key));
+        match slot {
+            Some(node) => Some(std::mem::replace(&mut node.value.value, value)),
+            None => {
+                *slot = Some(Box::new(Node::new(Entry { key, value })));
+                self.size += 1;
+                None
+            }
+        }
+    }
+
+    /// Returns `true` if the map contains an entry for the given key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// let mut map = BSTMap::new();
+    /// map.insert(1, "one");
+    ///
+    /// assert!(map.contains_key(&1));
+    /// assert!(!map.contains_key(&2));
+    /// ```
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns a reference to the value of the given key or `None` if the key does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// let mut map = BSTMap::new();
+    /// map.insert(1, "one");
+    ///
+    /// assert_eq!(map.get(&1), Some(&"one"));
+    /// assert_eq!(map.get(&2), None);
+    /// ```
+    pub fn get(&self, key: &K) -> Option<&V> {
+        Node::iterative_retrieve_by(&self.root, |entry| entry.key.cmp(key))
+            .map(|entry| &entry.value)
+    }
+
+    /// Returns a mutable reference to the value of the given key (see [BSTMap::get()])
+    /// or `None` if the key does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// let mut map = BSTMap::new();
+    /// map.insert(1, 10);
+    ///
+    /// *map.get_mut(&1).unwrap() += 5;
+    /// assert_eq!(map.get(&1), Some(&15));
+    /// ```
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        Node::iterative_slot_by(&mut self.root, |entry| entry.key.cmp(key))
+            .as_mut()
+            .map(|node| &mut node.value.value)
+    }
+
+    /// Removes the entry of the given key, returning its value or `None` if the key does not
+    /// exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// let mut map = BSTMap::new();
+    /// map.insert(1, "one");
+    ///
+    /// assert_eq!(map.remove(&1), Some("one"));
+    /// assert_eq!(map.remove(&1), None);
+    /// assert!(map.is_empty());
+    /// ```
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        let slot = Node::iterative_slot_by(&mut self.root, |entry| entry.key.cmp(key));
+        let removed = Node::iterative_take(slot);
+        if removed.is_some() {
+            self.size -= 1;
+        }
+        removed.map(|entry| entry.value)
+    }
+
+    /// Returns the entry stored at the **root** of the map, or `None` if the map is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// let map = BSTMap::from_iter(vec![(2, "two"), (1, "one"), (3, "three")]);
+    ///
+    /// assert_eq!(map.root_entry(), Some((&2, &"two")));
+    /// ```
+    pub fn root_entry(&self) -> Option<(&K, &V)> {
+        self.root
+            .as_ref()
+            .map(|node| (&node.value.key, &node.value.value))
+    }
+
+    /// Returns the entry with the **smallest key** or `None` if the map is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// let map = BSTMap::from_iter(vec![(2, "two"), (1, "one"), (3, "three")]);
+    ///
+    /// assert_eq!(map.first_key_value(), Some((&1, &"one")));
+    /// ```
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        Node::iterative_min(&self.root).map(|entry| (&entry.key, &entry.value))
+    }
+
+    /// Returns the entry with the **largest key** or `None` if the map is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// let map = BSTMap::from_iter(vec![(2, "two"), (1, "one"), (3, "three")]);
+    ///
+    /// assert_eq!(map.last_key_value(), Some((&3, &"three")));
+    /// ```
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        Node::iterative_max(&self.root).map(|entry| (&entry.key, &entry.value))
+    }
+
+    /// Removes and returns the entry with the **smallest key** or `None` if the map is empty.
+    ///
+    /// Together with [BSTMap::insert()], this makes the map an ordered queue of keyed jobs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// // Jobs keyed by the time they are due
+    /// let mut jobs = BSTMap::new();
+    /// jobs.insert(30, "backup");
+    /// jobs.insert(10, "report");
+    ///
+    /// assert_eq!(jobs.pop_first(), Some((10, "report")));
+    /// assert_eq!(jobs.pop_first(), Some((30, "backup")));
+    /// assert_eq!(jobs.pop_first(), None);
+    /// ```
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        let entry = Node::iterative_remove_min(&mut self.root)?;
+        self.size -= 1;
+        Some((entry.key, entry.value))
+    }
+
+    /// Removes and returns the entry with the **largest key** or `None` if the map is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// let mut map = BSTMap::from_iter(vec![(2, "two"), (1, "one"), (3, "three")]);
+    ///
+    /// assert_eq!(map.pop_last(), Some((3, "three")));
+    /// assert_eq!(map.len(), 2);
+    /// assert_eq!(map.last_key_value(), Some((&2, &"two")));
+    /// ```
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        let entry = Node::iterative_remove_max(&mut self.root)?;
+        self.size -= 1;
+        Some((entry.key, entry.value))
+    }
+
+    /// Returns an iterator over the entries of the map in **ascending key order.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// let mut map = BSTMap::new();
+    /// map.insert(2, "two");
+    /// map.insert(1, "one");
+    ///
+    /// let mut iter = map.iter();
+    /// assert_eq!(iter.next(), Some((&1, &"one")));
+    /// assert_eq!(iter.next(), Some((&2, &"two")));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        SortedIter::ascending(&self.root).map(|entry| (&entry.key, &entry.value))
+    }
+
+    /// Returns an iterator over the entries of the map in **ascending key order**, with
+    /// mutable references to the values.
+    ///
+    /// Keys can't be changed as that could break the order of the map.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// let mut map = BSTMap::from_iter(vec![(1, 10), (2, 20)]);
+    /// for (key, value) in map.iter_mut() {
+    ///     *value += key;
+    /// }
+    ///
+    /// assert_eq!(map.get(&1), Some(&11));
+    /// assert_eq!(map.get(&2), Some(&22));
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> {
+        RangeMut::ascending(&mut self.root).map(|entry| (&entry.key, &mut entry.value))
+    }
+
+    /// Returns an iterator over the keys of the map in **ascending order.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// let map = BSTMap::from_iter(vec![(2, "two"), (1, "one")]);
+    ///
+    /// assert_eq!(map.keys().collect::<Vec<&i32>>(), vec![&1, &2]);
+    /// ```
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    /// Returns an iterator over the values of the map in the **ascending order of their keys.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// let map = BSTMap::from_iter(vec![(2, "two"), (1, "one")]);
+    ///
+    /// assert_eq!(map.values().collect::<Vec<&&str>>(), vec![&"one", &"two"]);
+    /// ```
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    /// Returns an iterator over mutable references to the values of the map in the
+    /// **ascending order of their keys.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// let mut map = BSTMap::from_iter(vec![("a", 1), ("b", 2)]);
+    /// for value in map.values_mut() {
+    ///     *value *= 10;
+    /// }
+    ///
+    /// assert_eq!(map.values().collect::<Vec<&i32>>(), vec![&10, &20]);
+    /// ```
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.iter_mut().map(|(_, value)| value)
+    }
+
+    /// Returns an iterator over the entries whose key lies within `range`, in **ascending key
+    /// order.**
+    ///
+    /// Only the path down to the first entry of the range is walked before iterating, so
+    /// windows of a large map can be read without copying them out. A range which is empty or
+    /// whose end lies before its start yields no entries.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// let map = BSTMap::from_iter((0..10).map(|second| (second, second * 100)));
+    ///
+    /// // Total of the window from second 3 up to but excluding second 6
+    /// let total: i32 = map.range(3..6).map(|(_, value)| value).sum();
+    /// assert_eq!(total, 1200);
+    /// assert_eq!(map.range(8..).count(), 2);
+    /// ```
+    pub fn range<R: RangeBounds<K>>(&self, range: R) -> impl Iterator<Item = (&K, &V)> {
+        SortedIter::lower_bound_by(&self.root, |entry| cmp_start(&range, &entry.key))
+            .map(|entry| (&entry.key, &entry.value))
+            .take_while(move |(key, _)| below_end(&range, key))
+    }
+
+    /// Returns an iterator over the entries whose key lies within `range`, in **ascending key
+    /// order**, with mutable references to the values (see [BSTMap::range()]).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMap;
+    ///
+    /// let mut map = BSTMap::from_iter(vec![("apple", 3), ("banana", 5), ("cherry", 7)]);
+    /// for (_, stock) in map.range_mut("b"..) {
+    ///     *stock = 0;
+    /// }
+    ///
+    /// assert_eq!(map.values().collect::<Vec<&i32>>(), vec![&3, &0, &0]);
+    /// ```
+    pub fn range_mut<R: RangeBounds<K>>(&mut self, range: R) -> impl Iterator<Item = (&K, &mut V)> {
+        RangeMut::lower_bound_by(&mut self.root, |entry| cmp_start(&range, &entry.key))
+            .map(|entry| (&entry.key, &mut entry.value))
+            .take_while(move |(key, _)| below_end(&range, key))
+    }
+}
+
+impl<K: Ord, V> Default for BSTMap<K, V> {
+    /// Creates an empty `BSTMap<K, V>`
+    fn default() -> BSTMap<K, V> {
+        BSTMap::new()
+    }
+}
+
+impl<K: Ord + Debug, V: Debug> Debug for BSTMap<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: Ord, V: PartialEq> PartialEq for BSTMap<K, V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for BSTMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter.into_iter() {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for BSTMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = BSTMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::ops::Bound::{self, Excluded, Included, Unbounded};
+
+    use crate::BSTMap;
+
+    #[test]
+    fn successfully_insert_entries_into_map() {
+        let mut map = BSTMap::new();
+        assert_eq!(map.insert(5, "five"), None);
+        assert_eq!(map.insert(3, "three"), None);
+        assert_eq!(map.insert(5, "cinq"), Some("five"));
+
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&5), Some(&"cinq"));
+        assert_eq!(map.get(&3), Some(&"three"));
+        assert_eq!(map.get(&4), None);
+    }
+
+    #[test]
+    fn successfully_modify_value_through_get_mut() {
+        let mut map = BSTMap::new();
+        map.insert("a", 1);
+        map.insert("b", 2);
+
+        *map.get_mut(&"b").unwrap() = 20;
+
+        assert_eq!(map.get(&"b"), Some(&20));
+        assert_eq!(map.get_mut(&"c"), None);
+    }
+
+    #[test]
+    fn successfully_remove_entries_from_map() {
+        let mut map = BSTMap::from_iter(vec![(4, 'd'), (2, 'b'), (6, 'f'), (1, 'a'), (3, 'c')]);
+
+        assert_eq!(map.remove(&2), Some('b'));
+        assert_eq!(map.remove(&4), Some('d'));
+        assert_eq!(map.remove(&10), None);
+
+        assert_eq!(map.len(), 3);
+        assert!(!map.contains_key(&2));
+        assert!(map.contains_key(&3));
+        assert_eq!(
+            map.iter().collect::<Vec<_>>(),
+            vec![(&1, &'a'), (&3, &'c'), (&6, &'f')]
+        );
+    }
+
+    #[test]
+    fn successfully_pop_entries_from_both_ends_of_map() {
+        let mut map = BSTMap::from_iter(vec![(5, 'e'), (2, 'b'), (8, 'h'), (1, 'a'), (9, 'i')]);
+
+        assert_eq!(map.root_entry(), Some((&5, &'e')));
+        assert_eq!(map.first_key_value(), Some((&1, &'a')));
+        assert_eq!(map.last_key_value(), Some((&9, &'i')));
+        assert_eq!(map.pop_first(), Some((1, 'a')));
+        assert_eq!(map.pop_last(), Some((9, 'i')));
+        assert_eq!(map.pop_last(), Some((8, 'h')));
+        assert_eq!(map.first_key_value(), Some((&2, &'b')));
+        assert_eq!(map.len(), 2);
+
+        assert_eq!(map.pop_first(), Some((2, 'b')));
+        assert_eq!(map.pop_first(), Some((5, 'e')));
+        assert_eq!(map.pop_first(), None);
+        assert_eq!(map.pop_last(), None);
+        assert_eq!(map.first_key_value(), None);
+        assert_eq!(map.root_entry(), None);
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn successfully_iterate_map_in_key_order() {
+        let map = BSTMap::from_iter(vec![(3, "c"), (1, "a"), (2, "b")]);
+
+        let keys: Vec<&i32> = map.iter().map(|(key, _)| key).collect();
+
+        assert_eq!(keys, vec![&1, &2, &3]);
+    }
+
+    #[test]
+    fn successfully_iterate_keys_and_values_separately() {
+        let mut map = BSTMap::from_iter(vec![(3, 'c'), (1, 'a'), (4, 'd'), (2, 'b')]);
+
+        assert_eq!(map.keys().copied().collect::<Vec<i32>>(), vec![1, 2, 3, 4]);
+        assert_eq!(map.values().collect::<String>(), "abcd");
+
+        map.values_mut()
+            .for_each(|value| *value = value.to_ascii_uppercase());
+        for (key, value) in map.iter_mut().filter(|(key, _)| **key % 2 == 0) {
+            *value = char::from_digit(*key as u32, 10).unwrap();
+        }
+
+        assert_eq!(map.values().collect::<String>(), "A2C4");
+        assert_eq!(map.get(&4), Some(&'4'));
+        assert_eq!(map.len(), 4);
+        assert_eq!(BSTMap::<i32, char>::new().values_mut().next(), None);
+    }
+
+    #[test]
+    fn successfully_iterate_ranges_of_map() {
+        let mut map = BSTMap::from_iter((0..20).rev().map(|key| (key * 5, key)));
+
+        let keys = |map: &BSTMap<i32, i32>, range: (Bound<i32>, Bound<i32>)| {
+            map.range(range).map(|(key, _)| *key).collect::<Vec<i32>>()
+        };
+        assert_eq!(keys(&map, (Included(10), Excluded(25))), vec![10, 15, 20]);
+        assert_eq!(keys(&map, (Excluded(10), Included(25))), vec![15, 20, 25]);
+        assert_eq!(keys(&map, (Included(11), Included(14))), vec![]);
+        assert_eq!(keys(&map, (Included(90), Unbounded)), vec![90, 95]);
+        assert_eq!(keys(&map, (Unbounded, Excluded(5))), vec![0]);
+        assert_eq!(keys(&map, (Included(40), Included(20))), vec![]);
+        assert_eq!(map.range(..).count(), 20);
+
+        for (key, value) in map.range_mut(30..=45) {
+            *value = -key;
+        }
+        assert_eq!(
+            map.range(25..45).collect::<Vec<_>>(),
+            vec![(&25, &5), (&30, &-30), (&35, &-35), (&40, &-40)]
+        );
+        assert_eq!(map.range_mut(100..).next(), None);
+        assert_eq!(map.len(), 20);
+    }
+}
@@ -0,0 +1,233 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+
+use crate::{Augment, AugmentedBST};
+
+/// 64-bit FNV-1a, used because its output is fully specified and therefore identical for tree
+/// owners and verifiers regardless of platform or Rust version.
+pub(crate) struct Fnv64(u64);
+
+impl Fnv64 {
+    pub(crate) fn new() -> Fnv64 {
+        Fnv64(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for Fnv64 {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+}
+
+pub(crate) fn hash_value<T: Hash>(value: &T) -> u64 {
+    let mut hasher = Fnv64::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn hash_node(left: Option<u64>, value_hash: u64, right: Option<u64>) -> u64 {
+    let mut hasher = Fnv64::new();
+    // Presence is encoded explicitly, as hashing the `Option` discriminant is platform dependent
+    for hash in [left, Some(value_hash), right] {
+        match hash {
+            None => hasher.write_u8(0),
+            Some(hash) => {
+                hasher.write_u8(1);
+                hasher.write(&hash.to_le_bytes());
+            }
+        }
+    }
+    hasher.finish()
+}
+
+/// [Augment] which maintains a Merkle hash of every subtree.
+///
+/// The hash of a node covers its value and the hashes of both of its subtrees, so the hash of
+/// the root ([MerkleBST::root_hash()]) commits to the contents **and** the shape of the whole
+/// tree.
+///
+/// # Important
+///
+/// Hashes are 64-bit FNV-1a over the [Hash] implementation of the elements. This detects
+/// accidental divergence between replicas but is **not** collision resistant against an
+/// adversary. Elements should also hash identically on every platform involved, which rules
+/// out types such as `usize`.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Merkle;
+
+impl<T: Hash> Augment<T> for Merkle {
+    type Summary = u64;
+
+    fn summarize(&self, value: &T, left: Option<&u64>, right: Option<&u64>) -> u64 {
+        hash_node(left.copied(), hash_value(value), right.copied())
+    }
+}
+
+/// Binary Search Tree maintaining a Merkle hash of every subtree (see [Merkle]).
+pub type MerkleBST<T> = AugmentedBST<T, Merkle>;
+
+/// Ancestor of the proven node, ordered from the proven node towards the root.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ProofStep {
+    value_hash: u64,
+    sibling: Option<u64>,
+    from_left: bool,
+}
+
+/// Evidence that a value is stored in a [MerkleBST] with a given root hash, created by
+/// [MerkleBST::prove()].
+///
+/// The proof only holds the hashes along the path to the value, so its size is O(h) no matter
+/// how big the tree is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MembershipProof {
+    left: Option<u64>,
+    right: Option<u64>,
+    path: Vec<ProofStep>,
+}
+
+impl<T: Ord + Hash> MerkleBST<T> {
+    /// Returns the Merkle hash of the whole tree or `None` if it is empty.
+    ///
+    /// Two trees have the same root hash if they contain equal elements in the same shape.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::MerkleBST;
+    ///
+    /// let first = MerkleBST::from_iter(vec![2, 1, 3]);
+    /// let second = MerkleBST::from_iter(vec![2, 3, 1]);
+    /// let different = MerkleBST::from_iter(vec![2, 3, 4]);
+    ///
+    /// assert_eq!(first.root_hash(), second.root_hash());
+    /// assert_ne!(first.root_hash(), different.root_hash());
+    /// ```
+    pub fn root_hash(&self) -> Option<u64> {
+        self.summary().copied()
+    }
+
+    /// Creates a [MembershipProof] for the given value or `None` if it is not present.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::MerkleBST;
+    ///
+    /// let bst = MerkleBST::from_iter(vec![4, 2, 6, 1, 3]);
+    /// let root_hash = bst.root_hash().unwrap();
+    ///
+    /// let proof = bst.prove(&3).unwrap();
+    ///
+    /// assert!(MerkleBST::verify(root_hash, &3, &proof));
+    /// assert!(!MerkleBST::verify(root_hash, &5, &proof));
+    /// assert_eq!(bst.prove(&5), None);
+    /// ```
+    pub fn prove(&self, value: &T) -> Option<MembershipProof> {
+        let mut path = Vec::new();
+        let mut current = &self.root;
+
+        while let Some(node) = current {
+            let (next, sibling, from_left) = match value.cmp(&node.value) {
+                Ordering::Equal => {
+                    path.reverse();
+                    return Some(MembershipProof {
+                        left: node.left.as_ref().map(|left| left.summary),
+                        right: node.right.as_ref().map(|right| right.summary),
+                        path,
+                    });
+                }
+                Ordering::Less => (&node.left, &node.right, true),
+                Ordering::Greater => (&node.right, &node.left, false),
+            };
+            path.push(ProofStep {
+                value_hash: hash_value(&node.value),
+                sibling: sibling.as_ref().map(|sibling| sibling.summary),
+                from_left,
+            });
+            current = next;
+        }
+
+        None
+    }
+
+    /// Returns `true` if the proof shows that the value is stored in a tree with the given root
+    /// hash.
+    ///
+    /// Only the root hash has to be known by the verifier, not the tree itself.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::MerkleBST;
+    ///
+    /// let mut bst = MerkleBST::from_iter(vec![2, 1, 3]);
+    /// let proof = bst.prove(&1).unwrap();
+    /// let old_root_hash = bst.root_hash().unwrap();
+    ///
+    /// bst.insert(4);
+    ///
+    /// assert!(MerkleBST::verify(old_root_hash, &1, &proof));
+    /// assert!(!MerkleBST::verify(bst.root_hash().unwrap(), &1, &proof));
+    /// ```
+    pub fn verify(root_hash: u64, value: &T, proof: &MembershipProof) -> bool {
+        let mut hash = hash_node(proof.left, hash_value(value), proof.right);
+        for step in &proof.path {
+            hash = if step.from_left {
+                hash_node(Some(hash), step.value_hash, step.sibling)
+            } else {
+                hash_node(step.sibling, step.value_hash, Some(hash))
+            };
+        }
+
+        hash == root_hash
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MerkleBST;
+
+    #[test]
+    fn successfully_prove_every_element() {
+        let bst = MerkleBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14, 1, 3]);
+        let root_hash = bst.root_hash().unwrap();
+
+        for value in bst.iter() {
+            let proof = bst.prove(value).unwrap();
+            assert!(MerkleBST::verify(root_hash, value, &proof));
+            assert!(!MerkleBST::verify(root_hash, &(value + 100), &proof));
+        }
+    }
+
+    #[test]
+    fn successfully_change_root_hash_on_modification() {
+        let mut bst = MerkleBST::from_iter(vec!["b", "a", "c"]);
+        let root_hash = bst.root_hash();
+
+        bst.insert("d");
+        assert_ne!(bst.root_hash(), root_hash);
+
+        bst.remove(&"d");
+        assert_eq!(bst.root_hash(), root_hash);
+    }
+
+    #[test]
+    fn successfully_reject_tampered_proof() {
+        let bst = MerkleBST::from_iter(vec![4, 2, 6]);
+        let root_hash = bst.root_hash().unwrap();
+        let mut proof = bst.prove(&2).unwrap();
+
+        proof.path[0].from_left = false;
+
+        assert!(!MerkleBST::verify(root_hash, &2, &proof));
+        assert_eq!(MerkleBST::<i32>::default().prove(&2), None);
+    }
+}
@@ -0,0 +1,1095 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::vec::IntoIter;
+
+use crate::iter::SortedIter;
+use crate::node::{HeapNode, Node};
+use crate::BinarySearchTree;
+
+/// Element stored inside the nodes of a [MetaBST] along with its metadata, ordered by the
+/// element **only**.
+#[derive(Debug, Clone)]
+struct WithMeta<T, M> {
+    value: T,
+    meta: M,
+}
+
+impl<T, M> WithMeta<T, M> {
+    fn values(elements: Vec<&WithMeta<T, M>>) -> Vec<&T> {
+        elements.into_iter().map(|current| &current.value).collect()
+    }
+
+    fn into_values(elements: Vec<WithMeta<T, M>>) -> IntoIter<T> {
+        elements
+            .into_iter()
+            .map(|current| current.value)
+            .collect::<Vec<T>>()
+            .into_iter()
+    }
+}
+
+impl<T: Ord, M> PartialEq for WithMeta<T, M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Ord, M> Eq for WithMeta<T, M> {}
+
+impl<T: Ord, M> PartialOrd for WithMeta<T, M> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord, M> Ord for WithMeta<T, M> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+/// Binary Search Tree implementation storing auxiliary metadata alongside every element.
+///
+/// The metadata never takes part in comparisons, so it can be read and modified through
+/// [MetaBST::meta()] and [MetaBST::meta_mut()] without any risk of breaking the ordering of the
+/// tree, unlike mutating elements through [BinarySearchTree::retrieve_as_mut()].
+///
+/// Elements inserted through [BinarySearchTree::insert()] get the [Default] metadata. `M`
+/// defaults to `()` for trees which do not need any.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{BinarySearchTree, MetaBST};
+///
+/// let mut bst = MetaBST::new();
+/// bst.insert_with_meta(4096, "heap");
+/// bst.insert_with_meta(0, "stack");
+///
+/// assert_eq!(bst.meta(&4096), Some(&"heap"));
+/// *bst.meta_mut(&0).unwrap() = "guard";
+/// assert_eq!(bst.iter().collect::<Vec<_>>(), vec![(&0, &"guard"), (&4096, &"heap")]);
+/// ```
+#[derive(Debug)]
+pub struct MetaBST<T: Ord, M = ()> {
+    root: HeapNode<WithMeta<T, M>>,
+    size: usize,
+}
+
+impl<T: Ord, M> MetaBST<T, M> {
+    /// Creates an empty `MetaBST<T, M>`
+    ///
+    /// No nodes are allocated on the heap yet
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// // Empty tree is created
+    /// let mut bst: MetaBST<i32, &str> = MetaBST::new();
+    /// assert!(bst.is_empty())
+    /// ```
+    pub const fn new() -> MetaBST<T, M> {
+        MetaBST {
+            root: None,
+            size: 0,
+        }
+    }
+
+    /// Inserts the given value along with its metadata.
+    ///
+    /// If an equal element already exists, its metadata is replaced and the previous metadata is
+    /// returned while the stored element is kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst = MetaBST::new();
+    ///
+    /// assert_eq!(bst.insert_with_meta(1, 'a'), None);
+    /// assert_eq!(bst.insert_with_meta(1, 'b'), Some('a'));
+    /// assert_eq!(bst.size(), 1);
+    /// ```
+    pub fn insert_with_meta(&mut self, value: T, meta: M) -> Option<M> {
+        let slot = Node::iterative_slot_by(&mut self.root, |current| current.value.cmp(&value));
+        match slot {
+            Some(node) => Some(std::mem::replace(&mut node.value.meta, meta)),
+            None => {
+                *slot = Some(Box::new(Node::new(WithMeta { value, meta })));
+                self.size += 1;
+                None
+            }
+        }
+    }
+
+    /// Returns a reference to the metadata of the given element or `None` if the element does
+    /// not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst = MetaBST::new();
+    /// bst.insert_with_meta(5, "five");
+    ///
+    /// assert_eq!(bst.meta(&5), Some(&"five"));
+    /// assert_eq!(bst.meta(&6), None);
+    /// ```
+    pub fn meta(&self, value: &T) -> Option<&M> {
+        Node::iterative_retrieve_by(&self.root, |current| current.value.cmp(value))
+            .map(|current| &current.meta)
+    }
+
+    /// Returns a mutable reference to the metadata of the given element (see [MetaBST::meta()])
+    /// or `None` if the element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst = MetaBST::new();
+    /// bst.insert_with_meta("page", 0);
+    ///
+    /// *bst.meta_mut(&"page").unwrap() += 1;
+    /// assert_eq!(bst.meta(&"page"), Some(&1));
+    /// ```
+    pub fn meta_mut(&mut self, value: &T) -> Option<&mut M> {
+        Node::iterative_slot_by(&mut self.root, |current| current.value.cmp(value))
+            .as_mut()
+            .map(|node| &mut node.value.meta)
+    }
+
+    /// Removes the given element, returning it together with its metadata or `None` if it does
+    /// not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst = MetaBST::new();
+    /// bst.insert_with_meta(5, "five");
+    ///
+    /// assert_eq!(bst.remove_with_meta(&5), Some((5, "five")));
+    /// assert_eq!(bst.remove_with_meta(&5), None);
+    /// assert!(bst.is_empty());
+    /// ```
+    pub fn remove_with_meta(&mut self, value: &T) -> Option<(T, M)> {
+        let slot = Node::iterative_slot_by(&mut self.root, |current| current.value.cmp(value));
+        let removed = Node::iterative_take(slot)?;
+        self.size -= 1;
+        Some((removed.value, removed.meta))
+    }
+
+    /// Returns an iterator over the elements and their metadata in **ascending order.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst = MetaBST::new();
+    /// bst.insert_with_meta(2, 'b');
+    /// bst.insert_with_meta(1, 'a');
+    ///
+    /// let mut iter = bst.iter();
+    /// assert_eq!(iter.next(), Some((&1, &'a')));
+    /// assert_eq!(iter.next(), Some((&2, &'b')));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&T, &M)> {
+        SortedIter::ascending(&self.root).map(|current| (&current.value, &current.meta))
+    }
+}
+
+impl<T: Ord, M> Default for MetaBST<T, M> {
+    /// Creates an empty `MetaBST<T, M>`
+    fn default() -> MetaBST<T, M> {
+        MetaBST::new()
+    }
+}
+
+impl<T: Ord, M: PartialEq> PartialEq for MetaBST<T, M> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Ord, M: Default> Extend<T> for MetaBST<T, M> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter.into_iter() {
+            self.insert(value)
+        }
+    }
+}
+
+impl<T: Ord, M: Default> FromIterator<T> for MetaBST<T, M> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bst = MetaBST::new();
+        bst.extend(iter);
+        bst
+    }
+}
+
+impl<T: Ord, M: Default> From<Vec<T>> for MetaBST<T, M> {
+    fn from(vec: Vec<T>) -> Self {
+        let mut bst = MetaBST::new();
+        for value in vec.into_iter() {
+            bst.insert(value);
+        }
+        bst
+    }
+}
+
+impl<T: Ord + Clone, M: Default> From<&[T]> for MetaBST<T, M> {
+    fn from(slice: &[T]) -> Self {
+        let mut bst = MetaBST::new();
+        for value in slice {
+            bst.insert((*value).clone());
+        }
+        bst
+    }
+}
+
+impl<T: Ord + Clone, M: Clone> Clone for MetaBST<T, M> {
+    fn clone(&self) -> Self {
+        let mut bst = MetaBST::new();
+
+        for current in Node::iterative_pre_order_vec(&self.root) {
+            bst.insert_with_meta(current.value.clone(), current.meta.clone());
+        }
+
+        bst
+    }
+}
+
+impl<T: Ord + Debug, M> Display for MetaBST<T, M> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:?}",
+            WithMeta::values(Node::iterative_in_order_vec(&self.root))
+        )
+    }
+}
+
+impl<T: Ord, M: Default> BinarySearchTree<T> for MetaBST<T, M> {
+    /// Returns the total **number of nodes** within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(5);
+    /// bst.insert(10);
+    /// bst.insert(3);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// ```
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the binary search tree contains no nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// assert!(bst.is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns `true` if the binary search tree contains one or more nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// assert!(bst.is_empty());
+    ///
+    /// bst.insert(2);
+    ///
+    /// assert!(bst.is_not_empty());
+    /// ```
+    fn is_not_empty(&self) -> bool {
+        self.size != 0
+    }
+
+    /// Inserts given value as a node.
+    ///
+    /// **Duplicate values are _not allowed_**.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    ///
+    /// bst.insert(10);
+    /// bst.insert(10);   // Element is not inserted
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(15);
+    /// bst.insert(25);
+    ///
+    /// assert_eq!(bst.size(), 5);
+    /// ```
+    fn insert(&mut self, value: T) {
+        let slot = Node::iterative_slot_by(&mut self.root, |current| current.value.cmp(&value));
+        if slot.is_none() {
+            *slot = Some(Box::new(Node::new(WithMeta {
+                value,
+                meta: M::default(),
+            })));
+            self.size += 1;
+        }
+    }
+
+    /// Returns `true` if the binary search tree contains an element with the given value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert!(bst.contains(&5));
+    /// assert!(!bst.contains(&10));
+    /// ```
+    fn contains(&self, value: &T) -> bool {
+        self.retrieve(value).is_some()
+    }
+
+    /// Removes the given value.
+    ///
+    /// Tree will not be modified if trying to remove element that does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// assert_eq!(bst.size(), 3);
+    ///
+    /// bst.remove(&5);
+    /// bst.remove(&10); // Element is not removed
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove(&mut self, value: &T) {
+        self.remove_with_meta(value);
+    }
+
+    /// Returns a reference to the element or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert_eq!(bst.retrieve(&5), Some(&5));
+    /// assert_eq!(bst.retrieve(&10), None);
+    /// ```
+    fn retrieve(&self, value: &T) -> Option<&T> {
+        Node::iterative_retrieve_by(&self.root, |current| current.value.cmp(value))
+            .map(|current| &current.value)
+    }
+
+    /// Returns a mutable reference to the element (see [MetaBST::retrieve()])
+    /// or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(10);
+    /// bst.insert(5);
+    ///
+    /// let optional_retrieved_value_as_mut = bst.retrieve_as_mut(&5);
+    /// assert_eq!(optional_retrieved_value_as_mut, Some(&mut 5));
+    ///
+    /// let mut retrieved_value = optional_retrieved_value_as_mut.unwrap();
+    /// *retrieved_value = 2; // Change value inside tree to '2'
+    ///
+    /// assert_eq!(bst.retrieve_as_mut(&5), None); // 5 does not exist anymore
+    /// assert_eq!(bst.retrieve_as_mut(&2), Some(&mut 2));
+    /// ```
+    fn retrieve_as_mut(&mut self, value: &T) -> Option<&mut T> {
+        Node::iterative_slot_by(&mut self.root, |current| current.value.cmp(value))
+            .as_mut()
+            .map(|node| &mut node.value.value)
+    }
+
+    /// Returns the **height** or `None` if tree is empty.
+    ///
+    /// The height is the number of edges between the root and it's furthest leaf node.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// assert_eq!(bst.height(), None);
+    ///
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The height is 2.
+    /// assert_eq!(bst.height(), Some(2));
+    /// ```
+    fn height(&self) -> Option<isize> {
+        self.root
+            .as_ref()
+            .map(|_| Node::iterative_height(&self.root))
+    }
+
+    /// Returns a reference to the minimum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// assert_eq!(bst.min(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.min(), Some(&2));
+    /// ```
+    fn min(&self) -> Option<&T> {
+        Node::iterative_min(&self.root).map(|current| &current.value)
+    }
+
+    /// Returns a reference to the maximum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// assert_eq!(bst.max(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.max(), Some(&10));
+    /// ```
+    fn max(&self) -> Option<&T> {
+        Node::iterative_max(&self.root).map(|current| &current.value)
+    }
+
+    /// Removes and returns the minimum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// assert_eq!(bst.remove_min(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_min(), Some(2));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_min(&mut self) -> Option<T> {
+        let removed_min = Node::iterative_remove_min(&mut self.root)?;
+        self.size -= 1;
+        Some(removed_min.value)
+    }
+
+    /// Removes and returns the maximum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// assert_eq!(bst.remove_max(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_max(), Some(10));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_max(&mut self) -> Option<T> {
+        let removed_max = Node::iterative_remove_max(&mut self.root)?;
+        self.size -= 1;
+        Some(removed_max.value)
+    }
+
+    /// Returns references to the elements of the tree in **ascending order.**`
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [MetaBST::in_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn asc_order_vec(&self) -> Vec<&T> {
+        self.in_order_vec()
+    }
+
+    /// Returns references to the elements of the tree in the order of a **pre-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The pre_order_vec is: [&4, &2, &1, &3, &6, &5, &7]
+    /// assert_eq!(bst.pre_order_vec(), vec![&4, &2, &1, &3, &6, &5, &7]);
+    /// ```
+    fn pre_order_vec(&self) -> Vec<&T> {
+        WithMeta::values(Node::iterative_pre_order_vec(&self.root))
+    }
+
+    /// Returns references to the elements of the tree in the order of an **in-order traversal.**
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [MetaBST::asc_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The in_order_vec is: [&1, &2, &3, &4, &5, &6, &7]
+    /// assert_eq!(bst.in_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn in_order_vec(&self) -> Vec<&T> {
+        WithMeta::values(Node::iterative_in_order_vec(&self.root))
+    }
+
+    /// Returns references to the elements of the tree in the order of a **post-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The post_order_vec is: [&1, &3, &2, &5, &7, &6, &4]
+    /// assert_eq!(bst.post_order_vec(), vec![&1, &3, &2, &5, &7, &6, &4]);
+    /// ```
+    fn post_order_vec(&self) -> Vec<&T> {
+        WithMeta::values(Node::iterative_post_order_vec(&self.root))
+    }
+
+    /// Returns references to the elements of the tree in the order of a **level-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The level_order_vec is: [&4, &2, &6, &1, &3, &5, &7]
+    /// assert_eq!(bst.level_order_vec(), vec![&4, &2, &6, &1, &3, &5, &7]);
+    /// ```
+    fn level_order_vec(&self) -> Vec<&T> {
+        WithMeta::values(Node::iterative_level_order_vec(&self.root))
+    }
+
+    /// Returns an iterator over [MetaBST::asc_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [MetaBST::in_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut asc_order_iter = bst.asc_order_iter();
+    ///
+    /// assert_eq!(asc_order_iter.next(), Some(&1));
+    /// assert_eq!(asc_order_iter.next(), Some(&2));
+    /// assert_eq!(asc_order_iter.next(), Some(&3));
+    /// assert_eq!(asc_order_iter.next(), Some(&4));
+    /// assert_eq!(asc_order_iter.next(), Some(&5));
+    /// assert_eq!(asc_order_iter.next(), None);
+    /// ```
+    fn asc_order_iter(&self) -> IntoIter<&T> {
+        self.in_order_iter()
+    }
+
+    /// Returns an iterator over [MetaBST::pre_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut pre_order_iter = bst.pre_order_iter();
+    ///
+    /// assert_eq!(pre_order_iter.next(), Some(&3));
+    /// assert_eq!(pre_order_iter.next(), Some(&1));
+    /// assert_eq!(pre_order_iter.next(), Some(&2));
+    /// assert_eq!(pre_order_iter.next(), Some(&4));
+    /// assert_eq!(pre_order_iter.next(), Some(&5));
+    /// assert_eq!(pre_order_iter.next(), None);
+    /// ```
+    fn pre_order_iter(&self) -> IntoIter<&T> {
+        self.pre_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [MetaBST::in_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [MetaBST::asc_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut in_order_iter = bst.in_order_iter();
+    ///
+    /// assert_eq!(in_order_iter.next(), Some(&1));
+    /// assert_eq!(in_order_iter.next(), Some(&2));
+    /// assert_eq!(in_order_iter.next(), Some(&3));
+    /// assert_eq!(in_order_iter.next(), Some(&4));
+    /// assert_eq!(in_order_iter.next(), Some(&5));
+    /// assert_eq!(in_order_iter.next(), None);
+    /// ```
+    fn in_order_iter(&self) -> IntoIter<&T> {
+        self.in_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [MetaBST::post_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut post_order_iter = bst.post_order_iter();
+    ///
+    /// assert_eq!(post_order_iter.next(), Some(&2));
+    /// assert_eq!(post_order_iter.next(), Some(&1));
+    /// assert_eq!(post_order_iter.next(), Some(&5));
+    /// assert_eq!(post_order_iter.next(), Some(&4));
+    /// assert_eq!(post_order_iter.next(), Some(&3));
+    /// assert_eq!(post_order_iter.next(), None);
+    /// ```
+    fn post_order_iter(&self) -> IntoIter<&T> {
+        self.post_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [MetaBST::level_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut level_order_iter = bst.level_order_iter();
+    ///
+    /// assert_eq!(level_order_iter.next(), Some(&3));
+    /// assert_eq!(level_order_iter.next(), Some(&1));
+    /// assert_eq!(level_order_iter.next(), Some(&4));
+    /// assert_eq!(level_order_iter.next(), Some(&2));
+    /// assert_eq!(level_order_iter.next(), Some(&5));
+    /// assert_eq!(level_order_iter.next(), None);
+    /// ```
+    fn level_order_iter(&self) -> IntoIter<&T> {
+        self.level_order_vec().into_iter()
+    }
+
+    /// Returns [MetaBST::asc_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [MetaBST::into_in_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_asc_order_iter = bst.into_asc_order_iter();
+    ///
+    /// assert_eq!(into_asc_order_iter.next(), Some(1));
+    /// assert_eq!(into_asc_order_iter.next(), Some(2));
+    /// assert_eq!(into_asc_order_iter.next(), Some(3));
+    /// assert_eq!(into_asc_order_iter.next(), Some(4));
+    /// assert_eq!(into_asc_order_iter.next(), Some(5));
+    /// assert_eq!(into_asc_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_asc_order_iter(self) -> IntoIter<T> {
+        self.into_in_order_iter()
+    }
+
+    /// Returns [MetaBST::pre_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_pre_order_iter = bst.into_pre_order_iter();
+    ///
+    /// assert_eq!(into_pre_order_iter.next(), Some(3));
+    /// assert_eq!(into_pre_order_iter.next(), Some(1));
+    /// assert_eq!(into_pre_order_iter.next(), Some(2));
+    /// assert_eq!(into_pre_order_iter.next(), Some(4));
+    /// assert_eq!(into_pre_order_iter.next(), Some(5));
+    /// assert_eq!(into_pre_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_pre_order_iter(self) -> IntoIter<T> {
+        WithMeta::into_values(Node::iterative_consume_pre_order_vec(self.root))
+    }
+
+    /// Returns [MetaBST::in_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [MetaBST::asc_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_in_order_iter = bst.into_in_order_iter();
+    ///
+    /// assert_eq!(into_in_order_iter.next(), Some(1));
+    /// assert_eq!(into_in_order_iter.next(), Some(2));
+    /// assert_eq!(into_in_order_iter.next(), Some(3));
+    /// assert_eq!(into_in_order_iter.next(), Some(4));
+    /// assert_eq!(into_in_order_iter.next(), Some(5));
+    /// assert_eq!(into_in_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_in_order_iter(self) -> IntoIter<T> {
+        WithMeta::into_values(Node::iterative_consume_in_order_vec(self.root))
+    }
+
+    /// Returns [MetaBST::post_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_post_order_iter = bst.into_post_order_iter();
+    ///
+    /// assert_eq!(into_post_order_iter.next(), Some(2));
+    /// assert_eq!(into_post_order_iter.next(), Some(1));
+    /// assert_eq!(into_post_order_iter.next(), Some(5));
+    /// assert_eq!(into_post_order_iter.next(), Some(4));
+    /// assert_eq!(into_post_order_iter.next(), Some(3));
+    /// assert_eq!(into_post_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_post_order_iter(self) -> IntoIter<T> {
+        WithMeta::into_values(Node::iterative_consume_post_order_vec(self.root))
+    }
+
+    /// Returns [MetaBST::level_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, MetaBST};
+    ///
+    /// let mut bst: MetaBST<i32> = MetaBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_level_order_iter = bst.into_level_order_iter();
+    ///
+    /// assert_eq!(into_level_order_iter.next(), Some(3));
+    /// assert_eq!(into_level_order_iter.next(), Some(1));
+    /// assert_eq!(into_level_order_iter.next(), Some(4));
+    /// assert_eq!(into_level_order_iter.next(), Some(2));
+    /// assert_eq!(into_level_order_iter.next(), Some(5));
+    /// assert_eq!(into_level_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_level_order_iter(self) -> IntoIter<T> {
+        WithMeta::into_values(Node::iterative_consume_level_order_vec(self.root))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BinarySearchTree, MetaBST};
+
+    #[test]
+    fn successfully_attach_metadata_while_inserting() {
+        let mut bst = MetaBST::new();
+        bst.insert_with_meta(8, "eight");
+        bst.insert_with_meta(3, "three");
+        bst.insert(10);
+
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.meta(&8), Some(&"eight"));
+        assert_eq!(bst.meta(&10), Some(&""));
+        assert_eq!(bst.pre_order_vec(), vec![&8, &3, &10]);
+    }
+
+    #[test]
+    fn successfully_replace_metadata_of_existing_element() {
+        let mut bst = MetaBST::new();
+        bst.insert_with_meta(1, 10);
+
+        assert_eq!(bst.insert_with_meta(1, 20), Some(10));
+        bst.insert(1);
+
+        assert_eq!(bst.size(), 1);
+        assert_eq!(bst.meta(&1), Some(&20));
+    }
+
+    #[test]
+    fn successfully_mutate_metadata_without_affecting_order() {
+        let mut bst: MetaBST<i32, Vec<&str>> = MetaBST::from(vec![5, 2, 7]);
+
+        bst.meta_mut(&2).unwrap().push("first");
+        bst.meta_mut(&2).unwrap().push("second");
+
+        assert_eq!(bst.meta(&2), Some(&vec!["first", "second"]));
+        assert_eq!(bst.meta_mut(&3), None);
+        assert_eq!(bst.asc_order_vec(), vec![&2, &5, &7]);
+        assert!(bst.contains(&2));
+    }
+
+    #[test]
+    fn successfully_remove_elements_with_metadata() {
+        let mut bst = MetaBST::new();
+        for (value, meta) in [(4, 'd'), (2, 'b'), (6, 'f'), (1, 'a'), (3, 'c')] {
+            bst.insert_with_meta(value, meta);
+        }
+
+        assert_eq!(bst.remove_with_meta(&2), Some((2, 'b')));
+        bst.remove(&4);
+        assert_eq!(bst.remove_min(), Some(1));
+
+        assert_eq!(bst.size(), 2);
+        assert_eq!(bst.iter().collect::<Vec<_>>(), vec![(&3, &'c'), (&6, &'f')]);
+    }
+
+    #[test]
+    fn successfully_clone_metadata_and_shape() {
+        let mut bst = MetaBST::new();
+        bst.insert_with_meta(2, 'b');
+        bst.insert_with_meta(1, 'a');
+        bst.insert_with_meta(3, 'c');
+
+        let clone = bst.clone();
+        *bst.meta_mut(&1).unwrap() = 'z';
+
+        assert_eq!(clone.pre_order_vec(), vec![&2, &1, &3]);
+        assert_eq!(clone.meta(&1), Some(&'a'));
+        assert_ne!(clone, bst);
+    }
+}
@@ -0,0 +1,249 @@
+use metrics::{counter, gauge, histogram};
+
+/// Counter incremented by every insertion into an [IterativeBST](crate::IterativeBST) or
+/// [RecursiveBST](crate::RecursiveBST), whether the value was new or not.
+pub const INSERTS_METRIC: &str = "bst.inserts";
+/// Counter incremented by every removal of a single element, whether it was present or not, and
+/// by the number of removed elements for bulk removals such as
+/// [retain_greater](crate::Bst::retain_greater()).
+pub const REMOVES_METRIC: &str = "bst.removes";
+/// Counter incremented by every lookup through `contains()`.
+pub const LOOKUPS_METRIC: &str = "bst.lookups";
+/// Histogram of the depths at which insertions, removals and lookups end their search.
+pub const SEARCH_DEPTH_METRIC: &str = "bst.search_depth";
+/// Gauge of the number of elements of the tree which was modified last.
+///
+/// The gauge carries no labels, so all trees of the process share it and with several trees
+/// it only tells the size of whichever one changed most recently.
+pub const SIZE_METRIC: &str = "bst.size";
+
+/// Records an insertion whose search ended at `depth`, leaving the tree with `size` elements.
+pub(crate) fn insert(depth: usize, size: usize) {
+    counter!(INSERTS_METRIC).increment(1);
+    histogram!(SEARCH_DEPTH_METRIC).record(depth as f64);
+    gauge!(SIZE_METRIC).set(size as f64);
+}
+
+/// Records a removal whose search ended at `depth`, leaving the tree with `size` elements.
+pub(crate) fn remove(depth: usize, size: usize) {
+    counter!(REMOVES_METRIC).increment(1);
+    histogram!(SEARCH_DEPTH_METRIC).record(depth as f64);
+    gauge!(SIZE_METRIC).set(size as f64);
+}
+
+/// Records a bulk removal of `removed` elements, leaving the tree with `size` elements.
+pub(crate) fn remove_many(removed: usize, size: usize) {
+    counter!(REMOVES_METRIC).increment(removed as u64);
+    gauge!(SIZE_METRIC).set(size as f64);
+}
+
+/// Records a lookup whose search ended at `depth`.
+pub(crate) fn lookup(depth: usize) {
+    counter!(LOOKUPS_METRIC).increment(1);
+    histogram!(SEARCH_DEPTH_METRIC).record(depth as f64);
+}
+
+#[cfg(all(test, feature = "iterative", feature = "recursive"))]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    use metrics::{
+        Counter, CounterFn, Gauge, GaugeFn, Histogram, HistogramFn, Key, KeyName, Metadata,
+        SharedString, Unit,
+    };
+
+    use crate::{
+        BinarySearchTree, IterativeBST, Op, RecursiveBST, INSERTS_METRIC, LOOKUPS_METRIC,
+        REMOVES_METRIC, SEARCH_DEPTH_METRIC, SIZE_METRIC,
+    };
+
+    #[derive(Default)]
+    struct Recorded {
+        counters: Mutex<HashMap<String, u64>>,
+        histograms: Mutex<HashMap<String, Vec<f64>>>,
+        gauges: Mutex<HashMap<String, f64>>,
+    }
+
+    /// Handle of a single metric, writing into the shared [Recorded] values.
+    struct Handle(Arc<Recorded>, String);
+
+    impl Handle {
+        fn update<V: Default>(&self, values: &Mutex<HashMap<String, V>>, f: impl FnOnce(&mut V)) {
+            f(values.lock().unwrap().entry(self.1.clone()).or_default())
+        }
+    }
+
+    impl CounterFn for Handle {
+        fn increment(&self, value: u64) {
+            self.update(&self.0.counters, |count| *count += value)
+        }
+
+        fn absolute(&self, value: u64) {
+            self.update(&self.0.counters, |count| *count = value)
+        }
+    }
+
+    impl HistogramFn for Handle {
+        fn record(&self, value: f64) {
+            self.update(&self.0.histograms, |values| values.push(value))
+        }
+    }
+
+    impl GaugeFn for Handle {
+        fn increment(&self, value: f64) {
+            self.update(&self.0.gauges, |gauge| *gauge += value)
+        }
+
+        fn decrement(&self, value: f64) {
+            self.update(&self.0.gauges, |gauge| *gauge -= value)
+        }
+
+        fn set(&self, value: f64) {
+            self.update(&self.0.gauges, |gauge| *gauge = value)
+        }
+    }
+
+    struct Recorder(Arc<Recorded>);
+
+    impl Recorder {
+        fn handle(&self, key: &Key) -> Arc<Handle> {
+            Arc::new(Handle(self.0.clone(), key.name().to_string()))
+        }
+    }
+
+    impl metrics::Recorder for Recorder {
+        fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+        fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+            Counter::from_arc(self.handle(key))
+        }
+
+        fn register_gauge(&self, key: &Key, _: &Metadata<'_>) -> Gauge {
+            Gauge::from_arc(self.handle(key))
+        }
+
+        fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+            Histogram::from_arc(self.handle(key))
+        }
+    }
+
+    fn record(f: impl FnOnce()) -> Arc<Recorded> {
+        let recorded = Arc::new(Recorded::default());
+        metrics::with_local_recorder(&Recorder(recorded.clone()), f);
+        recorded
+    }
+
+    #[test]
+    fn successfully_record_operation_metrics() {
+        let recorded = record(|| {
+            let mut bst = IterativeBST::from(vec![4, 2, 6, 2]);
+            bst.remove(&6);
+            bst.remove(&7);
+            assert!(bst.contains(&2));
+        });
+
+        let counters = recorded.counters.lock().unwrap();
+        assert_eq!(counters[INSERTS_METRIC], 4);
+        assert_eq!(counters[REMOVES_METRIC], 2);
+        assert_eq!(counters[LOOKUPS_METRIC], 1);
+        assert_eq!(
+            recorded.histograms.lock().unwrap()[SEARCH_DEPTH_METRIC],
+            vec![0.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0]
+        );
+        assert_eq!(recorded.gauges.lock().unwrap()[SIZE_METRIC], 2.0);
+    }
+
+    #[test]
+    fn successfully_record_same_metrics_for_both_trees() {
+        fn operations<B: BinarySearchTree<i32>>(mut bst: B) {
+            bst.insert(3);
+            bst.insert(1);
+            bst.insert(5);
+            bst.remove(&3);
+            bst.contains(&1);
+            bst.remove_max();
+            bst.remove_min();
+            bst.remove_min();
+        }
+
+        let iterative = record(|| operations(IterativeBST::new()));
+        let recursive = record(|| operations(RecursiveBST::new()));
+
+        assert_eq!(
+            *iterative.counters.lock().unwrap(),
+            *recursive.counters.lock().unwrap()
+        );
+        assert_eq!(
+            *iterative.histograms.lock().unwrap(),
+            *recursive.histograms.lock().unwrap()
+        );
+        assert_eq!(
+            *iterative.gauges.lock().unwrap(),
+            *recursive.gauges.lock().unwrap()
+        );
+    }
+
+    #[test]
+    fn verify_rejected_push_max_is_not_recorded() {
+        let recorded = record(|| {
+            let mut iterative = IterativeBST::from(vec![1, 3]);
+            assert!(iterative.push_max(2).is_err());
+            assert!(iterative.push_max(4).is_ok());
+            let mut recursive = RecursiveBST::from(vec![1, 3]);
+            assert!(recursive.push_max(3).is_err());
+        });
+
+        assert_eq!(recorded.counters.lock().unwrap()[INSERTS_METRIC], 5);
+        assert_eq!(recorded.gauges.lock().unwrap()[SIZE_METRIC], 2.0);
+    }
+
+    #[test]
+    fn successfully_record_bounded_insertions_like_insert() {
+        let bounded = record(|| {
+            let mut iterative = IterativeBST::new();
+            assert!(iterative.insert_bounded(2, 1).is_ok());
+            assert!(iterative.insert_bounded(1, 1).is_ok());
+            assert!(iterative.insert_bounded(1, 1).is_ok());
+            assert!(iterative.insert_bounded(0, 1).is_err());
+            let mut recursive = RecursiveBST::with_max_depth(1);
+            assert!(recursive.try_insert(3).is_ok());
+            assert!(recursive.try_insert(0).is_ok());
+        });
+
+        assert_eq!(bounded.counters.lock().unwrap()[INSERTS_METRIC], 5);
+        assert_eq!(
+            bounded.histograms.lock().unwrap()[SEARCH_DEPTH_METRIC],
+            vec![0.0, 1.0, 1.0, 0.0, 1.0]
+        );
+        assert_eq!(bounded.gauges.lock().unwrap()[SIZE_METRIC], 2.0);
+    }
+
+    #[test]
+    fn successfully_record_every_kind_of_removal() {
+        let recorded = record(|| {
+            let mut bst = IterativeBST::from(vec![5, 3, 8, 1, 4, 7, 9, 2, 6, 10]);
+            assert_eq!(bst.remove_min(), Some(1));
+            assert_eq!(bst.remove_max_n(2), vec![10, 9]);
+            assert_eq!(bst.retain_greater(&2), 1);
+            assert_eq!(bst.remove_all(&[4, 6]), 2);
+            assert_eq!(bst.drain_sorted().next(), Some(3));
+            assert_eq!(bst.remove_min_if(|_| false), None);
+            assert_eq!(bst.retain_mut(|value| *value != 7), 1);
+            assert_eq!(bst.apply(vec![Op::Remove(8), Op::Insert(11)]), Ok(()));
+        });
+
+        let counters = recorded.counters.lock().unwrap();
+        assert_eq!(counters[INSERTS_METRIC], 11);
+        assert_eq!(counters[REMOVES_METRIC], 9);
+        // Only removals of a single element record a depth, the minimum 1 being at depth 2
+        let depths = &recorded.histograms.lock().unwrap()[SEARCH_DEPTH_METRIC];
+        assert_eq!(depths.len(), 10 + 1 + 2 + 2);
+        assert_eq!(depths[10], 2.0);
+        assert_eq!(recorded.gauges.lock().unwrap()[SIZE_METRIC], 2.0);
+    }
+}
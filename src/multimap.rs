@@ -0,0 +1,327 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::BSTMap;
+
+/// Ordered multimap built on top of [BSTMap], storing any number of values per key.
+///
+/// Values of the same key are kept in insertion order.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::BSTMultiMap;
+///
+/// let mut multimap = BSTMultiMap::new();
+/// multimap.insert("fruit", "apple");
+/// multimap.insert("fruit", "pear");
+/// multimap.insert("vegetable", "leek");
+///
+/// assert_eq!(multimap.get_all(&"fruit").collect::<Vec<_>>(), vec![&"apple", &"pear"]);
+/// assert!(multimap.remove_entry(&"fruit", &"apple"));
+/// assert_eq!(multimap.len(), 2);
+/// ```
+pub struct BSTMultiMap<K: Ord, V> {
+    map: BSTMap<K, Vec<V>>,
+    size: usize,
+}
+
+impl<K: Ord, V> BSTMultiMap<K, V> {
+    /// Creates an empty `BSTMultiMap<K, V>`
+    ///
+    /// No nodes are allocated on the heap yet
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMultiMap;
+    ///
+    /// let multimap: BSTMultiMap<i32, &str> = BSTMultiMap::new();
+    /// assert!(multimap.is_empty());
+    /// ```
+    pub const fn new() -> BSTMultiMap<K, V> {
+        BSTMultiMap {
+            map: BSTMap::new(),
+            size: 0,
+        }
+    }
+
+    /// Returns the total **number of key-value pairs** within the multimap.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMultiMap;
+    ///
+    /// let mut multimap = BSTMultiMap::new();
+    /// multimap.insert(1, "one");
+    /// multimap.insert(1, "uno");
+    ///
+    /// assert_eq!(multimap.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the multimap contains no key-value pairs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMultiMap;
+    ///
+    /// let mut multimap = BSTMultiMap::new();
+    /// assert!(multimap.is_empty());
+    ///
+    /// multimap.insert(1, "one");
+    /// assert!(!multimap.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Adds the given value to the values of the key.
+    ///
+    /// **Duplicate values are _allowed_**, even under the same key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMultiMap;
+    ///
+    /// let mut multimap = BSTMultiMap::new();
+    /// multimap.insert(1, "one");
+    /// multimap.insert(1, "one");
+    ///
+    /// assert_eq!(multimap.get_all(&1).count(), 2);
+    /// ```
+    pub fn insert(&mut self, key: K, value: V) {
+        match self.map.get_mut(&key) {
+            Some(values) => values.push(value),
+            None => {
+                self.map.insert(key, vec![value]);
+            }
+        }
+        self.size += 1;
+    }
+
+    /// Returns `true` if the multimap contains at least one value for the given key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMultiMap;
+    ///
+    /// let mut multimap = BSTMultiMap::new();
+    /// multimap.insert(1, "one");
+    ///
+    /// assert!(multimap.contains_key(&1));
+    /// assert!(!multimap.contains_key(&2));
+    /// ```
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.map.contains_key(key)
+    }
+
+    /// Returns an iterator over all values of the given key, in insertion order.
+    ///
+    /// The iterator is empty if the key does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMultiMap;
+    ///
+    /// let mut multimap = BSTMultiMap::new();
+    /// multimap.insert(1, "one");
+    /// multimap.insert(1, "uno");
+    ///
+    /// let mut get_all = multimap.get_all(&1);
+    /// assert_eq!(get_all.next(), Some(&"one"));
+    /// assert_eq!(get_all.next(), Some(&"uno"));
+    /// assert_eq!(get_all.next(), None);
+    ///
+    /// assert_eq!(multimap.get_all(&2).next(), None);
+    /// ```
+    pub fn get_all(&self, key: &K) -> impl Iterator<Item = &V> {
+        self.map.get(key).into_iter().flatten()
+    }
+
+    /// Removes every value of the given key, returning them in insertion order.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMultiMap;
+    ///
+    /// let mut multimap = BSTMultiMap::new();
+    /// multimap.insert(1, "one");
+    /// multimap.insert(1, "uno");
+    ///
+    /// assert_eq!(multimap.remove(&1), vec!["one", "uno"]);
+    /// assert!(multimap.is_empty());
+    /// ```
+    pub fn remove(&mut self, key: &K) -> Vec<V> {
+        let removed = self.map.remove(key).unwrap_or_default();
+        self.size -= removed.len();
+        removed
+    }
+
+    /// Returns an iterator over every key-value pair in **ascending key order.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMultiMap;
+    ///
+    /// let mut multimap = BSTMultiMap::new();
+    /// multimap.insert(2, "two");
+    /// multimap.insert(1, "one");
+    /// multimap.insert(1, "uno");
+    ///
+    /// let pairs: Vec<_> = multimap.iter().collect();
+    /// assert_eq!(pairs, vec![(&1, &"one"), (&1, &"uno"), (&2, &"two")]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.map
+            .iter()
+            .flat_map(|(key, values)| values.iter().map(move |value| (key, value)))
+    }
+}
+
+impl<K: Ord, V: PartialEq> BSTMultiMap<K, V> {
+    /// Removes one pairing of the given key and value.
+    ///
+    /// Returns `true` if the pairing existed. The key itself is removed once it has no values
+    /// left.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMultiMap;
+    ///
+    /// let mut multimap = BSTMultiMap::new();
+    /// multimap.insert(1, "one");
+    /// multimap.insert(1, "uno");
+    ///
+    /// assert!(multimap.remove_entry(&1, &"one"));
+    /// assert!(!multimap.remove_entry(&1, &"one"));  // Pairing does not exist anymore
+    /// assert!(multimap.remove_entry(&1, &"uno"));
+    /// assert!(!multimap.contains_key(&1));
+    /// ```
+    pub fn remove_entry(&mut self, key: &K, value: &V) -> bool {
+        let values = match self.map.get_mut(key) {
+            None => return false,
+            Some(values) => values,
+        };
+        let position = match values.iter().position(|current| current == value) {
+            None => return false,
+            Some(position) => position,
+        };
+
+        values.remove(position);
+        if values.is_empty() {
+            self.map.remove(key);
+        }
+        self.size -= 1;
+
+        true
+    }
+}
+
+impl<K: Ord, V> Default for BSTMultiMap<K, V> {
+    /// Creates an empty `BSTMultiMap<K, V>`
+    fn default() -> BSTMultiMap<K, V> {
+        BSTMultiMap::new()
+    }
+}
+
+impl<K: Ord + Debug, V: Debug> Debug for BSTMultiMap<K, V> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_map().entries(self.map.iter()).finish()
+    }
+}
+
+impl<K: Ord, V> Extend<(K, V)> for BSTMultiMap<K, V> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter.into_iter() {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K: Ord, V> FromIterator<(K, V)> for BSTMultiMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut multimap = BSTMultiMap::new();
+        multimap.extend(iter);
+        multimap
+    }
+}
+
+impl<K: Ord, V> From<Vec<(K, V)>> for BSTMultiMap<K, V> {
+    /// Inserts the pairings in the order of the vector, keeping every value of a key.
+    fn from(vec: Vec<(K, V)>) -> Self {
+        let mut multimap = BSTMultiMap::new();
+        for (key, value) in vec.into_iter() {
+            multimap.insert(key, value);
+        }
+        multimap
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BSTMultiMap;
+
+    #[test]
+    fn successfully_insert_multiple_values_per_key() {
+        let mut multimap = BSTMultiMap::new();
+        multimap.insert(2, 'b');
+        multimap.insert(1, 'a');
+        multimap.insert(2, 'B');
+        multimap.insert(2, 'b');
+
+        assert_eq!(multimap.len(), 4);
+        assert_eq!(
+            multimap.get_all(&2).collect::<Vec<_>>(),
+            vec![&'b', &'B', &'b']
+        );
+        assert_eq!(multimap.get_all(&1).collect::<Vec<_>>(), vec![&'a']);
+        assert_eq!(multimap.get_all(&3).next(), None);
+
+        let from_vec = BSTMultiMap::from(vec![(2, 'b'), (1, 'a'), (2, 'B'), (2, 'b')]);
+        assert!(from_vec.iter().eq(multimap.iter()));
+    }
+
+    #[test]
+    fn successfully_remove_specific_pairing() {
+        let mut multimap = BSTMultiMap::from_iter(vec![(1, 'a'), (1, 'b'), (2, 'c')]);
+
+        assert!(multimap.remove_entry(&1, &'b'));
+        assert!(!multimap.remove_entry(&1, &'z'));
+        assert!(!multimap.remove_entry(&5, &'a'));
+
+        assert_eq!(multimap.len(), 2);
+        assert_eq!(multimap.get_all(&1).collect::<Vec<_>>(), vec![&'a']);
+    }
+
+    #[test]
+    fn successfully_remove_key_once_last_pairing_is_removed() {
+        let mut multimap = BSTMultiMap::new();
+        multimap.insert(1, 'a');
+
+        assert!(multimap.remove_entry(&1, &'a'));
+
+        assert!(!multimap.contains_key(&1));
+        assert!(multimap.is_empty());
+    }
+
+    #[test]
+    fn successfully_remove_all_values_of_key() {
+        let mut multimap = BSTMultiMap::from_iter(vec![(1, 'a'), (2, 'b'), (1, 'c')]);
+
+        assert_eq!(multimap.remove(&1), vec!['a', 'c']);
+        assert_eq!(multimap.remove(&1), vec![]);
+
+        assert_eq!(multimap.len(), 1);
+        assert_eq!(multimap.iter().collect::<Vec<_>>(), vec![(&2, &'b')]);
+    }
+}
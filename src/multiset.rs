@@ -0,0 +1,485 @@
+use std::fmt::{Debug, Formatter};
+
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+use crate::bst::{Bst, Strategy};
+use crate::iter::SortedIter;
+use crate::node::{HeapNode, Node};
+#[cfg(feature = "iterative")]
+use crate::IterativeBST;
+
+/// Ordered multiset built on top of the iterative node operations.
+///
+/// Unlike [IterativeBST](crate::IterativeBST) and [RecursiveBST](crate::RecursiveBST),
+/// **duplicates are _allowed_** and kept as separate nodes, equal elements are always inserted
+/// _after_ the ones already present.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::BSTMultiSet;
+///
+/// let mut multiset = BSTMultiSet::new();
+/// multiset.insert(2);
+/// multiset.insert(1);
+/// multiset.insert(2);
+///
+/// assert_eq!(multiset.len(), 3);
+/// assert_eq!(multiset.count(&2), 2);
+/// assert_eq!(multiset.iter().collect::<Vec<_>>(), vec![&1, &2, &2]);
+/// ```
+pub struct BSTMultiSet<T: Ord> {
+    root: HeapNode<T>,
+    size: usize,
+}
+
+impl<T: Ord> BSTMultiSet<T> {
+    /// Creates an empty `BSTMultiSet<T>`
+    ///
+    /// No nodes are allocated on the heap yet
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMultiSet;
+    ///
+    /// let multiset: BSTMultiSet<i32> = BSTMultiSet::new();
+    /// assert!(multiset.is_empty());
+    /// ```
+    pub const fn new() -> BSTMultiSet<T> {
+        BSTMultiSet {
+            root: None,
+            size: 0,
+        }
+    }
+
+    /// Returns the total **number of elements** within the multiset, duplicates included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMultiSet;
+    ///
+    /// let mut multiset = BSTMultiSet::new();
+    /// multiset.insert(1);
+    /// multiset.insert(1);
+    ///
+    /// assert_eq!(multiset.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the multiset contains no elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMultiSet;
+    ///
+    /// let mut multiset = BSTMultiSet::new();
+    /// assert!(multiset.is_empty());
+    ///
+    /// multiset.insert(1);
+    /// assert!(!multiset.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Inserts the given value, placing it after every element equal to it.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMultiSet;
+    ///
+    /// let mut multiset = BSTMultiSet::new();
+    /// multiset.insert(1);
+    /// multiset.insert(1);
+    ///
+    /// assert_eq!(multiset.count(&1), 2);
+    /// ```
+    pub fn insert(&mut self, value: T) {
+        let slot = Node::iterative_slot_by(&mut self.root, |current| {
+            current.cmp(&value).then(std::cmp::Ordering::Less)
+        });
+        *slot = Some(Box::new(Node::new(value)));
+        self.size += 1;
+    }
+
+    /// Returns `true` if the multiset contains at least one element equal to the given value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMultiSet;
+    ///
+    /// let mut multiset = BSTMultiSet::new();
+    /// multiset.insert(1);
+    ///
+    /// assert!(multiset.contains(&1));
+    /// assert!(!multiset.contains(&2));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        Node::iterative_contains(&self.root, value)
+    }
+
+    /// Returns the **number of elements** equal to the given value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMultiSet;
+    ///
+    /// let multiset = BSTMultiSet::from_iter(vec![3, 1, 3, 3]);
+    ///
+    /// assert_eq!(multiset.count(&3), 3);
+    /// assert_eq!(multiset.count(&2), 0);
+    /// ```
+    pub fn count(&self, value: &T) -> usize {
+        self.equal_range(value).count()
+    }
+
+    /// Returns an iterator over the contiguous run of elements equal to the given value, in
+    /// insertion order.
+    ///
+    /// Mirrors C++'s `std::multiset::equal_range`: the iterator is seeded with a single
+    /// root-to-leaf descent and stops at the first greater element, so only the run itself
+    /// is walked. The iterator is empty if no element is equal to the value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMultiSet;
+    ///
+    /// let multiset = BSTMultiSet::from_iter(vec![(1, 'a'), (2, 'b'), (1, 'a')]);
+    ///
+    /// let mut equal_range = multiset.equal_range(&(1, 'a'));
+    /// assert_eq!(equal_range.next(), Some(&(1, 'a')));
+    /// assert_eq!(equal_range.next(), Some(&(1, 'a')));
+    /// assert_eq!(equal_range.next(), None);
+    /// ```
+    pub fn equal_range<'a>(&'a self, value: &'a T) -> impl Iterator<Item = &'a T> {
+        SortedIter::lower_bound_by(&self.root, |current| current.cmp(value))
+            .take_while(move |current| *current == value)
+    }
+
+    /// Removes a single element equal to the given value.
+    ///
+    /// Returns `true` if such an element existed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMultiSet;
+    ///
+    /// let mut multiset = BSTMultiSet::from_iter(vec![1, 1]);
+    ///
+    /// assert!(multiset.remove(&1));
+    /// assert_eq!(multiset.count(&1), 1);
+    /// assert!(multiset.remove(&1));
+    /// assert!(!multiset.remove(&1));
+    /// ```
+    pub fn remove(&mut self, value: &T) -> bool {
+        let slot = Node::iterative_slot_by(&mut self.root, |current| current.cmp(value));
+        let removed = Node::iterative_take(slot).is_some();
+        if removed {
+            self.size -= 1;
+        }
+        removed
+    }
+
+    /// Returns an iterator over every element in **ascending order**, duplicates included.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMultiSet;
+    ///
+    /// let multiset = BSTMultiSet::from_iter(vec![2, 1, 2]);
+    ///
+    /// let mut iter = multiset.iter();
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        SortedIter::ascending(&self.root)
+    }
+
+    /// Returns an iterator over the **distinct elements** in ascending order, each paired with
+    /// the number of elements equal to it.
+    ///
+    /// Equal elements are adjacent in order, so every run is counted while walking the
+    /// multiset once. The first element of each run is the one yielded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::BSTMultiSet;
+    ///
+    /// let multiset = BSTMultiSet::from(vec!["b", "a", "b", "c", "b"]);
+    ///
+    /// assert_eq!(
+    ///     multiset.counts().collect::<Vec<_>>(),
+    ///     vec![(&"a", 1), (&"b", 3), (&"c", 1)]
+    /// );
+    /// ```
+    pub fn counts(&self) -> impl Iterator<Item = (&T, usize)> {
+        let mut iter = self.iter().peekable();
+        std::iter::from_fn(move || {
+            let first = iter.next()?;
+            let mut count = 1;
+            while iter.next_if(|next| *next == first).is_some() {
+                count += 1;
+            }
+            Some((first, count))
+        })
+    }
+}
+
+#[cfg(feature = "iterative")]
+impl<T: Ord> BSTMultiSet<T> {
+    /// Consumes the multiset and returns an [IterativeBST] holding each distinct element once,
+    /// collapsing the counts.
+    ///
+    /// The first element of every run of equal elements, i.e. the earliest inserted one, is
+    /// kept. The result is built balanced in one pass over the sorted elements rather than by
+    /// inserting them one at a time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, BSTMultiSet};
+    ///
+    /// let multiset = BSTMultiSet::from(vec![3, 1, 3, 2, 1, 3]);
+    /// let set = multiset.dedup();
+    ///
+    /// assert_eq!(set.size(), 3);
+    /// assert_eq!(set.pre_order_vec(), vec![&2, &1, &3]);
+    /// ```
+    pub fn dedup(self) -> IterativeBST<T> {
+        let mut elements = Node::iterative_consume_in_order_vec(self.root);
+        elements.dedup();
+        Bst::from_sorted(elements, None, None)
+    }
+}
+
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+impl<T: Ord, S: Strategy> Bst<T, S> {
+    /// Consumes the tree and returns a [BSTMultiSet] with the same elements, which then accepts
+    /// duplicates.
+    ///
+    /// Both share the node layout and a tree without duplicates is a valid multiset, so the
+    /// nodes are handed over as they are in O(1).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from_iter(vec![2, 1, 3]);
+    ///
+    /// let mut multiset = bst.to_counted();
+    /// multiset.insert(2);
+    /// assert_eq!(multiset.count(&2), 2);
+    /// assert_eq!(multiset.len(), 4);
+    /// ```
+    pub fn to_counted(self) -> BSTMultiSet<T> {
+        BSTMultiSet {
+            root: self.root,
+            size: self.size,
+        }
+    }
+}
+
+impl<T: Ord> Default for BSTMultiSet<T> {
+    /// Creates an empty `BSTMultiSet<T>`
+    fn default() -> BSTMultiSet<T> {
+        BSTMultiSet::new()
+    }
+}
+
+impl<T: Ord + Debug> Debug for BSTMultiSet<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Ord> PartialEq for BSTMultiSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Ord> Extend<T> for BSTMultiSet<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter.into_iter() {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for BSTMultiSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut multiset = BSTMultiSet::new();
+        multiset.extend(iter);
+        multiset
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for BSTMultiSet<T> {
+    /// Inserts the elements in the order of the vector, keeping every duplicate.
+    fn from(vec: Vec<T>) -> Self {
+        let mut multiset = BSTMultiSet::new();
+        for value in vec.into_iter() {
+            multiset.insert(value);
+        }
+        multiset
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::BSTMultiSet;
+    #[cfg(all(feature = "iterative", feature = "recursive"))]
+    use crate::{BinarySearchTree, RecursiveBST};
+
+    /// Compares by `key` only so that equal elements can still be told apart.
+    #[derive(Debug)]
+    struct Tagged {
+        key: i32,
+        tag: char,
+    }
+
+    impl PartialEq for Tagged {
+        fn eq(&self, other: &Self) -> bool {
+            self.key == other.key
+        }
+    }
+
+    impl Eq for Tagged {}
+
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Tagged {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.key.cmp(&other.key)
+        }
+    }
+
+    fn tagged(key: i32, tag: char) -> Tagged {
+        Tagged { key, tag }
+    }
+
+    #[test]
+    fn successfully_insert_duplicates() {
+        let mut multiset = BSTMultiSet::new();
+        multiset.insert(5);
+        multiset.insert(3);
+        multiset.insert(5);
+        multiset.insert(5);
+
+        assert_eq!(multiset.len(), 4);
+        assert_eq!(multiset.count(&5), 3);
+        assert_eq!(multiset.count(&3), 1);
+        assert_eq!(multiset.iter().collect::<Vec<_>>(), vec![&3, &5, &5, &5]);
+    }
+
+    #[test]
+    fn successfully_get_equal_range_in_insertion_order() {
+        let multiset = BSTMultiSet::from_iter(vec![
+            tagged(4, 'a'),
+            tagged(2, 'b'),
+            tagged(4, 'c'),
+            tagged(6, 'd'),
+            tagged(4, 'e'),
+            tagged(3, 'f'),
+        ]);
+
+        let tags: Vec<char> = multiset
+            .equal_range(&tagged(4, '_'))
+            .map(|current| current.tag)
+            .collect();
+
+        assert_eq!(tags, vec!['a', 'c', 'e']);
+        assert_eq!(multiset.equal_range(&tagged(5, '_')).next(), None);
+        assert_eq!(multiset.equal_range(&tagged(7, '_')).next(), None);
+    }
+
+    #[test]
+    fn successfully_get_equal_range_after_removals() {
+        let mut multiset = BSTMultiSet::from_iter(vec![5, 2, 8, 5, 1, 5, 9, 5]);
+
+        assert!(multiset.remove(&5));
+        assert!(multiset.remove(&5));
+        assert!(!multiset.remove(&7));
+
+        assert_eq!(multiset.len(), 6);
+        assert_eq!(multiset.equal_range(&5).collect::<Vec<_>>(), vec![&5, &5]);
+        assert_eq!(
+            multiset.iter().collect::<Vec<_>>(),
+            vec![&1, &2, &5, &5, &8, &9]
+        );
+    }
+
+    #[test]
+    fn successfully_count_runs_of_duplicates() {
+        let values = vec![7, 3, 7, 1, 3, 7, 9, 7];
+        let multiset = BSTMultiSet::from(values.clone());
+
+        assert_eq!(multiset, BSTMultiSet::from_iter(values));
+        assert_eq!(multiset.len(), 8);
+        assert_eq!(
+            multiset.counts().collect::<Vec<_>>(),
+            vec![(&1, 1), (&3, 2), (&7, 4), (&9, 1)]
+        );
+        assert_eq!(multiset.counts().map(|(_, count)| count).sum::<usize>(), 8);
+        assert_eq!(BSTMultiSet::<i32>::from(vec![]).counts().next(), None);
+
+        let tagged = BSTMultiSet::from(vec![tagged(2, 'a'), tagged(1, 'b'), tagged(2, 'c')]);
+        let runs: Vec<(char, usize)> = tagged
+            .counts()
+            .map(|(first, count)| (first.tag, count))
+            .collect();
+        assert_eq!(runs, vec![('b', 1), ('a', 2)]);
+    }
+
+    #[test]
+    #[cfg(all(feature = "iterative", feature = "recursive"))]
+    fn successfully_switch_between_set_and_multiset() {
+        let multiset = BSTMultiSet::from(vec![
+            tagged(2, 'a'),
+            tagged(1, 'b'),
+            tagged(2, 'c'),
+            tagged(3, 'd'),
+            tagged(1, 'e'),
+        ]);
+
+        let set = multiset.dedup();
+        assert_eq!(set.size(), 3);
+        assert_eq!(
+            set.asc_order_vec()
+                .iter()
+                .map(|current| current.tag)
+                .collect::<Vec<_>>(),
+            vec!['b', 'a', 'd']
+        );
+
+        let mut multiset = set.to_counted();
+        multiset.insert(tagged(2, 'f'));
+        assert_eq!(multiset.len(), 4);
+        assert_eq!(multiset.count(&tagged(2, '_')), 2);
+
+        let recursive = RecursiveBST::from_iter(vec![5, 4, 6]).to_counted();
+        assert_eq!(recursive.iter().collect::<Vec<_>>(), vec![&4, &5, &6]);
+        assert!(BSTMultiSet::<i32>::new().dedup().is_empty());
+    }
+}
@@ -1,13 +1,15 @@
-use std::cmp::{max, Ordering};
+#[cfg(any(feature = "iterative", feature = "recursive"))]
+use std::cmp::max;
+use std::cmp::Ordering;
 use std::collections::VecDeque;
 
 pub(crate) type HeapNode<T> = Option<Box<Node<T>>>;
 
 #[derive(Debug)]
 pub(crate) struct Node<T: Ord> {
-    value: T,
-    left: HeapNode<T>,
-    right: HeapNode<T>,
+    pub(crate) value: T,
+    pub(crate) left: HeapNode<T>,
+    pub(crate) right: HeapNode<T>,
 }
 
 impl<T: Ord> Node<T> {
@@ -19,39 +21,98 @@ impl<T: Ord> Node<T> {
         }
     }
 
-    pub(crate) fn iterative_insert(mut root: &mut HeapNode<T>, value: T) -> Result<(), ()> {
+    /// Returns the depth of the inserted node, or the depth of the equal node as `Err` if the
+    /// value already exists.
+    pub(crate) fn iterative_insert(mut root: &mut HeapNode<T>, value: T) -> Result<usize, usize> {
+        let mut depth = 0;
         while let Some(ref mut node) = root {
             match value.cmp(&node.value) {
-                Ordering::Equal => return Err(()),
+                Ordering::Equal => return Err(depth),
                 Ordering::Less => root = &mut node.left,
                 Ordering::Greater => root = &mut node.right,
             }
+            depth += 1;
         }
         *root = Some(Box::new(Node::new(value)));
 
-        Ok(())
+        Ok(depth)
     }
 
-    pub(crate) fn recursive_insert(&mut self, value: T) -> Result<(), ()> {
+    /// Returns the depth of the inserted node relative to `self`, or the depth of the equal node
+    /// as `Err` if the value already exists.
+    #[cfg(feature = "recursive")]
+    pub(crate) fn recursive_insert(&mut self, value: T) -> Result<usize, usize> {
         match value.cmp(&self.value) {
-            Ordering::Equal => Err(()),
+            Ordering::Equal => Err(0),
             Ordering::Less => match self.left {
                 None => {
                     self.left = Some(Box::from(Node::new(value)));
-                    Ok(())
+                    Ok(1)
                 }
-                Some(ref mut node) => node.recursive_insert(value),
+                Some(ref mut node) => node
+                    .recursive_insert(value)
+                    .map(|depth| depth + 1)
+                    .map_err(|depth| depth + 1),
             },
             Ordering::Greater => match self.right {
                 None => {
                     self.right = Some(Box::from(Node::new(value)));
-                    Ok(())
+                    Ok(1)
                 }
-                Some(ref mut node) => node.recursive_insert(value),
+                Some(ref mut node) => node
+                    .recursive_insert(value)
+                    .map(|depth| depth + 1)
+                    .map_err(|depth| depth + 1),
             },
         }
     }
 
+    /// Attaches `value` below the maximum if it is greater than it, returning the depth of the
+    /// new node, or hands `value` back otherwise.
+    #[cfg(feature = "iterative")]
+    pub(crate) fn iterative_push_max(mut root: &mut HeapNode<T>, value: T) -> Result<usize, T> {
+        let mut depth = 0;
+        while let Some(ref mut node) = root {
+            if node.right.is_none() && node.value >= value {
+                return Err(value);
+            }
+            root = &mut node.right;
+            depth += 1;
+        }
+        *root = Some(Box::new(Node::new(value)));
+
+        Ok(depth)
+    }
+
+    #[cfg(feature = "recursive")]
+    pub(crate) fn recursive_push_max(root: &mut HeapNode<T>, value: T) -> Result<usize, T> {
+        match root {
+            None => {
+                *root = Some(Box::from(Node::new(value)));
+                Ok(0)
+            }
+            Some(node) if node.right.is_none() && node.value >= value => Err(value),
+            Some(node) => Node::recursive_push_max(&mut node.right, value).map(|depth| depth + 1),
+        }
+    }
+
+    /// Returns the depth at which the search for `value` ends and whether an equal value was
+    /// found there.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn search_depth(mut root: &HeapNode<T>, value: &T) -> (usize, bool) {
+        let mut depth = 0;
+        while let Some(node) = root {
+            match value.cmp(&node.value) {
+                Ordering::Equal => return (depth, true),
+                Ordering::Less => root = &node.left,
+                Ordering::Greater => root = &node.right,
+            }
+            depth += 1;
+        }
+
+        (depth, false)
+    }
+
     pub(crate) fn iterative_contains(mut root: &HeapNode<T>, value: &T) -> bool {
         while let Some(current) = root {
             match value.cmp(&current.value) {
@@ -64,20 +125,25 @@ impl<T: Ord> Node<T> {
         false
     }
 
-    pub(crate) fn recursive_contains(&self, value: &T) -> bool {
-        match value.cmp(&self.value) {
-            Ordering::Equal => true,
-            Ordering::Less => match self.left {
-                None => false,
-                Some(ref node) => node.recursive_contains(value),
-            },
-            Ordering::Greater => match self.right {
-                None => false,
-                Some(ref node) => node.recursive_contains(value),
-            },
+    /// Returns the depth relative to `self` at which the search for `value` ends and whether an
+    /// equal value was found there, like [Node::search_depth()].
+    #[cfg(feature = "recursive")]
+    pub(crate) fn recursive_search_depth(&self, value: &T) -> (usize, bool) {
+        let child = match value.cmp(&self.value) {
+            Ordering::Equal => return (0, true),
+            Ordering::Less => &self.left,
+            Ordering::Greater => &self.right,
+        };
+        match child {
+            None => (1, false),
+            Some(node) => {
+                let (depth, found) = node.recursive_search_depth(value);
+                (depth + 1, found)
+            }
         }
     }
 
+    #[cfg(feature = "iterative")]
     pub(crate) fn iterative_retrieve<'a>(mut root: &'a HeapNode<T>, value: &T) -> Option<&'a T> {
         while let Some(current) = root {
             match value.cmp(&current.value) {
@@ -90,6 +156,7 @@ impl<T: Ord> Node<T> {
         None
     }
 
+    #[cfg(feature = "recursive")]
     pub(crate) fn recursive_retrieve(&self, value: &T) -> Option<&T> {
         match value.cmp(&self.value) {
             Ordering::Equal => Some(&self.value),
@@ -104,6 +171,7 @@ impl<T: Ord> Node<T> {
         }
     }
 
+    #[cfg(feature = "iterative")]
     pub(crate) fn iterative_retrieve_as_mut<'a>(
         mut root: &'a mut HeapNode<T>,
         value: &T,
@@ -119,6 +187,7 @@ impl<T: Ord> Node<T> {
         None
     }
 
+    #[cfg(feature = "recursive")]
     pub(crate) fn recursive_retrieve_as_mut(&mut self, value: &T) -> Option<&mut T> {
         match value.cmp(&self.value) {
             Ordering::Equal => Some(&mut self.value),
@@ -133,6 +202,165 @@ impl<T: Ord> Node<T> {
         }
     }
 
+    /// Walks down the tree using `f`, which returns how a node's value compares to the element
+    /// being searched for.
+    ///
+    /// Returns the slot holding the element, or the empty slot where it would be inserted.
+    pub(crate) fn iterative_slot_by<F>(mut root: &mut HeapNode<T>, mut f: F) -> &mut HeapNode<T>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        loop {
+            let ordering = match root.as_ref() {
+                None => break,
+                Some(node) => f(&node.value),
+            };
+            match ordering {
+                Ordering::Equal => break,
+                Ordering::Less => root = &mut root.as_mut().unwrap().right,
+                Ordering::Greater => root = &mut root.as_mut().unwrap().left,
+            }
+        }
+
+        root
+    }
+
+    /// Returns the slot holding the element equal to `value`, or the empty slot where it would
+    /// be inserted, along with the depth of that slot.
+    #[cfg(feature = "iterative")]
+    pub(crate) fn iterative_search_slot<'a>(
+        mut root: &'a mut HeapNode<T>,
+        value: &T,
+    ) -> (&'a mut HeapNode<T>, usize) {
+        let mut depth = 0;
+        loop {
+            let ordering = match root.as_ref() {
+                None => break,
+                Some(node) => value.cmp(&node.value),
+            };
+            match ordering {
+                Ordering::Equal => break,
+                Ordering::Less => root = &mut root.as_mut().unwrap().left,
+                Ordering::Greater => root = &mut root.as_mut().unwrap().right,
+            }
+            depth += 1;
+        }
+
+        (root, depth)
+    }
+
+    #[cfg(feature = "recursive")]
+    pub(crate) fn recursive_search_slot<'a>(
+        root: &'a mut HeapNode<T>,
+        value: &T,
+        depth: usize,
+    ) -> (&'a mut HeapNode<T>, usize) {
+        match root.as_ref().map(|node| value.cmp(&node.value)) {
+            Some(Ordering::Less) => {
+                Node::recursive_search_slot(&mut root.as_mut().unwrap().left, value, depth + 1)
+            }
+            Some(Ordering::Greater) => {
+                Node::recursive_search_slot(&mut root.as_mut().unwrap().right, value, depth + 1)
+            }
+            _ => (root, depth),
+        }
+    }
+
+    pub(crate) fn iterative_retrieve_by<F>(mut root: &HeapNode<T>, mut f: F) -> Option<&T>
+    where
+        F: FnMut(&T) -> Ordering,
+    {
+        while let Some(current) = root {
+            match f(&current.value) {
+                Ordering::Equal => return Some(&current.value),
+                Ordering::Less => root = &current.right,
+                Ordering::Greater => root = &current.left,
+            }
+        }
+
+        None
+    }
+
+    /// Returns the turns leading from the root to the element equal to `value`, `true` standing
+    /// for a left turn, or `None` if there is no such element.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn iterative_path(mut root: &HeapNode<T>, value: &T) -> Option<Vec<bool>> {
+        let mut path = Vec::new();
+        while let Some(current) = root {
+            let left = match value.cmp(&current.value) {
+                Ordering::Equal => return Some(path),
+                Ordering::Less => true,
+                Ordering::Greater => false,
+            };
+            path.push(left);
+            root = if left { &current.left } else { &current.right };
+        }
+
+        None
+    }
+
+    /// Returns the slot reached by following the turns of `path`, see [Node::iterative_path()].
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn slot_at_path<'a>(
+        mut root: &'a mut HeapNode<T>,
+        path: &[bool],
+    ) -> &'a mut HeapNode<T> {
+        for &left in path {
+            let node = root.as_mut().unwrap();
+            root = if left {
+                &mut node.left
+            } else {
+                &mut node.right
+            };
+        }
+
+        root
+    }
+
+    /// Returns `true` if the element reached by following the turns of `path` is bigger than
+    /// its in-order predecessor and smaller than its successor.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn fits_at_path(mut root: &HeapNode<T>, path: &[bool]) -> bool {
+        let (mut lower, mut upper) = (None, None);
+        for &left in path {
+            let node = root.as_ref().unwrap();
+            if left {
+                upper = Some(&node.value);
+                root = &node.left;
+            } else {
+                lower = Some(&node.value);
+                root = &node.right;
+            }
+        }
+
+        let node = root.as_ref().unwrap();
+        let lower = Node::iterative_max(&node.left).or(lower);
+        let upper = Node::iterative_min(&node.right).or(upper);
+        lower.is_none_or(|lower| *lower < node.value)
+            && upper.is_none_or(|upper| node.value < *upper)
+    }
+
+    /// Removes the node held in `slot`, returning its value.
+    pub(crate) fn iterative_take(slot: &mut HeapNode<T>) -> Option<T> {
+        let mut node = slot.take()?;
+        match (node.left.take(), node.right.take()) {
+            (None, None) => {}
+            (Some(left), None) => *slot = Some(left),
+            (None, Some(right)) => *slot = Some(right),
+            (Some(left), Some(right)) => {
+                let mut right = Some(right);
+                let successor = Node::iterative_remove_min(&mut right).unwrap();
+                let value = std::mem::replace(&mut node.value, successor);
+                node.left = Some(left);
+                node.right = right;
+                *slot = Some(node);
+                return Some(value);
+            }
+        }
+
+        Some(node.value)
+    }
+
     pub(crate) fn iterative_height(root: &HeapNode<T>) -> isize {
         let mut height = -1;
         let mut queue = VecDeque::new();
@@ -156,6 +384,7 @@ impl<T: Ord> Node<T> {
         height
     }
 
+    #[cfg(feature = "recursive")]
     pub(crate) fn recursive_height(root: &HeapNode<T>) -> isize {
         match root {
             None => -1,
@@ -168,7 +397,96 @@ impl<T: Ord> Node<T> {
         }
     }
 
-    pub(crate) fn iterative_remove(mut root: &mut HeapNode<T>, value: &T) -> Result<(), ()> {
+    /// Counts the nodes at every depth, the root being at depth `0`.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn iterative_depth_histogram(root: &HeapNode<T>) -> Vec<usize> {
+        let mut histogram = Vec::new();
+        let mut level: Vec<&Node<T>> = root.as_deref().into_iter().collect();
+
+        while !level.is_empty() {
+            histogram.push(level.len());
+            level = level
+                .into_iter()
+                .flat_map(|node| {
+                    node.left
+                        .as_deref()
+                        .into_iter()
+                        .chain(node.right.as_deref())
+                })
+                .collect();
+        }
+
+        histogram
+    }
+
+    /// Pairs every element with the height of its left subtree minus the height of its right
+    /// subtree, in post-order.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn iterative_balance_factors(root: &HeapNode<T>) -> Vec<(&T, isize)> {
+        let mut reversed_post_order = Vec::new();
+        let mut stack: Vec<&Node<T>> = root.as_deref().into_iter().collect();
+        while let Some(node) = stack.pop() {
+            reversed_post_order.push(node);
+            stack.extend(node.left.as_deref());
+            stack.extend(node.right.as_deref());
+        }
+
+        let mut heights = Vec::new();
+        let mut factors = Vec::with_capacity(reversed_post_order.len());
+        for node in reversed_post_order.into_iter().rev() {
+            let right = node.right.as_ref().map_or(-1, |_| heights.pop().unwrap());
+            let left = node.left.as_ref().map_or(-1, |_| heights.pop().unwrap());
+            heights.push(1 + max(left, right));
+            factors.push((&node.value, left - right));
+        }
+
+        factors
+    }
+
+    /// Rotates the subtree in `slot` to the left, its right child becoming the new root of the
+    /// subtree. Returns `false` if there is no right child.
+    #[cfg(all(
+        feature = "unstable-internals",
+        any(feature = "iterative", feature = "recursive")
+    ))]
+    pub(crate) fn rotate_left(slot: &mut HeapNode<T>) -> bool {
+        let node = match slot {
+            Some(node) if node.right.is_some() => node,
+            _ => return false,
+        };
+
+        let mut pivot = node.right.take().unwrap();
+        node.right = pivot.left.take();
+        pivot.left = slot.take();
+        *slot = Some(pivot);
+
+        true
+    }
+
+    /// Mirror of [Node::rotate_left()], the left child becomes the new root of the subtree.
+    #[cfg(all(
+        feature = "unstable-internals",
+        any(feature = "iterative", feature = "recursive")
+    ))]
+    pub(crate) fn rotate_right(slot: &mut HeapNode<T>) -> bool {
+        let node = match slot {
+            Some(node) if node.left.is_some() => node,
+            _ => return false,
+        };
+
+        let mut pivot = node.left.take().unwrap();
+        node.left = pivot.right.take();
+        pivot.right = slot.take();
+        *slot = Some(pivot);
+
+        true
+    }
+
+    /// Returns the depth of the removed node, or the depth at which the search ended as `Err`
+    /// if the value does not exist.
+    #[cfg(feature = "iterative")]
+    pub(crate) fn iterative_remove(mut root: &mut HeapNode<T>, value: &T) -> Result<usize, usize> {
+        let mut depth = 0;
         while let Some(ref mut current) = root {
             match value.cmp(&current.value) {
                 Ordering::Less => root = &mut root.as_mut().unwrap().left,
@@ -184,19 +502,27 @@ impl<T: Ord> Node<T> {
                         }
                     }
 
-                    return Ok(());
+                    return Ok(depth);
                 }
             }
+            depth += 1;
         }
 
-        Err(())
+        Err(depth)
     }
 
-    pub(crate) fn recursive_remove(root: &mut HeapNode<T>, value: &T) -> Result<(), ()> {
+    /// Returns the depth of the removed node relative to `root`, or the depth at which the
+    /// search ended as `Err` if the value does not exist.
+    #[cfg(feature = "recursive")]
+    pub(crate) fn recursive_remove(root: &mut HeapNode<T>, value: &T) -> Result<usize, usize> {
         if let Some(ref mut node) = root {
             return match value.cmp(&node.value) {
-                Ordering::Less => Node::recursive_remove(&mut node.left, value),
-                Ordering::Greater => Node::recursive_remove(&mut node.right, value),
+                Ordering::Less => Node::recursive_remove(&mut node.left, value)
+                    .map(|depth| depth + 1)
+                    .map_err(|depth| depth + 1),
+                Ordering::Greater => Node::recursive_remove(&mut node.right, value)
+                    .map(|depth| depth + 1)
+                    .map_err(|depth| depth + 1),
                 Ordering::Equal => {
                     match (&node.left, &node.right) {
                         (None, None) => *root = None,
@@ -207,12 +533,12 @@ impl<T: Ord> Node<T> {
                         }
                     }
 
-                    Ok(())
+                    Ok(0)
                 }
             };
         }
 
-        Err(())
+        Err(0)
     }
 
     pub(crate) fn iterative_min(mut root: &HeapNode<T>) -> Option<&T> {
@@ -226,6 +552,7 @@ impl<T: Ord> Node<T> {
         None
     }
 
+    #[cfg(feature = "recursive")]
     pub(crate) fn recursive_min(&self) -> Option<&T> {
         match &self.left {
             None => Some(&self.value),
@@ -244,6 +571,7 @@ impl<T: Ord> Node<T> {
         None
     }
 
+    #[cfg(feature = "recursive")]
     pub(crate) fn recursive_max(&self) -> Option<&T> {
         match &self.right {
             None => Some(&self.value),
@@ -251,52 +579,474 @@ impl<T: Ord> Node<T> {
         }
     }
 
-    pub(crate) fn iterative_remove_min(mut root: &mut HeapNode<T>) -> Option<T> {
+    pub(crate) fn iterative_remove_min(root: &mut HeapNode<T>) -> Option<T> {
+        Node::iterative_remove_min_at(root).map(|(value, _)| value)
+    }
+
+    /// Removes the minimum element, returning it along with the depth of its node.
+    pub(crate) fn iterative_remove_min_at(mut root: &mut HeapNode<T>) -> Option<(T, usize)> {
         if root.is_some() {
+            let mut depth = 0;
             while root.as_ref().unwrap().left.is_some() {
-                root = &mut root.as_mut().unwrap().left
+                root = &mut root.as_mut().unwrap().left;
+                depth += 1;
             }
 
             let node = root.take().unwrap();
             *root = node.right;
-            return Some(node.value);
+            return Some((node.value, depth));
         }
 
         None
     }
 
+    #[cfg(feature = "recursive")]
     pub(crate) fn recursive_remove_min(root: &mut HeapNode<T>) -> Option<T> {
+        Node::recursive_remove_min_at(root).map(|(value, _)| value)
+    }
+
+    /// Removes the minimum element of the non-empty subtree, returning it along with the depth
+    /// of its node relative to `root`.
+    #[cfg(feature = "recursive")]
+    pub(crate) fn recursive_remove_min_at(root: &mut HeapNode<T>) -> Option<(T, usize)> {
         if root.as_ref().unwrap().left.is_some() {
-            Node::recursive_remove_min(&mut root.as_mut().unwrap().left)
+            Node::recursive_remove_min_at(&mut root.as_mut().unwrap().left)
+                .map(|(value, depth)| (value, depth + 1))
         } else {
             let node = root.take().unwrap();
             *root = node.right;
-            Some(node.value)
+            Some((node.value, 0))
         }
     }
 
-    pub(crate) fn iterative_remove_max(mut root: &mut HeapNode<T>) -> Option<T> {
+    pub(crate) fn iterative_remove_max(root: &mut HeapNode<T>) -> Option<T> {
+        Node::iterative_remove_max_at(root).map(|(value, _)| value)
+    }
+
+    /// Removes the maximum element, returning it along with the depth of its node.
+    pub(crate) fn iterative_remove_max_at(mut root: &mut HeapNode<T>) -> Option<(T, usize)> {
         if root.is_some() {
+            let mut depth = 0;
             while root.as_ref().unwrap().right.is_some() {
-                root = &mut root.as_mut().unwrap().right
+                root = &mut root.as_mut().unwrap().right;
+                depth += 1;
             }
 
             let node = root.take().unwrap();
             *root = node.left;
-            return Some(node.value);
+            return Some((node.value, depth));
         }
 
         None
     }
 
+    #[cfg(feature = "recursive")]
     pub(crate) fn recursive_remove_max(root: &mut HeapNode<T>) -> Option<T> {
+        Node::recursive_remove_max_at(root).map(|(value, _)| value)
+    }
+
+    /// Removes the maximum element of the non-empty subtree, returning it along with the depth
+    /// of its node relative to `root`.
+    #[cfg(feature = "recursive")]
+    pub(crate) fn recursive_remove_max_at(root: &mut HeapNode<T>) -> Option<(T, usize)> {
         if root.as_ref().unwrap().right.is_some() {
-            Node::recursive_remove_max(&mut root.as_mut().unwrap().right)
+            Node::recursive_remove_max_at(&mut root.as_mut().unwrap().right)
+                .map(|(value, depth)| (value, depth + 1))
         } else {
             let node = root.take().unwrap();
             *root = node.left;
-            Some(node.value)
+            Some((node.value, 0))
+        }
+    }
+
+    /// Removes the minimum element if `f` returns `true` for it, in the same descent which finds
+    /// it.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn iterative_remove_min_if<F>(mut root: &mut HeapNode<T>, f: F) -> Option<T>
+    where
+        F: FnOnce(&T) -> bool,
+    {
+        while root.as_ref()?.left.is_some() {
+            root = &mut root.as_mut().unwrap().left
+        }
+        if !f(&root.as_ref().unwrap().value) {
+            return None;
+        }
+
+        let node = root.take().unwrap();
+        *root = node.right;
+        Some(node.value)
+    }
+
+    /// Removes the maximum element if `f` returns `true` for it, in the same descent which finds
+    /// it.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn iterative_remove_max_if<F>(mut root: &mut HeapNode<T>, f: F) -> Option<T>
+    where
+        F: FnOnce(&T) -> bool,
+    {
+        while root.as_ref()?.right.is_some() {
+            root = &mut root.as_mut().unwrap().right
+        }
+        if !f(&root.as_ref().unwrap().value) {
+            return None;
+        }
+
+        let node = root.take().unwrap();
+        *root = node.left;
+        Some(node.value)
+    }
+
+    /// Removes elements in ascending order for as long as `f` returns `true`.
+    ///
+    /// The left spine is detached onto a stack so that each removal continues from where the
+    /// previous one stopped, instead of descending from the root again.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn iterative_remove_min_while<F>(root: &mut HeapNode<T>, mut f: F) -> Vec<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut elements = Vec::new();
+        let mut stack = Vec::new();
+        Node::detach_left_spine(root.take(), &mut stack);
+
+        while let Some(mut current) = stack.pop() {
+            if !f(&current.value) {
+                stack.push(current);
+                break;
+            }
+            Node::detach_left_spine(current.right.take(), &mut stack);
+            elements.push(current.value);
+        }
+        *root = Node::reattach_left_spine(stack);
+
+        elements
+    }
+
+    /// Removes elements in descending order for as long as `f` returns `true`.
+    ///
+    /// Mirror image of [Node::iterative_remove_min_while()] working on the right spine.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn iterative_remove_max_while<F>(root: &mut HeapNode<T>, mut f: F) -> Vec<T>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut elements = Vec::new();
+        let mut stack = Vec::new();
+        Node::detach_right_spine(root.take(), &mut stack);
+
+        while let Some(mut current) = stack.pop() {
+            if !f(&current.value) {
+                stack.push(current);
+                break;
+            }
+            Node::detach_right_spine(current.left.take(), &mut stack);
+            elements.push(current.value);
         }
+        *root = Node::reattach_right_spine(stack);
+
+        elements
+    }
+
+    /// Drops every element which is not greater than `value`, returning how many were dropped.
+    ///
+    /// Along the search path for `value`, each node which is not greater than it is replaced by
+    /// its right subtree, detaching the node together with its whole left subtree at once.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn iterative_retain_greater(mut root: &mut HeapNode<T>, value: &T) -> usize {
+        let mut removed = 0;
+        loop {
+            let keep = match root.as_ref() {
+                None => break,
+                Some(node) => node.value > *value,
+            };
+            if keep {
+                root = &mut root.as_mut().unwrap().left;
+            } else {
+                let mut node = root.take().unwrap();
+                *root = node.right.take();
+                removed += 1 + Node::iterative_count(&node.left);
+            }
+        }
+
+        removed
+    }
+
+    /// Drops every element which is not less than `value`, returning how many were dropped.
+    ///
+    /// Mirror image of [Node::iterative_retain_greater()] detaching right subtrees.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn iterative_retain_less(mut root: &mut HeapNode<T>, value: &T) -> usize {
+        let mut removed = 0;
+        loop {
+            let keep = match root.as_ref() {
+                None => break,
+                Some(node) => node.value < *value,
+            };
+            if keep {
+                root = &mut root.as_mut().unwrap().right;
+            } else {
+                let mut node = root.take().unwrap();
+                *root = node.left.take();
+                removed += 1 + Node::iterative_count(&node.right);
+            }
+        }
+
+        removed
+    }
+
+    /// Returns the number of nodes in the subtree.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn iterative_count(root: &HeapNode<T>) -> usize {
+        let mut count = 0;
+        let mut stack = vec![root];
+        while let Some(node) = stack.pop() {
+            if let Some(node) = node {
+                count += 1;
+                stack.push(&node.left);
+                stack.push(&node.right);
+            }
+        }
+
+        count
+    }
+
+    /// Pushes `root` and its chain of left children onto `stack`, unlinking each of them from
+    /// its parent along the way.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn detach_left_spine(mut root: HeapNode<T>, stack: &mut Vec<Box<Node<T>>>) {
+        while let Some(mut node) = root {
+            root = node.left.take();
+            stack.push(node);
+        }
+    }
+
+    /// Inverse of [Node::detach_left_spine()], every node becomes the left child of the node
+    /// below it on the stack.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn reattach_left_spine(mut stack: Vec<Box<Node<T>>>) -> HeapNode<T> {
+        let mut root = None;
+        while let Some(mut node) = stack.pop() {
+            node.left = root;
+            root = Some(node);
+        }
+
+        root
+    }
+
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn detach_right_spine(mut root: HeapNode<T>, stack: &mut Vec<Box<Node<T>>>) {
+        while let Some(mut node) = root {
+            root = node.right.take();
+            stack.push(node);
+        }
+    }
+
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn reattach_right_spine(mut stack: Vec<Box<Node<T>>>) -> HeapNode<T> {
+        let mut root = None;
+        while let Some(mut node) = stack.pop() {
+            node.right = root;
+            root = Some(node);
+        }
+
+        root
+    }
+
+    /// Collects probe values into ascending order without duplicates, ready for the batch
+    /// operations below.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn sorted_probes<'a, I: IntoIterator<Item = &'a T>>(values: I) -> Vec<&'a T>
+    where
+        T: 'a,
+    {
+        let mut probes: Vec<&T> = values.into_iter().collect();
+        probes.sort_unstable();
+        probes.dedup();
+        probes
+    }
+
+    /// Splits sorted probes around a node's value into the ranges belonging to the left
+    /// subtree, the node itself and the right subtree.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    fn split_probes(value: &T, probes: &[&T]) -> (usize, usize) {
+        let less = probes.partition_point(|probe| *probe < value);
+        let less_or_equal = less + probes[less..].partition_point(|probe| *probe == value);
+        (less, less_or_equal)
+    }
+
+    /// Returns `true` if every probe is present, visiting each node at most once no matter how
+    /// many probes there are.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn iterative_contains_all(root: &HeapNode<T>, probes: &[&T]) -> bool {
+        let mut stack = vec![(root, probes)];
+
+        while let Some((root, probes)) = stack.pop() {
+            if probes.is_empty() {
+                continue;
+            }
+            match root {
+                None => return false,
+                Some(node) => {
+                    let (less, less_or_equal) = Node::split_probes(&node.value, probes);
+                    stack.push((&node.left, &probes[..less]));
+                    stack.push((&node.right, &probes[less_or_equal..]));
+                }
+            }
+        }
+
+        true
+    }
+
+    /// Answers `probes` in their given order, `lookup` storing the element equal to the `i`-th
+    /// of the probes sorted in ascending order at index `i`.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn get_many<'a, F>(probes: &[T], lookup: F) -> Vec<Option<&'a T>>
+    where
+        F: FnOnce(&[&T], &mut [Option<&'a T>]),
+    {
+        let mut order: Vec<usize> = (0..probes.len()).collect();
+        order.sort_unstable_by(|&first, &second| probes[first].cmp(&probes[second]));
+        let sorted: Vec<&T> = order.iter().map(|&index| &probes[index]).collect();
+
+        let mut found = vec![None; probes.len()];
+        lookup(&sorted, &mut found);
+
+        let mut elements = vec![None; probes.len()];
+        for (index, element) in order.into_iter().zip(found) {
+            elements[index] = element;
+        }
+        elements
+    }
+
+    /// Stores the element equal to each of the sorted probes at its index in `found`, visiting
+    /// each node at most once no matter how many probes there are.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn iterative_get_many<'a>(
+        root: &'a HeapNode<T>,
+        probes: &[&T],
+        found: &mut [Option<&'a T>],
+    ) {
+        let mut stack = vec![(root, 0, probes.len())];
+
+        while let Some((root, start, end)) = stack.pop() {
+            if let (Some(node), true) = (root, start < end) {
+                let (less, less_or_equal) = Node::split_probes(&node.value, &probes[start..end]);
+                found[start + less..start + less_or_equal].fill(Some(&node.value));
+                stack.push((&node.left, start, start + less));
+                stack.push((&node.right, start + less_or_equal, end));
+            }
+        }
+    }
+
+    /// Removes every element equal to one of the probes in a single coordinated traversal,
+    /// returning how many were removed.
+    ///
+    /// Subtrees are detached while their probes are handled and reattached afterwards, so no
+    /// node is descended into more than once.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn iterative_remove_all(root: &mut HeapNode<T>, probes: &[&T]) -> usize {
+        enum Step<'p, T: Ord> {
+            Visit(HeapNode<T>, &'p [&'p T]),
+            Reattach(Box<Node<T>>, bool),
+        }
+
+        let mut removed = 0;
+        let mut steps = vec![Step::Visit(root.take(), probes)];
+        let mut subtrees = Vec::new();
+
+        while let Some(step) = steps.pop() {
+            match step {
+                Step::Visit(Some(mut node), probes) if !probes.is_empty() => {
+                    let (less, less_or_equal) = Node::split_probes(&node.value, probes);
+                    let (left, right) = (node.left.take(), node.right.take());
+                    steps.push(Step::Reattach(node, less_or_equal > less));
+                    steps.push(Step::Visit(right, &probes[less_or_equal..]));
+                    steps.push(Step::Visit(left, &probes[..less]));
+                }
+                Step::Visit(subtree, _) => subtrees.push(subtree),
+                Step::Reattach(mut node, matched) => {
+                    node.right = subtrees.pop().unwrap();
+                    node.left = subtrees.pop().unwrap();
+                    let mut subtree = Some(node);
+                    if matched {
+                        Node::iterative_take(&mut subtree);
+                        removed += 1;
+                    }
+                    subtrees.push(subtree);
+                }
+            }
+        }
+        *root = subtrees.pop().unwrap();
+
+        removed
+    }
+
+    /// Returns a copy of the subtree at `root` with exactly the same structure.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn clone_tree(root: &HeapNode<T>) -> HeapNode<T>
+    where
+        T: Clone,
+    {
+        let mut copy = None;
+        let mut pending = Vec::from_iter(root.as_deref().map(|node| (node, &mut copy)));
+        while let Some((node, slot)) = pending.pop() {
+            let cloned = slot.insert(Box::new(Node::new(node.value.clone())));
+            if let Some(left) = node.left.as_deref() {
+                pending.push((left, &mut cloned.left));
+            }
+            if let Some(right) = node.right.as_deref() {
+                pending.push((right, &mut cloned.right));
+            }
+        }
+        copy
+    }
+
+    /// Builds a perfectly balanced tree out of elements which are already sorted in ascending
+    /// order and free of duplicates.
+    ///
+    /// Recursion depth is bounded by `log2(n)`, so this is safe to use for both implementations.
+    pub(crate) fn build_balanced(elements: Vec<T>) -> HeapNode<T> {
+        let size = elements.len();
+        Node::build_balanced_from(&mut elements.into_iter(), size)
+    }
+
+    fn build_balanced_from<I: Iterator<Item = T>>(elements: &mut I, size: usize) -> HeapNode<T> {
+        if size == 0 {
+            return None;
+        }
+
+        let left_size = size / 2;
+        let left = Node::build_balanced_from(elements, left_size);
+        let mut node = Box::new(Node::new(elements.next().unwrap()));
+        node.left = left;
+        node.right = Node::build_balanced_from(elements, size - left_size - 1);
+
+        Some(node)
+    }
+
+    /// Merges two ascending sequences into one, keeping the element from `first` whenever both
+    /// sequences contain an equal element.
+    #[cfg(any(feature = "iterative", feature = "recursive"))]
+    pub(crate) fn merge_sorted(first: Vec<T>, second: Vec<T>) -> Vec<T> {
+        let mut elements = Vec::with_capacity(first.len() + second.len());
+        let mut first = first.into_iter().peekable();
+        let mut second = second.into_iter().peekable();
+
+        while let (Some(x), Some(y)) = (first.peek(), second.peek()) {
+            match x.cmp(y) {
+                Ordering::Less => elements.push(first.next().unwrap()),
+                Ordering::Greater => elements.push(second.next().unwrap()),
+                Ordering::Equal => {
+                    elements.push(first.next().unwrap());
+                    second.next();
+                }
+            }
+        }
+        elements.extend(first);
+        elements.extend(second);
+
+        elements
     }
 
     pub(crate) fn iterative_pre_order_vec(node: &HeapNode<T>) -> Vec<&T> {
@@ -316,6 +1066,7 @@ impl<T: Ord> Node<T> {
         elements
     }
 
+    #[cfg(feature = "recursive")]
     pub(crate) fn recursive_pre_order_vec<'a>(node: &'a HeapNode<T>, elements: &mut Vec<&'a T>) {
         if let Some(ref node) = node {
             elements.push(&node.value);
@@ -342,6 +1093,7 @@ impl<T: Ord> Node<T> {
         elements
     }
 
+    #[cfg(feature = "recursive")]
     pub(crate) fn recursive_in_order_vec<'a>(node: &'a HeapNode<T>, elements: &mut Vec<&'a T>) {
         if let Some(ref node) = node {
             Node::recursive_in_order_vec(&node.left, elements);
@@ -372,6 +1124,7 @@ impl<T: Ord> Node<T> {
         elements
     }
 
+    #[cfg(feature = "recursive")]
     pub(crate) fn recursive_post_order_vec<'a>(node: &'a HeapNode<T>, elements: &mut Vec<&'a T>) {
         if let Some(ref node) = node {
             Node::recursive_post_order_vec(&node.left, elements);
@@ -398,6 +1151,43 @@ impl<T: Ord> Node<T> {
         elements
     }
 
+    /// Returns one element per horizontal distance from the root, from the leftmost column to
+    /// the rightmost: the first one reached by a level-order walk, or the last one if
+    /// `keep_last` is set.
+    #[cfg(all(
+        feature = "traversals-extra",
+        any(feature = "iterative", feature = "recursive")
+    ))]
+    pub(crate) fn horizontal_view(root: &HeapNode<T>, keep_last: bool) -> Vec<&T> {
+        let mut columns = VecDeque::new();
+        let mut leftmost: isize = 0;
+        let mut deque = VecDeque::new();
+        if let Some(root) = root {
+            deque.push_back((root, 0));
+        }
+
+        while let Some((current, distance)) = deque.pop_front() {
+            if distance < leftmost {
+                columns.push_front(&current.value);
+                leftmost = distance;
+            } else if (distance - leftmost) as usize == columns.len() {
+                columns.push_back(&current.value);
+            } else if keep_last {
+                columns[(distance - leftmost) as usize] = &current.value;
+            }
+
+            if let Some(left) = &current.left {
+                deque.push_back((left, distance - 1));
+            }
+            if let Some(right) = &current.right {
+                deque.push_back((right, distance + 1));
+            }
+        }
+
+        columns.into()
+    }
+
+    #[cfg(feature = "recursive")]
     pub(crate) fn recursive_level_order_vec<'a>(root: &'a HeapNode<T>, elements: &mut Vec<&'a T>) {
         let height = Node::recursive_height(root);
         for i in 1..=height + 1 {
@@ -405,6 +1195,7 @@ impl<T: Ord> Node<T> {
         }
     }
 
+    #[cfg(feature = "recursive")]
     fn recursive_current_level<'a>(root: &'a HeapNode<T>, elements: &mut Vec<&'a T>, level: isize) {
         if root.is_some() {
             match level.cmp(&1) {
@@ -443,6 +1234,7 @@ impl<T: Ord> Node<T> {
         elements
     }
 
+    #[cfg(feature = "recursive")]
     pub(crate) fn recursive_consume_pre_order_vec(node: HeapNode<T>, elements: &mut Vec<T>) {
         if let Some(node) = node {
             elements.push(node.value);
@@ -472,6 +1264,7 @@ impl<T: Ord> Node<T> {
         elements
     }
 
+    #[cfg(feature = "recursive")]
     pub(crate) fn recursive_consume_in_order_vec(node: HeapNode<T>, elements: &mut Vec<T>) {
         if let Some(node) = node {
             Node::recursive_consume_in_order_vec(node.left, elements);
@@ -502,6 +1295,7 @@ impl<T: Ord> Node<T> {
         elements
     }
 
+    #[cfg(feature = "recursive")]
     pub(crate) fn recursive_consume_post_order_vec(node: HeapNode<T>, elements: &mut Vec<T>) {
         if let Some(node) = node {
             Node::recursive_consume_post_order_vec(node.left, elements);
@@ -528,6 +1322,7 @@ impl<T: Ord> Node<T> {
         elements
     }
 
+    #[cfg(feature = "recursive")]
     pub(crate) fn recursive_consume_level_order_vec(root: HeapNode<T>, elements: &mut Vec<T>) {
         let height = Node::recursive_height(&root);
         for i in 0..height + 1 {
@@ -543,6 +1338,7 @@ impl<T: Ord> Node<T> {
     /// The caller must ensure that the values contained in the heap are not dropped again.
     ///
     /// Otherwise this could lead to a double free.
+    #[cfg(feature = "recursive")]
     unsafe fn write_level_into_vec(root: &HeapNode<T>, elements: &mut Vec<T>, level: isize) {
         if let Some(node) = root {
             if level == 0 {
@@ -556,6 +1352,7 @@ impl<T: Ord> Node<T> {
         }
     }
 
+    #[cfg(feature = "recursive")]
     fn dealloc_boxes(root: HeapNode<T>) {
         if let Some(node) = root {
             // move out of the box by de-referencing to drop it and destructure the `Node`
@@ -0,0 +1,239 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::bst::{Bst, Strategy};
+use crate::node::Node;
+
+/// Read-only view of a node of a [Bst], see [Bst::root_ref()].
+///
+/// Lets callers walk the structure of the tree themselves, e.g. for custom traversals or
+/// visualizations. Views borrow the tree, so it can't be modified while they are alive.
+pub struct NodeRef<'a, T: Ord> {
+    node: &'a Node<T>,
+}
+
+impl<'a, T: Ord> NodeRef<'a, T> {
+    pub(crate) fn new(node: &'a Node<T>) -> NodeRef<'a, T> {
+        NodeRef { node }
+    }
+
+    /// Returns the element stored in the node.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![2, 1, 3]);
+    ///
+    /// assert_eq!(bst.root_ref().unwrap().value(), &2);
+    /// ```
+    pub fn value(&self) -> &'a T {
+        &self.node.value
+    }
+
+    /// Returns the left child of the node, holding the smaller elements, or `None` if it has no
+    /// left child.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let bst = RecursiveBST::from_iter(vec![2, 1, 3]);
+    /// let left = bst.root_ref().unwrap().left().unwrap();
+    ///
+    /// assert_eq!(left.value(), &1);
+    /// assert!(left.left().is_none());
+    /// ```
+    pub fn left(&self) -> Option<NodeRef<'a, T>> {
+        self.node.left.as_deref().map(|node| NodeRef { node })
+    }
+
+    /// Returns the right child of the node, holding the bigger elements, or `None` if it has no
+    /// right child.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![2, 1, 3]);
+    /// let right = bst.root_ref().unwrap().right().unwrap();
+    ///
+    /// assert_eq!(right.value(), &3);
+    /// assert!(right.right().is_none());
+    /// ```
+    pub fn right(&self) -> Option<NodeRef<'a, T>> {
+        self.node.right.as_deref().map(|node| NodeRef { node })
+    }
+
+    /// Returns `true` if the node has no children.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let bst = IterativeBST::from_iter(vec![2, 1]);
+    /// let root = bst.root_ref().unwrap();
+    ///
+    /// assert!(!root.is_leaf());
+    /// assert!(root.left().unwrap().is_leaf());
+    /// ```
+    pub fn is_leaf(&self) -> bool {
+        self.node.left.is_none() && self.node.right.is_none()
+    }
+}
+
+impl<T: Ord> Clone for NodeRef<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: Ord> Copy for NodeRef<'_, T> {}
+
+impl<T: Ord + Debug> Debug for NodeRef<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NodeRef")
+            .field("value", self.value())
+            .field("left", &self.left().map(|left| left.value()))
+            .field("right", &self.right().map(|right| right.value()))
+            .finish()
+    }
+}
+
+impl<T: Ord, S: Strategy> Bst<T, S> {
+    /// Returns a read-only view of the root node or `None` if the tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, NodeRef};
+    ///
+    /// // Custom traversal, visiting the right subtree first
+    /// fn descending<'a>(node: Option<NodeRef<'a, i32>>, elements: &mut Vec<&'a i32>) {
+    ///     if let Some(node) = node {
+    ///         descending(node.right(), elements);
+    ///         elements.push(node.value());
+    ///         descending(node.left(), elements);
+    ///     }
+    /// }
+    ///
+    /// let bst = IterativeBST::from_iter(vec![4, 2, 6, 1, 3]);
+    /// let mut elements = Vec::new();
+    /// descending(bst.root_ref(), &mut elements);
+    ///
+    /// assert_eq!(elements, vec![&6, &4, &3, &2, &1]);
+    /// assert!(IterativeBST::<i32>::new().root_ref().is_none());
+    /// ```
+    pub fn root_ref(&self) -> Option<NodeRef<'_, T>> {
+        self.root.as_deref().map(|node| NodeRef { node })
+    }
+}
+
+/// Checks that the elements below `root` are in strictly ascending in-order, returning the
+/// first pair of in-order neighbours which is not.
+///
+/// Meant for auditing trees which were built or changed without going through the usual
+/// insertions, e.g. after changing elements through
+/// [retrieve_as_mut()](crate::BinarySearchTree::retrieve_as_mut()). The violating pair points
+/// to where searches will start to go wrong, which narrows down the change that caused it.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{validate, BinarySearchTree, IterativeBST};
+///
+/// let mut bst = IterativeBST::from_iter(vec![2, 1, 3]);
+/// assert_eq!(validate(bst.root_ref()), Ok(()));
+///
+/// *bst.retrieve_as_mut(&1).unwrap() = 5;
+/// assert_eq!(validate(bst.root_ref()), Err((&5, &2)));
+/// ```
+pub fn validate<T: Ord>(root: Option<NodeRef<'_, T>>) -> Result<(), (&T, &T)> {
+    let mut stack = Vec::new();
+    let mut current = root;
+    let mut previous: Option<&T> = None;
+
+    loop {
+        while let Some(node) = current {
+            stack.push(node);
+            current = node.left();
+        }
+        let node = match stack.pop() {
+            None => return Ok(()),
+            Some(node) => node,
+        };
+
+        if let Some(previous) = previous {
+            if previous >= node.value() {
+                return Err((previous, node.value()));
+            }
+        }
+        previous = Some(node.value());
+        current = node.right();
+    }
+}
+
+#[cfg(all(test, feature = "iterative", feature = "recursive"))]
+mod tests {
+    use crate::{validate, BinarySearchTree, IterativeBST, NodeRef, RecursiveBST};
+
+    fn pre_order<'a>(node: Option<NodeRef<'a, i32>>) -> Vec<&'a i32> {
+        let mut elements = Vec::new();
+        let mut stack = Vec::from_iter(node);
+        while let Some(node) = stack.pop() {
+            elements.push(node.value());
+            stack.extend(node.right());
+            stack.extend(node.left());
+        }
+        elements
+    }
+
+    #[test]
+    fn successfully_walk_structure_through_node_refs() {
+        let iterative = IterativeBST::from_iter(vec![8, 4, 12, 2, 6, 14, 1]);
+        let recursive = RecursiveBST::from_iter(vec![8, 4, 12, 2, 6, 14, 1]);
+
+        assert_eq!(pre_order(iterative.root_ref()), iterative.pre_order_vec());
+        assert_eq!(pre_order(recursive.root_ref()), recursive.pre_order_vec());
+        assert!(std::ptr::eq(
+            iterative.root_ref().unwrap().left().unwrap().value(),
+            iterative.retrieve(&4).unwrap()
+        ));
+    }
+
+    #[test]
+    fn successfully_format_node_ref() {
+        let bst = IterativeBST::from_iter(vec![2, 1]);
+        let root = bst.root_ref().unwrap();
+        let copy = root;
+
+        assert_eq!(
+            format!("{:?}", copy),
+            "NodeRef { value: 2, left: Some(1), right: None }"
+        );
+        assert!(root.right().is_none());
+        assert!(copy.left().unwrap().is_leaf());
+    }
+
+    #[test]
+    fn successfully_find_first_violating_pair() {
+        let mut bst = RecursiveBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14]);
+        assert_eq!(validate(bst.root_ref()), Ok(()));
+        assert_eq!(validate(RecursiveBST::<i32>::new().root_ref()), Ok(()));
+
+        *bst.retrieve_as_mut(&10).unwrap() = 6;
+        *bst.retrieve_as_mut(&14).unwrap() = 13;
+        assert_eq!(validate(bst.root_ref()), Err((&8, &6)));
+
+        // Found through the left subtree, the misplaced 6 can't be reached by searches
+        *bst.retrieve_as_mut(&6).unwrap() = 7;
+        assert_eq!(validate(bst.root_ref()), Err((&8, &6)));
+
+        let mut bst = IterativeBST::from_iter(vec![2, 1, 3]);
+        *bst.retrieve_as_mut(&1).unwrap() = 2;
+        assert_eq!(validate(bst.root_ref()), Err((&2, &2)));
+    }
+}
@@ -0,0 +1,370 @@
+use std::cmp::Ordering;
+
+use crate::bst::{Bst, Strategy};
+use crate::node::{HeapNode, Node};
+
+/// Trees with fewer elements in total are combined on the calling thread.
+const PARALLEL_CUTOFF: usize = 1 << 14;
+
+/// Set operation performed by [combine()].
+#[derive(Clone, Copy)]
+enum SetOp {
+    Union,
+    Intersection,
+    Difference,
+}
+
+impl SetOp {
+    /// Returns whether an element of the first tree is kept, given whether the second tree
+    /// contains it as well.
+    fn keeps(self, common: bool) -> bool {
+        match self {
+            SetOp::Union => true,
+            SetOp::Intersection => common,
+            SetOp::Difference => !common,
+        }
+    }
+}
+
+/// Splits `root` into the elements smaller and greater than `key`, along with the element equal
+/// to it, if any.
+fn split<T: Ord>(root: HeapNode<T>, key: &T) -> (HeapNode<T>, Option<T>, HeapNode<T>) {
+    let mut less = None;
+    let mut greater = None;
+    let mut less_slot = &mut less;
+    let mut greater_slot = &mut greater;
+    let mut current = root;
+
+    while let Some(mut node) = current {
+        match node.value.cmp(key) {
+            Ordering::Less => {
+                current = node.right.take();
+                less_slot = &mut less_slot.insert(node).right;
+            }
+            Ordering::Greater => {
+                current = node.left.take();
+                greater_slot = &mut greater_slot.insert(node).left;
+            }
+            Ordering::Equal => {
+                *less_slot = node.left.take();
+                *greater_slot = node.right.take();
+                return (less, Some(node.value), greater);
+            }
+        }
+    }
+
+    (less, None, greater)
+}
+
+/// Joins two trees whose elements are all smaller in `left` than in `right`, the maximum of
+/// `left` becoming the new root.
+fn join<T: Ord>(mut left: HeapNode<T>, right: HeapNode<T>) -> HeapNode<T> {
+    match Node::iterative_remove_max(&mut left) {
+        None => right,
+        Some(value) => Some(Box::new(Node { value, left, right })),
+    }
+}
+
+/// Combines both trees by merging their sorted elements, returning the result as a balanced
+/// tree along with the number of elements of `first` which are also in `second`.
+fn combine_sequential<T: Ord>(
+    first: HeapNode<T>,
+    second: HeapNode<T>,
+    op: SetOp,
+) -> (HeapNode<T>, usize) {
+    let first = Node::iterative_consume_in_order_vec(first);
+    let second = Node::iterative_consume_in_order_vec(second);
+    let mut elements = Vec::with_capacity(first.len() + second.len());
+    let mut common = 0;
+    let mut first = first.into_iter().peekable();
+    let mut second = second.into_iter().peekable();
+
+    while let (Some(x), Some(y)) = (first.peek(), second.peek()) {
+        match x.cmp(y) {
+            Ordering::Less => {
+                let x = first.next().unwrap();
+                if op.keeps(false) {
+                    elements.push(x);
+                }
+            }
+            Ordering::Greater => {
+                let y = second.next().unwrap();
+                if let SetOp::Union = op {
+                    elements.push(y);
+                }
+            }
+            Ordering::Equal => {
+                let x = first.next().unwrap();
+                second.next();
+                common += 1;
+                if op.keeps(true) {
+                    elements.push(x);
+                }
+            }
+        }
+    }
+    if op.keeps(false) {
+        elements.extend(first);
+    }
+    if let SetOp::Union = op {
+        elements.extend(second);
+    }
+
+    (Node::build_balanced(elements), common)
+}
+
+/// Combines both trees by splitting `second` around the root of `first` and combining the
+/// halves in parallel through [rayon::join()], for `levels` levels below which the halves are merged sequentially.
+///
+/// Returns the result along with the number of elements of `first` which are also in `second`.
+fn combine<T: Ord + Send>(
+    first: HeapNode<T>,
+    second: HeapNode<T>,
+    op: SetOp,
+    levels: u32,
+) -> (HeapNode<T>, usize) {
+    if levels == 0 {
+        return combine_sequential(first, second, op);
+    }
+    let (mut root, second) = match (first, second) {
+        (None, second) => return (if let SetOp::Union = op { second } else { None }, 0),
+        (first, None) => return (if op.keeps(false) { first } else { None }, 0),
+        (Some(root), second) => (root, second),
+    };
+
+    let (less, found, greater) = split(second, &root.value);
+    let (first_left, first_right) = (root.left.take(), root.right.take());
+    let ((left, left_common), (right, right_common)) = rayon::join(
+        move || combine(first_left, less, op, levels - 1),
+        move || combine(first_right, greater, op, levels - 1),
+    );
+
+    let common = left_common + right_common + found.is_some() as usize;
+    if op.keeps(found.is_some()) {
+        root.left = left;
+        root.right = right;
+        (Some(root), common)
+    } else {
+        (join(left, right), common)
+    }
+}
+
+/// Returns how many levels of [combine()] fork when combining trees of `size` elements in total,
+/// oversubscribing the threads of the current rayon pool to even out unbalanced splits.
+fn fork_levels(size: usize) -> u32 {
+    let threads = rayon::current_num_threads();
+    if threads == 1 || size < PARALLEL_CUTOFF {
+        return 0;
+    }
+    threads.next_power_of_two().ilog2() + 2
+}
+
+impl<T: Ord + Send, S: Strategy> Bst<T, S> {
+    /// Returns a tree with the elements of both trees, consuming them. Elements present in both
+    /// trees are only kept once, the one of `self` being kept.
+    ///
+    /// Uses the join-based algorithm: `other` is split around the root of `self` and both
+    /// halves are combined with the subtrees of `self` in parallel on the current rayon thread
+    /// pool, recursively. Once every thread of the pool has been handed work, the remaining
+    /// pieces are merged sequentially and rebuilt balanced, so the top levels of the result keep the shape of `self` while the
+    /// rest is balanced. The result keeps the rebalance setting of `self`.
+    ///
+    /// Trees with fewer than 16384 elements in total are combined on the calling thread.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let first = IterativeBST::from_iter(vec![4, 2, 6, 1, 3]);
+    /// let second = IterativeBST::from_iter(vec![5, 3, 7]);
+    ///
+    /// let union = first.par_union(second);
+    ///
+    /// assert_eq!(union.size(), 7);
+    /// assert_eq!(union.asc_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    pub fn par_union(self, other: Bst<T, S>) -> Bst<T, S> {
+        let size = self.size + other.size;
+        self.combine(other, SetOp::Union, |common| size - common)
+    }
+
+    /// Returns a tree with the elements present in both trees, consuming them. The elements of
+    /// `self` are the ones being kept.
+    ///
+    /// Computed in parallel like [Bst::par_union()], elements of `self` which are not in
+    /// `other` are dropped by joining the two subtrees below them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let first = RecursiveBST::from_iter(vec![4, 2, 6, 1, 3]);
+    /// let second = RecursiveBST::from_iter(vec![5, 3, 7, 4]);
+    ///
+    /// let intersection = first.par_intersection(second);
+    ///
+    /// assert_eq!(intersection.size(), 2);
+    /// assert_eq!(intersection.asc_order_vec(), vec![&3, &4]);
+    /// ```
+    pub fn par_intersection(self, other: Bst<T, S>) -> Bst<T, S> {
+        self.combine(other, SetOp::Intersection, |common| common)
+    }
+
+    /// Returns a tree with the elements of `self` which are not in `other`, consuming both.
+    ///
+    /// Computed in parallel like [Bst::par_union()], elements of `self` which are in `other`
+    /// are dropped by joining the two subtrees below them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST};
+    ///
+    /// let first = IterativeBST::from_iter(vec![4, 2, 6, 1, 3]);
+    /// let second = IterativeBST::from_iter(vec![5, 3, 7, 4]);
+    ///
+    /// let difference = first.par_difference(second);
+    ///
+    /// assert_eq!(difference.size(), 3);
+    /// assert_eq!(difference.asc_order_vec(), vec![&1, &2, &6]);
+    /// ```
+    pub fn par_difference(self, other: Bst<T, S>) -> Bst<T, S> {
+        let size = self.size;
+        self.combine(other, SetOp::Difference, |common| size - common)
+    }
+
+    /// Combines both trees with `op`, `size` computing the number of elements of the result
+    /// from the number of elements they have in common.
+    fn combine<F: FnOnce(usize) -> usize>(self, other: Bst<T, S>, op: SetOp, size: F) -> Bst<T, S> {
+        let levels = fork_levels(self.size + other.size);
+        let (root, common) = combine(self.root, other.root, op, levels);
+
+        let mut bst = Bst::new();
+        bst.root = root;
+        bst.size = size(common);
+        bst.rebalance = self.rebalance;
+        if let Some(rebalance) = &mut bst.rebalance {
+            let height = Node::iterative_height(&bst.root).max(0) as usize;
+            if rebalance.measured(height, bst.size) {
+                let elements = Node::iterative_consume_in_order_vec(bst.root.take());
+                bst.root = Node::build_balanced(elements);
+                rebalance.rebuilt(bst.size);
+            }
+        }
+        bst
+    }
+}
+
+#[cfg(all(test, feature = "iterative", feature = "recursive"))]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::{combine, split, SetOp};
+    use crate::node::Node;
+    use crate::{BinarySearchTree, IterativeBST, RecursiveBST};
+
+    fn random_set(seed: u32, len: usize) -> Vec<u32> {
+        let mut state = seed;
+        (0..len)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                (state >> 8) % 4096
+            })
+            .collect()
+    }
+
+    #[test]
+    fn successfully_split_around_key() {
+        let bst = IterativeBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14]);
+
+        let (less, found, greater) = split(bst.root, &6);
+        assert_eq!(found, Some(6));
+        assert_eq!(Node::iterative_consume_in_order_vec(less), vec![2, 4]);
+        assert_eq!(
+            Node::iterative_consume_in_order_vec(greater),
+            vec![8, 10, 12, 14]
+        );
+
+        let bst = IterativeBST::from_iter(vec![8, 4, 12]);
+        let (less, found, greater) = split(bst.root, &9);
+        assert_eq!(found, None);
+        assert_eq!(Node::iterative_consume_in_order_vec(less), vec![4, 8]);
+        assert_eq!(Node::iterative_consume_in_order_vec(greater), vec![12]);
+    }
+
+    #[test]
+    fn successfully_match_btreeset_at_every_fork_level() {
+        let first = random_set(3, 3000);
+        let second = random_set(5, 2000);
+        let first_set: BTreeSet<u32> = first.iter().copied().collect();
+        let second_set: BTreeSet<u32> = second.iter().copied().collect();
+
+        for levels in 0..6 {
+            for (op, expected) in [
+                (
+                    SetOp::Union,
+                    first_set.union(&second_set).copied().collect::<Vec<u32>>(),
+                ),
+                (
+                    SetOp::Intersection,
+                    first_set.intersection(&second_set).copied().collect(),
+                ),
+                (
+                    SetOp::Difference,
+                    first_set.difference(&second_set).copied().collect(),
+                ),
+            ] {
+                let first = IterativeBST::from_iter(first.clone());
+                let second = IterativeBST::from_iter(second.clone());
+                let (root, _) = combine(first.root, second.root, op, levels);
+
+                assert_eq!(Node::iterative_consume_in_order_vec(root), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn successfully_compute_sizes_of_large_trees() {
+        let first = RecursiveBST::from_iter(random_set(7, 40_000));
+        let second = RecursiveBST::from_iter(random_set(11, 40_000));
+        let first_set: BTreeSet<u32> = first.asc_order_iter().copied().collect();
+        let second_set: BTreeSet<u32> = second.asc_order_iter().copied().collect();
+
+        let union = first.clone().par_union(second.clone());
+        let intersection = first.clone().par_intersection(second.clone());
+        let difference = first.par_difference(second);
+
+        assert_eq!(union.size(), first_set.union(&second_set).count());
+        assert!(union.asc_order_iter().eq(first_set.union(&second_set)));
+        assert_eq!(
+            intersection.size(),
+            first_set.intersection(&second_set).count()
+        );
+        assert!(intersection
+            .asc_order_iter()
+            .eq(first_set.intersection(&second_set)));
+        assert_eq!(difference.size(), first_set.difference(&second_set).count());
+        assert!(difference
+            .asc_order_iter()
+            .eq(first_set.difference(&second_set)));
+    }
+
+    #[test]
+    fn successfully_keep_rebalance_setting() {
+        let mut first = IterativeBST::with_rebalance_factor(1.5);
+        first.extend(0..2_000);
+        let second = IterativeBST::from_iter(2_000..4_000);
+
+        let mut union = first.par_union(second);
+        assert_eq!(union.size(), 4_000);
+        assert!(union.height().unwrap() as f64 <= 1.5 * 4_000f64.log2());
+
+        union.extend(4_000..8_000);
+        assert!(union.height().unwrap() as f64 <= 1.5 * 8_000f64.log2());
+        assert!(IterativeBST::<u32>::new()
+            .par_intersection(IterativeBST::from_iter(0..10))
+            .is_empty());
+    }
+}
@@ -0,0 +1,742 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::marker::PhantomData;
+
+/// Key which is ordered by its bytes, allowing [PrefixBST] to store prefixes shared between keys
+/// only once.
+///
+/// The [Ord] implementation must agree with the lexicographic order of [PrefixKey::as_bytes()].
+///
+/// Implemented for [String] and `Vec<u8>`.
+pub trait PrefixKey: Ord {
+    /// Returns the bytes of the key.
+    fn as_bytes(&self) -> &[u8];
+
+    /// Rebuilds a key out of bytes previously returned by [PrefixKey::as_bytes()].
+    fn from_bytes(bytes: Vec<u8>) -> Self;
+}
+
+impl PrefixKey for String {
+    fn as_bytes(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+
+    fn from_bytes(bytes: Vec<u8>) -> String {
+        String::from_utf8(bytes).expect("keys are only rebuilt from whole strings")
+    }
+}
+
+impl PrefixKey for Vec<u8> {
+    fn as_bytes(&self) -> &[u8] {
+        self
+    }
+
+    fn from_bytes(bytes: Vec<u8>) -> Vec<u8> {
+        bytes
+    }
+}
+
+/// Node of a [PrefixBST], holding its key relative to the key of its parent.
+#[derive(Debug, Clone)]
+struct PrefixNode {
+    /// Length of the prefix shared with the key of the parent, which is not stored again.
+    shared: usize,
+    suffix: Box<[u8]>,
+    left: Option<Box<PrefixNode>>,
+    right: Option<Box<PrefixNode>>,
+}
+
+type PrefixSlot = Option<Box<PrefixNode>>;
+
+impl PrefixNode {
+    fn new(parent: &[u8], key: &[u8], left: PrefixSlot, right: PrefixSlot) -> Box<PrefixNode> {
+        let shared = common_prefix(parent, key);
+        Box::new(PrefixNode {
+            shared,
+            suffix: key[shared..].into(),
+            left,
+            right,
+        })
+    }
+
+    /// Compares `probe` to the key of the node, without rebuilding it.
+    fn compare(&self, parent: &[u8], probe: &[u8]) -> Ordering {
+        let head = probe.len().min(self.shared);
+        match compare_bytes(&probe[..head], &parent[..head]) {
+            Ordering::Equal if probe.len() < self.shared => Ordering::Less,
+            Ordering::Equal => compare_bytes(&probe[self.shared..], &self.suffix),
+            ordering => ordering,
+        }
+    }
+
+    /// Replaces the key of the parent held in `buffer` with the key of this node.
+    fn descend(&self, buffer: &mut Vec<u8>) {
+        buffer.truncate(self.shared);
+        buffer.extend_from_slice(&self.suffix);
+    }
+
+    fn key(&self, parent: &[u8]) -> Vec<u8> {
+        let mut key = parent.to_vec();
+        self.descend(&mut key);
+        key
+    }
+
+    /// Stores the key relative to `new_parent` instead of `old_parent`.
+    fn reencode(&mut self, old_parent: &[u8], new_parent: &[u8]) {
+        let key = self.key(old_parent);
+        self.shared = common_prefix(new_parent, &key);
+        self.suffix = key[self.shared..].into();
+    }
+}
+
+/// Compares two byte strings lexicographically, like `memcmp` followed by their lengths.
+///
+/// Keys mostly differ in their first byte, which is checked before handing the common part of
+/// both slices to the bulk comparison.
+pub(crate) fn compare_bytes(first: &[u8], second: &[u8]) -> Ordering {
+    match (first.first(), second.first()) {
+        (Some(a), Some(b)) if a != b => return a.cmp(b),
+        _ => {}
+    }
+    let common = first.len().min(second.len());
+    first[..common]
+        .cmp(&second[..common])
+        .then(first.len().cmp(&second.len()))
+}
+
+fn common_prefix(first: &[u8], second: &[u8]) -> usize {
+    first
+        .iter()
+        .zip(second.iter())
+        .take_while(|(first, second)| first == second)
+        .count()
+}
+
+/// Removes the smallest node below `slot`, whose parent has the key `parent`, returning its key.
+fn take_min(mut slot: &mut PrefixSlot, parent: &[u8]) -> Option<Vec<u8>> {
+    let mut parent = parent.to_vec();
+    loop {
+        let node = slot.as_ref()?;
+        if node.left.is_none() {
+            break;
+        }
+        node.descend(&mut parent);
+        slot = &mut slot.as_mut().unwrap().left;
+    }
+
+    let mut node = slot.take().unwrap();
+    let key = node.key(&parent);
+    if let Some(mut right) = node.right.take() {
+        right.reencode(&key, &parent);
+        *slot = Some(right);
+    }
+
+    Some(key)
+}
+
+/// Removes the largest node below `slot`, whose parent has the key `parent`, returning its key.
+fn take_max(mut slot: &mut PrefixSlot, parent: &[u8]) -> Option<Vec<u8>> {
+    let mut parent = parent.to_vec();
+    loop {
+        let node = slot.as_ref()?;
+        if node.right.is_none() {
+            break;
+        }
+        node.descend(&mut parent);
+        slot = &mut slot.as_mut().unwrap().right;
+    }
+
+    let mut node = slot.take().unwrap();
+    let key = node.key(&parent);
+    if let Some(mut left) = node.left.take() {
+        left.reencode(&key, &parent);
+        *slot = Some(left);
+    }
+
+    Some(key)
+}
+
+/// Sorted set of byte-like keys such as URLs or paths, storing the prefix a key shares with its
+/// parent node only once.
+///
+/// Every key of a subtree lies between the keys of its ancestors, so keys deep down the tree
+/// tend to share long prefixes with their parents. Each node only stores the length of that
+/// shared prefix and the remaining suffix, which cuts the memory taken by keys with long common
+/// prefixes dramatically. Keys are rebuilt along the way while walking down the tree.
+///
+/// As keys are not stored whole, elements are returned by value instead of by reference, which
+/// is why [BinarySearchTree](crate::BinarySearchTree) is not implemented. The methods follow
+/// the same names and semantics otherwise.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::PrefixBST;
+///
+/// let mut bst = PrefixBST::new();
+/// bst.insert(String::from("https://example.com/docs/intro"));
+/// bst.insert(String::from("https://example.com/blog"));
+/// bst.insert(String::from("https://example.com/docs/setup"));
+///
+/// assert!(bst.contains(&String::from("https://example.com/blog")));
+/// assert_eq!(bst.min(), Some(String::from("https://example.com/blog")));
+/// assert!(bst.stored_bytes() < bst.key_bytes());
+/// ```
+pub struct PrefixBST<K: PrefixKey> {
+    root: PrefixSlot,
+    size: usize,
+    /// Total length of all keys as inserted.
+    key_bytes: usize,
+    key: PhantomData<K>,
+}
+
+impl<K: PrefixKey> PrefixBST<K> {
+    /// Creates an empty `PrefixBST<K>`
+    ///
+    /// No nodes are allocated on the heap yet
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::PrefixBST;
+    ///
+    /// // Empty tree is created
+    /// let bst: PrefixBST<String> = PrefixBST::new();
+    /// assert!(bst.is_empty())
+    /// ```
+    pub const fn new() -> PrefixBST<K> {
+        PrefixBST {
+            root: None,
+            size: 0,
+            key_bytes: 0,
+            key: PhantomData,
+        }
+    }
+
+    /// Returns the total **number of nodes** within the tree.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the binary search tree contains no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns `true` if the binary search tree contains one or more nodes.
+    pub fn is_not_empty(&self) -> bool {
+        self.size != 0
+    }
+
+    /// Returns the number of key bytes actually stored in the nodes, once shared prefixes are
+    /// left out.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::PrefixBST;
+    ///
+    /// let bst = PrefixBST::from_iter(vec![
+    ///     b"/usr/lib".to_vec(),
+    ///     b"/usr/bin".to_vec(),
+    ///     b"/usr/local".to_vec(),
+    /// ]);
+    ///
+    /// // "/usr/lib" is stored whole, "bin" and "ocal" after the prefixes shared with it
+    /// assert_eq!(bst.stored_bytes(), 8 + 3 + 4);
+    /// assert_eq!(bst.key_bytes(), 8 + 8 + 10);
+    /// ```
+    pub fn stored_bytes(&self) -> usize {
+        let mut stored = 0;
+        let mut stack = vec![&self.root];
+        while let Some(node) = stack.pop() {
+            if let Some(node) = node {
+                stored += node.suffix.len();
+                stack.push(&node.left);
+                stack.push(&node.right);
+            }
+        }
+
+        stored
+    }
+
+    /// Returns the total length of the keys, i.e. the number of bytes they would take if they
+    /// were stored whole.
+    pub fn key_bytes(&self) -> usize {
+        self.key_bytes
+    }
+
+    /// Inserts given key as a node.
+    ///
+    /// **Duplicate keys are _not allowed_**.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::PrefixBST;
+    ///
+    /// let mut bst = PrefixBST::new();
+    /// bst.insert(String::from("/home/user"));
+    /// bst.insert(String::from("/home/user"));
+    /// bst.insert(String::from("/home"));
+    ///
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    pub fn insert(&mut self, key: K) {
+        let probe = key.as_bytes();
+        let mut parent = Vec::new();
+        let mut slot = &mut self.root;
+        loop {
+            let ordering = match slot.as_ref() {
+                None => break,
+                Some(node) => node.compare(&parent, probe),
+            };
+            let node = slot.as_mut().unwrap();
+            node.descend(&mut parent);
+            match ordering {
+                Ordering::Equal => return,
+                Ordering::Less => slot = &mut node.left,
+                Ordering::Greater => slot = &mut node.right,
+            }
+        }
+
+        *slot = Some(PrefixNode::new(&parent, probe, None, None));
+        self.size += 1;
+        self.key_bytes += probe.len();
+    }
+
+    /// Returns `true` if the binary search tree contains the given key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::PrefixBST;
+    ///
+    /// let bst = PrefixBST::from_iter(vec![String::from("/etc"), String::from("/etc/hosts")]);
+    ///
+    /// assert!(bst.contains(&String::from("/etc/hosts")));
+    /// assert!(!bst.contains(&String::from("/etc/host")));
+    /// ```
+    pub fn contains(&self, key: &K) -> bool {
+        self.contains_key_bytes(key.as_bytes())
+    }
+
+    /// Returns `true` if the binary search tree contains a key with the given bytes.
+    ///
+    /// Accepts borrowed probes such as `&str` or `&[u8]` directly, so looking up a [String] key
+    /// does not allocate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::PrefixBST;
+    ///
+    /// let bst = PrefixBST::from_iter(vec![String::from("/usr/bin"), String::from("/usr/lib")]);
+    ///
+    /// assert!(bst.contains_key_bytes("/usr/lib"));
+    /// assert!(bst.contains_key_bytes(b"/usr/bin"));
+    /// assert!(!bst.contains_key_bytes("/usr"));
+    /// ```
+    pub fn contains_key_bytes(&self, key: impl AsRef<[u8]>) -> bool {
+        let probe = key.as_ref();
+        let mut parent = Vec::new();
+        let mut root = &self.root;
+        while let Some(node) = root {
+            let ordering = node.compare(&parent, probe);
+            node.descend(&mut parent);
+            match ordering {
+                Ordering::Equal => return true,
+                Ordering::Less => root = &node.left,
+                Ordering::Greater => root = &node.right,
+            }
+        }
+
+        false
+    }
+
+    /// Removes the given key.
+    ///
+    /// Tree will not be modified if trying to remove a key that does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::PrefixBST;
+    ///
+    /// let mut bst = PrefixBST::from_iter(vec![b"ab".to_vec(), b"a".to_vec(), b"abc".to_vec()]);
+    /// bst.remove(&b"ab".to_vec());
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![b"a".to_vec(), b"abc".to_vec()]);
+    /// ```
+    pub fn remove(&mut self, key: &K) {
+        let probe = key.as_bytes();
+        let mut parent = Vec::new();
+        let mut slot = &mut self.root;
+        loop {
+            let ordering = match slot.as_ref() {
+                None => return,
+                Some(node) => node.compare(&parent, probe),
+            };
+            if ordering == Ordering::Equal {
+                break;
+            }
+            let node = slot.as_mut().unwrap();
+            node.descend(&mut parent);
+            slot = match ordering {
+                Ordering::Less => &mut node.left,
+                _ => &mut node.right,
+            };
+        }
+
+        let mut node = slot.take().unwrap();
+        let key = node.key(&parent);
+        *slot = match (node.left.take(), node.right.take()) {
+            (None, None) => None,
+            (Some(mut child), None) | (None, Some(mut child)) => {
+                child.reencode(&key, &parent);
+                Some(child)
+            }
+            (Some(mut left), Some(right)) => {
+                let mut right = Some(right);
+                let successor = take_min(&mut right, &key).unwrap();
+                left.reencode(&key, &successor);
+                if let Some(right) = right.as_mut() {
+                    right.reencode(&key, &successor);
+                }
+                Some(PrefixNode::new(&parent, &successor, Some(left), right))
+            }
+        };
+        self.size -= 1;
+        self.key_bytes -= key.len();
+    }
+
+    /// Returns the height of the tree or `None` if the tree is empty.
+    ///
+    /// The height is the number of edges between the root and the furthest leaf node.
+    pub fn height(&self) -> Option<isize> {
+        let mut height = -1;
+        let mut level = Vec::from_iter(self.root.as_deref());
+        while !level.is_empty() {
+            height += 1;
+            level = level
+                .into_iter()
+                .flat_map(|node| {
+                    node.left
+                        .as_deref()
+                        .into_iter()
+                        .chain(node.right.as_deref())
+                })
+                .collect();
+        }
+
+        match height {
+            -1 => None,
+            _ => Some(height),
+        }
+    }
+
+    /// Returns the minimum key of the tree or `None` if the tree is empty.
+    pub fn min(&self) -> Option<K> {
+        let mut node = self.root.as_deref()?;
+        let mut key = node.suffix.to_vec();
+        while let Some(left) = node.left.as_deref() {
+            left.descend(&mut key);
+            node = left;
+        }
+
+        Some(K::from_bytes(key))
+    }
+
+    /// Returns the maximum key of the tree or `None` if the tree is empty.
+    pub fn max(&self) -> Option<K> {
+        let mut node = self.root.as_deref()?;
+        let mut key = node.suffix.to_vec();
+        while let Some(right) = node.right.as_deref() {
+            right.descend(&mut key);
+            node = right;
+        }
+
+        Some(K::from_bytes(key))
+    }
+
+    /// Removes and returns the minimum key from the tree or `None` if the tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::PrefixBST;
+    ///
+    /// let mut bst = PrefixBST::from_iter(vec![String::from("b"), String::from("a")]);
+    ///
+    /// assert_eq!(bst.remove_min(), Some(String::from("a")));
+    /// assert_eq!(bst.remove_max(), Some(String::from("b")));
+    /// assert_eq!(bst.remove_min(), None);
+    /// ```
+    pub fn remove_min(&mut self) -> Option<K> {
+        let key = take_min(&mut self.root, &[])?;
+        self.size -= 1;
+        self.key_bytes -= key.len();
+        Some(K::from_bytes(key))
+    }
+
+    /// Removes and returns the maximum key from the tree or `None` if the tree is empty.
+    pub fn remove_max(&mut self) -> Option<K> {
+        let key = take_max(&mut self.root, &[])?;
+        self.size -= 1;
+        self.key_bytes -= key.len();
+        Some(K::from_bytes(key))
+    }
+
+    /// Returns the keys in **ascending order**, rebuilt out of their shared prefixes.
+    pub fn asc_order_vec(&self) -> Vec<K> {
+        self.asc_order_iter().collect()
+    }
+
+    /// Returns a lazy iterator over the keys in **ascending order**.
+    ///
+    /// Each key is rebuilt out of the key of its parent as the iterator walks down the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::PrefixBST;
+    ///
+    /// let bst = PrefixBST::from_iter(vec![
+    ///     String::from("/srv/www"),
+    ///     String::from("/srv"),
+    ///     String::from("/srv/www/index.html"),
+    /// ]);
+    ///
+    /// let mut asc_order_iter = bst.asc_order_iter();
+    /// assert_eq!(asc_order_iter.next(), Some(String::from("/srv")));
+    /// assert_eq!(asc_order_iter.next(), Some(String::from("/srv/www")));
+    /// assert_eq!(asc_order_iter.next(), Some(String::from("/srv/www/index.html")));
+    /// assert_eq!(asc_order_iter.next(), None);
+    /// ```
+    pub fn asc_order_iter(&self) -> impl Iterator<Item = K> + '_ {
+        let mut iter = PrefixIter {
+            stack: Vec::new(),
+            key: PhantomData,
+        };
+        iter.push_spine(&self.root, &[]);
+        iter
+    }
+}
+
+/// Lazy iterator over the keys of a [PrefixBST] in ascending order.
+struct PrefixIter<'a, K> {
+    /// Nodes on the path to the current key, along with their rebuilt keys.
+    stack: Vec<(&'a PrefixNode, Vec<u8>)>,
+    key: PhantomData<K>,
+}
+
+impl<'a, K: PrefixKey> PrefixIter<'a, K> {
+    fn push_spine(&mut self, mut root: &'a PrefixSlot, parent: &[u8]) {
+        let mut key = parent.to_vec();
+        while let Some(node) = root {
+            node.descend(&mut key);
+            self.stack.push((node, key.clone()));
+            root = &node.left;
+        }
+    }
+}
+
+impl<'a, K: PrefixKey> Iterator for PrefixIter<'a, K> {
+    type Item = K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (node, key) = self.stack.pop()?;
+        self.push_spine(&node.right, &key);
+
+        Some(K::from_bytes(key))
+    }
+}
+
+impl<K: PrefixKey> Default for PrefixBST<K> {
+    /// Creates an empty `PrefixBST<K>`
+    fn default() -> PrefixBST<K> {
+        PrefixBST::new()
+    }
+}
+
+impl<K: PrefixKey> PartialEq for PrefixBST<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && self.asc_order_iter().eq(other.asc_order_iter())
+    }
+}
+
+impl<K: PrefixKey> Extend<K> for PrefixBST<K> {
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for key in iter.into_iter() {
+            self.insert(key)
+        }
+    }
+}
+
+impl<K: PrefixKey> FromIterator<K> for PrefixBST<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut bst = PrefixBST::new();
+        bst.extend(iter);
+        bst
+    }
+}
+
+impl<K: PrefixKey> From<Vec<K>> for PrefixBST<K> {
+    fn from(vec: Vec<K>) -> Self {
+        let mut bst = PrefixBST::new();
+        for key in vec.into_iter() {
+            bst.insert(key);
+        }
+        bst
+    }
+}
+
+impl<K: PrefixKey> Clone for PrefixBST<K> {
+    fn clone(&self) -> Self {
+        PrefixBST {
+            root: self.root.clone(),
+            size: self.size,
+            key_bytes: self.key_bytes,
+            key: PhantomData,
+        }
+    }
+}
+
+impl<K: PrefixKey + Debug> Debug for PrefixBST<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.asc_order_vec())
+    }
+}
+
+impl<K: PrefixKey + Debug> Display for PrefixBST<K> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.asc_order_vec())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::compare_bytes;
+    use crate::PrefixBST;
+
+    fn urls() -> Vec<String> {
+        let mut urls = Vec::new();
+        for section in ["blog", "docs", "api"] {
+            for page in 0..50 {
+                urls.push(format!(
+                    "https://example.com/{}/{:03}",
+                    section,
+                    (page * 37) % 50
+                ));
+            }
+        }
+        urls
+    }
+
+    #[test]
+    fn successfully_store_shared_prefixes_once() {
+        let bst = PrefixBST::from_iter(urls());
+
+        assert_eq!(bst.size(), 150);
+        assert_eq!(
+            bst.key_bytes(),
+            urls().iter().map(String::len).sum::<usize>()
+        );
+        assert!(bst.stored_bytes() * 4 < bst.key_bytes());
+        assert!(urls().iter().all(|url| bst.contains(url)));
+        assert!(!bst.contains(&String::from("https://example.com/docs")));
+        assert_eq!(
+            bst.asc_order_vec(),
+            urls()
+                .into_iter()
+                .collect::<BTreeSet<String>>()
+                .into_iter()
+                .collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn successfully_remove_keys_while_reencoding_children() {
+        let mut bst = PrefixBST::from_iter(urls());
+        let mut expected: BTreeSet<String> = urls().into_iter().collect();
+
+        for (index, url) in urls().into_iter().enumerate() {
+            if index % 3 == 0 {
+                bst.remove(&url);
+                expected.remove(&url);
+                assert!(!bst.contains(&url));
+            }
+        }
+        assert_eq!(bst.remove_min(), expected.pop_first());
+        assert_eq!(bst.remove_max(), expected.pop_last());
+
+        assert_eq!(bst.size(), expected.len());
+        assert_eq!(
+            bst.key_bytes(),
+            expected.iter().map(String::len).sum::<usize>()
+        );
+        assert!(bst.asc_order_iter().eq(expected.iter().cloned()));
+        assert!(expected.iter().all(|url| bst.contains(url)));
+        assert_eq!(bst.min().as_ref(), expected.first());
+        assert_eq!(bst.max().as_ref(), expected.last());
+
+        while bst.remove_min().is_some() {}
+        assert!(bst.is_empty());
+        assert_eq!(bst.stored_bytes(), 0);
+        assert_eq!(bst.height(), None);
+    }
+
+    #[test]
+    fn successfully_handle_keys_which_are_prefixes_of_each_other() {
+        let keys: Vec<Vec<u8>> = vec![
+            b"abc".to_vec(),
+            b"".to_vec(),
+            b"ab".to_vec(),
+            b"abcd".to_vec(),
+            b"a".to_vec(),
+        ];
+        let mut bst = PrefixBST::from_iter(keys.clone());
+
+        assert_eq!(bst.size(), 5);
+        assert_eq!(bst.min(), Some(Vec::new()));
+        assert_eq!(bst.max(), Some(b"abcd".to_vec()));
+        assert_eq!(bst.height(), Some(3));
+
+        bst.remove(&b"abc".to_vec());
+        assert_eq!(
+            bst.asc_order_vec(),
+            vec![
+                b"".to_vec(),
+                b"a".to_vec(),
+                b"ab".to_vec(),
+                b"abcd".to_vec()
+            ]
+        );
+        assert_eq!(bst.clone(), bst);
+        assert_eq!(
+            format!("{:?}", PrefixBST::from(vec![String::from("x")])),
+            "[\"x\"]"
+        );
+    }
+
+    #[test]
+    fn successfully_look_up_borrowed_key_bytes() {
+        let bst = PrefixBST::from_iter(urls());
+
+        assert!(urls()
+            .iter()
+            .all(|url| bst.contains_key_bytes(url.as_str())));
+        assert!(!bst.contains_key_bytes("https://example.com/docs"));
+        assert!(!bst.contains_key_bytes("https://example.com/docs/0000"));
+        assert!(!bst.contains_key_bytes(""));
+
+        let probes: [&[u8]; 7] = [b"", b"a", b"ab", b"b", b"abc", b"\xff", b"ab\x00"];
+        for first in probes {
+            for second in probes {
+                assert_eq!(compare_bytes(first, second), first.cmp(second));
+            }
+        }
+    }
+}
@@ -0,0 +1,253 @@
+use std::fmt::{Debug, Formatter};
+use std::ops::{Bound, RangeBounds};
+
+use crate::augmented::AugmentedNode;
+use crate::{Augment, AugmentedBST};
+
+/// [Augment] which maintains the lowest priority found in every subtree, the priority of each
+/// element being computed by the given function.
+///
+/// Wrapping the priorities in [Reverse](std::cmp::Reverse) maintains the **highest** priority
+/// instead.
+#[derive(Clone, Copy)]
+pub struct MinPriority<F> {
+    priority: F,
+}
+
+impl<F> MinPriority<F> {
+    /// Creates a `MinPriority` augmentation using `priority` to compute the priority of every
+    /// element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::cmp::Reverse;
+    /// use bst_rs::{AugmentedBST, MinPriority};
+    ///
+    /// // Highest score first
+    /// let mut bst = AugmentedBST::new(MinPriority::new(|player: &(&str, u32)| Reverse(player.1)));
+    /// bst.insert(("alice", 30));
+    /// bst.insert(("bob", 70));
+    ///
+    /// assert_eq!(bst.min_priority_in(..), Some(&("bob", 70)));
+    /// ```
+    pub fn new(priority: F) -> MinPriority<F> {
+        MinPriority { priority }
+    }
+}
+
+impl<F> Debug for MinPriority<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MinPriority").finish_non_exhaustive()
+    }
+}
+
+impl<T, P: Ord + Clone, F: Fn(&T) -> P> Augment<T> for MinPriority<F> {
+    type Summary = P;
+
+    fn summarize(&self, value: &T, left: Option<&P>, right: Option<&P>) -> P {
+        let own = (self.priority)(value);
+        [left, right]
+            .into_iter()
+            .flatten()
+            .fold(own, |lowest, priority| lowest.min(priority.clone()))
+    }
+}
+
+/// Binary Search Tree maintaining the lowest priority of every subtree (see [MinPriority]),
+/// a **priority search tree**.
+pub type PriorityBST<T, F> = AugmentedBST<T, MinPriority<F>>;
+
+/// Part of the queried key range which is considered as a whole.
+enum Candidate<'a, T, P> {
+    /// A single node, without its subtrees.
+    Node(&'a AugmentedNode<T, P>),
+    /// A whole subtree lying within the range.
+    Subtree(&'a AugmentedNode<T, P>),
+}
+
+fn above_start<T: Ord, R: RangeBounds<T>>(range: &R, value: &T) -> bool {
+    match range.start_bound() {
+        Bound::Included(start) => value >= start,
+        Bound::Excluded(start) => value > start,
+        Bound::Unbounded => true,
+    }
+}
+
+fn below_end<T: Ord, R: RangeBounds<T>>(range: &R, value: &T) -> bool {
+    match range.end_bound() {
+        Bound::Included(end) => value <= end,
+        Bound::Excluded(end) => value < end,
+        Bound::Unbounded => true,
+    }
+}
+
+impl<T: Ord, P: Ord + Clone, F: Fn(&T) -> P> PriorityBST<T, F> {
+    /// Returns the element with the lowest priority among the elements whose value lies within
+    /// `range`, or `None` if the range contains no element.
+    ///
+    /// Of several elements with the lowest priority, the smallest one is returned. Only the two
+    /// root-to-leaf paths bounding the range and a single path down to the result are visited,
+    /// O(h) no matter how many elements the range contains.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AugmentedBST, MinPriority};
+    ///
+    /// // Timestamps of pending jobs, keyed by their id
+    /// let mut jobs = AugmentedBST::new(MinPriority::new(|job: &(u32, u64)| job.1));
+    /// jobs.extend(vec![(1, 500), (2, 100), (3, 300), (4, 200), (5, 400)]);
+    ///
+    /// assert_eq!(jobs.min_priority_in(..), Some(&(2, 100)));
+    /// assert_eq!(jobs.min_priority_in((3, 0)..), Some(&(4, 200)));
+    /// assert_eq!(jobs.min_priority_in((3, 0)..(4, 0)), Some(&(3, 300)));
+    /// assert_eq!(jobs.min_priority_in((6, 0)..), None);
+    /// ```
+    pub fn min_priority_in<'a, R: RangeBounds<T>>(&'a self, range: R) -> Option<&'a T>
+    where
+        P: 'a,
+    {
+        let mut current = self.root.as_deref();
+        let split = loop {
+            let node = current?;
+            if !above_start(&range, &node.value) {
+                current = node.right.as_deref();
+            } else if !below_end(&range, &node.value) {
+                current = node.left.as_deref();
+            } else {
+                break node;
+            }
+        };
+
+        // Candidates are collected in ascending order of their values, so that ties are broken
+        // in favor of the smallest value
+        let mut candidates = Vec::new();
+        let mut current = split.left.as_deref();
+        while let Some(node) = current {
+            if above_start(&range, &node.value) {
+                candidates.extend(node.right.as_deref().map(Candidate::Subtree));
+                candidates.push(Candidate::Node(node));
+                current = node.left.as_deref();
+            } else {
+                current = node.right.as_deref();
+            }
+        }
+        candidates.reverse();
+        candidates.push(Candidate::Node(split));
+
+        let mut current = split.right.as_deref();
+        while let Some(node) = current {
+            if below_end(&range, &node.value) {
+                candidates.extend(node.left.as_deref().map(Candidate::Subtree));
+                candidates.push(Candidate::Node(node));
+                current = node.right.as_deref();
+            } else {
+                current = node.left.as_deref();
+            }
+        }
+
+        let lowest = candidates
+            .into_iter()
+            .map(|candidate| match candidate {
+                Candidate::Node(node) => ((self.augment.priority)(&node.value), candidate),
+                Candidate::Subtree(node) => (node.summary.clone(), candidate),
+            })
+            .min_by(|(first, _), (second, _)| first.cmp(second))?;
+
+        match lowest {
+            (_, Candidate::Node(node)) => Some(&node.value),
+            (priority, Candidate::Subtree(node)) => Some(self.find_priority(node, &priority)),
+        }
+    }
+
+    /// Returns the smallest element of the subtree whose priority is the summary of the subtree.
+    fn find_priority<'a>(&self, mut node: &'a AugmentedNode<T, P>, priority: &P) -> &'a T {
+        loop {
+            match node.left.as_deref() {
+                Some(left) if left.summary == *priority => node = left,
+                _ if (self.augment.priority)(&node.value) == *priority => return &node.value,
+                _ => node = node.right.as_deref().unwrap(),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Reverse;
+
+    use crate::{AugmentedBST, MinPriority, PriorityBST};
+
+    type Jobs = PriorityBST<(u32, u32), fn(&(u32, u32)) -> u32>;
+
+    fn jobs(values: Vec<(u32, u32)>) -> Jobs {
+        let priority: fn(&(u32, u32)) -> u32 = |job| job.1;
+        let mut bst = AugmentedBST::new(MinPriority::new(priority));
+        bst.extend(values);
+        bst
+    }
+
+    /// Finds the expected result by scanning every element in the range
+    fn brute_force(bst: &Jobs, start: u32, end: u32) -> Option<&(u32, u32)> {
+        bst.iter()
+            .filter(|job| start <= job.0 && job.0 < end)
+            .min_by_key(|job| job.1)
+    }
+
+    #[test]
+    fn successfully_match_brute_force_for_every_range() {
+        let mut state = 17u32;
+        let values: Vec<(u32, u32)> = (0..64)
+            .map(|key| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                // Few distinct priorities, so that ties get exercised
+                ((key * 37) % 64, (state >> 16) % 8)
+            })
+            .collect();
+        let bst = jobs(values);
+
+        for start in 0..65 {
+            for end in start..65 {
+                assert_eq!(
+                    bst.min_priority_in((start, 0)..(end, 0)),
+                    brute_force(&bst, start, end)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn successfully_update_priorities_after_removal() {
+        let mut bst = jobs(vec![(4, 40), (2, 20), (6, 10), (1, 50), (3, 30), (5, 60)]);
+        assert_eq!(bst.summary(), Some(&10));
+        assert_eq!(bst.min_priority_in((1, 0)..(6, 0)), Some(&(2, 20)));
+
+        bst.remove(&(6, 10));
+        bst.remove(&(2, 20));
+
+        assert_eq!(bst.summary(), Some(&30));
+        assert_eq!(bst.min_priority_in(..), Some(&(3, 30)));
+        assert_eq!(bst.min_priority_in((4, 0)..=(5, 60)), Some(&(4, 40)));
+    }
+
+    #[test]
+    fn successfully_query_empty_tree_and_ranges() {
+        let bst = jobs(vec![]);
+        assert_eq!(bst.min_priority_in(..), None);
+
+        let bst = jobs(vec![(1, 1), (9, 9)]);
+        assert_eq!(bst.min_priority_in((2, 0)..(9, 0)), None);
+        assert_eq!(bst.min_priority_in((9, 9)..=(9, 9)), Some(&(9, 9)));
+    }
+
+    #[test]
+    fn successfully_track_maximum_with_reverse() {
+        let mut bst = AugmentedBST::new(MinPriority::new(|value: &i32| Reverse(value.abs())));
+        bst.extend(vec![-8, 3, -1, 5, 7]);
+
+        assert_eq!(bst.min_priority_in(..), Some(&-8));
+        assert_eq!(bst.min_priority_in(-1..), Some(&7));
+        assert_eq!(bst.summary(), Some(&Reverse(8)));
+    }
+}
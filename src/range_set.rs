@@ -0,0 +1,398 @@
+use std::fmt::{Debug, Formatter};
+use std::ops::RangeInclusive;
+
+use crate::iter::SortedIter;
+use crate::node::{HeapNode, Node};
+use crate::Step;
+
+/// Inclusive range stored in a single node of a [RangeSetBST].
+///
+/// The spans of a tree never overlap or touch, so ordering them by `start` alone is enough.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Span<T> {
+    start: T,
+    end: T,
+}
+
+impl<T: Step> Span<T> {
+    fn range(&self) -> RangeInclusive<T> {
+        self.start..=self.end
+    }
+}
+
+/// Set of values stored as **disjoint ranges**, one node per range.
+///
+/// Inserting a range merges it with every stored range it overlaps or is adjacent to, and
+/// removing a range splits the stored ranges it cuts through. Dense key spaces such as ID
+/// allocation maps therefore only need a handful of nodes no matter how many values they
+/// contain.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::RangeSetBST;
+///
+/// let mut set = RangeSetBST::new();
+/// set.insert(1..=3);
+/// set.insert(4..=4);
+/// set.insert(8..=10);
+///
+/// set.remove(9..=9);
+///
+/// assert!(set.contains(&4));
+/// assert!(!set.contains(&9));
+/// assert_eq!(set.iter_ranges().collect::<Vec<_>>(), vec![1..=4, 8..=8, 10..=10]);
+/// ```
+pub struct RangeSetBST<T: Step> {
+    root: HeapNode<Span<T>>,
+    size: usize,
+}
+
+impl<T: Step> RangeSetBST<T> {
+    /// Creates an empty `RangeSetBST<T>`
+    ///
+    /// No nodes are allocated on the heap yet
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::RangeSetBST;
+    ///
+    /// let set: RangeSetBST<u32> = RangeSetBST::new();
+    /// assert!(set.is_empty());
+    /// ```
+    pub const fn new() -> RangeSetBST<T> {
+        RangeSetBST {
+            root: None,
+            size: 0,
+        }
+    }
+
+    /// Returns the **number of disjoint ranges** within the set, which is also its number of
+    /// nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::RangeSetBST;
+    ///
+    /// let mut set = RangeSetBST::new();
+    /// set.insert(1..=5);
+    /// set.insert(6..=9);
+    /// set.insert(20..=30);
+    ///
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the set contains no values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::RangeSetBST;
+    ///
+    /// let mut set = RangeSetBST::new();
+    /// assert!(set.is_empty());
+    ///
+    /// set.insert(1..=1);
+    /// assert!(!set.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Adds every value of the given range to the set, merging it with the stored ranges it
+    /// overlaps or is adjacent to. Empty ranges are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::RangeSetBST;
+    ///
+    /// let mut set = RangeSetBST::new();
+    /// set.insert(1..=2);
+    /// set.insert(6..=8);
+    /// set.insert(3..=6);
+    ///
+    /// assert_eq!(set.iter_ranges().collect::<Vec<_>>(), vec![1..=8]);
+    /// ```
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        loop {
+            // The last range starting at or right after `end` is the only candidate left, as
+            // every range before it ends even earlier
+            let bound = end.successor().unwrap_or(end);
+            let touches = match (self.floor(&bound), start.predecessor()) {
+                (None, _) => false,
+                (Some(_), None) => true,
+                (Some(span), Some(before)) => span.end >= before,
+            };
+            if !touches {
+                break;
+            }
+
+            let span = self.take_floor(&bound);
+            start = start.min(span.start);
+            end = end.max(span.end);
+        }
+
+        self.put(Span { start, end });
+    }
+
+    /// Removes every value of the given range from the set, splitting the stored ranges it cuts
+    /// through. Empty ranges are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::RangeSetBST;
+    ///
+    /// let mut set = RangeSetBST::new();
+    /// set.insert(1..=10);
+    /// set.remove(4..=6);
+    ///
+    /// assert_eq!(set.iter_ranges().collect::<Vec<_>>(), vec![1..=3, 7..=10]);
+    /// ```
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        while self.floor(&end).is_some_and(|span| span.end >= start) {
+            let span = self.take_floor(&end);
+            if let Some(before) = start.predecessor().filter(|_| span.start < start) {
+                self.put(Span {
+                    start: span.start,
+                    end: before,
+                });
+            }
+            if let Some(after) = end.successor().filter(|_| span.end > end) {
+                self.put(Span {
+                    start: after,
+                    end: span.end,
+                });
+            }
+        }
+    }
+
+    /// Returns `true` if the given value is covered by one of the stored ranges.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::RangeSetBST;
+    ///
+    /// let mut set = RangeSetBST::new();
+    /// set.insert(10..=20);
+    ///
+    /// assert!(set.contains(&10));
+    /// assert!(set.contains(&20));
+    /// assert!(!set.contains(&21));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool {
+        self.floor(value).is_some_and(|span| *value <= span.end)
+    }
+
+    /// Returns an iterator over the stored ranges in **ascending order.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::RangeSetBST;
+    ///
+    /// let set = RangeSetBST::from_iter(vec![5..=6, 1..=2]);
+    ///
+    /// let mut ranges = set.iter_ranges();
+    /// assert_eq!(ranges.next(), Some(1..=2));
+    /// assert_eq!(ranges.next(), Some(5..=6));
+    /// assert_eq!(ranges.next(), None);
+    /// ```
+    pub fn iter_ranges(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        SortedIter::ascending(&self.root).map(Span::range)
+    }
+
+    /// Returns the range with the largest start not greater than `value`.
+    fn floor(&self, value: &T) -> Option<&Span<T>> {
+        let mut floor = None;
+        let mut current = &self.root;
+        while let Some(node) = current {
+            if node.value.start <= *value {
+                floor = Some(&node.value);
+                current = &node.right;
+            } else {
+                current = &node.left;
+            }
+        }
+        floor
+    }
+
+    /// Removes the range returned by [RangeSetBST::floor()], which must exist.
+    fn take_floor(&mut self, value: &T) -> Span<T> {
+        let start = self.floor(value).map(|span| span.start).unwrap();
+        let slot = Node::iterative_slot_by(&mut self.root, |current| current.start.cmp(&start));
+        self.size -= 1;
+        Node::iterative_take(slot).unwrap()
+    }
+
+    /// Stores a range which neither overlaps nor touches any stored range.
+    fn put(&mut self, span: Span<T>) {
+        let slot =
+            Node::iterative_slot_by(&mut self.root, |current| current.start.cmp(&span.start));
+        *slot = Some(Box::new(Node::new(span)));
+        self.size += 1;
+    }
+}
+
+impl<T: Step> Default for RangeSetBST<T> {
+    /// Creates an empty `RangeSetBST<T>`
+    fn default() -> RangeSetBST<T> {
+        RangeSetBST::new()
+    }
+}
+
+impl<T: Step + Debug> Debug for RangeSetBST<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(self.iter_ranges()).finish()
+    }
+}
+
+impl<T: Step> PartialEq for RangeSetBST<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter_ranges().eq(other.iter_ranges())
+    }
+}
+
+impl<T: Step> Clone for RangeSetBST<T> {
+    fn clone(&self) -> Self {
+        RangeSetBST {
+            root: Node::build_balanced(SortedIter::ascending(&self.root).copied().collect()),
+            size: self.size,
+        }
+    }
+}
+
+impl<T: Step> Extend<RangeInclusive<T>> for RangeSetBST<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter.into_iter() {
+            self.insert(range);
+        }
+    }
+}
+
+impl<T: Step> FromIterator<RangeInclusive<T>> for RangeSetBST<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = RangeSetBST::new();
+        set.extend(iter);
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RangeSetBST;
+
+    fn ranges(set: &RangeSetBST<u8>) -> Vec<std::ops::RangeInclusive<u8>> {
+        set.iter_ranges().collect()
+    }
+
+    #[test]
+    fn successfully_merge_overlapping_and_adjacent_ranges() {
+        let mut set = RangeSetBST::from_iter(vec![10..=12, 20..=25, 30..=30, 40..=50]);
+        assert_eq!(set.len(), 4);
+
+        set.insert(13..=19);
+        assert_eq!(ranges(&set), vec![10..=25, 30..=30, 40..=50]);
+
+        set.insert(24..=45);
+        assert_eq!(ranges(&set), vec![10..=50]);
+
+        set.insert(15..=20);
+        set.insert(8..=8);
+        assert_eq!(ranges(&set), vec![8..=8, 10..=50]);
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn successfully_split_ranges_on_removal() {
+        let mut set = RangeSetBST::from_iter(vec![0..=9, 20..=29, 40..=49]);
+
+        set.remove(5..=5);
+        assert_eq!(ranges(&set), vec![0..=4, 6..=9, 20..=29, 40..=49]);
+
+        set.remove(8..=44);
+        assert_eq!(ranges(&set), vec![0..=4, 6..=7, 45..=49]);
+
+        set.remove(0..=100);
+        assert!(set.is_empty());
+    }
+
+    #[test]
+    fn successfully_handle_bounds_of_value_type() {
+        let mut set = RangeSetBST::new();
+        set.insert(0..=0);
+        set.insert(255..=255);
+        set.insert(1..=254);
+        assert_eq!(ranges(&set), vec![0..=255]);
+
+        set.remove(0..=0);
+        set.remove(255..=255);
+        assert_eq!(ranges(&set), vec![1..=254]);
+
+        set.remove(0..=255);
+        assert!(!set.contains(&0));
+        assert!(!set.contains(&255));
+    }
+
+    #[test]
+    fn successfully_match_set_of_single_values() {
+        let mut set = RangeSetBST::new();
+        let mut values = std::collections::BTreeSet::new();
+
+        let mut state = 7u32;
+        for _ in 0..500 {
+            state = state.wrapping_mul(1_103_515_245).wrapping_add(12345);
+            let start = (state >> 16) as u8;
+            let end = start.saturating_add((state >> 8) as u8 % 8);
+            if state.is_multiple_of(3) {
+                set.remove(start..=end);
+                (start..=end).for_each(|value| {
+                    values.remove(&value);
+                });
+            } else {
+                set.insert(start..=end);
+                values.extend(start..=end);
+            }
+
+            for value in 0..=255 {
+                assert_eq!(set.contains(&value), values.contains(&value));
+            }
+            for pair in ranges(&set).windows(2) {
+                assert!(*pair[0].end() as u16 + 1 < *pair[1].start() as u16);
+            }
+            let covered: Vec<u8> = ranges(&set).into_iter().flatten().collect();
+            assert_eq!(covered, values.iter().copied().collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn successfully_ignore_empty_ranges() {
+        let mut set = RangeSetBST::new();
+        #[allow(clippy::reversed_empty_ranges)]
+        set.insert(5..=4);
+        assert!(set.is_empty());
+
+        set.insert(1..=9);
+        #[allow(clippy::reversed_empty_ranges)]
+        set.remove(5..=4);
+        assert_eq!(ranges(&set), vec![1..=9]);
+        assert_eq!(set.clone(), set);
+    }
+}
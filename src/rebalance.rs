@@ -0,0 +1,37 @@
+/// Opt-in degeneration detection shared by [IterativeBST](crate::IterativeBST) and
+/// [RecursiveBST](crate::RecursiveBST).
+///
+/// Tracks an upper bound of the tree height from the depth of every inserted node, removals
+/// are not accounted for. The bound is reset whenever the tree gets rebuilt.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AutoRebalance {
+    factor: f64,
+    height: usize,
+}
+
+impl AutoRebalance {
+    pub(crate) fn new(factor: f64) -> AutoRebalance {
+        assert!(factor >= 1.0, "rebalance factor must be at least 1.0");
+        AutoRebalance { factor, height: 0 }
+    }
+
+    /// Records the depth of a newly inserted node, returning `true` once the height exceeds
+    /// `factor · log2(size)`.
+    pub(crate) fn should_rebuild(&mut self, depth: usize, size: usize) -> bool {
+        self.height = self.height.max(depth);
+        self.height as f64 > self.factor * (size as f64).log2()
+    }
+
+    /// Replaces the tracked height with the measured `height` of a tree which has been
+    /// restructured as a whole, returning `true` if it exceeds `factor · log2(size)`.
+    #[cfg(feature = "parallel")]
+    pub(crate) fn measured(&mut self, height: usize, size: usize) -> bool {
+        self.height = 0;
+        self.should_rebuild(height, size)
+    }
+
+    /// Resets the tracked height to the one of a perfectly balanced tree with `size` nodes.
+    pub(crate) fn rebuilt(&mut self, size: usize) {
+        self.height = size.checked_ilog2().unwrap_or(0) as usize;
+    }
+}
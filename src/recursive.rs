@@ -1,110 +1,293 @@
-use std::fmt::{Debug, Display, Formatter};
 use std::vec::IntoIter;
 
-use crate::BinarySearchTree;
-use crate::Node;
-use crate::HeapNode;
+use crate::bst::{Bst, Recursive};
+use crate::instrument;
+use crate::node::{HeapNode, Node};
+#[cfg(feature = "tracing")]
+use crate::trace::{self, TraceEvent};
+use crate::{BinarySearchTree, HeightExceeded, NotGreater};
 /// Recursive Binary Search Tree implementation.
 ///
 /// # Important
 ///
 /// It is also important to note that [RecursiveBST] is more likely to **blow the stack** and is
-/// generally less performant compared to [IterativeBST].
+/// generally less performant compared to [IterativeBST](crate::IterativeBST).
 ///
 /// For more information on why that is the case, please have a look at
 /// [The Story of Tail Call Optimizations in Rust.](https://seanchen1991.github.io/posts/tco-story/)
-#[derive(Debug)]
-pub struct RecursiveBST<T: Ord> {
-    root: HeapNode<T>,
-    size: usize,
-}
+///
+/// [RecursiveBST::with_max_depth()] bounds the recursion of every operation by refusing
+/// insertions which would make the tree too deep.
+pub type RecursiveBST<T> = Bst<T, Recursive>;
 
 impl<T: Ord> RecursiveBST<T> {
-    /// Creates an empty `RecursiveBST<T>`
+    /// Creates an empty `RecursiveBST<T>` which never lets a node end up deeper than
+    /// `max_depth`, so no operation recurses deeper than that and the tree can't
+    /// **blow the stack**.
     ///
-    /// No nodes are allocated on the heap yet
+    /// Every operation recurses at most once per level of the tree, so it is enough to refuse
+    /// insertions which would go further down. [RecursiveBST::try_insert()] returns such an
+    /// element in a [HeightExceeded] error, which makes the hazard detectable and handleable at
+    /// runtime. Other ways of inserting, such as [insert()](BinarySearchTree::insert()) or
+    /// [Extend], can't report an error and panic instead, which unlike a stack overflow can be
+    /// caught.
     ///
-    /// # Examples
+    /// The guard carries over to clones and to trees rebuilt out of this one, such as by
+    /// [RecursiveBST::into_balanced()] or [RecursiveBST::partition()].
+    ///
+    /// # Example
     ///
     /// ```rust
     /// use bst_rs::{BinarySearchTree, RecursiveBST};
     ///
-    /// // Empty tree is created
-    /// let mut bst: RecursiveBST<i32> = RecursiveBST::new();
-    /// assert!(bst.is_empty())
+    /// let mut bst = RecursiveBST::with_max_depth(2);
+    /// for value in 1..=3 {
+    ///     assert!(bst.try_insert(value).is_ok());
+    /// }
+    ///
+    /// let error = bst.try_insert(4).unwrap_err();
+    /// assert_eq!(error.depth(), 3);
+    /// assert_eq!(bst.size(), 3);
     /// ```
-    pub fn new() -> RecursiveBST<T> {
-        RecursiveBST {
-            root: None,
-            size: 0,
-        }
+    pub fn with_max_depth(max_depth: usize) -> RecursiveBST<T> {
+        let mut bst = RecursiveBST::new();
+        bst.max_depth = Some(max_depth);
+        bst
     }
-}
 
-impl<T: Ord> Default for RecursiveBST<T> {
-    /// Creates an empty `RecursiveBST<T>`
-    fn default() -> RecursiveBST<T> {
-        RecursiveBST::new()
+    /// Returns the depth nodes are kept within, see [RecursiveBST::with_max_depth()].
+    pub fn max_depth(&self) -> Option<usize> {
+        self.max_depth
     }
-}
 
-impl<T: Ord> PartialEq for RecursiveBST<T> {
-    fn eq(&self, other: &Self) -> bool {
-        self.asc_order_vec() == other.asc_order_vec()
+    /// Inserts given value as a node, unless it would end up deeper than the
+    /// [max depth](RecursiveBST::with_max_depth()) of the tree, in which case the tree is left
+    /// untouched and the value is handed back in a [HeightExceeded].
+    ///
+    /// Always succeeds for trees without a max depth.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::with_max_depth(0);
+    /// assert!(bst.try_insert(2).is_ok());
+    /// assert_eq!(bst.try_insert(1).unwrap_err().into_value(), 1);
+    ///
+    /// // Elements which already exist don't go any deeper
+    /// assert!(bst.try_insert(2).is_ok());
+    /// ```
+    pub fn try_insert(&mut self, value: T) -> Result<(), HeightExceeded<T>> {
+        self.insert_bounded(value, usize::MAX)
     }
-}
 
-impl<T: Ord> Extend<T> for RecursiveBST<T> {
-    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        for value in iter.into_iter() {
-            self.insert(value)
+    /// Inserts given value as a node unless it would end up deeper than `max_height`, in which
+    /// case the tree is left untouched and the value is handed back in a [HeightExceeded].
+    ///
+    /// Keeps the worst-case search length bounded for callers which must guarantee lookup
+    /// latency, and lets them decide when to rebuild the tree instead. Like
+    /// [insert()](BinarySearchTree::insert()), a value which already exists is not inserted
+    /// again and counts as success.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::new();
+    /// assert!(bst.insert_bounded(1, 1).is_ok());
+    /// assert!(bst.insert_bounded(2, 1).is_ok());
+    ///
+    /// let error = bst.insert_bounded(3, 1).unwrap_err();
+    /// assert_eq!(error.into_value(), 3);
+    /// assert_eq!(bst.height(), Some(1));
+    ///
+    /// // Rebuild into a balanced tree to make room
+    /// let mut bst = RecursiveBST::from_iter(vec![1, 2]).merge_balanced(RecursiveBST::from_iter(vec![3]));
+    /// assert!(bst.insert_bounded(4, 2).is_ok());
+    /// ```
+    pub fn insert_bounded(&mut self, value: T, max_height: usize) -> Result<(), HeightExceeded<T>> {
+        let max_height = max_height.min(self.max_depth.unwrap_or(usize::MAX));
+        let (slot, depth) = Node::recursive_search_slot(&mut self.root, &value, 0);
+        let found = slot.is_some();
+        if !found {
+            if depth > max_height {
+                return Err(HeightExceeded::new(depth, value));
+            }
+            *slot = Some(Box::new(Node::new(value)));
+            self.size += 1;
         }
+        instrument::insert(depth, found, self.size);
+        if !found {
+            self.rebalance_if_degenerate(depth);
+        }
+        Ok(())
     }
-}
 
-impl<T: Ord> FromIterator<T> for RecursiveBST<T> {
-    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
-        let mut bst = RecursiveBST::new();
-        bst.extend(iter);
-        bst
+    /// Appends `value` as the new maximum of the tree, or returns it in a [NotGreater] error if
+    /// it is not greater than the current maximum.
+    ///
+    /// Only the right spine is walked and no comparison is made against the rest of the tree,
+    /// which makes this the fast path for keys arriving in increasing order, such as
+    /// timestamps of a time series. Auto rebalancing still applies, so appending sorted keys to
+    /// a tree created with [RecursiveBST::with_rebalance_factor()] keeps it shallow.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut series = RecursiveBST::new();
+    /// for timestamp in [100, 105, 110] {
+    ///     series.push_max(timestamp).unwrap();
+    /// }
+    ///
+    /// // Late arrivals are rejected and handed back
+    /// let late = series.push_max(103).unwrap_err();
+    /// series.insert(late.into_value());
+    ///
+    /// assert_eq!(series.asc_order_vec(), vec![&100, &103, &105, &110]);
+    /// assert!(series.push_max(110).is_err());
+    /// ```
+    pub fn push_max(&mut self, value: T) -> Result<(), NotGreater<T>> {
+        // The right spine is no longer than the max depth, which bounds the recursion
+        let depth = Node::recursive_push_max(&mut self.root, value).map_err(NotGreater::new)?;
+        if self.max_depth.is_some_and(|max_depth| depth > max_depth) {
+            Node::recursive_remove_max(&mut self.root);
+            self.depth_exceeded(depth);
+        }
+        self.size += 1;
+        instrument::insert(depth, false, self.size);
+        self.rebalance_if_degenerate(depth);
+        Ok(())
     }
-}
 
-impl<T: Ord> From<Vec<T>> for RecursiveBST<T> {
-    fn from(vec: Vec<T>) -> Self {
-        let mut bst = RecursiveBST::new();
-        for value in vec.into_iter() {
-            bst.insert(value);
+    /// Returns a reference to the element equal to `value`, inserting `value` first if there is
+    /// none.
+    ///
+    /// The element is looked up and inserted in a single descent, instead of a
+    /// [contains()](BinarySearchTree::contains()) followed by an
+    /// [insert()](BinarySearchTree::insert()) and a [retrieve()](BinarySearchTree::retrieve()).
+    /// `value` is dropped if an equal element is already present.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut bst = RecursiveBST::from_iter(vec![2, 1]);
+    ///
+    /// assert_eq!(bst.get_or_insert(3), &3);
+    /// assert_eq!(bst.get_or_insert(1), &1);
+    /// assert_eq!(bst.size(), 3);
+    /// ```
+    pub fn get_or_insert(&mut self, value: T) -> &T {
+        let (slot, size) = self.insertion_slot(&value);
+        if slot.is_none() {
+            *slot = Some(Box::new(Node::new(value)));
+            *size += 1;
         }
-        bst
+        &slot.as_ref().unwrap().value
     }
-}
 
-impl<T: Ord + Clone> From<&[T]> for RecursiveBST<T> {
-    fn from(slice: &[T]) -> Self {
-        let mut bst = RecursiveBST::new();
-        for value in slice {
-            bst.insert((*value).clone());
+    /// Returns a reference to the element equal to `probe`, inserting the value returned by `f`
+    /// first if there is none.
+    ///
+    /// Like [RecursiveBST::get_or_insert()] this takes a single descent, and `f` is only called
+    /// when the element is missing, so building an owned value can be skipped for elements
+    /// which are already present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value returned by `f` is not equal to `probe`, as it would end up at the
+    /// wrong position.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST};
+    ///
+    /// let mut symbols: RecursiveBST<String> = RecursiveBST::new();
+    ///
+    /// let probe = String::from("main");
+    /// let symbol = symbols.get_or_insert_with(&probe, || probe.clone());
+    /// assert_eq!(symbol, "main");
+    ///
+    /// // The element is present, so no value is built
+    /// symbols.get_or_insert_with(&probe, || unreachable!());
+    /// assert_eq!(symbols.size(), 1);
+    /// ```
+    pub fn get_or_insert_with<F: FnOnce() -> T>(&mut self, probe: &T, f: F) -> &T {
+        let (slot, size) = self.insertion_slot(probe);
+        if slot.is_none() {
+            let value = f();
+            assert!(
+                value == *probe,
+                "value returned by f must be equal to the probe"
+            );
+            *slot = Some(Box::new(Node::new(value)));
+            *size += 1;
         }
-        bst
+        &slot.as_ref().unwrap().value
     }
-}
 
-impl<T: Ord + Clone> Clone for RecursiveBST<T> {
-    fn clone(&self) -> Self {
-        let mut bst = RecursiveBST::new();
+    /// Returns the slot holding the element equal to `probe`, or the empty slot where it is about
+    /// to be inserted by the caller, along with the size of the tree which the caller increments
+    /// once the new node has been written into the slot.
+    ///
+    /// A rebuild moves every element, so if inserting `probe` would trigger one, it happens up
+    /// front and the slot is looked up in the rebuilt tree.
+    fn insertion_slot(&mut self, probe: &T) -> (&mut HeapNode<T>, &mut usize) {
+        self.guard_depth(probe);
+        if self.rebalance.is_some() {
+            let (depth, found) = Node::search_depth(&self.root, probe);
+            if !found {
+                // The rebuild is decided on the size the tree will have after the insertion
+                self.size += 1;
+                self.rebalance_if_degenerate(depth);
+                self.size -= 1;
+            }
+        }
+
+        let (slot, depth) = Node::recursive_search_slot(&mut self.root, probe, 0);
+        let found = slot.is_some();
+        instrument::insert(depth, found, self.size + !found as usize);
+        (slot, &mut self.size)
+    }
 
-        for value in self.in_order_iter() {
-            bst.insert((*value).clone());
+    /// Panics if inserting `probe` would put a node deeper than the max depth of the tree.
+    ///
+    /// The depth is found iteratively, so the check itself never recurses.
+    fn guard_depth(&self, probe: &T) {
+        if let Some(max_depth) = self.max_depth {
+            let (depth, found) = Node::search_depth(&self.root, probe);
+            if !found && depth > max_depth {
+                self.depth_exceeded(depth);
+            }
         }
+    }
 
-        bst
+    /// Panics for an insertion at `depth`, beyond the max depth of the tree.
+    fn depth_exceeded(&self, depth: usize) -> ! {
+        panic!(
+            "element would be inserted at depth {}, exceeding the max depth of {}",
+            depth,
+            self.max_depth.unwrap()
+        );
     }
-}
 
-impl<T: Ord + Debug> Display for RecursiveBST<T> {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:?}", self.asc_order_vec())
+    fn rebalance_if_degenerate(&mut self, depth: usize) {
+        if let Some(rebalance) = &mut self.rebalance {
+            if rebalance.should_rebuild(depth, self.size) {
+                let mut elements = Vec::with_capacity(self.size);
+                Node::recursive_consume_in_order_vec(self.root.take(), &mut elements);
+                self.root = Node::build_balanced(elements);
+                rebalance.rebuilt(self.size);
+                #[cfg(feature = "tracing")]
+                trace::emit(|| TraceEvent::Rebalance { size: self.size });
+            }
+        }
     }
 }
 
@@ -180,16 +363,21 @@ impl<T: Ord> BinarySearchTree<T> for RecursiveBST<T> {
     /// assert_eq!(bst.size(), 5);
     /// ```
     fn insert(&mut self, value: T) {
-        match self.root {
+        self.guard_depth(&value);
+        let inserted = match self.root {
             None => {
                 self.root = Some(Box::from(Node::new(value)));
-                self.size += 1;
-            }
-            Some(ref mut node) => {
-                if node.recursive_insert(value).is_ok() {
-                    self.size += 1;
-                }
+                Ok(0)
             }
+            Some(ref mut node) => node.recursive_insert(value),
+        };
+        let (Ok(depth) | Err(depth)) = inserted;
+        if inserted.is_ok() {
+            self.size += 1;
+        }
+        instrument::insert(depth, inserted.is_err(), self.size);
+        if inserted.is_ok() {
+            self.rebalance_if_degenerate(depth);
         }
     }
 
@@ -209,10 +397,12 @@ impl<T: Ord> BinarySearchTree<T> for RecursiveBST<T> {
     /// assert!(!bst.contains(&10));
     /// ```
     fn contains(&self, value: &T) -> bool {
-        match self.root {
-            None => false,
-            Some(ref node) => node.recursive_contains(value),
-        }
+        let (depth, found) = match self.root {
+            None => (0, false),
+            Some(ref node) => node.recursive_search_depth(value),
+        };
+        instrument::lookup(depth);
+        found
     }
 
     /// Removes the given value.
@@ -235,9 +425,12 @@ impl<T: Ord> BinarySearchTree<T> for RecursiveBST<T> {
     /// assert_eq!(bst.size(), 2);
     /// ```
     fn remove(&mut self, value: &T) {
-        if Node::recursive_remove(&mut self.root, value).is_ok() {
+        let removed = Node::recursive_remove(&mut self.root, value);
+        let (Ok(depth) | Err(depth)) = removed;
+        if removed.is_ok() {
             self.size -= 1;
         }
+        instrument::remove(depth, removed.is_ok(), self.size);
     }
 
     /// Returns a reference to the element or `None` if element does not exist.
@@ -390,14 +583,18 @@ impl<T: Ord> BinarySearchTree<T> for RecursiveBST<T> {
     /// assert_eq!(bst.size(), 2);
     /// ```
     fn remove_min(&mut self) -> Option<T> {
-        let removed_min = match self.root {
-            None => None,
-            Some(_) => Node::recursive_remove_min(&mut self.root),
+        let (removed_min, depth) = match self.root {
+            None => (None, 0),
+            Some(_) => {
+                let (min, depth) = Node::recursive_remove_min_at(&mut self.root).unwrap();
+                (Some(min), depth)
+            }
         };
 
         if removed_min.is_some() {
             self.size -= 1;
         }
+        instrument::remove_end(depth, removed_min.is_some(), self.size);
 
         removed_min
     }
@@ -421,14 +618,18 @@ impl<T: Ord> BinarySearchTree<T> for RecursiveBST<T> {
     /// assert_eq!(bst.size(), 2);
     /// ```
     fn remove_max(&mut self) -> Option<T> {
-        let removed_max = match self.root {
-            None => None,
-            Some(_) => Node::recursive_remove_max(&mut self.root),
+        let (removed_max, depth) = match self.root {
+            None => (None, 0),
+            Some(_) => {
+                let (max, depth) = Node::recursive_remove_max_at(&mut self.root).unwrap();
+                (Some(max), depth)
+            }
         };
 
         if removed_max.is_some() {
             self.size -= 1;
         }
+        instrument::remove_end(depth, removed_max.is_some(), self.size);
 
         removed_max
     }
@@ -912,9 +1113,10 @@ impl<T: Ord> BinarySearchTree<T> for RecursiveBST<T> {
 
 #[cfg(test)]
 mod tests {
+    use std::panic::{self, AssertUnwindSafe};
     use std::vec::IntoIter;
 
-    use crate::{BinarySearchTree, RecursiveBST};
+    use crate::{BatchError, BinarySearchTree, DuplicatePolicy, InsertReport, Op, RecursiveBST};
 
     #[test]
     fn successfully_insert_elements_into_bst() {
@@ -1598,7 +1800,7 @@ mod tests {
         actual_bst.insert(2);
         actual_bst.insert(5);
 
-        actual_bst.extend(vec.into_iter());
+        actual_bst.extend(vec);
 
         assert_eq!(actual_bst.size(), 6);
         assert_eq!(actual_bst, expected_bst);
@@ -1614,7 +1816,7 @@ mod tests {
         expected_bst.insert(1);
         expected_bst.insert(10);
 
-        let actual_bst = RecursiveBST::from_iter(vec![3, 2, 5, 8, 1, 10].into_iter());
+        let actual_bst = RecursiveBST::from_iter(vec![3, 2, 5, 8, 1, 10]);
 
         assert_eq!(actual_bst, expected_bst);
     }
@@ -1652,4 +1854,904 @@ mod tests {
 
         assert_eq!(actual_bst, expected_bst);
     }
+
+    #[test]
+    fn enumerate_order_iter() {
+        let mut bst = RecursiveBST::new();
+        bst.insert(3);
+        bst.insert(4);
+        bst.insert(5);
+        bst.insert(1);
+        bst.insert(2);
+
+        let mut enumerate_order_iter = bst.enumerate_order_iter();
+
+        assert_eq!(enumerate_order_iter.next(), Some((0, &1)));
+        assert_eq!(enumerate_order_iter.next(), Some((1, &2)));
+        assert_eq!(enumerate_order_iter.next(), Some((2, &3)));
+        assert_eq!(enumerate_order_iter.next(), Some((3, &4)));
+        assert_eq!(enumerate_order_iter.next(), Some((4, &5)));
+        assert_eq!(enumerate_order_iter.next(), None);
+
+        let empty_bst: RecursiveBST<i32> = RecursiveBST::new();
+        assert_eq!(empty_bst.enumerate_order_iter().next(), None);
+    }
+
+    #[test]
+    fn successfully_get_k_smallest_elements() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.k_smallest(3).next(), None);
+
+        bst.insert(4);
+        bst.insert(2);
+        bst.insert(6);
+        bst.insert(1);
+        bst.insert(3);
+        bst.insert(5);
+        bst.insert(7);
+
+        assert_eq!(bst.k_smallest(0).next(), None);
+        assert_eq!(bst.k_smallest(3).collect::<Vec<&i32>>(), vec![&1, &2, &3]);
+        assert_eq!(
+            bst.k_smallest(7).collect::<Vec<&i32>>(),
+            bst.asc_order_vec()
+        );
+        assert_eq!(bst.k_smallest(100).count(), 7);
+    }
+
+    #[test]
+    fn successfully_get_k_largest_elements() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.k_largest(3).next(), None);
+
+        bst.insert(4);
+        bst.insert(2);
+        bst.insert(6);
+        bst.insert(1);
+        bst.insert(3);
+        bst.insert(5);
+        bst.insert(7);
+
+        assert_eq!(bst.k_largest(0).next(), None);
+        assert_eq!(bst.k_largest(3).collect::<Vec<&i32>>(), vec![&7, &6, &5]);
+        assert_eq!(
+            bst.k_largest(100).collect::<Vec<&i32>>(),
+            vec![&7, &6, &5, &4, &3, &2, &1]
+        );
+    }
+
+    #[test]
+    fn successfully_remove_min_n_from_bst() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.remove_min_n(2), vec![]);
+
+        bst.insert(5);
+        bst.insert(3);
+        bst.insert(8);
+        bst.insert(1);
+        bst.insert(4);
+        bst.insert(7);
+        bst.insert(9);
+
+        assert_eq!(bst.remove_min_n(0), vec![]);
+        assert_eq!(bst.remove_min_n(4), vec![1, 3, 4, 5]);
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.asc_order_vec(), vec![&7, &8, &9]);
+
+        assert_eq!(bst.remove_min_n(10), vec![7, 8, 9]);
+        assert!(bst.is_empty());
+    }
+
+    #[test]
+    fn successfully_remove_max_n_from_bst() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.remove_max_n(2), vec![]);
+
+        bst.insert(5);
+        bst.insert(3);
+        bst.insert(8);
+        bst.insert(1);
+        bst.insert(4);
+        bst.insert(7);
+        bst.insert(9);
+
+        assert_eq!(bst.remove_max_n(4), vec![9, 8, 7, 5]);
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.asc_order_vec(), vec![&1, &3, &4]);
+        assert_eq!(bst.pre_order_vec(), vec![&3, &1, &4]);
+
+        assert_eq!(bst.remove_max_n(10), vec![4, 3, 1]);
+        assert!(bst.is_empty());
+    }
+
+    #[test]
+    fn successfully_remove_while_predicate_holds() {
+        let mut bst = RecursiveBST::new();
+        assert_eq!(bst.remove_while(|_| true), vec![]);
+
+        bst.insert(5);
+        bst.insert(3);
+        bst.insert(8);
+        bst.insert(1);
+        bst.insert(4);
+        bst.insert(7);
+        bst.insert(9);
+
+        assert_eq!(bst.remove_while(|value| *value < 0), vec![]);
+        assert_eq!(bst.remove_while(|value| *value < 6), vec![1, 3, 4, 5]);
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.asc_order_vec(), vec![&7, &8, &9]);
+        assert!(bst.contains(&8));
+        assert_eq!(bst.min(), Some(&7));
+    }
+
+    #[test]
+    fn successfully_rebuild_degenerate_bst_into_balanced_bst() {
+        let mut bst = RecursiveBST::with_rebalance_factor(100.0);
+        for value in (1..=15).rev() {
+            bst.insert(value);
+        }
+        assert_eq!(bst.height(), Some(14));
+
+        let mut bst = bst.into_balanced();
+        assert_eq!(bst.size(), 15);
+        assert_eq!(bst.height(), Some(3));
+        assert_eq!(bst.level_order_vec()[..3], [&8, &4, &12]);
+
+        bst.insert(16);
+        assert_eq!(bst.height(), Some(4));
+        assert!(RecursiveBST::<i32>::new().into_balanced().is_empty());
+    }
+
+    #[test]
+    fn successfully_retain_one_side_of_bst() {
+        let values = vec![8, 4, 12, 2, 6, 10, 14, 1, 3, 5, 7, 9, 11, 13, 15];
+
+        for split in 0..=16 {
+            let mut greater = RecursiveBST::from_iter(values.clone());
+            let mut less = RecursiveBST::from_iter(values.clone());
+
+            let dropped = greater.retain_greater(&split);
+            assert_eq!(dropped, split.min(15) as usize);
+            assert!(greater.into_asc_order_iter().eq(split + 1..=15));
+
+            let dropped = less.retain_less(&split);
+            assert_eq!(less.size(), (split - 1).clamp(0, 15) as usize);
+            assert_eq!(dropped + less.size(), 15);
+            assert!(less.asc_order_iter().all(|value| *value < split));
+        }
+
+        let mut bst = RecursiveBST::from_iter(vec![3, 1, 2]);
+        assert_eq!(bst.retain_greater(&0), 0);
+        assert_eq!(bst.retain_less(&0), 3);
+        assert!(bst.is_empty());
+        assert_eq!(bst.retain_greater(&0), 0);
+    }
+
+    #[test]
+    fn successfully_refuse_insertions_beyond_max_depth() {
+        let mut bst = RecursiveBST::with_max_depth(100);
+
+        let refused = (0..200)
+            .filter(|value| bst.try_insert(*value).is_err())
+            .count();
+        assert_eq!(refused, 99);
+        assert_eq!(bst.height(), Some(100));
+        assert_eq!(bst.max_depth(), Some(100));
+        assert!(bst.insert_bounded(150, 1000).is_err());
+
+        let mut clone = bst.clone();
+        assert!(clone.try_insert(-1).is_ok());
+        assert!(clone.try_insert(200).is_err());
+        assert_eq!(bst.clone().into_balanced().max_depth(), Some(100));
+        assert!(RecursiveBST::new().try_insert(1).is_ok());
+    }
+
+    #[test]
+    fn successfully_clone_balanced_tree_with_max_depth() {
+        let mut bst = RecursiveBST::with_max_depth(3);
+        bst.extend(vec![4, 2, 6, 1, 3, 5, 7]);
+
+        let mut clone = bst.clone();
+        assert_eq!(clone, bst);
+        assert_eq!(clone.pre_order_vec(), bst.pre_order_vec());
+        assert_eq!(clone.max_depth(), Some(3));
+        assert!(clone.try_insert(8).is_ok());
+        assert!(clone.try_insert(9).is_err());
+        assert_eq!(bst.size(), 7);
+    }
+
+    #[test]
+    fn successfully_keep_max_depth_when_rebuilding() {
+        let mut bst = RecursiveBST::with_max_depth(2);
+        bst.extend(vec![1, 2, 3]);
+        assert!(bst.try_insert(4).is_err());
+
+        let mut balanced = bst.into_balanced();
+        assert_eq!(balanced.max_depth(), Some(2));
+        assert!(balanced.try_insert(4).is_ok());
+        assert!(balanced.try_insert(5).is_err());
+
+        let (even, odd) = balanced.partition(|value| value % 2 == 0);
+        assert_eq!((even.max_depth(), odd.max_depth()), (Some(2), Some(2)));
+        let merged = odd.merge_balanced(RecursiveBST::from_iter(vec![2, 5, 6, 7]));
+        assert_eq!(merged.max_depth(), Some(2));
+        assert_eq!(merged.height(), Some(2));
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "balanced tree of 4 elements has nodes at depth 2, exceeding the max depth of 1"
+    )]
+    fn panic_on_merge_beyond_max_depth() {
+        let bst = RecursiveBST::with_max_depth(1);
+
+        bst.merge_balanced(RecursiveBST::from_iter(vec![1, 2, 3, 4]));
+    }
+
+    #[test]
+    #[should_panic(expected = "element would be inserted at depth 2, exceeding the max depth of 1")]
+    fn panic_on_insert_beyond_max_depth() {
+        let mut bst = RecursiveBST::with_max_depth(1);
+        bst.extend(vec![2, 1, 3]);
+        assert_eq!(bst.get_or_insert(3), &3);
+
+        bst.insert(4);
+    }
+
+    #[test]
+    fn verify_push_max_beyond_max_depth_leaves_tree_untouched() {
+        let mut bst = RecursiveBST::with_max_depth(1);
+        bst.extend(vec![1, 2]);
+
+        let pushed = panic::catch_unwind(AssertUnwindSafe(|| bst.push_max(3)));
+
+        assert!(pushed.is_err());
+        assert_eq!(bst.size(), 2);
+        assert_eq!(bst.pre_order_vec(), vec![&1, &2]);
+    }
+
+    #[test]
+    fn successfully_look_up_keys_by_borrowed_bytes() {
+        let words = vec!["kiwi", "fig", "banana", "cherry", "fi", "figs", ""];
+        let bst = RecursiveBST::from_iter(words.iter().map(|word| word.to_string()));
+
+        for word in &words {
+            assert_eq!(bst.retrieve_key_bytes(word), Some(&word.to_string()));
+        }
+        assert!(!bst.contains_key_bytes("f"));
+        assert!(!bst.contains_key_bytes("figz"));
+        assert!(!bst.contains_key_bytes([0xff]));
+
+        let bytes = RecursiveBST::from_iter(vec![vec![1, 2], vec![1], vec![0, 9]]);
+        assert!(bytes.contains_key_bytes([1u8, 2]));
+        assert!(!bytes.contains_key_bytes([1u8, 2, 0]));
+    }
+
+    #[test]
+    fn successfully_remove_extremes_only_when_predicate_holds() {
+        let mut bst = RecursiveBST::from_iter(vec![5, 3, 8, 1, 4, 9, 7]);
+        let mut tested = Vec::new();
+
+        assert_eq!(
+            bst.remove_min_if(|min| {
+                tested.push(*min);
+                *min > 1
+            }),
+            None
+        );
+        assert_eq!(bst.remove_min_if(|min| *min == 1), Some(1));
+        assert_eq!(bst.remove_min_if(|min| *min == 3), Some(3));
+        assert_eq!(bst.remove_max_if(|max| *max < 9), None);
+        assert_eq!(bst.remove_max_if(|max| *max == 9), Some(9));
+        assert_eq!(bst.remove_max_if(|max| *max == 8), Some(8));
+
+        assert_eq!(tested, vec![1]);
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.pre_order_vec(), vec![&5, &4, &7]);
+
+        let mut empty = RecursiveBST::<i32>::new();
+        assert_eq!(empty.remove_min_if(|_| true), None);
+        assert_eq!(empty.remove_max_if(|_| true), None);
+    }
+
+    #[test]
+    fn successfully_push_increasing_keys_as_new_maximum() {
+        let mut bst = RecursiveBST::from_iter(vec![50, 30, 70, 60]);
+
+        assert_eq!(bst.push_max(80), Ok(()));
+        assert_eq!(bst.push_max(75).unwrap_err().into_value(), 75);
+        assert_eq!(bst.push_max(80).unwrap_err().value(), &80);
+        assert_eq!(bst.push_max(90), Ok(()));
+        assert_eq!(bst.size(), 6);
+        assert_eq!(bst.pre_order_vec(), vec![&50, &30, &70, &60, &80, &90]);
+
+        let mut empty = RecursiveBST::new();
+        assert_eq!(empty.push_max(1), Ok(()));
+        assert_eq!(empty.max(), Some(&1));
+
+        let mut balanced = RecursiveBST::with_rebalance_factor(2.0);
+        for value in 0..1024 {
+            balanced.push_max(value).unwrap();
+        }
+        assert_eq!(balanced.size(), 1024);
+        assert!(balanced.height().unwrap() <= 20);
+        assert!(balanced.asc_order_iter().copied().eq(0..1024));
+    }
+
+    #[test]
+    fn successfully_report_progress_of_load_and_cancel() {
+        let mut bst = RecursiveBST::new();
+        let mut calls = Vec::new();
+
+        let report = bst.load_from(vec![5, 3, 8, 3, 1, 9, 7], 3, |taken| {
+            calls.push(taken);
+            true
+        });
+        assert_eq!(calls, vec![3, 6]);
+        assert_eq!(
+            report,
+            InsertReport {
+                inserted: 6,
+                duplicates: 1
+            }
+        );
+        assert_eq!(bst.asc_order_vec(), vec![&1, &3, &5, &7, &8, &9]);
+
+        let mut values = 10..20;
+        let report = bst.load_from(values.by_ref(), 1, |taken| taken < 2);
+        assert_eq!(report.inserted, 2);
+        assert_eq!(values.next(), Some(12));
+        assert_eq!(bst.size(), 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "progress interval must be at least 1")]
+    fn panic_on_load_without_progress_interval() {
+        RecursiveBST::new().load_from(vec![1], 0, |_| true);
+    }
+
+    #[test]
+    fn successfully_export_bst_into_std_containers() {
+        let bst = RecursiveBST::from_iter(vec![5, 3, 8, 1, 4, 7, 9]);
+
+        assert_eq!(bst.clone().into_sorted_vec(), vec![1, 3, 4, 5, 7, 8, 9]);
+        assert_eq!(
+            bst.into_binary_heap().into_sorted_vec(),
+            vec![1, 3, 4, 5, 7, 8, 9]
+        );
+        assert!(RecursiveBST::<i32>::new().into_sorted_vec().is_empty());
+        assert!(RecursiveBST::<i32>::new().into_binary_heap().is_empty());
+    }
+
+    #[test]
+    fn successfully_merge_bsts_into_balanced_bst() {
+        let mut first = RecursiveBST::new();
+        for value in 1..=4 {
+            first.insert(value);
+        }
+        let mut second = RecursiveBST::new();
+        for value in 3..=7 {
+            second.insert(value);
+        }
+
+        let merged = first.merge_balanced(second);
+
+        assert_eq!(merged.size(), 7);
+        assert_eq!(merged.height(), Some(2));
+        assert_eq!(merged.pre_order_vec(), vec![&4, &2, &1, &3, &6, &5, &7]);
+    }
+
+    #[test]
+    fn successfully_merge_bst_with_empty_bst() {
+        let mut bst = RecursiveBST::new();
+        bst.insert(2);
+        bst.insert(1);
+
+        let merged = bst.merge_balanced(RecursiveBST::new());
+        assert_eq!(merged.asc_order_vec(), vec![&1, &2]);
+        assert_eq!(merged.size(), 2);
+
+        let empty: RecursiveBST<i32> = RecursiveBST::new().merge_balanced(RecursiveBST::new());
+        assert!(empty.is_empty());
+        assert_eq!(empty.height(), None);
+    }
+
+    #[test]
+    fn successfully_merge_iter_over_two_bsts() {
+        let mut first = RecursiveBST::new();
+        first.insert((2, 'a'));
+        first.insert((1, 'a'));
+        first.insert((4, 'a'));
+        let mut second = RecursiveBST::new();
+        second.insert((3, 'b'));
+        second.insert((2, 'a'));
+        second.insert((5, 'b'));
+
+        assert_eq!(
+            first
+                .merge_iter(&second, DuplicatePolicy::KeepBoth)
+                .collect::<Vec<_>>(),
+            vec![
+                &(1, 'a'),
+                &(2, 'a'),
+                &(2, 'a'),
+                &(3, 'b'),
+                &(4, 'a'),
+                &(5, 'b')
+            ]
+        );
+        assert_eq!(
+            first
+                .merge_iter(&second, DuplicatePolicy::KeepFirst)
+                .count(),
+            5
+        );
+        assert_eq!(
+            first
+                .merge_iter(&second, DuplicatePolicy::KeepSecond)
+                .count(),
+            5
+        );
+    }
+
+    #[test]
+    fn successfully_merge_iter_keeps_element_of_chosen_bst() {
+        let mut first = RecursiveBST::new();
+        first.insert(1);
+        let mut second = RecursiveBST::new();
+        second.insert(1);
+
+        let kept_first = first.merge_iter(&second, DuplicatePolicy::KeepFirst).next();
+        let kept_second = first
+            .merge_iter(&second, DuplicatePolicy::KeepSecond)
+            .next();
+
+        assert!(std::ptr::eq(kept_first.unwrap(), first.min().unwrap()));
+        assert!(std::ptr::eq(kept_second.unwrap(), second.min().unwrap()));
+
+        let empty = RecursiveBST::<i32>::new();
+        assert_eq!(
+            empty.merge_iter(&empty, DuplicatePolicy::KeepBoth).next(),
+            None
+        );
+    }
+
+    #[test]
+    fn successfully_report_duplicates_of_insert_all() {
+        let mut bst = RecursiveBST::from_iter(vec![5, 3]);
+
+        let report = bst.insert_all(vec![3, 8, 1, 8, 5, 9]);
+
+        assert_eq!(
+            report,
+            InsertReport {
+                inserted: 3,
+                duplicates: 3
+            }
+        );
+        assert_eq!(bst.size(), 5);
+        assert_eq!(bst.asc_order_vec(), vec![&1, &3, &5, &8, &9]);
+        assert_eq!(bst.insert_all(Vec::new()), InsertReport::default());
+    }
+
+    #[test]
+    fn successfully_report_duplicates_skipped_while_collecting() {
+        let (bst, duplicates) = RecursiveBST::from_iter_report(vec![5, 3, 5, 8, 3, 5]);
+
+        assert_eq!(duplicates, 3);
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst, RecursiveBST::from_iter(vec![5, 3, 8]));
+
+        let (empty, duplicates) = RecursiveBST::<i32>::from_iter_report(Vec::new());
+        assert!(empty.is_empty());
+        assert_eq!(duplicates, 0);
+    }
+
+    #[test]
+    fn successfully_get_or_insert_elements() {
+        let mut bst = RecursiveBST::from_iter(vec![5, 3, 8]);
+
+        assert_eq!(bst.get_or_insert(3), &3);
+        assert_eq!(bst.get_or_insert(4), &4);
+        assert_eq!(bst.get_or_insert_with(&9, || 9), &9);
+        assert_eq!(bst.get_or_insert_with(&8, || unreachable!()), &8);
+
+        assert_eq!(bst.size(), 5);
+        assert_eq!(bst.pre_order_vec(), vec![&5, &3, &4, &8, &9]);
+    }
+
+    #[test]
+    fn successfully_get_or_insert_into_rebalancing_bst() {
+        let mut bst = RecursiveBST::with_rebalance_factor(1.0);
+        for value in 1..=64 {
+            assert_eq!(bst.get_or_insert(value), &value);
+        }
+
+        assert_eq!(bst.size(), 64);
+        assert!(bst.height().unwrap() <= 6);
+        assert_eq!(
+            bst.asc_order_iter().copied().collect::<Vec<i32>>(),
+            (1..=64).collect::<Vec<i32>>()
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "must be equal to the probe")]
+    fn panic_on_get_or_insert_with_unequal_value() {
+        let mut bst = RecursiveBST::from_iter(vec![2]);
+        bst.get_or_insert_with(&1, || 3);
+    }
+
+    #[test]
+    fn verify_size_is_kept_when_get_or_insert_with_panics() {
+        let mut bst = RecursiveBST::from_iter(vec![2]);
+        let mut rebalanced = RecursiveBST::with_rebalance_factor(1.0);
+        rebalanced.extend(vec![1, 2, 3]);
+
+        for tree in [&mut bst, &mut rebalanced] {
+            let size = tree.size();
+            let panicking = panic::catch_unwind(AssertUnwindSafe(|| {
+                tree.get_or_insert_with(&10, || panic!("failed to build value"));
+            }));
+            let unequal = panic::catch_unwind(AssertUnwindSafe(|| {
+                tree.get_or_insert_with(&10, || 11);
+            }));
+
+            assert!(panicking.is_err() && unequal.is_err());
+            assert_eq!(tree.size(), size);
+            assert_eq!(tree.asc_order_vec().len(), size);
+            assert!(!tree.contains(&10));
+            assert_eq!(tree.get_or_insert_with(&10, || 10), &10);
+            assert_eq!(tree.size(), size + 1);
+        }
+    }
+
+    #[test]
+    fn successfully_reject_insertions_exceeding_height_budget() {
+        let mut bst = RecursiveBST::from_iter(vec![4, 2, 6]);
+
+        assert_eq!(bst.insert_bounded(1, 2), Ok(()));
+        assert_eq!(bst.insert_bounded(4, 0), Ok(()));
+
+        let error = bst.insert_bounded(0, 2).unwrap_err();
+        assert_eq!(error.depth(), 3);
+        assert_eq!(error.value(), &0);
+        assert_eq!(
+            error.to_string(),
+            "element would be inserted at depth 3, exceeding the height budget"
+        );
+
+        assert_eq!(bst.size(), 4);
+        assert_eq!(bst.height(), Some(2));
+        assert!(!bst.contains(&0));
+    }
+
+    #[test]
+    fn successfully_check_contains_all() {
+        let bst = RecursiveBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14, 1, 3]);
+
+        assert!(bst.contains_all(&[14, 1, 8, 6, 1]));
+        assert!(bst.contains_all(&[]));
+        assert!(!bst.contains_all(&[1, 2, 5]));
+        assert!(!bst.contains_all(&[15]));
+        assert!(!RecursiveBST::new().contains_all(&[1]));
+    }
+
+    #[test]
+    fn successfully_get_many_in_probe_order() {
+        let bst = RecursiveBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14, 1, 3]);
+
+        let found = bst.get_many(&[14, 5, 1, 8, 14, 0]);
+        assert_eq!(
+            found,
+            vec![Some(&14), None, Some(&1), Some(&8), Some(&14), None]
+        );
+        assert!(std::ptr::eq(found[0].unwrap(), bst.max().unwrap()));
+        assert_eq!(bst.get_many(&[]), Vec::<Option<&i32>>::new());
+        assert_eq!(RecursiveBST::new().get_many(&[1]), vec![None]);
+    }
+
+    #[test]
+    fn successfully_descend_with_custom_comparison() {
+        let bst = RecursiveBST::from_iter(vec![40, 20, 60, 10, 30, 50, 70]);
+
+        let mut visited = Vec::new();
+        let found = bst.descend_with(|current| {
+            visited.push(*current);
+            (current / 10).cmp(&5)
+        });
+
+        assert_eq!(found, Some(&50));
+        assert_eq!(visited, vec![40, 60, 50]);
+        assert_eq!(bst.descend_with(|current| current.cmp(&35)), None);
+        assert_eq!(
+            RecursiveBST::<i32>::new().descend_with(|_| unreachable!()),
+            None
+        );
+    }
+
+    #[test]
+    fn successfully_remove_all_given_elements() {
+        let mut bst = RecursiveBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14, 1, 3]);
+
+        assert_eq!(bst.remove_all(&[8, 2, 14, 2, 11, 1]), 4);
+
+        assert_eq!(bst.size(), 5);
+        assert_eq!(bst.asc_order_vec(), vec![&3, &4, &6, &10, &12]);
+        assert_eq!(bst.remove_all(&[]), 0);
+        assert_eq!(bst.remove_all(&[3, 4, 6, 10, 12]), 5);
+        assert!(bst.is_empty());
+    }
+
+    #[test]
+    fn successfully_drain_sorted_completely() {
+        let mut bst = RecursiveBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14, 1, 3]);
+
+        let drained: Vec<i32> = bst.drain_sorted().collect();
+
+        assert_eq!(drained, vec![1, 2, 3, 4, 6, 8, 10, 12, 14]);
+        assert!(bst.is_empty());
+        assert_eq!(bst, RecursiveBST::new());
+    }
+
+    #[test]
+    fn successfully_keep_rest_of_bst_after_stopping_drain_sorted_early() {
+        let mut bst = RecursiveBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14, 1, 3]);
+
+        let mut drain_sorted = bst.drain_sorted();
+        assert_eq!(drain_sorted.size_hint(), (9, Some(9)));
+        assert_eq!(drain_sorted.next(), Some(1));
+        assert_eq!(drain_sorted.next(), Some(2));
+        assert_eq!(drain_sorted.next(), Some(3));
+        assert_eq!(drain_sorted.next(), Some(4));
+        drop(drain_sorted);
+
+        assert_eq!(bst.size(), 5);
+        assert_eq!(bst.asc_order_vec(), vec![&6, &8, &10, &12, &14]);
+        assert_eq!(bst.pre_order_vec(), vec![&8, &6, &12, &10, &14]);
+        bst.insert(5);
+        assert_eq!(bst.min(), Some(&5));
+    }
+
+    #[test]
+    fn successfully_rebalance_degenerate_bst_automatically() {
+        let mut bst = RecursiveBST::with_rebalance_factor(1.5);
+        for value in 0..500 {
+            bst.insert(value);
+        }
+
+        assert_eq!(bst.size(), 500);
+        assert!(bst.height().unwrap() as f64 <= 1.5 * 500f64.log2());
+        assert!(bst.asc_order_iter().copied().eq(0..500));
+        assert!(bst.clone().rebalance.is_some());
+    }
+
+    #[test]
+    fn successfully_keep_degenerate_bst_without_rebalance_factor() {
+        let mut bst = RecursiveBST::new();
+        for value in 0..50 {
+            bst.insert(value);
+        }
+
+        assert_eq!(bst.height(), Some(49));
+    }
+
+    #[test]
+    #[should_panic(expected = "rebalance factor must be at least 1.0")]
+    fn panic_on_rebalance_factor_below_one() {
+        let _: RecursiveBST<i32> = RecursiveBST::with_rebalance_factor(0.5);
+    }
+
+    #[test]
+    fn successfully_get_depth_histogram() {
+        let balanced = RecursiveBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14, 1]);
+        let degenerate = RecursiveBST::from_iter(1..=4);
+
+        assert_eq!(balanced.depth_histogram(), vec![1, 2, 4, 1]);
+        assert_eq!(degenerate.depth_histogram(), vec![1, 1, 1, 1]);
+        assert_eq!(
+            balanced.depth_histogram().iter().sum::<usize>(),
+            balanced.size()
+        );
+    }
+
+    #[test]
+    fn successfully_get_average_depth() {
+        let balanced = RecursiveBST::from_iter(vec![2, 1, 3]);
+        let degenerate = RecursiveBST::from_iter(1..=4);
+
+        assert_eq!(balanced.average_depth(), Some(2.0 / 3.0));
+        assert_eq!(degenerate.average_depth(), Some(1.5));
+        assert_eq!(RecursiveBST::from_iter(vec![1]).average_depth(), Some(0.0));
+    }
+
+    #[test]
+    fn successfully_get_balance_factors_in_post_order() {
+        let bst = RecursiveBST::from_iter(vec![8, 4, 12, 2, 1, 10, 9]);
+
+        let balance_factors: Vec<(&i32, isize)> = bst.balance_factors().collect();
+
+        assert_eq!(
+            balance_factors,
+            vec![
+                (&1, 0),
+                (&2, 1),
+                (&4, 2),
+                (&9, 0),
+                (&10, 1),
+                (&12, 2),
+                (&8, 0)
+            ]
+        );
+        assert_eq!(
+            bst.balance_factors()
+                .map(|(value, _)| value)
+                .collect::<Vec<_>>(),
+            bst.post_order_vec()
+        );
+        assert_eq!(RecursiveBST::<i32>::new().balance_factors().next(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "unstable-internals")]
+    fn successfully_rotate_inner_subtrees() {
+        let mut bst = RecursiveBST::from_iter(vec![8, 4, 12, 2, 6, 5, 7]);
+
+        assert!(bst.rotate_left_at(&4));
+        assert_eq!(bst.pre_order_vec(), vec![&8, &6, &4, &2, &5, &7, &12]);
+
+        assert!(bst.rotate_right_at(&6));
+        assert_eq!(bst.pre_order_vec(), vec![&8, &4, &2, &6, &5, &7, &12]);
+
+        assert!(!bst.rotate_right_at(&12));
+        assert!(!bst.rotate_left_at(&3));
+        assert_eq!(bst.size(), 7);
+        assert_eq!(bst.asc_order_vec(), vec![&2, &4, &5, &6, &7, &8, &12]);
+    }
+
+    #[test]
+    #[cfg(feature = "unstable-internals")]
+    fn verify_rotations_beyond_max_depth_leave_tree_untouched() {
+        let mut bst = RecursiveBST::with_max_depth(2);
+        bst.extend(vec![4, 2, 6, 1, 3]);
+
+        assert!(!bst.rotate_left_at(&4));
+        assert!(!bst.rotate_left_at(&2));
+        assert_eq!(bst.pre_order_vec(), vec![&4, &2, &1, &3, &6]);
+
+        assert!(bst.rotate_right_at(&4));
+        assert_eq!(bst.pre_order_vec(), vec![&2, &1, &4, &3, &6]);
+        assert_eq!(bst.height(), Some(2));
+    }
+
+    #[test]
+    fn successfully_apply_batch_of_operations() {
+        let mut bst = RecursiveBST::from_iter(vec![5, 3, 8]);
+
+        let result = bst.apply(vec![
+            Op::Insert(1),
+            Op::Remove(3),
+            Op::Insert(3),
+            Op::Remove(3),
+            Op::Remove(8),
+        ]);
+
+        assert_eq!(result, Ok(()));
+        assert_eq!(bst.size(), 2);
+        assert_eq!(bst.asc_order_vec(), vec![&1, &5]);
+        assert_eq!(bst.apply(Vec::new()), Ok(()));
+    }
+
+    #[test]
+    fn successfully_reject_whole_batch_on_first_failing_operation() {
+        let mut bst = RecursiveBST::from_iter(vec![5, 3, 8]);
+
+        let result = bst.apply(vec![
+            Op::Insert(1),
+            Op::Remove(9),
+            Op::Remove(5),
+            Op::Remove(5),
+            Op::Insert(3),
+        ]);
+
+        assert_eq!(result, Err(BatchError::NotPresent { index: 1 }));
+        assert_eq!(
+            bst.apply(vec![Op::Remove(8), Op::Insert(8), Op::Insert(8)]),
+            Err(BatchError::AlreadyPresent { index: 2 })
+        );
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.asc_order_vec(), vec![&3, &5, &8]);
+    }
+
+    #[test]
+    fn successfully_partition_bst_by_predicate() {
+        let bst = RecursiveBST::from_iter(vec![8, 3, 10, 1, 6, 14, 4, 7, 13]);
+
+        let (small, large) = bst.partition(|value| *value < 7);
+
+        assert_eq!(small.size(), 4);
+        assert_eq!(small.asc_order_vec(), vec![&1, &3, &4, &6]);
+        assert_eq!(large.size(), 5);
+        assert_eq!(large.asc_order_vec(), vec![&7, &8, &10, &13, &14]);
+        assert_eq!(large.height(), Some(2));
+    }
+
+    #[test]
+    fn successfully_partition_bst_into_empty_side() {
+        let bst = RecursiveBST::from_iter(vec![2, 1, 3]);
+
+        let (all, none) = bst.partition(|_| true);
+
+        assert_eq!(all.size(), 3);
+        assert!(none.is_empty());
+        assert_eq!(none.height(), None);
+    }
+
+    #[test]
+    fn successfully_get_adjacent_pairs_of_bst() {
+        let bst = RecursiveBST::from_iter(vec![4, 2, 6, 1]);
+
+        assert_eq!(
+            bst.pairs().collect::<Vec<_>>(),
+            vec![(&1, &2), (&2, &4), (&4, &6)]
+        );
+        assert_eq!(RecursiveBST::from_iter(vec![1]).pairs().next(), None);
+        assert_eq!(RecursiveBST::<i32>::new().pairs().next(), None);
+    }
+
+    #[test]
+    fn successfully_get_gaps_between_offsets() {
+        let mut offsets = RecursiveBST::from_iter(vec![0usize, 4096, 8192, 9216, 16384]);
+
+        assert_eq!(
+            offsets.gaps().collect::<Vec<_>>(),
+            vec![4096, 4096, 1024, 7168]
+        );
+        assert_eq!(offsets.min_gap(), Some(1024));
+
+        offsets.remove(&9216);
+        assert_eq!(offsets.min_gap(), Some(4096));
+    }
+
+    #[test]
+    fn successfully_create_bst_from_vec_without_duplicates() {
+        let bst = RecursiveBST::try_from_vec(vec![5, 3, 8, 1]).unwrap();
+
+        assert_eq!(bst, RecursiveBST::from(vec![5, 3, 8, 1]));
+        assert_eq!(bst.pre_order_vec(), vec![&5, &3, &1, &8]);
+    }
+
+    #[test]
+    fn successfully_reject_vec_with_duplicates() {
+        let error = RecursiveBST::try_from_vec(vec![5, 3, 8, 3, 5]).unwrap_err();
+
+        assert_eq!(error.index(), 3);
+        assert_eq!(error.into_value(), 3);
+        assert_eq!(
+            RecursiveBST::try_from_vec(vec!["a", "a"])
+                .unwrap_err()
+                .to_string(),
+            "element 1 is a duplicate of an earlier element"
+        );
+    }
+
+    #[test]
+    fn successfully_coalesce_contiguous_ranges() {
+        let mut ids = RecursiveBST::from_iter(vec![-2i8, -1, 0, 5, 126, 127]);
+
+        assert_eq!(
+            ids.coalesced_ranges().collect::<Vec<_>>(),
+            vec![-2..=0, 5..=5, 126..=127]
+        );
+
+        ids.insert(-3);
+        ids.remove(&-1);
+        assert_eq!(
+            ids.coalesced_ranges().collect::<Vec<_>>(),
+            vec![-3..=-2, 0..=0, 5..=5, 126..=127]
+        );
+
+        assert_eq!(RecursiveBST::<u8>::new().coalesced_ranges().next(), None);
+    }
 }
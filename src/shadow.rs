@@ -0,0 +1,519 @@
+use std::collections::BTreeSet;
+use std::fmt::{Debug, Display, Formatter};
+use std::vec::IntoIter;
+
+use crate::BinarySearchTree;
+
+/// Differential testing wrapper running every operation against both a binary search tree and
+/// a [BTreeSet], panicking as soon as the two disagree.
+///
+/// Every result is compared with the one of the [BTreeSet], and the whole contents are compared
+/// after every modification, including the elements handed out by consuming traversals. This
+/// makes each operation `O(n)` and the wrapper only suited to tests and fuzzing harnesses, where
+/// it validates the unsafe consuming traversals of the trees along with any balancing code.
+///
+/// Traversals whose order can't be derived from a [BTreeSet], such as the pre-order one, are
+/// checked to hold exactly the elements of the set.
+///
+/// # Panics
+///
+/// Every operation panics with the name of the operation if the tree diverged from the
+/// [BTreeSet].
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{BinarySearchTree, RecursiveBST, ShadowBST};
+///
+/// let mut bst = ShadowBST::new(RecursiveBST::new());
+/// bst.insert(3);
+/// bst.insert(1);
+/// bst.insert(2);
+/// bst.remove(&3);
+///
+/// assert_eq!(bst.remove_min(), Some(1));
+/// assert_eq!(bst.into_inner().asc_order_vec(), vec![&2]);
+/// ```
+pub struct ShadowBST<T: Ord + Clone + Debug, B: BinarySearchTree<T>> {
+    bst: B,
+    shadow: BTreeSet<T>,
+}
+
+impl<T: Ord + Clone + Debug, B: BinarySearchTree<T>> ShadowBST<T, B> {
+    /// Wraps `bst`, shadowing a copy of its current elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bst` does not hold its elements in ascending order without duplicates.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, ShadowBST};
+    ///
+    /// let bst = ShadowBST::new(IterativeBST::from_iter(vec![2, 1, 3]));
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.max(), Some(&3));
+    /// ```
+    pub fn new(bst: B) -> ShadowBST<T, B> {
+        let shadow = bst.asc_order_iter().cloned().collect();
+        let shadow = ShadowBST { bst, shadow };
+        shadow.verify("new");
+        shadow
+    }
+
+    /// Returns the wrapped tree.
+    pub fn inner(&self) -> &B {
+        &self.bst
+    }
+
+    /// Unwraps the tree, after checking its contents one last time.
+    pub fn into_inner(self) -> B {
+        self.verify("into_inner");
+        self.bst
+    }
+
+    /// Asserts that the tree holds the same elements as the [BTreeSet], in the same order.
+    fn verify(&self, operation: &str) {
+        assert_eq!(
+            self.bst.size(),
+            self.shadow.len(),
+            "shadow: size diverged after {}",
+            operation
+        );
+        assert!(
+            self.bst.asc_order_iter().eq(self.shadow.iter()),
+            "shadow: contents diverged after {}\n  tree: {:?}\n  set:  {:?}",
+            operation,
+            self.bst.asc_order_vec(),
+            self.shadow
+        );
+    }
+
+    /// Asserts that `elements` are exactly the elements of the [BTreeSet], in any order.
+    fn verify_permutation<'a>(&'a self, mut elements: Vec<&'a T>, operation: &str) -> Vec<&'a T> {
+        let unsorted = elements.clone();
+        elements.sort();
+        assert!(
+            elements.iter().copied().eq(self.shadow.iter()),
+            "shadow: {} diverged\n  tree: {:?}\n  set:  {:?}",
+            operation,
+            unsorted,
+            self.shadow
+        );
+        unsorted
+    }
+
+    /// Asserts that consumed `elements` are exactly the elements of the [BTreeSet].
+    fn verify_consumed(
+        shadow: BTreeSet<T>,
+        elements: IntoIter<T>,
+        sorted: bool,
+        operation: &str,
+    ) -> IntoIter<T> {
+        let elements: Vec<T> = elements.collect();
+        let mut expected = elements.clone();
+        if !sorted {
+            expected.sort();
+        }
+        assert!(
+            expected.iter().eq(shadow.iter()),
+            "shadow: {} diverged\n  tree: {:?}\n  set:  {:?}",
+            operation,
+            elements,
+            shadow
+        );
+        elements.into_iter()
+    }
+}
+
+impl<T: Ord + Clone + Debug, B: BinarySearchTree<T> + Default> Default for ShadowBST<T, B> {
+    /// Creates an empty `ShadowBST<T, B>`
+    fn default() -> ShadowBST<T, B> {
+        ShadowBST::new(B::default())
+    }
+}
+
+impl<T: Ord + Clone + Debug, B: BinarySearchTree<T> + Debug> Debug for ShadowBST<T, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShadowBST")
+            .field("bst", &self.bst)
+            .field("shadow", &self.shadow)
+            .finish()
+    }
+}
+
+impl<T: Ord + Clone + Debug, B: BinarySearchTree<T> + Display> Display for ShadowBST<T, B> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.bst)
+    }
+}
+
+impl<T: Ord + Clone + Debug, B: BinarySearchTree<T>> Extend<T> for ShadowBST<T, B> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter.into_iter() {
+            self.insert(value)
+        }
+    }
+}
+
+impl<T: Ord + Clone + Debug, B: BinarySearchTree<T> + Default> FromIterator<T> for ShadowBST<T, B> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bst = ShadowBST::default();
+        bst.extend(iter);
+        bst
+    }
+}
+
+impl<T: Ord + Clone + Debug, B: BinarySearchTree<T>> BinarySearchTree<T> for ShadowBST<T, B> {
+    /// Returns the total **number of nodes** within the tree, checked against the [BTreeSet].
+    fn size(&self) -> usize {
+        let size = self.bst.size();
+        assert_eq!(size, self.shadow.len(), "shadow: size diverged");
+        size
+    }
+
+    /// Returns `true` if the tree contains no nodes, checked against the [BTreeSet].
+    fn is_empty(&self) -> bool {
+        let is_empty = self.bst.is_empty();
+        assert_eq!(
+            is_empty,
+            self.shadow.is_empty(),
+            "shadow: is_empty diverged"
+        );
+        is_empty
+    }
+
+    /// Returns `true` if the tree contains nodes, checked against the [BTreeSet].
+    fn is_not_empty(&self) -> bool {
+        let is_not_empty = self.bst.is_not_empty();
+        assert_eq!(
+            is_not_empty,
+            !self.shadow.is_empty(),
+            "shadow: is_not_empty diverged"
+        );
+        is_not_empty
+    }
+
+    /// Inserts given value into both the tree and the [BTreeSet], then compares their contents.
+    fn insert(&mut self, value: T) {
+        self.shadow.insert(value.clone());
+        self.bst.insert(value);
+        self.verify("insert");
+    }
+
+    /// Returns `true` if the tree contains the value, checked against the [BTreeSet].
+    fn contains(&self, value: &T) -> bool {
+        let contains = self.bst.contains(value);
+        assert_eq!(
+            contains,
+            self.shadow.contains(value),
+            "shadow: contains({:?}) diverged",
+            value
+        );
+        contains
+    }
+
+    /// Removes given value from both the tree and the [BTreeSet], then compares their contents.
+    fn remove(&mut self, value: &T) {
+        self.shadow.remove(value);
+        self.bst.remove(value);
+        self.verify("remove");
+    }
+
+    /// Returns a reference to the element equal to given value, checked against the
+    /// [BTreeSet].
+    fn retrieve(&self, value: &T) -> Option<&T> {
+        let retrieved = self.bst.retrieve(value);
+        assert_eq!(
+            retrieved,
+            self.shadow.get(value),
+            "shadow: retrieve({:?}) diverged",
+            value
+        );
+        retrieved
+    }
+
+    /// Returns a mutable reference to the element equal to given value, checked against the
+    /// [BTreeSet].
+    ///
+    /// The copy kept in the [BTreeSet] is not updated: changing the element such that it no
+    /// longer compares equal to that copy is reported by the next check of the contents.
+    fn retrieve_as_mut(&mut self, value: &T) -> Option<&mut T> {
+        let expected = self.shadow.get(value);
+        let retrieved = self.bst.retrieve_as_mut(value);
+        assert_eq!(
+            retrieved.as_deref(),
+            expected,
+            "shadow: retrieve_as_mut({:?}) diverged",
+            value
+        );
+        retrieved
+    }
+
+    /// Returns the height of the tree, only checked to be [None] for an empty
+    /// [BTreeSet] as the set has no shape.
+    fn height(&self) -> Option<isize> {
+        let height = self.bst.height();
+        assert_eq!(
+            height.is_none(),
+            self.shadow.is_empty(),
+            "shadow: height diverged"
+        );
+        height
+    }
+
+    /// Returns a reference to the minimum element, checked against the [BTreeSet].
+    fn min(&self) -> Option<&T> {
+        let min = self.bst.min();
+        assert_eq!(min, self.shadow.first(), "shadow: min diverged");
+        min
+    }
+
+    /// Returns a reference to the maximum element, checked against the [BTreeSet].
+    fn max(&self) -> Option<&T> {
+        let max = self.bst.max();
+        assert_eq!(max, self.shadow.last(), "shadow: max diverged");
+        max
+    }
+
+    /// Removes and returns the minimum element of both the tree and the [BTreeSet], then
+    /// compares their contents.
+    fn remove_min(&mut self) -> Option<T> {
+        let removed = self.bst.remove_min();
+        assert_eq!(
+            removed,
+            self.shadow.pop_first(),
+            "shadow: remove_min diverged"
+        );
+        self.verify("remove_min");
+        removed
+    }
+
+    /// Removes and returns the maximum element of both the tree and the [BTreeSet], then
+    /// compares their contents.
+    fn remove_max(&mut self) -> Option<T> {
+        let removed = self.bst.remove_max();
+        assert_eq!(
+            removed,
+            self.shadow.pop_last(),
+            "shadow: remove_max diverged"
+        );
+        self.verify("remove_max");
+        removed
+    }
+
+    /// Returns references to the elements in **ascending order**, checked against the
+    /// [BTreeSet].
+    fn asc_order_vec(&self) -> Vec<&T> {
+        let elements = self.bst.asc_order_vec();
+        assert!(
+            elements.iter().copied().eq(self.shadow.iter()),
+            "shadow: asc_order_vec diverged"
+        );
+        elements
+    }
+
+    /// Returns references to the elements in **pre-order**, checked to be a permutation of the
+    /// [BTreeSet].
+    fn pre_order_vec(&self) -> Vec<&T> {
+        self.verify_permutation(self.bst.pre_order_vec(), "pre_order_vec")
+    }
+
+    /// Returns references to the elements **in-order**, checked against the [BTreeSet].
+    fn in_order_vec(&self) -> Vec<&T> {
+        let elements = self.bst.in_order_vec();
+        assert!(
+            elements.iter().copied().eq(self.shadow.iter()),
+            "shadow: in_order_vec diverged"
+        );
+        elements
+    }
+
+    /// Returns references to the elements in **post-order**, checked to be a permutation of
+    /// the [BTreeSet].
+    fn post_order_vec(&self) -> Vec<&T> {
+        self.verify_permutation(self.bst.post_order_vec(), "post_order_vec")
+    }
+
+    /// Returns references to the elements in **level-order**, checked to be a permutation of
+    /// the [BTreeSet].
+    fn level_order_vec(&self) -> Vec<&T> {
+        self.verify_permutation(self.bst.level_order_vec(), "level_order_vec")
+    }
+
+    /// Returns an iterator over [ShadowBST::asc_order_vec()].
+    fn asc_order_iter(&self) -> IntoIter<&T> {
+        self.asc_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [ShadowBST::pre_order_vec()].
+    fn pre_order_iter(&self) -> IntoIter<&T> {
+        self.pre_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [ShadowBST::in_order_vec()].
+    fn in_order_iter(&self) -> IntoIter<&T> {
+        self.in_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [ShadowBST::post_order_vec()].
+    fn post_order_iter(&self) -> IntoIter<&T> {
+        self.post_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [ShadowBST::level_order_vec()].
+    fn level_order_iter(&self) -> IntoIter<&T> {
+        self.level_order_vec().into_iter()
+    }
+
+    /// Consumes the tree, checking the elements handed out against the [BTreeSet] in
+    /// **ascending order**.
+    fn into_asc_order_iter(self) -> IntoIter<T> {
+        let elements = self.bst.into_asc_order_iter();
+        ShadowBST::<T, B>::verify_consumed(self.shadow, elements, true, "into_asc_order_iter")
+    }
+
+    /// Consumes the tree, checking the elements handed out in **pre-order** to be a
+    /// permutation of the [BTreeSet].
+    fn into_pre_order_iter(self) -> IntoIter<T> {
+        let elements = self.bst.into_pre_order_iter();
+        ShadowBST::<T, B>::verify_consumed(self.shadow, elements, false, "into_pre_order_iter")
+    }
+
+    /// Consumes the tree, checking the elements handed out against the [BTreeSet]
+    /// **in-order**.
+    fn into_in_order_iter(self) -> IntoIter<T> {
+        let elements = self.bst.into_in_order_iter();
+        ShadowBST::<T, B>::verify_consumed(self.shadow, elements, true, "into_in_order_iter")
+    }
+
+    /// Consumes the tree, checking the elements handed out in **post-order** to be a
+    /// permutation of the [BTreeSet].
+    fn into_post_order_iter(self) -> IntoIter<T> {
+        let elements = self.bst.into_post_order_iter();
+        ShadowBST::<T, B>::verify_consumed(self.shadow, elements, false, "into_post_order_iter")
+    }
+
+    /// Consumes the tree, checking the elements handed out in **level-order** to be a
+    /// permutation of the [BTreeSet].
+    fn into_level_order_iter(self) -> IntoIter<T> {
+        let elements = self.bst.into_level_order_iter();
+        ShadowBST::<T, B>::verify_consumed(self.shadow, elements, false, "into_level_order_iter")
+    }
+}
+
+#[cfg(all(test, feature = "iterative", feature = "recursive"))]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::{BinarySearchTree, IterativeBST, RecursiveBST, ShadowBST};
+
+    /// Small deterministic generator so the operation sequences are reproducible.
+    fn next(seed: &mut u64) -> u64 {
+        *seed ^= *seed << 13;
+        *seed ^= *seed >> 7;
+        *seed ^= *seed << 17;
+        *seed
+    }
+
+    fn exercise<B: BinarySearchTree<u64> + Default>() {
+        let mut bst: ShadowBST<u64, B> = ShadowBST::default();
+        let mut seed = 0x9E37_79B9_7F4A_7C15;
+
+        for _ in 0..2000 {
+            let value = next(&mut seed) % 64;
+            match next(&mut seed) % 6 {
+                0 | 1 => bst.insert(value),
+                2 => bst.remove(&value),
+                3 => {
+                    bst.remove_min();
+                }
+                4 => {
+                    bst.remove_max();
+                }
+                _ => {
+                    bst.contains(&value);
+                    bst.retrieve(&value);
+                    bst.min();
+                    bst.max();
+                    bst.height();
+                    bst.pre_order_vec();
+                    bst.post_order_vec();
+                    bst.level_order_vec();
+                }
+            }
+        }
+
+        let expected = bst.asc_order_iter().cloned().collect::<Vec<u64>>();
+        assert_eq!(bst.into_post_order_iter().count(), expected.len());
+    }
+
+    #[test]
+    fn successfully_shadow_random_operations_on_iterative_bst() {
+        exercise::<IterativeBST<u64>>();
+    }
+
+    #[test]
+    fn successfully_shadow_random_operations_on_recursive_bst() {
+        exercise::<RecursiveBST<u64>>();
+    }
+
+    #[test]
+    fn successfully_shadow_consuming_traversals() {
+        let values = vec![8, 4, 12, 2, 6, 10, 14, 1];
+        let shadow = |values: &Vec<i32>| -> ShadowBST<i32, IterativeBST<i32>> {
+            ShadowBST::from_iter(values.clone())
+        };
+
+        assert_eq!(
+            shadow(&values).into_asc_order_iter().collect::<Vec<i32>>(),
+            vec![1, 2, 4, 6, 8, 10, 12, 14]
+        );
+        assert_eq!(
+            shadow(&values).into_pre_order_iter().collect::<Vec<i32>>(),
+            vec![8, 4, 2, 1, 6, 12, 10, 14]
+        );
+        assert_eq!(
+            shadow(&values)
+                .into_level_order_iter()
+                .collect::<Vec<i32>>(),
+            vec![8, 4, 12, 2, 6, 10, 14, 1]
+        );
+    }
+
+    /// Element whose ordering ignores the flag, so a tree mixing up equal elements is caught.
+    #[derive(Debug, Clone)]
+    struct Tagged(i32, bool);
+
+    impl PartialEq for Tagged {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0 && self.1 == other.1
+        }
+    }
+
+    impl Eq for Tagged {}
+
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Tagged {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "shadow: contents diverged after retrieve_as_mut")]
+    fn panic_on_tree_diverging_from_btree_set() {
+        let mut bst: ShadowBST<Tagged, RecursiveBST<Tagged>> = ShadowBST::default();
+        bst.insert(Tagged(1, false));
+
+        bst.retrieve_as_mut(&Tagged(1, false)).unwrap().1 = true;
+        bst.verify("retrieve_as_mut");
+    }
+}
@@ -0,0 +1,294 @@
+use std::fmt::{Display, Write};
+use std::str::FromStr;
+
+use crate::bst::{Bst, Strategy};
+use crate::iter::SortedIter;
+use crate::node::{HeapNode, Node};
+use crate::ShapeError;
+
+/// Piece of a shape string still to be written by [Bst::to_shape_string()].
+enum Pending<'a, T: Ord> {
+    Subtree(&'a Node<T>),
+    Text(&'static str),
+}
+
+/// Node whose children are still being parsed by [Bst::from_shape_string()].
+struct Open<T: Ord> {
+    node: Box<Node<T>>,
+    /// Offset of the value of the node within the shape.
+    position: usize,
+    /// Whether the left child has been parsed already.
+    right: bool,
+}
+
+impl<T: Ord + Display, S: Strategy> Bst<T, S> {
+    /// Returns a compact encoding of the **exact structure** of the tree along with its values.
+    ///
+    /// Each node is written as its value, followed by its children in parentheses separated by
+    /// a comma if it has any: `value(left,right)`. Missing children are left empty and leaves
+    /// are written as their value alone, while an empty tree is an empty string.
+    ///
+    /// Unlike the traversals, the shape string tells trees with the same elements apart, which
+    /// makes it suited to golden-file snapshot tests of the shapes produced by insertion and
+    /// balancing logic. [Bst::from_shape_string()] rebuilds the same tree.
+    ///
+    /// Values are written with [Display], and must not contain any of `(`, `,` or `)` to be
+    /// read back.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, RecursiveBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //      4
+    ///  //     / \
+    ///  //    2   6
+    ///  //     \
+    ///  //      3
+    /// let bst = IterativeBST::from_iter(vec![4, 2, 6, 3]);
+    /// assert_eq!(bst.to_shape_string(), "4(2(,3),6)");
+    ///
+    /// // Same elements, different shape
+    /// let chain = RecursiveBST::from_iter(vec![2, 3, 4, 6]);
+    /// assert_eq!(chain.to_shape_string(), "2(,3(,4(,6)))");
+    /// ```
+    pub fn to_shape_string(&self) -> String {
+        let mut shape = String::new();
+        let mut pending = Vec::from_iter(self.root.as_deref().map(Pending::Subtree));
+
+        while let Some(current) = pending.pop() {
+            let node = match current {
+                Pending::Text(text) => {
+                    shape.push_str(text);
+                    continue;
+                }
+                Pending::Subtree(node) => node,
+            };
+            write!(shape, "{}", node.value).unwrap();
+            if node.left.is_none() && node.right.is_none() {
+                continue;
+            }
+
+            pending.push(Pending::Text(")"));
+            pending.extend(node.right.as_deref().map(Pending::Subtree));
+            pending.push(Pending::Text(","));
+            pending.extend(node.left.as_deref().map(Pending::Subtree));
+            pending.push(Pending::Text("("));
+        }
+
+        shape
+    }
+}
+
+impl<T: Ord + FromStr, S: Strategy> Bst<T, S> {
+    /// Rebuilds a tree with the exact structure encoded by [Bst::to_shape_string()].
+    ///
+    /// Trailing whitespace is ignored, so shapes can be read straight from golden files.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [ShapeError] pointing at the offending position if the shape is malformed, a
+    /// value can't be parsed with [FromStr], or the values are not in strictly ascending order
+    /// from left to right.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST, ShapeError};
+    ///
+    /// let bst = RecursiveBST::<i32>::from_shape_string("4(2(,3),6)\n").unwrap();
+    /// assert_eq!(bst.pre_order_vec(), vec![&4, &2, &3, &6]);
+    /// assert_eq!(bst.size(), 4);
+    ///
+    /// let error = RecursiveBST::<i32>::from_shape_string("4(2,x)").unwrap_err();
+    /// assert_eq!(error, ShapeError::InvalidValue { position: 4 });
+    /// ```
+    pub fn from_shape_string(shape: &str) -> Result<Bst<T, S>, ShapeError> {
+        let shape = shape.trim_end();
+        let bytes = shape.as_bytes();
+        let mut position = 0;
+        // Offsets of the values in the order of their elements, to point at unordered values
+        let mut in_order = Vec::new();
+        let mut open: Vec<Open<T>> = Vec::new();
+
+        let root = 'parse: loop {
+            let mut subtree: HeapNode<T> = None;
+            if !matches!(bytes.get(position), None | Some(b',') | Some(b')')) {
+                let start = position;
+                while !matches!(bytes.get(position), None | Some(b'(' | b',' | b')')) {
+                    position += 1;
+                }
+                let value = shape[start..position]
+                    .parse()
+                    .map_err(|_| ShapeError::InvalidValue { position: start })?;
+                let node = Box::new(Node::new(value));
+
+                if bytes.get(position) == Some(&b'(') {
+                    position += 1;
+                    open.push(Open {
+                        node,
+                        position: start,
+                        right: false,
+                    });
+                    continue;
+                }
+                in_order.push(start);
+                subtree = Some(node);
+            }
+
+            // Hand the finished subtree to its parent, closing every parent it completes
+            loop {
+                let parent = match open.last_mut() {
+                    None => break 'parse subtree,
+                    Some(parent) => parent,
+                };
+                let expected = if parent.right { b')' } else { b',' };
+                match bytes.get(position) {
+                    Some(byte) if *byte == expected => position += 1,
+                    Some(_) => return Err(ShapeError::UnexpectedChar { position }),
+                    None => return Err(ShapeError::UnexpectedEnd { position }),
+                }
+
+                if !parent.right {
+                    parent.node.left = subtree;
+                    parent.right = true;
+                    in_order.push(parent.position);
+                    continue 'parse;
+                }
+                parent.node.right = subtree;
+                subtree = open.pop().map(|parent| parent.node);
+            }
+        };
+        if position < bytes.len() {
+            return Err(ShapeError::UnexpectedChar { position });
+        }
+
+        let mut elements = SortedIter::ascending(&root).zip(in_order.iter());
+        if let Some((mut previous, _)) = elements.next() {
+            for (current, position) in elements {
+                if previous >= current {
+                    return Err(ShapeError::Unordered {
+                        position: *position,
+                    });
+                }
+                previous = current;
+            }
+        }
+
+        let mut bst = Bst::new();
+        bst.size = in_order.len();
+        bst.root = root;
+        Ok(bst)
+    }
+}
+
+impl<T: Ord + FromStr, S: Strategy> TryFrom<&str> for Bst<T, S> {
+    type Error = ShapeError;
+
+    /// Builds a tree with the exact structure of a shape string, see
+    /// [Bst::from_shape_string()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, ShapeError};
+    ///
+    /// let bst = IterativeBST::<u8>::try_from("4(2(1,3),6(5,7))").unwrap();
+    /// assert_eq!(bst.level_order_vec(), vec![&4, &2, &6, &1, &3, &5, &7]);
+    ///
+    /// let error = IterativeBST::<u8>::try_from("4(2(1,3),6(5,7)").unwrap_err();
+    /// assert_eq!(error, ShapeError::UnexpectedEnd { position: 15 });
+    /// ```
+    fn try_from(shape: &str) -> Result<Self, Self::Error> {
+        Bst::from_shape_string(shape)
+    }
+}
+
+#[cfg(all(test, feature = "iterative", feature = "recursive"))]
+mod tests {
+    use crate::{BinarySearchTree, IterativeBST, RecursiveBST, ShapeError};
+
+    #[test]
+    fn successfully_round_trip_exact_shapes() {
+        let shapes = [
+            "",
+            "1",
+            "8(4(2(1,3),6(5,7)),12(10(9,11),14(13,15)))",
+            "5(,6(,7(,8)))",
+            "5(4(3(2,),),)",
+            "20(10(,15(12,)),30)",
+        ];
+
+        for shape in shapes {
+            let iterative = IterativeBST::<i32>::from_shape_string(shape).unwrap();
+            let recursive = RecursiveBST::<i32>::from_shape_string(shape).unwrap();
+
+            assert_eq!(iterative.to_shape_string(), shape);
+            assert_eq!(recursive.to_shape_string(), shape);
+            assert_eq!(iterative.pre_order_vec(), recursive.pre_order_vec());
+            assert_eq!(iterative.size(), iterative.in_order_vec().len());
+        }
+
+        let fixture: RecursiveBST<u8> = "4(2(1,3),6(5,))".try_into().unwrap();
+        assert_eq!(fixture.to_shape_string(), "4(2(1,3),6(5,))");
+        assert_eq!(
+            RecursiveBST::<u8>::try_from("4(2(1,3),6(5,))"),
+            RecursiveBST::from_shape_string("4(2(1,3),6(5,))")
+        );
+
+        let words = IterativeBST::from_iter(vec!["m", "c", "x", "a"].into_iter().map(String::from));
+        assert_eq!(words.to_shape_string(), "m(c(a,),x)");
+        assert_eq!(
+            IterativeBST::<String>::from_shape_string(&words.to_shape_string()).unwrap(),
+            words
+        );
+    }
+
+    #[test]
+    fn successfully_round_trip_deep_chain() {
+        let chain = IterativeBST::from_iter(0..5_000);
+        let shape = chain.to_shape_string();
+
+        let read = IterativeBST::<i32>::from_shape_string(&shape).unwrap();
+        assert_eq!(read.size(), 5_000);
+        assert_eq!(read.height(), Some(4_999));
+        assert_eq!(read.to_shape_string(), shape);
+    }
+
+    #[test]
+    fn verify_malformed_shapes_are_rejected() {
+        let parse = IterativeBST::<i32>::from_shape_string;
+
+        assert_eq!(parse("1(2"), Err(ShapeError::UnexpectedEnd { position: 3 }));
+        assert_eq!(
+            parse("2(1,3"),
+            Err(ShapeError::UnexpectedEnd { position: 5 })
+        );
+        assert_eq!(
+            parse("2(1)"),
+            Err(ShapeError::UnexpectedChar { position: 3 })
+        );
+        assert_eq!(
+            parse("2(1,3))"),
+            Err(ShapeError::UnexpectedChar { position: 6 })
+        );
+        assert_eq!(
+            parse("1,2"),
+            Err(ShapeError::UnexpectedChar { position: 1 })
+        );
+        assert_eq!(
+            parse("2(a,3)"),
+            Err(ShapeError::InvalidValue { position: 2 })
+        );
+        assert_eq!(parse("2(1,2)"), Err(ShapeError::Unordered { position: 4 }));
+        assert_eq!(
+            parse("5(3(,6),7)"),
+            Err(ShapeError::Unordered { position: 0 })
+        );
+        assert_eq!(
+            ShapeError::UnexpectedEnd { position: 3 }.to_string(),
+            "shape ends unexpectedly at 3"
+        );
+    }
+}
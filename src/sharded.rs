@@ -0,0 +1,366 @@
+use std::fmt::{Debug, Formatter};
+use std::sync::{RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::{BinarySearchTree, IterativeBST};
+
+/// Binary Search Tree split into several [IterativeBST] shards, each behind its own lock, so
+/// threads writing to different parts of the key space don't wait on each other.
+///
+/// The key space is partitioned by sorted boundaries: the first shard holds the elements
+/// smaller than the first boundary, the next one the elements from the first boundary up to
+/// the second one, and so on. Shards are therefore ordered as well, which lets merged
+/// traversals simply walk them one after another.
+///
+/// All operations take `&self`, the tree can be shared between threads through an
+/// [Arc](std::sync::Arc) or a scoped thread. Elements can't be borrowed out of a locked shard,
+/// so lookups return owned values or hand a reference to a closure instead.
+///
+/// This is a pragmatic alternative to a concurrent tree: operations on the same shard are
+/// serialized, and boundaries should be picked to spread the expected keys evenly.
+///
+/// # Panics
+///
+/// Operations panic if a thread panicked while modifying the same shard, e.g. because the
+/// [Ord] implementation of `T` panicked.
+///
+/// # Example
+///
+/// ```rust
+/// use std::thread;
+/// use bst_rs::ShardedBST;
+///
+/// let bst = ShardedBST::with_boundaries(vec![250, 500, 750]);
+///
+/// thread::scope(|scope| {
+///     for start in [0, 250, 500, 750] {
+///         let bst = &bst;
+///         scope.spawn(move || {
+///             for value in start..start + 250 {
+///                 bst.insert(value);
+///             }
+///         });
+///     }
+/// });
+///
+/// assert_eq!(bst.size(), 1000);
+/// assert_eq!(bst.shard_sizes(), vec![250, 250, 250, 250]);
+/// assert_eq!(bst.min(), Some(0));
+/// ```
+pub struct ShardedBST<T: Ord> {
+    boundaries: Vec<T>,
+    shards: Vec<RwLock<IterativeBST<T>>>,
+}
+
+impl<T: Ord> ShardedBST<T> {
+    /// Creates an empty `ShardedBST<T>` with one shard more than there are distinct
+    /// `boundaries`.
+    ///
+    /// The boundaries don't need to be sorted, duplicates are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::ShardedBST;
+    ///
+    /// let bst = ShardedBST::with_boundaries(vec!["m", "f", "s", "m"]);
+    ///
+    /// assert_eq!(bst.shard_count(), 4);
+    /// assert!(bst.is_empty());
+    /// ```
+    pub fn with_boundaries(mut boundaries: Vec<T>) -> ShardedBST<T> {
+        boundaries.sort();
+        boundaries.dedup();
+        let shards = (0..=boundaries.len())
+            .map(|_| RwLock::new(IterativeBST::new()))
+            .collect();
+
+        ShardedBST { boundaries, shards }
+    }
+
+    /// Returns the number of shards the key space is split into.
+    pub fn shard_count(&self) -> usize {
+        self.shards.len()
+    }
+
+    /// Returns the **number of elements** of every shard, in the order of their keys.
+    pub fn shard_sizes(&self) -> Vec<usize> {
+        (0..self.shards.len())
+            .map(|index| self.read(index).size())
+            .collect()
+    }
+
+    /// Returns the total **number of elements** within the tree.
+    ///
+    /// Shards are counted one after another, so concurrent modifications of shards which have
+    /// already been counted are missed.
+    pub fn size(&self) -> usize {
+        self.shard_sizes().into_iter().sum()
+    }
+
+    /// Returns `true` if no shard holds any element.
+    pub fn is_empty(&self) -> bool {
+        (0..self.shards.len()).all(|index| self.read(index).is_empty())
+    }
+
+    /// Inserts the given value into its shard, returning `false` if it was already present.
+    ///
+    /// **Duplicate values are _not allowed_**.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::ShardedBST;
+    ///
+    /// let bst = ShardedBST::with_boundaries(vec![10]);
+    ///
+    /// assert!(bst.insert(5));
+    /// assert!(bst.insert(15));
+    /// assert!(!bst.insert(5));   // Element is not inserted
+    /// assert_eq!(bst.shard_sizes(), vec![1, 1]);
+    /// ```
+    pub fn insert(&self, value: T) -> bool {
+        let mut shard = self.write(self.shard_of(&value));
+        let size = shard.size();
+        shard.insert(value);
+        shard.size() != size
+    }
+
+    /// Removes the given value from its shard, returning `false` if it was not present.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::ShardedBST;
+    ///
+    /// let bst = ShardedBST::with_boundaries(vec![10]);
+    /// bst.insert(5);
+    ///
+    /// assert!(bst.remove(&5));
+    /// assert!(!bst.remove(&5));
+    /// assert!(bst.is_empty());
+    /// ```
+    pub fn remove(&self, value: &T) -> bool {
+        let mut shard = self.write(self.shard_of(value));
+        let size = shard.size();
+        shard.remove(value);
+        shard.size() != size
+    }
+
+    /// Returns `true` if the tree contains an element with the given value.
+    pub fn contains(&self, value: &T) -> bool {
+        self.read(self.shard_of(value)).contains(value)
+    }
+
+    /// Calls `f` with a reference to the element equal to `value`, returning its result or
+    /// `None` if the element does not exist.
+    ///
+    /// The shard stays read-locked while `f` runs.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::ShardedBST;
+    ///
+    /// let bst = ShardedBST::with_boundaries(vec![String::from("m")]);
+    /// bst.insert(String::from("zebra"));
+    ///
+    /// assert_eq!(bst.retrieve_with(&String::from("zebra"), |value| value.len()), Some(5));
+    /// assert_eq!(bst.retrieve_with(&String::from("ant"), |value| value.len()), None);
+    /// ```
+    pub fn retrieve_with<R, F: FnOnce(&T) -> R>(&self, value: &T, f: F) -> Option<R> {
+        self.read(self.shard_of(value)).retrieve(value).map(f)
+    }
+
+    /// Removes and returns the minimum element from the tree or `None` if tree is empty.
+    pub fn remove_min(&self) -> Option<T> {
+        (0..self.shards.len()).find_map(|index| self.write(index).remove_min())
+    }
+
+    /// Removes and returns the maximum element from the tree or `None` if tree is empty.
+    pub fn remove_max(&self) -> Option<T> {
+        (0..self.shards.len())
+            .rev()
+            .find_map(|index| self.write(index).remove_max())
+    }
+
+    /// Calls `f` on every element in **ascending order**, walking the shards one after another.
+    ///
+    /// Only the shard being walked is read-locked, so the other shards can be modified in the
+    /// meantime.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::ShardedBST;
+    ///
+    /// let bst = ShardedBST::with_boundaries(vec![3, 6]);
+    /// for value in [7, 1, 4, 8, 2] {
+    ///     bst.insert(value);
+    /// }
+    ///
+    /// let mut elements = Vec::new();
+    /// bst.for_each(|value| elements.push(*value));
+    /// assert_eq!(elements, vec![1, 2, 4, 7, 8]);
+    /// ```
+    pub fn for_each<F: FnMut(&T)>(&self, mut f: F) {
+        for index in 0..self.shards.len() {
+            self.read(index).asc_order_iter().for_each(&mut f);
+        }
+    }
+
+    /// Returns an iterator over the elements of all shards in **ascending order**, consuming
+    /// the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::ShardedBST;
+    ///
+    /// let bst = ShardedBST::with_boundaries(vec![3, 6]);
+    /// for value in [7, 1, 4, 8, 2] {
+    ///     bst.insert(value);
+    /// }
+    ///
+    /// assert_eq!(bst.into_asc_order_iter().collect::<Vec<i32>>(), vec![1, 2, 4, 7, 8]);
+    /// ```
+    pub fn into_asc_order_iter(self) -> impl Iterator<Item = T> {
+        self.shards.into_iter().flat_map(|shard| {
+            shard
+                .into_inner()
+                .expect("shard lock poisoned")
+                .into_asc_order_iter()
+        })
+    }
+
+    /// Merges all shards into a single **balanced** [IterativeBST], consuming the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ShardedBST};
+    ///
+    /// let bst = ShardedBST::with_boundaries(vec![4]);
+    /// for value in 1..=7 {
+    ///     bst.insert(value);
+    /// }
+    ///
+    /// let merged = bst.into_inner();
+    /// assert_eq!(merged.height(), Some(2));
+    /// assert_eq!(merged.size(), 7);
+    /// ```
+    pub fn into_inner(self) -> IterativeBST<T> {
+        IterativeBST::from_sorted(self.into_asc_order_iter().collect(), None, None)
+    }
+
+    /// Returns the index of the shard responsible for `value`.
+    fn shard_of(&self, value: &T) -> usize {
+        self.boundaries
+            .partition_point(|boundary| boundary <= value)
+    }
+
+    fn read(&self, index: usize) -> RwLockReadGuard<'_, IterativeBST<T>> {
+        self.shards[index].read().expect("shard lock poisoned")
+    }
+
+    fn write(&self, index: usize) -> RwLockWriteGuard<'_, IterativeBST<T>> {
+        self.shards[index].write().expect("shard lock poisoned")
+    }
+}
+
+impl<T: Ord + Clone> ShardedBST<T> {
+    /// Returns a copy of the minimum element of the tree or `None` if tree is empty.
+    pub fn min(&self) -> Option<T> {
+        (0..self.shards.len()).find_map(|index| self.read(index).min().cloned())
+    }
+
+    /// Returns a copy of the maximum element of the tree or `None` if tree is empty.
+    pub fn max(&self) -> Option<T> {
+        (0..self.shards.len())
+            .rev()
+            .find_map(|index| self.read(index).max().cloned())
+    }
+}
+
+impl<T: Ord> Default for ShardedBST<T> {
+    /// Creates an empty `ShardedBST<T>` with a single shard
+    fn default() -> ShardedBST<T> {
+        ShardedBST::with_boundaries(Vec::new())
+    }
+}
+
+impl<T: Ord + Debug> Debug for ShardedBST<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ShardedBST")
+            .field("boundaries", &self.boundaries)
+            .field("shard_sizes", &self.shard_sizes())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use crate::{BinarySearchTree, ShardedBST};
+
+    #[test]
+    fn successfully_insert_and_remove_from_many_threads() {
+        let bst = ShardedBST::with_boundaries(vec![100, 200, 300]);
+
+        thread::scope(|scope| {
+            for thread in 0..4 {
+                let bst = &bst;
+                scope.spawn(move || {
+                    // Every thread touches every shard
+                    for value in (thread..400).step_by(4) {
+                        assert!(bst.insert(value));
+                    }
+                    for value in (thread..400).step_by(8) {
+                        assert!(bst.remove(&value));
+                    }
+                });
+            }
+        });
+
+        assert_eq!(bst.size(), 200);
+        assert_eq!(bst.shard_sizes(), vec![48, 52, 48, 52]);
+        assert!(bst.contains(&4));
+        assert!(!bst.contains(&0));
+
+        let merged = bst.into_inner();
+        assert_eq!(merged.size(), 200);
+        assert_eq!(merged.min(), Some(&4));
+        assert_eq!(merged.max(), Some(&399));
+    }
+
+    #[test]
+    fn successfully_route_elements_at_boundaries() {
+        let bst = ShardedBST::with_boundaries(vec![20, 10]);
+        for value in [10, 9, 20, 30, 19] {
+            bst.insert(value);
+        }
+
+        assert_eq!(bst.shard_sizes(), vec![1, 2, 2]);
+        assert_eq!(bst.min(), Some(9));
+        assert_eq!(bst.max(), Some(30));
+        assert_eq!(bst.remove_min(), Some(9));
+        assert_eq!(bst.remove_max(), Some(30));
+        assert_eq!(bst.remove_max(), Some(20));
+        assert_eq!(bst.shard_sizes(), vec![0, 2, 0]);
+        assert_eq!(
+            format!("{:?}", bst),
+            "ShardedBST { boundaries: [10, 20], shard_sizes: [0, 2, 0] }"
+        );
+    }
+
+    #[test]
+    fn successfully_use_single_shard() {
+        let bst: ShardedBST<i32> = ShardedBST::default();
+        assert_eq!(bst.shard_count(), 1);
+        assert_eq!(bst.min(), None);
+        assert_eq!(bst.remove_min(), None);
+
+        bst.insert(1);
+        assert_eq!(bst.into_asc_order_iter().collect::<Vec<i32>>(), vec![1]);
+    }
+}
@@ -0,0 +1,126 @@
+use std::cmp::Ordering;
+
+use crate::node::{HeapNode, Node};
+
+/// Sorts the given elements in ascending order by inserting them into a binary search tree and
+/// reading it back in-order, a **tree sort**.
+///
+/// Duplicates are kept and the sort is stable: equal elements are inserted into the right
+/// subtree of each other and therefore come out in their original order.
+///
+/// # Complexity
+///
+/// Every insertion walks from the root down to a leaf, so sorting `n` elements takes
+/// `O(n log n)` comparisons on average. Already sorted (or reverse sorted) input degenerates
+/// the tree into a list and takes `O(n²)`. One node is allocated per element, `O(n)` space.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::bst_sort;
+///
+/// assert_eq!(bst_sort(vec![5, 1, 4, 1, 3]), vec![1, 1, 3, 4, 5]);
+/// ```
+pub fn bst_sort<T: Ord>(elements: Vec<T>) -> Vec<T> {
+    let mut root: HeapNode<T> = None;
+    for value in elements {
+        let slot = Node::iterative_slot_by(&mut root, |current| {
+            current.cmp(&value).then(Ordering::Less)
+        });
+        *slot = Some(Box::new(Node::new(value)));
+    }
+
+    Node::iterative_consume_in_order_vec(root)
+}
+
+/// Sorts the given elements in ascending order through a binary search tree, dropping
+/// duplicates.
+///
+/// Of several equal elements only the first one is kept. The complexity is the same as the
+/// one of [bst_sort()].
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::bst_sort_dedup;
+///
+/// assert_eq!(bst_sort_dedup(vec![5, 1, 4, 1, 3]), vec![1, 3, 4, 5]);
+/// ```
+pub fn bst_sort_dedup<T: Ord>(elements: Vec<T>) -> Vec<T> {
+    let mut root: HeapNode<T> = None;
+    for value in elements {
+        // Duplicates are rejected by the insertion itself
+        let _ = Node::iterative_insert(&mut root, value);
+    }
+
+    Node::iterative_consume_in_order_vec(root)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+
+    use crate::{bst_sort, bst_sort_dedup};
+
+    /// Element of which only the key takes part in comparisons.
+    #[derive(Debug, PartialEq, Eq)]
+    struct Tagged(i32, char);
+
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Tagged {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    #[test]
+    fn successfully_sort_like_std() {
+        let elements = vec![8, -3, 12, 0, 8, 5, -3, 99, 1, 0];
+
+        let mut expected = elements.clone();
+        expected.sort();
+
+        assert_eq!(bst_sort(elements), expected);
+        assert_eq!(bst_sort(Vec::<i32>::new()), vec![]);
+    }
+
+    #[test]
+    fn successfully_keep_equal_elements_in_original_order() {
+        let sorted = bst_sort(vec![
+            Tagged(2, 'a'),
+            Tagged(1, 'b'),
+            Tagged(2, 'c'),
+            Tagged(2, 'd'),
+        ]);
+
+        assert_eq!(
+            sorted,
+            vec![
+                Tagged(1, 'b'),
+                Tagged(2, 'a'),
+                Tagged(2, 'c'),
+                Tagged(2, 'd')
+            ]
+        );
+    }
+
+    #[test]
+    fn successfully_keep_first_of_equal_elements_when_deduplicating() {
+        let sorted = bst_sort_dedup(vec![Tagged(2, 'a'), Tagged(1, 'b'), Tagged(2, 'c')]);
+
+        assert_eq!(sorted, vec![Tagged(1, 'b'), Tagged(2, 'a')]);
+    }
+
+    #[test]
+    fn successfully_sort_already_sorted_input() {
+        let elements: Vec<u32> = (0..5000).collect();
+
+        assert_eq!(bst_sort(elements.clone()), elements);
+        assert_eq!(bst_sort_dedup(elements.clone()), elements);
+    }
+}
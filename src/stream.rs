@@ -0,0 +1,386 @@
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+
+use crate::bst::{Bst, Strategy};
+use crate::iter::SortedIter;
+
+/// Encoding used by [Bst::write_to()] and [Bst::read_from()].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamFormat {
+    /// One JSON value per line, in ascending order.
+    JsonSequence,
+    /// The number of elements as a little-endian `u64` followed by the encoded elements, in
+    /// ascending order. Integers are encoded as fixed-width little-endian, booleans as a single
+    /// byte and strings as their length (little-endian `u64`) followed by their UTF-8 bytes.
+    Binary,
+}
+
+/// Element which can be written and read by [Bst::write_to()] and [Bst::read_from()].
+///
+/// Implemented for all primitive integer types, [bool] and [String].
+pub trait StreamElement: Sized {
+    /// Writes the element as a single JSON value, without any line break.
+    fn write_json<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+
+    /// Parses an element from a single JSON value.
+    fn read_json(json: &str) -> io::Result<Self>;
+
+    /// Writes the element in the binary encoding.
+    fn write_binary<W: Write>(&self, writer: &mut W) -> io::Result<()>;
+
+    /// Reads an element in the binary encoding.
+    fn read_binary<R: Read>(reader: &mut R) -> io::Result<Self>;
+}
+
+fn invalid_data(message: &str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+macro_rules! impl_stream_element {
+    ($($primitive:ty),*) => {
+        $(
+            impl StreamElement for $primitive {
+                fn write_json<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                    write!(writer, "{}", self)
+                }
+
+                fn read_json(json: &str) -> io::Result<$primitive> {
+                    json.parse()
+                        .map_err(|_| invalid_data(concat!("expected ", stringify!($primitive))))
+                }
+
+                fn write_binary<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+                    writer.write_all(&self.to_le_bytes())
+                }
+
+                fn read_binary<R: Read>(reader: &mut R) -> io::Result<$primitive> {
+                    let mut bytes = [0; std::mem::size_of::<$primitive>()];
+                    reader.read_exact(&mut bytes)?;
+                    Ok(<$primitive>::from_le_bytes(bytes))
+                }
+            }
+        )*
+    };
+}
+
+impl_stream_element!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl StreamElement for bool {
+    fn write_json<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(writer, "{}", self)
+    }
+
+    fn read_json(json: &str) -> io::Result<bool> {
+        json.parse().map_err(|_| invalid_data("expected bool"))
+    }
+
+    fn write_binary<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(&[*self as u8])
+    }
+
+    fn read_binary<R: Read>(reader: &mut R) -> io::Result<bool> {
+        match u8::read_binary(reader)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(invalid_data("expected bool")),
+        }
+    }
+}
+
+/// Reads the four hexadecimal digits of a `\u` escape.
+fn read_hex_escape(chars: &mut std::str::Chars) -> io::Result<u32> {
+    let digits: String = chars.take(4).collect();
+    if digits.len() != 4 {
+        return Err(invalid_data("truncated unicode escape"));
+    }
+    u32::from_str_radix(&digits, 16).map_err(|_| invalid_data("invalid unicode escape"))
+}
+
+impl StreamElement for String {
+    fn write_json<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(b"\"")?;
+        for char in self.chars() {
+            match char {
+                '"' => writer.write_all(b"\\\"")?,
+                '\\' => writer.write_all(b"\\\\")?,
+                '\n' => writer.write_all(b"\\n")?,
+                '\r' => writer.write_all(b"\\r")?,
+                '\t' => writer.write_all(b"\\t")?,
+                char if char.is_control() => write!(writer, "\\u{:04x}", char as u32)?,
+                char => write!(writer, "{}", char)?,
+            }
+        }
+        writer.write_all(b"\"")
+    }
+
+    fn read_json(json: &str) -> io::Result<String> {
+        let inner = json
+            .strip_prefix('"')
+            .and_then(|json| json.strip_suffix('"'))
+            .ok_or_else(|| invalid_data("expected string"))?;
+
+        let mut string = String::with_capacity(inner.len());
+        let mut chars = inner.chars();
+        while let Some(char) = chars.next() {
+            let unescaped = match char {
+                '\\' => match chars.next() {
+                    Some('"') => '"',
+                    Some('\\') => '\\',
+                    Some('/') => '/',
+                    Some('b') => '\u{8}',
+                    Some('f') => '\u{c}',
+                    Some('n') => '\n',
+                    Some('r') => '\r',
+                    Some('t') => '\t',
+                    Some('u') => {
+                        let mut code = read_hex_escape(&mut chars)?;
+                        if (0xD800..0xDC00).contains(&code) {
+                            // High surrogate, which must be followed by an escaped low one
+                            if chars.next() != Some('\\') || chars.next() != Some('u') {
+                                return Err(invalid_data("unpaired surrogate"));
+                            }
+                            let low = read_hex_escape(&mut chars)?;
+                            if !(0xDC00..0xE000).contains(&low) {
+                                return Err(invalid_data("unpaired surrogate"));
+                            }
+                            code = 0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00);
+                        }
+                        char::from_u32(code).ok_or_else(|| invalid_data("unpaired surrogate"))?
+                    }
+                    _ => return Err(invalid_data("invalid escape")),
+                },
+                '"' => return Err(invalid_data("unescaped quote")),
+                char if (char as u32) < 0x20 => return Err(invalid_data("unescaped control")),
+                char => char,
+            };
+            string.push(unescaped);
+        }
+
+        Ok(string)
+    }
+
+    fn write_binary<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        (self.len() as u64).write_binary(writer)?;
+        writer.write_all(self.as_bytes())
+    }
+
+    fn read_binary<R: Read>(reader: &mut R) -> io::Result<String> {
+        let len = u64::read_binary(reader)?;
+        let mut bytes = Vec::new();
+        // Bounded by what the reader actually yields, not by the possibly corrupt length
+        reader.take(len).read_to_end(&mut bytes)?;
+        if bytes.len() as u64 != len {
+            return Err(io::ErrorKind::UnexpectedEof.into());
+        }
+        String::from_utf8(bytes).map_err(|_| invalid_data("invalid UTF-8"))
+    }
+}
+
+impl<T: Ord + StreamElement, S: Strategy> Bst<T, S> {
+    /// Writes every element to `writer` in **ascending order** using the given format, one
+    /// element at a time.
+    ///
+    /// Elements are encoded one at a time while walking the tree, without building a `Vec` or
+    /// `String` of the whole tree first, so trees of any size can be streamed to disk or over
+    /// the network. The writer is buffered internally.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, StreamFormat};
+    ///
+    /// let bst = IterativeBST::from(vec![3, 1, 2]);
+    ///
+    /// let mut json = Vec::new();
+    /// bst.write_to(&mut json, StreamFormat::JsonSequence).unwrap();
+    /// assert_eq!(json, b"1\n2\n3\n");
+    /// ```
+    pub fn write_to<W: Write>(&self, writer: W, format: StreamFormat) -> io::Result<()> {
+        let mut writer = BufWriter::new(writer);
+        match format {
+            StreamFormat::JsonSequence => {
+                for value in SortedIter::ascending(&self.root) {
+                    value.write_json(&mut writer)?;
+                    writer.write_all(b"\n")?;
+                }
+            }
+            StreamFormat::Binary => {
+                (self.size as u64).write_binary(&mut writer)?;
+                for value in SortedIter::ascending(&self.root) {
+                    value.write_binary(&mut writer)?;
+                }
+            }
+        }
+        writer.flush()
+    }
+
+    /// Reads a tree written by [Bst::write_to()] from `reader` using the given format.
+    ///
+    /// The elements are expected in strictly ascending order, which lets the tree be built
+    /// perfectly balanced in O(n). As with any deserialization, the input should be trusted or
+    /// bounded, elements are read until the end of the input.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [InvalidData](io::ErrorKind::InvalidData) if an element can't
+    /// be decoded or the elements are not in strictly ascending order, and the errors of
+    /// `reader` itself, e.g. [UnexpectedEof](io::ErrorKind::UnexpectedEof) for truncated
+    /// binary input.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST, StreamFormat};
+    ///
+    /// let bst = RecursiveBST::from(vec![String::from("b"), String::from("a")]);
+    ///
+    /// let mut bytes = Vec::new();
+    /// bst.write_to(&mut bytes, StreamFormat::Binary).unwrap();
+    ///
+    /// let read = RecursiveBST::read_from(bytes.as_slice(), StreamFormat::Binary).unwrap();
+    /// assert_eq!(read, bst);
+    ///
+    /// let unsorted = RecursiveBST::<i32>::read_from(&b"2\n1\n"[..], StreamFormat::JsonSequence);
+    /// assert!(unsorted.is_err());
+    /// ```
+    pub fn read_from<R: Read>(reader: R, format: StreamFormat) -> io::Result<Bst<T, S>> {
+        let mut reader = BufReader::new(reader);
+        let mut elements: Vec<T> = Vec::new();
+        let mut push = |value: T| {
+            if elements.last().is_some_and(|last| *last >= value) {
+                return Err(invalid_data("elements are not in strictly ascending order"));
+            }
+            elements.push(value);
+            Ok(())
+        };
+
+        match format {
+            StreamFormat::JsonSequence => {
+                for line in reader.lines() {
+                    let line = line?;
+                    if !line.trim().is_empty() {
+                        push(T::read_json(line.trim())?)?;
+                    }
+                }
+            }
+            StreamFormat::Binary => {
+                let len = u64::read_binary(&mut reader)?;
+                for _ in 0..len {
+                    push(T::read_binary(&mut reader)?)?;
+                }
+                if !reader.fill_buf()?.is_empty() {
+                    return Err(invalid_data("trailing bytes after the last element"));
+                }
+            }
+        }
+
+        Ok(Bst::from_sorted(elements, None, None))
+    }
+}
+
+#[cfg(all(test, feature = "iterative", feature = "recursive"))]
+mod tests {
+    use std::io::ErrorKind;
+
+    use crate::{BinarySearchTree, IterativeBST, RecursiveBST, StreamElement, StreamFormat};
+
+    fn round_trip<T: Ord + StreamElement + std::fmt::Debug + Clone>(values: Vec<T>) {
+        let bst = IterativeBST::from(values);
+        for format in [StreamFormat::JsonSequence, StreamFormat::Binary] {
+            let mut bytes = Vec::new();
+            bst.write_to(&mut bytes, format).unwrap();
+
+            let iterative = IterativeBST::read_from(bytes.as_slice(), format).unwrap();
+            let recursive = RecursiveBST::<T>::read_from(bytes.as_slice(), format).unwrap();
+            assert_eq!(iterative, bst);
+            assert_eq!(recursive.asc_order_vec(), bst.asc_order_vec());
+        }
+    }
+
+    #[test]
+    fn successfully_round_trip_primitives() {
+        round_trip(vec![i64::MIN, -1, 0, 42, i64::MAX]);
+        round_trip(vec![u128::MAX, 0, 7]);
+        round_trip(vec![true, false]);
+        round_trip(Vec::<u8>::new());
+    }
+
+    #[test]
+    fn successfully_round_trip_strings_with_escapes() {
+        let values = vec![
+            String::new(),
+            String::from("plain"),
+            String::from("quote \" and backslash \\"),
+            String::from("lines\nand\ttabs\r"),
+            String::from("\u{1}control\u{7f}"),
+            String::from("unicode é ✓ 🦀"),
+        ];
+        round_trip(values);
+    }
+
+    #[test]
+    fn successfully_read_standard_json_escapes() {
+        let json = "\"plain\"\n\"\\u00e9\\ud83e\\udd80\\/\\b\\f\"".as_bytes();
+        let bst: IterativeBST<String> =
+            IterativeBST::read_from(json, StreamFormat::JsonSequence).unwrap();
+
+        assert_eq!(
+            bst.asc_order_vec(),
+            vec![&String::from("plain"), &String::from("é🦀/\u{8}\u{c}")]
+        );
+    }
+
+    #[test]
+    fn successfully_build_balanced_tree_when_reading() {
+        let bst = IterativeBST::from_iter(0..1023u32);
+        assert_eq!(bst.height(), Some(1022));
+
+        let mut bytes = Vec::new();
+        bst.write_to(&mut bytes, StreamFormat::Binary).unwrap();
+        assert_eq!(bytes.len(), 8 + 1023 * 4);
+
+        let read = IterativeBST::<u32>::read_from(bytes.as_slice(), StreamFormat::Binary).unwrap();
+        assert_eq!(read.height(), Some(9));
+        assert_eq!(read.size(), 1023);
+    }
+
+    #[test]
+    fn successfully_reject_malformed_input() {
+        let read_json = |json: &[u8]| {
+            IterativeBST::<String>::read_from(json, StreamFormat::JsonSequence)
+                .unwrap_err()
+                .kind()
+        };
+        assert_eq!(read_json(b"\"a\"\n\"a\"\n"), ErrorKind::InvalidData);
+        assert_eq!(read_json(b"\"unterminated\n"), ErrorKind::InvalidData);
+        assert_eq!(read_json(b"\"bad \\x escape\"\n"), ErrorKind::InvalidData);
+        assert_eq!(read_json(b"\"\\ud83e alone\"\n"), ErrorKind::InvalidData);
+        assert_eq!(read_json(b"42\n"), ErrorKind::InvalidData);
+
+        let mut bytes = Vec::new();
+        IterativeBST::from(vec![1u16, 2, 3])
+            .write_to(&mut bytes, StreamFormat::Binary)
+            .unwrap();
+        let read_binary = |bytes: &[u8]| {
+            IterativeBST::<u16>::read_from(bytes, StreamFormat::Binary)
+                .unwrap_err()
+                .kind()
+        };
+        assert_eq!(
+            read_binary(&bytes[..bytes.len() - 1]),
+            ErrorKind::UnexpectedEof
+        );
+        assert_eq!(
+            read_binary(&[bytes.as_slice(), &[0]].concat()),
+            ErrorKind::InvalidData
+        );
+
+        let mut huge = u64::MAX.to_le_bytes().to_vec();
+        huge.extend(u64::MAX.to_le_bytes());
+        assert_eq!(
+            IterativeBST::<String>::read_from(huge.as_slice(), StreamFormat::Binary)
+                .unwrap_err()
+                .kind(),
+            ErrorKind::UnexpectedEof
+        );
+    }
+}
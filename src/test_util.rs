@@ -0,0 +1,769 @@
+//! Generators for trees of known shapes, for reproducible tests and benchmarks, along with a
+//! conformance suite for implementations of [BinarySearchTree], see [assert_bst_contract()].
+//!
+//! Every generator holds the elements `0..size` apart from [perfect_tree()], which generates
+//! its own, so the trees only differ in their shape. Trees
+//! are linked together directly instead of being built through insertions, which would take
+//! quadratic time for the degenerate shapes.
+//!
+//! # Example
+//!
+//! ```rust
+//! use bst_rs::test_util;
+//! use bst_rs::{BinarySearchTree, IterativeBST, RecursiveBST};
+//!
+//! let chain: IterativeBST<usize> = test_util::right_chain(1000);
+//! assert_eq!(chain.height(), Some(999));
+//!
+//! let perfect: RecursiveBST<usize> = test_util::perfect(3);
+//! assert_eq!(perfect.size(), 15);
+//! assert_eq!(perfect.height(), Some(3));
+//! ```
+
+use std::collections::BTreeSet;
+use std::collections::VecDeque;
+
+use crate::bst::{Bst, Strategy};
+use crate::node::{HeapNode, Node};
+use crate::BinarySearchTree;
+
+/// Returns a tree of the elements `0..size` where every node is the left child of the next
+/// bigger element, i.e. the shape of inserting them in descending order.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::test_util;
+/// use bst_rs::{BinarySearchTree, IterativeBST};
+///
+/// let bst: IterativeBST<usize> = test_util::left_chain(4);
+///
+/// assert_eq!(bst.pre_order_vec(), vec![&3, &2, &1, &0]);
+/// assert_eq!(bst.height(), Some(3));
+/// ```
+pub fn left_chain<S: Strategy>(size: usize) -> Bst<usize, S> {
+    let mut root: HeapNode<usize> = None;
+    for value in 0..size {
+        root = Some(Box::new(Node {
+            value,
+            left: root,
+            right: None,
+        }));
+    }
+    with_root(root, size)
+}
+
+/// Returns a tree of the elements `0..size` where every node is the right child of the next
+/// smaller element, i.e. the shape of inserting them in ascending order.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::test_util;
+/// use bst_rs::{BinarySearchTree, RecursiveBST};
+///
+/// let bst: RecursiveBST<usize> = test_util::right_chain(4);
+///
+/// assert_eq!(bst.pre_order_vec(), vec![&0, &1, &2, &3]);
+/// assert_eq!(bst.height(), Some(3));
+/// ```
+pub fn right_chain<S: Strategy>(size: usize) -> Bst<usize, S> {
+    let mut root: HeapNode<usize> = None;
+    for value in (0..size).rev() {
+        root = Some(Box::new(Node {
+            value,
+            left: None,
+            right: root,
+        }));
+    }
+    with_root(root, size)
+}
+
+/// Returns the perfect tree of height `depth`, holding the `2^(depth + 1) - 1` elements
+/// starting from `0` with every level completely filled.
+///
+/// # Panics
+///
+/// Panics if the tree would have more than `usize::MAX` elements.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::test_util;
+/// use bst_rs::{BinarySearchTree, IterativeBST};
+///
+/// let bst: IterativeBST<usize> = test_util::perfect(1);
+///
+/// assert_eq!(bst.level_order_vec(), vec![&1, &0, &2]);
+/// assert_eq!(bst.depth_histogram(), vec![1, 2]);
+/// ```
+pub fn perfect<S: Strategy>(depth: u32) -> Bst<usize, S> {
+    let size = 1usize
+        .checked_shl(depth + 1)
+        .filter(|&size| size != 0)
+        .expect("perfect tree is too big")
+        - 1;
+    with_root(Node::build_balanced((0..size).collect()), size)
+}
+
+/// Returns the perfect tree of `DEPTH` levels, holding the `2^DEPTH - 1` elements generated by
+/// calling `f` with the indices `0..2^DEPTH - 1`, with every level completely filled.
+///
+/// Fixing the depth at compile time makes the shape part of the type of a benchmark or test,
+/// while `f` maps the indices to elements of any type, such as keys of a realistic size. A
+/// `DEPTH` of `0` gives an empty tree.
+///
+/// # Panics
+///
+/// Panics if the tree would have more than `usize::MAX` elements, or if `f` does not generate
+/// strictly ascending elements.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::test_util;
+/// use bst_rs::{BinarySearchTree, IterativeBST};
+///
+/// let bst: IterativeBST<String> = test_util::perfect_tree::<_, 2, _>(|index| format!("k{}", index));
+///
+/// assert_eq!(bst.level_order_vec(), vec!["k1", "k0", "k2"]);
+/// assert_eq!(bst.height(), Some(1));
+/// ```
+pub fn perfect_tree<T, const DEPTH: usize, S>(f: impl Fn(usize) -> T) -> Bst<T, S>
+where
+    T: Ord,
+    S: Strategy,
+{
+    let size = u32::try_from(DEPTH)
+        .ok()
+        .and_then(|depth| 1usize.checked_shl(depth))
+        .filter(|&size| size != 0)
+        .expect("perfect tree is too big")
+        - 1;
+    let elements: Vec<T> = (0..size).map(f).collect();
+    assert!(
+        elements.windows(2).all(|pair| pair[0] < pair[1]),
+        "generated elements are not strictly ascending"
+    );
+    with_root(Node::build_balanced(elements), size)
+}
+
+/// Returns a tree of the elements `0..size` inserted in an order shuffled by `seed`, the same
+/// seed always giving the same shape.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::test_util;
+/// use bst_rs::{BinarySearchTree, IterativeBST};
+///
+/// let first: IterativeBST<usize> = test_util::random(100, 42);
+/// let second: IterativeBST<usize> = test_util::random(100, 42);
+///
+/// assert_eq!(first.pre_order_vec(), second.pre_order_vec());
+/// assert_eq!(first.size(), 100);
+/// ```
+pub fn random<S: Strategy>(size: usize, seed: u64) -> Bst<usize, S> {
+    let mut state = seed;
+    let mut values: Vec<usize> = (0..size).collect();
+    // Fisher-Yates shuffle driven by SplitMix64
+    for end in (1..size).rev() {
+        state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut random = state;
+        random = (random ^ (random >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        random = (random ^ (random >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        random ^= random >> 31;
+        values.swap(end, (random % (end as u64 + 1)) as usize);
+    }
+
+    let mut root = None;
+    for value in values {
+        // Values are distinct, so every insertion succeeds
+        let _ = Node::iterative_insert(&mut root, value);
+    }
+    with_root(root, size)
+}
+
+/// Checks that an implementation of [BinarySearchTree] fulfils the contract of the trait,
+/// panicking with a description of the first violation.
+///
+/// `factory` has to return an empty tree and is called once for every scenario: empty trees,
+/// insertions with duplicates, lookups, every traversal along with its iterator and consuming
+/// variants, removals and popping the extremes. Results are compared with a
+/// [BTreeSet](std::collections::BTreeSet). The shape of the tree is up to the implementation,
+/// but the pre-order, post-order and level-order traversals and the height must describe the
+/// same binary tree, and the same insertions must always lead to the same shape.
+///
+/// # Panics
+///
+/// Panics if the tree breaks the contract.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::test_util::assert_bst_contract;
+/// use bst_rs::{AaBST, IterativeBST, LlrbBST, RecursiveBST};
+///
+/// assert_bst_contract(IterativeBST::new);
+/// assert_bst_contract(RecursiveBST::new);
+/// assert_bst_contract(AaBST::new);
+/// assert_bst_contract(LlrbBST::new);
+/// ```
+pub fn assert_bst_contract<B, F>(factory: F)
+where
+    B: BinarySearchTree<i32>,
+    F: Fn() -> B,
+{
+    let values = contract_values();
+    let filled = || {
+        let mut bst = factory();
+        for value in &values {
+            bst.insert(*value);
+        }
+        bst
+    };
+
+    check_empty(factory());
+    check_insertions(factory(), &values);
+    check_traversals(&filled);
+    check_removals(filled(), &values);
+    check_extremes(filled(), &values);
+}
+
+/// Pseudo random values with duplicates, the same on every call.
+fn contract_values() -> Vec<i32> {
+    let mut state: u32 = 0x2545_f491;
+    (0..300)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            (state % 200) as i32
+        })
+        .collect()
+}
+
+fn check_empty<B: BinarySearchTree<i32>>(mut bst: B) {
+    assert_eq!(bst.size(), 0, "empty tree has a size");
+    assert!(bst.is_empty(), "empty tree is not empty");
+    assert!(!bst.is_not_empty(), "empty tree is not empty");
+    assert_eq!(bst.height(), None, "empty tree has a height");
+    assert_eq!(bst.min(), None, "empty tree has a minimum");
+    assert_eq!(bst.max(), None, "empty tree has a maximum");
+    assert!(!bst.contains(&0), "empty tree contains an element");
+    assert_eq!(bst.retrieve(&0), None, "empty tree retrieves an element");
+    assert_eq!(
+        bst.retrieve_as_mut(&0),
+        None,
+        "empty tree retrieves an element"
+    );
+    assert!(
+        bst.asc_order_vec().is_empty(),
+        "empty tree has elements in asc order"
+    );
+    assert!(
+        bst.in_order_vec().is_empty(),
+        "empty tree has elements in order"
+    );
+    assert!(
+        bst.pre_order_vec().is_empty(),
+        "empty tree has elements in pre order"
+    );
+    assert!(
+        bst.post_order_vec().is_empty(),
+        "empty tree has elements in post order"
+    );
+    assert!(
+        bst.level_order_vec().is_empty(),
+        "empty tree has elements in level order"
+    );
+
+    bst.remove(&0);
+    assert_eq!(bst.remove_min(), None, "empty tree removes a minimum");
+    assert_eq!(bst.remove_max(), None, "empty tree removes a maximum");
+    assert_eq!(bst.size(), 0, "removing from empty tree changes its size");
+    assert_eq!(
+        bst.into_asc_order_iter().next(),
+        None,
+        "empty tree consumes elements"
+    );
+}
+
+fn check_insertions<B: BinarySearchTree<i32>>(mut bst: B, values: &[i32]) {
+    let mut expected = BTreeSet::new();
+    for value in values {
+        bst.insert(*value);
+        expected.insert(*value);
+        assert_eq!(
+            bst.size(),
+            expected.len(),
+            "wrong size after inserting {}",
+            value
+        );
+        assert!(bst.contains(value), "inserted {} is not contained", value);
+        if expected.len() == 1 {
+            assert_eq!(
+                bst.height(),
+                Some(0),
+                "tree of one element is not of height 0"
+            );
+        }
+    }
+    assert!(bst.is_not_empty(), "tree with elements is empty");
+
+    for value in -5..205 {
+        let present = expected.get(&value);
+        assert_eq!(
+            bst.contains(&value),
+            present.is_some(),
+            "contains {} is wrong",
+            value
+        );
+        assert_eq!(bst.retrieve(&value), present, "retrieve {} is wrong", value);
+        assert_eq!(
+            bst.retrieve_as_mut(&value).map(|value| *value),
+            present.copied(),
+            "retrieve_as_mut {} is wrong",
+            value
+        );
+    }
+
+    let height = bst.height().expect("tree with elements has no height") as usize;
+    let size = expected.len();
+    assert!(
+        height < size,
+        "height {} is too big for {} elements",
+        height,
+        size
+    );
+    assert!(
+        1 << (height + 1) > size,
+        "height {} is too small for {} elements",
+        height,
+        size
+    );
+}
+
+fn check_traversals<B: BinarySearchTree<i32>, F: Fn() -> B>(filled: &F) {
+    let bst = filled();
+    let expected: Vec<i32> = bst
+        .asc_order_iter()
+        .copied()
+        .collect::<BTreeSet<i32>>()
+        .into_iter()
+        .collect();
+    let copied = |elements: Vec<&i32>| elements.into_iter().copied().collect::<Vec<i32>>();
+
+    assert_eq!(
+        copied(bst.asc_order_vec()),
+        expected,
+        "asc order is not ascending"
+    );
+    assert_eq!(
+        copied(bst.in_order_vec()),
+        expected,
+        "in order is not ascending"
+    );
+    assert_eq!(
+        bst.asc_order_iter().count(),
+        bst.size(),
+        "asc order misses elements"
+    );
+    assert_eq!(
+        copied(bst.in_order_iter().collect()),
+        expected,
+        "in order iterator differs"
+    );
+    assert_eq!(
+        filled().into_asc_order_iter().collect::<Vec<i32>>(),
+        expected,
+        "consuming asc order iterator differs"
+    );
+    assert_eq!(
+        filled().into_in_order_iter().collect::<Vec<i32>>(),
+        expected,
+        "consuming in order iterator differs"
+    );
+
+    let pre_order = copied(bst.pre_order_vec());
+    let shape = Shape::from_pre_order(&pre_order);
+    let mut sorted = pre_order.clone();
+    sorted.sort_unstable();
+    assert_eq!(sorted, expected, "pre order holds other elements");
+
+    assert_eq!(
+        copied(bst.pre_order_iter().collect()),
+        pre_order,
+        "pre order iterator differs"
+    );
+    assert_eq!(
+        filled().into_pre_order_iter().collect::<Vec<i32>>(),
+        pre_order,
+        "consuming pre order iterator differs"
+    );
+    let post_order = shape.post_order();
+    assert_eq!(
+        copied(bst.post_order_vec()),
+        post_order,
+        "post order differs from pre order"
+    );
+    assert_eq!(
+        copied(bst.post_order_iter().collect()),
+        post_order,
+        "post order iterator differs"
+    );
+    assert_eq!(
+        filled().into_post_order_iter().collect::<Vec<i32>>(),
+        post_order,
+        "consuming post order iterator differs"
+    );
+    let level_order = shape.level_order();
+    assert_eq!(
+        copied(bst.level_order_vec()),
+        level_order,
+        "level order differs from pre order"
+    );
+    assert_eq!(
+        copied(bst.level_order_iter().collect()),
+        level_order,
+        "level order iterator differs"
+    );
+    assert_eq!(
+        filled().into_level_order_iter().collect::<Vec<i32>>(),
+        level_order,
+        "consuming level order iterator differs"
+    );
+    assert_eq!(
+        bst.height(),
+        Some(shape.height()),
+        "height differs from pre order"
+    );
+}
+
+fn check_removals<B: BinarySearchTree<i32>>(mut bst: B, values: &[i32]) {
+    let mut expected: BTreeSet<i32> = values.iter().copied().collect();
+    for value in values.iter().step_by(2) {
+        bst.remove(value);
+        expected.remove(value);
+        bst.remove(value);
+        bst.remove(&-1);
+
+        assert_eq!(
+            bst.size(),
+            expected.len(),
+            "wrong size after removing {}",
+            value
+        );
+        assert!(!bst.contains(value), "removed {} is still contained", value);
+    }
+
+    let remaining: Vec<&i32> = expected.iter().collect();
+    assert_eq!(
+        bst.asc_order_vec(),
+        remaining,
+        "wrong elements after removals"
+    );
+    for value in &expected {
+        assert_eq!(
+            bst.retrieve(value),
+            Some(value),
+            "{} got lost by removals",
+            value
+        );
+    }
+}
+
+fn check_extremes<B: BinarySearchTree<i32>>(mut bst: B, values: &[i32]) {
+    let mut expected: BTreeSet<i32> = values.iter().copied().collect();
+    let mut from_min = true;
+    while !expected.is_empty() {
+        assert_eq!(bst.min(), expected.first(), "wrong minimum");
+        assert_eq!(bst.max(), expected.last(), "wrong maximum");
+        if from_min {
+            assert_eq!(
+                bst.remove_min(),
+                expected.pop_first(),
+                "wrong minimum removed"
+            );
+        } else {
+            assert_eq!(
+                bst.remove_max(),
+                expected.pop_last(),
+                "wrong maximum removed"
+            );
+        }
+        assert_eq!(
+            bst.size(),
+            expected.len(),
+            "wrong size after removing an extreme"
+        );
+        from_min = !from_min;
+    }
+
+    assert!(
+        bst.is_empty(),
+        "tree is not empty after removing every element"
+    );
+    assert_eq!(
+        bst.height(),
+        None,
+        "tree has a height after removing every element"
+    );
+    assert_eq!(bst.remove_min(), None, "emptied tree removes a minimum");
+}
+
+/// Binary search tree rebuilt from a pre-order traversal, whose other traversals are the ones
+/// expected from the tree under test.
+struct Shape {
+    /// Value, left child and right child of every node, the root being first.
+    nodes: Vec<(i32, Option<usize>, Option<usize>)>,
+}
+
+impl Shape {
+    /// Inserting the elements in pre-order gives back the same binary search tree.
+    fn from_pre_order(pre_order: &[i32]) -> Shape {
+        let mut shape = Shape { nodes: Vec::new() };
+        for &value in pre_order {
+            let index = shape.nodes.len();
+            shape.nodes.push((value, None, None));
+            let mut current = 0;
+            while current != index {
+                let (current_value, left, right) = &mut shape.nodes[current];
+                let child = if value < *current_value { left } else { right };
+                current = *child.get_or_insert(index);
+            }
+        }
+        shape
+    }
+
+    fn children(&self, index: usize) -> impl Iterator<Item = usize> {
+        let (_, left, right) = self.nodes[index];
+        left.into_iter().chain(right)
+    }
+
+    fn post_order(&self) -> Vec<i32> {
+        let mut post_order = Vec::with_capacity(self.nodes.len());
+        let mut stack = Vec::from_iter((!self.nodes.is_empty()).then_some(0));
+        while let Some(index) = stack.pop() {
+            post_order.push(self.nodes[index].0);
+            stack.extend(self.children(index));
+        }
+        // Root, right, left reversed
+        post_order.reverse();
+        post_order
+    }
+
+    fn level_order(&self) -> Vec<i32> {
+        let mut level_order = Vec::with_capacity(self.nodes.len());
+        let mut queue = VecDeque::from_iter((!self.nodes.is_empty()).then_some(0));
+        while let Some(index) = queue.pop_front() {
+            level_order.push(self.nodes[index].0);
+            queue.extend(self.children(index));
+        }
+        level_order
+    }
+
+    fn height(&self) -> isize {
+        let mut height = -1;
+        let mut level = Vec::from_iter((!self.nodes.is_empty()).then_some(0));
+        while !level.is_empty() {
+            height += 1;
+            level = level
+                .into_iter()
+                .flat_map(|index| self.children(index))
+                .collect();
+        }
+        height
+    }
+}
+
+fn with_root<T: Ord, S: Strategy>(root: HeapNode<T>, size: usize) -> Bst<T, S> {
+    let mut bst = Bst::new();
+    bst.root = root;
+    bst.size = size;
+    bst
+}
+
+#[cfg(all(test, feature = "iterative", feature = "recursive"))]
+mod tests {
+    use super::{assert_bst_contract, left_chain, perfect, perfect_tree, random, right_chain};
+    use crate::{
+        AaBST, ArenaBST, BinarySearchTree, CachedBST, ExtremaBST, IterativeBST, LlrbBST, MetaBST,
+        RecursiveBST, ThreadedBST, TombstoneBST,
+    };
+
+    #[test]
+    fn successfully_generate_chains() {
+        let left: IterativeBST<usize> = left_chain(5_000);
+        let right: RecursiveBST<usize> = right_chain(3);
+
+        assert_eq!(left.height(), Some(4_999));
+        assert!(left.asc_order_iter().copied().eq(0..5_000));
+        assert_eq!(left.min(), Some(&0));
+        assert_eq!(right.pre_order_vec(), vec![&0, &1, &2]);
+        assert!(left_chain::<crate::Iterative>(0).is_empty());
+    }
+
+    #[test]
+    fn successfully_generate_perfect_trees() {
+        for depth in 0..8 {
+            let bst: IterativeBST<usize> = perfect(depth);
+            let histogram: Vec<usize> = (0..=depth).map(|level| 1 << level).collect();
+
+            assert_eq!(bst.size(), (1 << (depth + 1)) - 1);
+            assert_eq!(bst.height(), Some(depth as isize));
+            assert_eq!(bst.depth_histogram(), histogram);
+        }
+    }
+
+    #[test]
+    fn successfully_generate_reproducible_random_trees() {
+        let first: IterativeBST<usize> = random(500, 7);
+        let same: RecursiveBST<usize> = random(500, 7);
+        let other: IterativeBST<usize> = random(500, 8);
+
+        assert_eq!(first.pre_order_vec(), same.pre_order_vec());
+        assert_ne!(first.pre_order_vec(), other.pre_order_vec());
+        assert!(first.asc_order_iter().copied().eq(0..500));
+        assert_eq!(random::<crate::Recursive>(1, 0).size(), 1);
+    }
+
+    #[test]
+    fn successfully_generate_const_depth_perfect_trees() {
+        let empty: IterativeBST<u8> = perfect_tree::<_, 0, _>(|_| unreachable!());
+        assert!(empty.is_empty());
+
+        let bst: RecursiveBST<u64> = perfect_tree::<_, 4, _>(|index| index as u64 * 10);
+        assert_eq!(bst.size(), 15);
+        assert_eq!(bst.height(), Some(3));
+        assert_eq!(bst.depth_histogram(), vec![1, 2, 4, 8]);
+        assert!(bst
+            .asc_order_iter()
+            .copied()
+            .eq((0..15).map(|index| index * 10)));
+
+        let indices: RecursiveBST<usize> = perfect(3);
+        let scaled = indices.pre_order_iter().map(|index| *index as u64 * 10);
+        assert!(bst.pre_order_iter().copied().eq(scaled));
+    }
+
+    #[test]
+    #[should_panic(expected = "generated elements are not strictly ascending")]
+    fn panic_on_perfect_tree_of_unordered_elements() {
+        let _: IterativeBST<usize> = perfect_tree::<_, 3, _>(|index| index % 4);
+    }
+
+    #[test]
+    fn successfully_check_contract_of_every_binary_tree() {
+        assert_bst_contract(IterativeBST::new);
+        assert_bst_contract(RecursiveBST::new);
+        assert_bst_contract(|| IterativeBST::with_rebalance_factor(1.5));
+        assert_bst_contract(AaBST::new);
+        assert_bst_contract(LlrbBST::new);
+        assert_bst_contract(ArenaBST::new);
+        assert_bst_contract(CachedBST::new);
+        assert_bst_contract(ExtremaBST::new);
+        assert_bst_contract(MetaBST::<i32>::new);
+        assert_bst_contract(ThreadedBST::new);
+        assert_bst_contract(TombstoneBST::new);
+    }
+
+    /// Tree which forgets every element bigger than 100 on insertion.
+    #[derive(Default)]
+    struct Forgetful(IterativeBST<i32>);
+
+    impl BinarySearchTree<i32> for Forgetful {
+        fn size(&self) -> usize {
+            self.0.size()
+        }
+        fn is_empty(&self) -> bool {
+            self.0.is_empty()
+        }
+        fn is_not_empty(&self) -> bool {
+            self.0.is_not_empty()
+        }
+        fn insert(&mut self, value: i32) {
+            if value <= 100 {
+                self.0.insert(value)
+            }
+        }
+        fn contains(&self, value: &i32) -> bool {
+            self.0.contains(value)
+        }
+        fn remove(&mut self, value: &i32) {
+            self.0.remove(value)
+        }
+        fn retrieve(&self, value: &i32) -> Option<&i32> {
+            self.0.retrieve(value)
+        }
+        fn retrieve_as_mut(&mut self, value: &i32) -> Option<&mut i32> {
+            self.0.retrieve_as_mut(value)
+        }
+        fn height(&self) -> Option<isize> {
+            self.0.height()
+        }
+        fn min(&self) -> Option<&i32> {
+            self.0.min()
+        }
+        fn max(&self) -> Option<&i32> {
+            self.0.max()
+        }
+        fn remove_min(&mut self) -> Option<i32> {
+            self.0.remove_min()
+        }
+        fn remove_max(&mut self) -> Option<i32> {
+            self.0.remove_max()
+        }
+        fn asc_order_vec(&self) -> Vec<&i32> {
+            self.0.asc_order_vec()
+        }
+        fn pre_order_vec(&self) -> Vec<&i32> {
+            self.0.pre_order_vec()
+        }
+        fn in_order_vec(&self) -> Vec<&i32> {
+            self.0.in_order_vec()
+        }
+        fn post_order_vec(&self) -> Vec<&i32> {
+            self.0.post_order_vec()
+        }
+        fn level_order_vec(&self) -> Vec<&i32> {
+            self.0.level_order_vec()
+        }
+        fn asc_order_iter(&self) -> std::vec::IntoIter<&i32> {
+            self.0.asc_order_iter()
+        }
+        fn pre_order_iter(&self) -> std::vec::IntoIter<&i32> {
+            self.0.pre_order_iter()
+        }
+        fn in_order_iter(&self) -> std::vec::IntoIter<&i32> {
+            self.0.in_order_iter()
+        }
+        fn post_order_iter(&self) -> std::vec::IntoIter<&i32> {
+            self.0.post_order_iter()
+        }
+        fn level_order_iter(&self) -> std::vec::IntoIter<&i32> {
+            self.0.level_order_iter()
+        }
+        fn into_asc_order_iter(self) -> std::vec::IntoIter<i32> {
+            self.0.into_asc_order_iter()
+        }
+        fn into_pre_order_iter(self) -> std::vec::IntoIter<i32> {
+            self.0.into_pre_order_iter()
+        }
+        fn into_in_order_iter(self) -> std::vec::IntoIter<i32> {
+            self.0.into_in_order_iter()
+        }
+        fn into_post_order_iter(self) -> std::vec::IntoIter<i32> {
+            self.0.into_post_order_iter()
+        }
+        fn into_level_order_iter(self) -> std::vec::IntoIter<i32> {
+            self.0.into_level_order_iter()
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "wrong size after inserting")]
+    fn panic_on_broken_contract() {
+        assert_bst_contract(Forgetful::default);
+    }
+}
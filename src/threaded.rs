@@ -0,0 +1,1326 @@
+use std::cmp::Ordering;
+use std::collections::VecDeque;
+use std::fmt::{Debug, Display, Formatter};
+use std::vec::IntoIter;
+
+use crate::BinarySearchTree;
+
+/// Right link of a [ThreadedNode], which points to an actual child or, when the node has no
+/// right subtree, is a thread to the node's in-order successor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Right {
+    Child(usize),
+    Thread(Option<usize>),
+}
+
+#[derive(Debug, Clone)]
+struct ThreadedNode<T> {
+    value: T,
+    left: Option<usize>,
+    right: Right,
+}
+
+/// Where a node hangs off the tree, needed to relink its subtree when it gets removed.
+#[derive(Debug, Clone, Copy)]
+enum Parent {
+    Root,
+    Left(usize),
+    Right(usize),
+}
+
+/// Right-threaded Binary Search Tree implementation.
+///
+/// Unused right child pointers link to the in-order successor of their node, which allows
+/// [ThreadedBST::iter()] to walk the tree in ascending order using O(1) extra space instead of
+/// the O(h) stack required by [IterativeBST](crate::IterativeBST).
+///
+/// Nodes are stored in an arena and linked by index, slots of removed nodes are reused by later
+/// insertions.
+#[derive(Debug)]
+pub struct ThreadedBST<T: Ord> {
+    nodes: Vec<Option<ThreadedNode<T>>>,
+    free: Vec<usize>,
+    root: Option<usize>,
+    size: usize,
+}
+
+/// Ascending iterator following the threads of a [ThreadedBST].
+struct ThreadedIter<'a, T> {
+    nodes: &'a [Option<ThreadedNode<T>>],
+    next: Option<usize>,
+}
+
+impl<'a, T> Iterator for ThreadedIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.nodes[self.next?].as_ref().unwrap();
+        self.next = match current.right {
+            Right::Thread(successor) => successor,
+            Right::Child(right) => Some(leftmost(self.nodes, right)),
+        };
+
+        Some(&current.value)
+    }
+}
+
+fn leftmost<T>(nodes: &[Option<ThreadedNode<T>>], mut index: usize) -> usize {
+    while let Some(left) = nodes[index].as_ref().unwrap().left {
+        index = left;
+    }
+    index
+}
+
+impl<T: Ord> ThreadedBST<T> {
+    /// Creates an empty `ThreadedBST<T>`
+    ///
+    /// No nodes are allocated on the heap yet
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// // Empty tree is created
+    /// let mut bst: ThreadedBST<i32> = ThreadedBST::new();
+    /// assert!(bst.is_empty())
+    /// ```
+    pub const fn new() -> ThreadedBST<T> {
+        ThreadedBST {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: None,
+            size: 0,
+        }
+    }
+
+    /// Returns an iterator over the elements in **ascending order** which follows the threads
+    /// of the tree.
+    ///
+    /// Unlike [BinarySearchTree::asc_order_iter()] nothing is collected up front and no stack is
+    /// kept, the iterator only ever holds the index of the next node.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let bst = ThreadedBST::from_iter(vec![3, 1, 4, 2]);
+    ///
+    /// let mut iter = bst.iter();
+    /// assert_eq!(iter.next(), Some(&1));
+    /// assert_eq!(iter.next(), Some(&2));
+    /// assert_eq!(iter.next(), Some(&3));
+    /// assert_eq!(iter.next(), Some(&4));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        ThreadedIter {
+            nodes: &self.nodes,
+            next: self.root.map(|root| leftmost(&self.nodes, root)),
+        }
+    }
+
+    fn node(&self, index: usize) -> &ThreadedNode<T> {
+        self.nodes[index].as_ref().unwrap()
+    }
+
+    fn node_mut(&mut self, index: usize) -> &mut ThreadedNode<T> {
+        self.nodes[index].as_mut().unwrap()
+    }
+
+    fn right_child(&self, index: usize) -> Option<usize> {
+        match self.node(index).right {
+            Right::Child(right) => Some(right),
+            Right::Thread(_) => None,
+        }
+    }
+
+    fn rightmost(&self, mut index: usize) -> usize {
+        while let Some(right) = self.right_child(index) {
+            index = right;
+        }
+        index
+    }
+
+    fn allocate(&mut self, value: T, right: Right) -> usize {
+        let node = Some(ThreadedNode {
+            value,
+            left: None,
+            right,
+        });
+        self.size += 1;
+        match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    fn find(&self, value: &T) -> Option<(usize, Parent)> {
+        let mut parent = Parent::Root;
+        let mut current = self.root?;
+
+        loop {
+            let next = match value.cmp(&self.node(current).value) {
+                Ordering::Equal => return Some((current, parent)),
+                Ordering::Less => {
+                    parent = Parent::Left(current);
+                    self.node(current).left
+                }
+                Ordering::Greater => {
+                    parent = Parent::Right(current);
+                    self.right_child(current)
+                }
+            };
+            current = next?;
+        }
+    }
+
+    fn set_child(&mut self, parent: Parent, child: usize) {
+        match parent {
+            Parent::Root => self.root = Some(child),
+            Parent::Left(index) => self.node_mut(index).left = Some(child),
+            Parent::Right(index) => self.node_mut(index).right = Right::Child(child),
+        }
+    }
+
+    /// Removes a node which has at most one child, keeping every thread pointing at a live
+    /// node.
+    fn unlink(&mut self, index: usize, parent: Parent) -> T {
+        let node = self.nodes[index].take().unwrap();
+        self.free.push(index);
+        self.size -= 1;
+
+        match (node.left, node.right) {
+            (None, Right::Thread(successor)) => match parent {
+                Parent::Root => self.root = None,
+                Parent::Left(parent) => self.node_mut(parent).left = None,
+                Parent::Right(parent) => self.node_mut(parent).right = Right::Thread(successor),
+            },
+            (Some(left), Right::Thread(successor)) => {
+                // The predecessor is the only node whose thread pointed at the removed node
+                let predecessor = self.rightmost(left);
+                self.node_mut(predecessor).right = Right::Thread(successor);
+                self.set_child(parent, left);
+            }
+            (None, Right::Child(right)) => self.set_child(parent, right),
+            (Some(_), Right::Child(_)) => {
+                unreachable!("node with two children must not be unlinked")
+            }
+        }
+
+        node.value
+    }
+
+    fn take(&mut self, index: usize, parent: Parent) -> T {
+        let (left, right) = (self.node(index).left, self.right_child(index));
+        match (left, right) {
+            (Some(_), Some(right)) => {
+                let mut successor_parent = Parent::Right(index);
+                let mut successor = right;
+                while let Some(left) = self.node(successor).left {
+                    successor_parent = Parent::Left(successor);
+                    successor = left;
+                }
+                let value = self.unlink(successor, successor_parent);
+                std::mem::replace(&mut self.node_mut(index).value, value)
+            }
+            _ => self.unlink(index, parent),
+        }
+    }
+
+    fn pre_order_indices(&self) -> Vec<usize> {
+        let mut indices = Vec::with_capacity(self.size);
+        let mut stack = Vec::from_iter(self.root);
+
+        while let Some(current) = stack.pop() {
+            indices.push(current);
+            stack.extend(self.right_child(current));
+            stack.extend(self.node(current).left);
+        }
+
+        indices
+    }
+
+    fn in_order_indices(&self) -> Vec<usize> {
+        let mut indices = Vec::with_capacity(self.size);
+        let mut next = self.root.map(|root| leftmost(&self.nodes, root));
+
+        while let Some(current) = next {
+            indices.push(current);
+            next = match self.node(current).right {
+                Right::Thread(successor) => successor,
+                Right::Child(right) => Some(leftmost(&self.nodes, right)),
+            };
+        }
+
+        indices
+    }
+
+    fn post_order_indices(&self) -> Vec<usize> {
+        let mut indices = Vec::with_capacity(self.size);
+        let mut stack = Vec::from_iter(self.root);
+
+        while let Some(current) = stack.pop() {
+            indices.push(current);
+            stack.extend(self.node(current).left);
+            stack.extend(self.right_child(current));
+        }
+        indices.reverse();
+
+        indices
+    }
+
+    fn level_order_indices(&self) -> Vec<usize> {
+        let mut indices = Vec::with_capacity(self.size);
+        let mut queue = VecDeque::from_iter(self.root);
+
+        while let Some(current) = queue.pop_front() {
+            indices.push(current);
+            queue.extend(self.node(current).left);
+            queue.extend(self.right_child(current));
+        }
+
+        indices
+    }
+
+    fn values(&self, indices: Vec<usize>) -> Vec<&T> {
+        indices
+            .into_iter()
+            .map(|index| &self.node(index).value)
+            .collect()
+    }
+
+    fn into_values(mut self, indices: Vec<usize>) -> IntoIter<T> {
+        indices
+            .into_iter()
+            .map(|index| self.nodes[index].take().unwrap().value)
+            .collect::<Vec<T>>()
+            .into_iter()
+    }
+}
+
+impl<T: Ord> Default for ThreadedBST<T> {
+    /// Creates an empty `ThreadedBST<T>`
+    fn default() -> ThreadedBST<T> {
+        ThreadedBST::new()
+    }
+}
+
+impl<T: Ord> PartialEq for ThreadedBST<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.size == other.size && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Ord> Extend<T> for ThreadedBST<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter.into_iter() {
+            self.insert(value)
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for ThreadedBST<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bst = ThreadedBST::new();
+        bst.extend(iter);
+        bst
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for ThreadedBST<T> {
+    fn from(vec: Vec<T>) -> Self {
+        let mut bst = ThreadedBST::new();
+        for value in vec.into_iter() {
+            bst.insert(value);
+        }
+        bst
+    }
+}
+
+impl<T: Ord + Clone> From<&[T]> for ThreadedBST<T> {
+    fn from(slice: &[T]) -> Self {
+        let mut bst = ThreadedBST::new();
+        for value in slice {
+            bst.insert((*value).clone());
+        }
+        bst
+    }
+}
+
+impl<T: Ord + Clone> Clone for ThreadedBST<T> {
+    fn clone(&self) -> Self {
+        ThreadedBST {
+            nodes: self.nodes.clone(),
+            free: self.free.clone(),
+            root: self.root,
+            size: self.size,
+        }
+    }
+}
+
+impl<T: Ord + Debug> Display for ThreadedBST<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.asc_order_vec())
+    }
+}
+
+impl<T: Ord> BinarySearchTree<T> for ThreadedBST<T> {
+    /// Returns the total **number of nodes** within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(5);
+    /// bst.insert(10);
+    /// bst.insert(3);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// ```
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the binary search tree contains no nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst: ThreadedBST<i32> = ThreadedBST::new();
+    /// assert!(bst.is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns `true` if the binary search tree contains one or more nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// assert!(bst.is_empty());
+    ///
+    /// bst.insert(2);
+    ///
+    /// assert!(bst.is_not_empty());
+    /// ```
+    fn is_not_empty(&self) -> bool {
+        self.size != 0
+    }
+
+    /// Inserts given value as a node.
+    ///
+    /// **Duplicate values are _not allowed_**.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    ///
+    /// bst.insert(10);
+    /// bst.insert(10);   // Element is not inserted
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(15);
+    /// bst.insert(25);
+    ///
+    /// assert_eq!(bst.size(), 5);
+    /// ```
+    fn insert(&mut self, value: T) {
+        let mut current = match self.root {
+            Some(root) => root,
+            None => {
+                self.root = Some(self.allocate(value, Right::Thread(None)));
+                return;
+            }
+        };
+
+        loop {
+            let (left, right) = (self.node(current).left, self.node(current).right);
+            match value.cmp(&self.node(current).value) {
+                Ordering::Equal => return,
+                Ordering::Less => match left {
+                    Some(left) => current = left,
+                    None => {
+                        let inserted = self.allocate(value, Right::Thread(Some(current)));
+                        self.node_mut(current).left = Some(inserted);
+                        return;
+                    }
+                },
+                Ordering::Greater => match right {
+                    Right::Child(right) => current = right,
+                    Right::Thread(successor) => {
+                        let inserted = self.allocate(value, Right::Thread(successor));
+                        self.node_mut(current).right = Right::Child(inserted);
+                        return;
+                    }
+                },
+            }
+        }
+    }
+
+    /// Returns `true` if the binary search tree contains an element with the given value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert!(bst.contains(&5));
+    /// assert!(!bst.contains(&10));
+    /// ```
+    fn contains(&self, value: &T) -> bool {
+        self.find(value).is_some()
+    }
+
+    /// Removes the given value.
+    ///
+    /// Tree will not be modified if trying to remove element that does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// assert_eq!(bst.size(), 3);
+    ///
+    /// bst.remove(&5);
+    /// bst.remove(&10); // Element is not removed
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove(&mut self, value: &T) {
+        if let Some((index, parent)) = self.find(value) {
+            self.take(index, parent);
+        }
+    }
+
+    /// Returns a reference to the element or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert_eq!(bst.retrieve(&5), Some(&5));
+    /// assert_eq!(bst.retrieve(&10), None);
+    /// ```
+    fn retrieve(&self, value: &T) -> Option<&T> {
+        self.find(value).map(|(index, _)| &self.node(index).value)
+    }
+
+    /// Returns a mutable reference to the element (see [ThreadedBST::retrieve()])
+    /// or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(10);
+    /// bst.insert(5);
+    ///
+    /// let optional_retrieved_value_as_mut = bst.retrieve_as_mut(&5);
+    /// assert_eq!(optional_retrieved_value_as_mut, Some(&mut 5));
+    ///
+    /// let mut retrieved_value = optional_retrieved_value_as_mut.unwrap();
+    /// *retrieved_value = 2; // Change value inside tree to '2'
+    ///
+    /// assert_eq!(bst.retrieve_as_mut(&5), None); // 5 does not exist anymore
+    /// assert_eq!(bst.retrieve_as_mut(&2), Some(&mut 2));
+    /// ```
+    fn retrieve_as_mut(&mut self, value: &T) -> Option<&mut T> {
+        let (index, _) = self.find(value)?;
+        Some(&mut self.node_mut(index).value)
+    }
+
+    /// Returns the **height** or `None` if tree is empty.
+    ///
+    /// The height is the number of edges between the root and it's furthest leaf node.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = ThreadedBST::new();
+    /// assert_eq!(bst.height(), None);
+    ///
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The height is 2.
+    /// assert_eq!(bst.height(), Some(2));
+    /// ```
+    fn height(&self) -> Option<isize> {
+        let mut height = -1;
+        let mut level = Vec::from_iter(self.root);
+
+        while !level.is_empty() {
+            level = level
+                .into_iter()
+                .flat_map(|current| {
+                    self.node(current)
+                        .left
+                        .into_iter()
+                        .chain(self.right_child(current))
+                })
+                .collect();
+            height += 1;
+        }
+
+        self.root.map(|_| height)
+    }
+
+    /// Returns a reference to the minimum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// assert_eq!(bst.min(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.min(), Some(&2));
+    /// ```
+    fn min(&self) -> Option<&T> {
+        self.root
+            .map(|root| &self.node(leftmost(&self.nodes, root)).value)
+    }
+
+    /// Returns a reference to the maximum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// assert_eq!(bst.max(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.max(), Some(&10));
+    /// ```
+    fn max(&self) -> Option<&T> {
+        self.root.map(|root| &self.node(self.rightmost(root)).value)
+    }
+
+    /// Removes and returns the minimum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// assert_eq!(bst.remove_min(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_min(), Some(2));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_min(&mut self) -> Option<T> {
+        let mut parent = Parent::Root;
+        let mut current = self.root?;
+        while let Some(left) = self.node(current).left {
+            parent = Parent::Left(current);
+            current = left;
+        }
+
+        Some(self.unlink(current, parent))
+    }
+
+    /// Removes and returns the maximum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// assert_eq!(bst.remove_max(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_max(), Some(10));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_max(&mut self) -> Option<T> {
+        let mut parent = Parent::Root;
+        let mut current = self.root?;
+        while let Some(right) = self.right_child(current) {
+            parent = Parent::Right(current);
+            current = right;
+        }
+
+        Some(self.unlink(current, parent))
+    }
+
+    /// Returns references to the elements of the tree in **ascending order.**`
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ThreadedBST::in_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn asc_order_vec(&self) -> Vec<&T> {
+        self.in_order_vec()
+    }
+
+    /// Returns references to the elements of the tree in the order of a **pre-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The pre_order_vec is: [&4, &2, &1, &3, &6, &5, &7]
+    /// assert_eq!(bst.pre_order_vec(), vec![&4, &2, &1, &3, &6, &5, &7]);
+    /// ```
+    fn pre_order_vec(&self) -> Vec<&T> {
+        self.values(self.pre_order_indices())
+    }
+
+    /// Returns references to the elements of the tree in the order of an **in-order traversal.**
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ThreadedBST::asc_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The in_order_vec is: [&1, &2, &3, &4, &5, &6, &7]
+    /// assert_eq!(bst.in_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn in_order_vec(&self) -> Vec<&T> {
+        self.iter().collect()
+    }
+
+    /// Returns references to the elements of the tree in the order of a **post-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The post_order_vec is: [&1, &3, &2, &5, &7, &6, &4]
+    /// assert_eq!(bst.post_order_vec(), vec![&1, &3, &2, &5, &7, &6, &4]);
+    /// ```
+    fn post_order_vec(&self) -> Vec<&T> {
+        self.values(self.post_order_indices())
+    }
+
+    /// Returns references to the elements of the tree in the order of a **level-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The level_order_vec is: [&4, &2, &6, &1, &3, &5, &7]
+    /// assert_eq!(bst.level_order_vec(), vec![&4, &2, &6, &1, &3, &5, &7]);
+    /// ```
+    fn level_order_vec(&self) -> Vec<&T> {
+        self.values(self.level_order_indices())
+    }
+
+    /// Returns an iterator over [ThreadedBST::asc_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ThreadedBST::in_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut asc_order_iter = bst.asc_order_iter();
+    ///
+    /// assert_eq!(asc_order_iter.next(), Some(&1));
+    /// assert_eq!(asc_order_iter.next(), Some(&2));
+    /// assert_eq!(asc_order_iter.next(), Some(&3));
+    /// assert_eq!(asc_order_iter.next(), Some(&4));
+    /// assert_eq!(asc_order_iter.next(), Some(&5));
+    /// assert_eq!(asc_order_iter.next(), None);
+    /// ```
+    fn asc_order_iter(&self) -> IntoIter<&T> {
+        self.in_order_iter()
+    }
+
+    /// Returns an iterator over [ThreadedBST::pre_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut pre_order_iter = bst.pre_order_iter();
+    ///
+    /// assert_eq!(pre_order_iter.next(), Some(&3));
+    /// assert_eq!(pre_order_iter.next(), Some(&1));
+    /// assert_eq!(pre_order_iter.next(), Some(&2));
+    /// assert_eq!(pre_order_iter.next(), Some(&4));
+    /// assert_eq!(pre_order_iter.next(), Some(&5));
+    /// assert_eq!(pre_order_iter.next(), None);
+    /// ```
+    fn pre_order_iter(&self) -> IntoIter<&T> {
+        self.pre_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [ThreadedBST::in_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ThreadedBST::asc_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut in_order_iter = bst.in_order_iter();
+    ///
+    /// assert_eq!(in_order_iter.next(), Some(&1));
+    /// assert_eq!(in_order_iter.next(), Some(&2));
+    /// assert_eq!(in_order_iter.next(), Some(&3));
+    /// assert_eq!(in_order_iter.next(), Some(&4));
+    /// assert_eq!(in_order_iter.next(), Some(&5));
+    /// assert_eq!(in_order_iter.next(), None);
+    /// ```
+    fn in_order_iter(&self) -> IntoIter<&T> {
+        self.in_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [ThreadedBST::post_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut post_order_iter = bst.post_order_iter();
+    ///
+    /// assert_eq!(post_order_iter.next(), Some(&2));
+    /// assert_eq!(post_order_iter.next(), Some(&1));
+    /// assert_eq!(post_order_iter.next(), Some(&5));
+    /// assert_eq!(post_order_iter.next(), Some(&4));
+    /// assert_eq!(post_order_iter.next(), Some(&3));
+    /// assert_eq!(post_order_iter.next(), None);
+    /// ```
+    fn post_order_iter(&self) -> IntoIter<&T> {
+        self.post_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [ThreadedBST::level_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut level_order_iter = bst.level_order_iter();
+    ///
+    /// assert_eq!(level_order_iter.next(), Some(&3));
+    /// assert_eq!(level_order_iter.next(), Some(&1));
+    /// assert_eq!(level_order_iter.next(), Some(&4));
+    /// assert_eq!(level_order_iter.next(), Some(&2));
+    /// assert_eq!(level_order_iter.next(), Some(&5));
+    /// assert_eq!(level_order_iter.next(), None);
+    /// ```
+    fn level_order_iter(&self) -> IntoIter<&T> {
+        self.level_order_vec().into_iter()
+    }
+
+    /// Returns [ThreadedBST::asc_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ThreadedBST::into_in_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_asc_order_iter = bst.into_asc_order_iter();
+    ///
+    /// assert_eq!(into_asc_order_iter.next(), Some(1));
+    /// assert_eq!(into_asc_order_iter.next(), Some(2));
+    /// assert_eq!(into_asc_order_iter.next(), Some(3));
+    /// assert_eq!(into_asc_order_iter.next(), Some(4));
+    /// assert_eq!(into_asc_order_iter.next(), Some(5));
+    /// assert_eq!(into_asc_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_asc_order_iter(self) -> IntoIter<T> {
+        self.into_in_order_iter()
+    }
+
+    /// Returns [ThreadedBST::pre_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_pre_order_iter = bst.into_pre_order_iter();
+    ///
+    /// assert_eq!(into_pre_order_iter.next(), Some(3));
+    /// assert_eq!(into_pre_order_iter.next(), Some(1));
+    /// assert_eq!(into_pre_order_iter.next(), Some(2));
+    /// assert_eq!(into_pre_order_iter.next(), Some(4));
+    /// assert_eq!(into_pre_order_iter.next(), Some(5));
+    /// assert_eq!(into_pre_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_pre_order_iter(self) -> IntoIter<T> {
+        let indices = self.pre_order_indices();
+        self.into_values(indices)
+    }
+
+    /// Returns [ThreadedBST::in_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [ThreadedBST::asc_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_in_order_iter = bst.into_in_order_iter();
+    ///
+    /// assert_eq!(into_in_order_iter.next(), Some(1));
+    /// assert_eq!(into_in_order_iter.next(), Some(2));
+    /// assert_eq!(into_in_order_iter.next(), Some(3));
+    /// assert_eq!(into_in_order_iter.next(), Some(4));
+    /// assert_eq!(into_in_order_iter.next(), Some(5));
+    /// assert_eq!(into_in_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_in_order_iter(self) -> IntoIter<T> {
+        let indices = self.in_order_indices();
+        self.into_values(indices)
+    }
+
+    /// Returns [ThreadedBST::post_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_post_order_iter = bst.into_post_order_iter();
+    ///
+    /// assert_eq!(into_post_order_iter.next(), Some(2));
+    /// assert_eq!(into_post_order_iter.next(), Some(1));
+    /// assert_eq!(into_post_order_iter.next(), Some(5));
+    /// assert_eq!(into_post_order_iter.next(), Some(4));
+    /// assert_eq!(into_post_order_iter.next(), Some(3));
+    /// assert_eq!(into_post_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_post_order_iter(self) -> IntoIter<T> {
+        let indices = self.post_order_indices();
+        self.into_values(indices)
+    }
+
+    /// Returns [ThreadedBST::level_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, ThreadedBST};
+    ///
+    /// let mut bst = ThreadedBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_level_order_iter = bst.into_level_order_iter();
+    ///
+    /// assert_eq!(into_level_order_iter.next(), Some(3));
+    /// assert_eq!(into_level_order_iter.next(), Some(1));
+    /// assert_eq!(into_level_order_iter.next(), Some(4));
+    /// assert_eq!(into_level_order_iter.next(), Some(2));
+    /// assert_eq!(into_level_order_iter.next(), Some(5));
+    /// assert_eq!(into_level_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_level_order_iter(self) -> IntoIter<T> {
+        let indices = self.level_order_indices();
+        self.into_values(indices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "iterative")]
+    use crate::IterativeBST;
+    use crate::{BinarySearchTree, ThreadedBST};
+
+    /// Deterministic sequence of pseudo random values for comparisons with [IterativeBST].
+    #[cfg(feature = "iterative")]
+    fn pseudo_random_values(count: usize) -> Vec<u32> {
+        let mut state: u32 = 0x2545_f491;
+        (0..count)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 17;
+                state ^= state << 5;
+                state % 500
+            })
+            .collect()
+    }
+
+    #[test]
+    fn successfully_insert_elements_into_bst() {
+        let mut bst = ThreadedBST::new();
+        bst.insert(8);
+        bst.insert(4);
+        bst.insert(12);
+        bst.insert(4);
+        bst.insert(10);
+
+        assert_eq!(bst.size(), 4);
+        assert!(bst.contains(&10));
+        assert!(!bst.contains(&11));
+        assert_eq!(bst.iter().collect::<Vec<_>>(), vec![&4, &8, &10, &12]);
+    }
+
+    #[test]
+    fn successfully_remove_leaf_node_with_thread() {
+        let mut bst = ThreadedBST::from_iter(vec![8, 4, 12, 10]);
+
+        bst.remove(&10);
+
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.iter().collect::<Vec<_>>(), vec![&4, &8, &12]);
+        assert_eq!(bst.pre_order_vec(), vec![&8, &4, &12]);
+    }
+
+    #[test]
+    fn successfully_remove_node_with_left_child_only() {
+        let mut bst = ThreadedBST::from_iter(vec![8, 4, 12, 10, 9, 11]);
+
+        bst.remove(&12);
+
+        assert_eq!(bst.iter().collect::<Vec<_>>(), vec![&4, &8, &9, &10, &11]);
+        assert_eq!(bst.max(), Some(&11));
+        bst.insert(13);
+        assert_eq!(bst.iter().last(), Some(&13));
+    }
+
+    #[test]
+    fn successfully_remove_node_with_two_children() {
+        let mut bst = ThreadedBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14, 5, 7]);
+
+        bst.remove(&4);
+        bst.remove(&8);
+
+        assert_eq!(bst.size(), 7);
+        assert_eq!(
+            bst.iter().collect::<Vec<_>>(),
+            vec![&2, &5, &6, &7, &10, &12, &14]
+        );
+        assert_eq!(bst.pre_order_vec(), vec![&10, &5, &2, &6, &7, &12, &14]);
+    }
+
+    #[test]
+    fn successfully_remove_min_and_max() {
+        let mut bst = ThreadedBST::from_iter(vec![5, 3, 8, 1, 4, 9]);
+
+        assert_eq!(bst.remove_min(), Some(1));
+        assert_eq!(bst.remove_max(), Some(9));
+        assert_eq!(bst.remove_min(), Some(3));
+
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.min(), Some(&4));
+        assert_eq!(bst.max(), Some(&8));
+        assert_eq!(bst.iter().collect::<Vec<_>>(), vec![&4, &5, &8]);
+        assert_eq!(ThreadedBST::<i32>::new().remove_min(), None);
+    }
+
+    #[test]
+    fn successfully_reuse_slots_of_removed_nodes() {
+        let mut bst = ThreadedBST::from_iter(vec![2, 1, 3]);
+
+        bst.remove(&1);
+        bst.remove(&3);
+        bst.insert(0);
+        bst.insert(4);
+
+        assert_eq!(bst.nodes.len(), 3);
+        assert_eq!(bst.iter().collect::<Vec<_>>(), vec![&0, &2, &4]);
+    }
+
+    #[test]
+    #[cfg(feature = "iterative")]
+    fn successfully_traverse_like_iterative_bst() {
+        let values = pseudo_random_values(200);
+        let mut threaded = ThreadedBST::from_iter(values.clone());
+        let mut iterative = IterativeBST::from_iter(values.clone());
+
+        for value in values.iter().step_by(3) {
+            threaded.remove(value);
+            iterative.remove(value);
+        }
+
+        assert_eq!(threaded.size(), iterative.size());
+        assert_eq!(threaded.height(), iterative.height());
+        assert_eq!(
+            threaded.iter().collect::<Vec<_>>(),
+            iterative.asc_order_vec()
+        );
+        assert_eq!(threaded.pre_order_vec(), iterative.pre_order_vec());
+        assert_eq!(threaded.post_order_vec(), iterative.post_order_vec());
+        assert_eq!(threaded.level_order_vec(), iterative.level_order_vec());
+        assert_eq!(
+            threaded.clone().into_post_order_iter().collect::<Vec<_>>(),
+            iterative.post_order_iter().copied().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            threaded.into_level_order_iter().collect::<Vec<_>>(),
+            iterative.into_level_order_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn successfully_get_height_of_bst() {
+        let mut bst = ThreadedBST::new();
+        assert_eq!(bst.height(), None);
+
+        bst.insert(4);
+        assert_eq!(bst.height(), Some(0));
+
+        bst.insert(2);
+        bst.insert(3);
+        assert_eq!(bst.height(), Some(2));
+    }
+
+    #[test]
+    fn successfully_mutate_retrieved_element() {
+        let mut bst = ThreadedBST::from_iter(vec![(2, 'b'), (1, 'a')]);
+
+        bst.retrieve_as_mut(&(1, 'a')).unwrap().0 = 0;
+
+        assert_eq!(bst.retrieve(&(0, 'a')), Some(&(0, 'a')));
+        assert_eq!(
+            bst.into_asc_order_iter().collect::<Vec<_>>(),
+            vec![(0, 'a'), (2, 'b')]
+        );
+    }
+}
@@ -0,0 +1,1168 @@
+use std::cmp::Ordering;
+use std::fmt::{Debug, Display, Formatter};
+use std::vec::IntoIter;
+
+use crate::iter::SortedIter;
+use crate::node::{HeapNode, Node};
+use crate::BinarySearchTree;
+
+/// Element stored inside the nodes of a [TombstoneBST], ordered by its value **only**.
+#[derive(Debug)]
+struct Tombstoned<T> {
+    value: T,
+    deleted: bool,
+}
+
+impl<T> Tombstoned<T> {
+    fn live(value: T) -> Tombstoned<T> {
+        Tombstoned {
+            value,
+            deleted: false,
+        }
+    }
+
+    fn live_refs(elements: Vec<&Tombstoned<T>>) -> Vec<&T> {
+        elements
+            .into_iter()
+            .filter(|current| !current.deleted)
+            .map(|current| &current.value)
+            .collect()
+    }
+
+    fn live_values(elements: Vec<Tombstoned<T>>) -> IntoIter<T> {
+        elements
+            .into_iter()
+            .filter(|current| !current.deleted)
+            .map(|current| current.value)
+            .collect::<Vec<T>>()
+            .into_iter()
+    }
+}
+
+impl<T: Ord> PartialEq for Tombstoned<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Ord> Eq for Tombstoned<T> {}
+
+impl<T: Ord> PartialOrd for Tombstoned<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Ord> Ord for Tombstoned<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+/// Binary Search Tree implementation with lazy deletion.
+///
+/// [BinarySearchTree::remove()] only marks the node as deleted, which costs a single descent
+/// and never restructures the tree. The tombstones are physically removed by
+/// [TombstoneBST::compact()], either called explicitly or triggered automatically once they make
+/// up a configurable share of the nodes (see [TombstoneBST::with_compaction_threshold()]).
+///
+/// Inserting a value whose node is a tombstone simply revives that node.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{BinarySearchTree, TombstoneBST};
+///
+/// let mut bst = TombstoneBST::from_iter(vec![3, 1, 4, 2]);
+/// bst.remove(&1);
+/// bst.remove(&4);
+///
+/// assert_eq!(bst.size(), 2);
+/// assert_eq!(bst.tombstones(), 2);
+/// assert_eq!(bst.asc_order_vec(), vec![&2, &3]);
+///
+/// assert_eq!(bst.compact(), 2);
+/// assert_eq!(bst.tombstones(), 0);
+/// ```
+#[derive(Debug)]
+pub struct TombstoneBST<T: Ord> {
+    root: HeapNode<Tombstoned<T>>,
+    size: usize,
+    tombstones: usize,
+    compaction_threshold: Option<f64>,
+}
+
+impl<T: Ord> TombstoneBST<T> {
+    /// Creates an empty `TombstoneBST<T>` which is only compacted when calling
+    /// [TombstoneBST::compact()].
+    ///
+    /// No nodes are allocated on the heap yet
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let bst: TombstoneBST<i32> = TombstoneBST::new();
+    /// assert!(bst.is_empty());
+    /// ```
+    pub const fn new() -> TombstoneBST<T> {
+        TombstoneBST {
+            root: None,
+            size: 0,
+            tombstones: 0,
+            compaction_threshold: None,
+        }
+    }
+
+    /// Creates an empty `TombstoneBST<T>` which compacts itself as soon as tombstones make up
+    /// more than `threshold` of its nodes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `threshold` is not within `0.0..=1.0`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::with_compaction_threshold(0.5);
+    /// bst.extend(vec![2, 1, 3, 4]);
+    ///
+    /// bst.remove(&1);
+    /// bst.remove(&2);
+    /// assert_eq!(bst.tombstones(), 2);
+    ///
+    /// bst.remove(&3);  // Now 3 out of 4 nodes are tombstones
+    /// assert_eq!(bst.tombstones(), 0);
+    /// assert_eq!(bst.asc_order_vec(), vec![&4]);
+    /// ```
+    pub fn with_compaction_threshold(threshold: f64) -> TombstoneBST<T> {
+        assert!(
+            (0.0..=1.0).contains(&threshold),
+            "compaction threshold must be within 0.0..=1.0"
+        );
+        TombstoneBST {
+            compaction_threshold: Some(threshold),
+            ..TombstoneBST::new()
+        }
+    }
+
+    /// Returns the **number of nodes** which were removed but not yet compacted away.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::from_iter(vec![1, 2]);
+    /// bst.remove(&1);
+    ///
+    /// assert_eq!(bst.tombstones(), 1);
+    /// ```
+    pub fn tombstones(&self) -> usize {
+        self.tombstones
+    }
+
+    /// Physically removes every tombstone by rebuilding the tree, returning how many were
+    /// removed.
+    ///
+    /// The remaining elements are rebuilt into a **perfectly balanced** tree in O(n).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::from_iter(vec![1, 2, 3, 4, 5]);
+    /// bst.remove(&4);
+    /// bst.remove(&5);
+    ///
+    /// assert_eq!(bst.compact(), 2);
+    /// assert_eq!(bst.pre_order_vec(), vec![&2, &1, &3]);
+    /// assert_eq!(bst.compact(), 0);
+    /// ```
+    pub fn compact(&mut self) -> usize {
+        let removed = self.tombstones;
+        let live = Node::iterative_consume_in_order_vec(self.root.take())
+            .into_iter()
+            .filter(|current| !current.deleted)
+            .collect();
+        self.root = Node::build_balanced(live);
+        self.tombstones = 0;
+        removed
+    }
+
+    fn compact_if_needed(&mut self) {
+        if let Some(threshold) = self.compaction_threshold {
+            let nodes = self.size + self.tombstones;
+            if self.tombstones as f64 > threshold * nodes as f64 {
+                self.compact();
+            }
+        }
+    }
+}
+
+impl<T: Ord> Default for TombstoneBST<T> {
+    /// Creates an empty `TombstoneBST<T>`
+    fn default() -> TombstoneBST<T> {
+        TombstoneBST::new()
+    }
+}
+
+impl<T: Ord> PartialEq for TombstoneBST<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.asc_order_vec() == other.asc_order_vec()
+    }
+}
+
+impl<T: Ord> Extend<T> for TombstoneBST<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter.into_iter() {
+            self.insert(value)
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for TombstoneBST<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut bst = TombstoneBST::new();
+        bst.extend(iter);
+        bst
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for TombstoneBST<T> {
+    fn from(vec: Vec<T>) -> Self {
+        let mut bst = TombstoneBST::new();
+        for value in vec.into_iter() {
+            bst.insert(value);
+        }
+        bst
+    }
+}
+
+impl<T: Ord + Clone> From<&[T]> for TombstoneBST<T> {
+    fn from(slice: &[T]) -> Self {
+        let mut bst = TombstoneBST::new();
+        for value in slice {
+            bst.insert((*value).clone());
+        }
+        bst
+    }
+}
+
+impl<T: Ord + Clone> Clone for TombstoneBST<T> {
+    fn clone(&self) -> Self {
+        let mut bst = TombstoneBST {
+            compaction_threshold: self.compaction_threshold,
+            ..TombstoneBST::new()
+        };
+
+        for value in Tombstoned::live_refs(Node::iterative_pre_order_vec(&self.root)) {
+            bst.insert((*value).clone());
+        }
+
+        bst
+    }
+}
+
+impl<T: Ord + Debug> Display for TombstoneBST<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}", self.asc_order_vec())
+    }
+}
+
+impl<T: Ord> BinarySearchTree<T> for TombstoneBST<T> {
+    /// Returns the total **number of nodes** within the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(5);
+    /// bst.insert(10);
+    /// bst.insert(3);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// ```
+    fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns `true` if the binary search tree contains no nodes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst: TombstoneBST<i32> = TombstoneBST::new();
+    /// assert!(bst.is_empty());
+    /// ```
+    fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+
+    /// Returns `true` if the binary search tree contains one or more nodes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// assert!(bst.is_empty());
+    ///
+    /// bst.insert(2);
+    ///
+    /// assert!(bst.is_not_empty());
+    /// ```
+    fn is_not_empty(&self) -> bool {
+        self.size != 0
+    }
+
+    /// Inserts given value as a node.
+    ///
+    /// **Duplicate values are _not allowed_**.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    ///
+    /// bst.insert(10);
+    /// bst.insert(10);   // Element is not inserted
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(15);
+    /// bst.insert(25);
+    ///
+    /// assert_eq!(bst.size(), 5);
+    /// ```
+    fn insert(&mut self, value: T) {
+        let slot = Node::iterative_slot_by(&mut self.root, |current| current.value.cmp(&value));
+        match slot {
+            Some(node) if node.value.deleted => {
+                node.value = Tombstoned::live(value);
+                self.tombstones -= 1;
+                self.size += 1;
+            }
+            Some(_) => {}
+            None => {
+                *slot = Some(Box::new(Node::new(Tombstoned::live(value))));
+                self.size += 1;
+            }
+        }
+    }
+
+    /// Returns `true` if the binary search tree contains an element with the given value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert!(bst.contains(&5));
+    /// assert!(!bst.contains(&10));
+    /// ```
+    fn contains(&self, value: &T) -> bool {
+        self.retrieve(value).is_some()
+    }
+
+    /// Removes the given value.
+    ///
+    /// Tree will not be modified if trying to remove element that does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// assert_eq!(bst.size(), 3);
+    ///
+    /// bst.remove(&5);
+    /// bst.remove(&10); // Element is not removed
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove(&mut self, value: &T) {
+        let slot = Node::iterative_slot_by(&mut self.root, |current| current.value.cmp(value));
+        if let Some(node) = slot {
+            if !node.value.deleted {
+                node.value.deleted = true;
+                self.size -= 1;
+                self.tombstones += 1;
+                self.compact_if_needed();
+            }
+        }
+    }
+
+    /// Returns a reference to the element or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    ///
+    /// assert_eq!(bst.retrieve(&5), Some(&5));
+    /// assert_eq!(bst.retrieve(&10), None);
+    /// ```
+    fn retrieve(&self, value: &T) -> Option<&T> {
+        Node::iterative_retrieve_by(&self.root, |current| current.value.cmp(value))
+            .filter(|current| !current.deleted)
+            .map(|current| &current.value)
+    }
+
+    /// Returns a mutable reference to the element (see [TombstoneBST::retrieve()])
+    /// or `None` if element does not exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(10);
+    /// bst.insert(5);
+    ///
+    /// let optional_retrieved_value_as_mut = bst.retrieve_as_mut(&5);
+    /// assert_eq!(optional_retrieved_value_as_mut, Some(&mut 5));
+    ///
+    /// let mut retrieved_value = optional_retrieved_value_as_mut.unwrap();
+    /// *retrieved_value = 2; // Change value inside tree to '2'
+    ///
+    /// assert_eq!(bst.retrieve_as_mut(&5), None); // 5 does not exist anymore
+    /// assert_eq!(bst.retrieve_as_mut(&2), Some(&mut 2));
+    /// ```
+    fn retrieve_as_mut(&mut self, value: &T) -> Option<&mut T> {
+        Node::iterative_slot_by(&mut self.root, |current| current.value.cmp(value))
+            .as_mut()
+            .filter(|node| !node.value.deleted)
+            .map(|node| &mut node.value.value)
+    }
+
+    /// Returns the **height** or `None` if tree is empty.
+    ///
+    /// The height is the number of edges between the root and it's furthest leaf node. It is
+    /// the physical height, counting the tombstones still linked into the tree, but a tree of
+    /// tombstones only is empty and has no height.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = TombstoneBST::new();
+    /// assert_eq!(bst.height(), None);
+    ///
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The height is 2.
+    /// assert_eq!(bst.height(), Some(2));
+    /// ```
+    fn height(&self) -> Option<isize> {
+        if self.is_empty() {
+            return None;
+        }
+        Some(Node::iterative_height(&self.root))
+    }
+
+    /// Returns a reference to the minimum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// assert_eq!(bst.min(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.min(), Some(&2));
+    /// ```
+    fn min(&self) -> Option<&T> {
+        SortedIter::ascending(&self.root)
+            .find(|current| !current.deleted)
+            .map(|current| &current.value)
+    }
+
+    /// Returns a reference to the maximum element of the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// assert_eq!(bst.max(), None);
+    ///
+    /// bst.insert(5);
+    /// bst.insert(2);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.max(), Some(&10));
+    /// ```
+    fn max(&self) -> Option<&T> {
+        SortedIter::descending(&self.root)
+            .find(|current| !current.deleted)
+            .map(|current| &current.value)
+    }
+
+    /// Removes and returns the minimum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// assert_eq!(bst.remove_min(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_min(), Some(2));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_min(&mut self) -> Option<T> {
+        while let Some(removed_min) = Node::iterative_remove_min(&mut self.root) {
+            if !removed_min.deleted {
+                self.size -= 1;
+                return Some(removed_min.value);
+            }
+            self.tombstones -= 1;
+        }
+        None
+    }
+
+    /// Removes and returns the maximum element from the tree or `None` if tree is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// assert_eq!(bst.remove_max(), None);
+    ///
+    /// bst.insert(2);
+    /// bst.insert(5);
+    /// bst.insert(10);
+    ///
+    /// assert_eq!(bst.size(), 3);
+    /// assert_eq!(bst.remove_max(), Some(10));
+    /// assert_eq!(bst.size(), 2);
+    /// ```
+    fn remove_max(&mut self) -> Option<T> {
+        while let Some(removed_max) = Node::iterative_remove_max(&mut self.root) {
+            if !removed_max.deleted {
+                self.size -= 1;
+                return Some(removed_max.value);
+            }
+            self.tombstones -= 1;
+        }
+        None
+    }
+
+    /// Returns references to the elements of the tree in **ascending order.**`
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [TombstoneBST::in_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// assert_eq!(bst.asc_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn asc_order_vec(&self) -> Vec<&T> {
+        self.in_order_vec()
+    }
+
+    /// Returns references to the elements of the tree in the order of a **pre-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The pre_order_vec is: [&4, &2, &1, &3, &6, &5, &7]
+    /// assert_eq!(bst.pre_order_vec(), vec![&4, &2, &1, &3, &6, &5, &7]);
+    /// ```
+    fn pre_order_vec(&self) -> Vec<&T> {
+        Tombstoned::live_refs(Node::iterative_pre_order_vec(&self.root))
+    }
+
+    /// Returns references to the elements of the tree in the order of an **in-order traversal.**
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [TombstoneBST::asc_order_vec()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The in_order_vec is: [&1, &2, &3, &4, &5, &6, &7]
+    /// assert_eq!(bst.in_order_vec(), vec![&1, &2, &3, &4, &5, &6, &7]);
+    /// ```
+    fn in_order_vec(&self) -> Vec<&T> {
+        Tombstoned::live_refs(Node::iterative_in_order_vec(&self.root))
+    }
+
+    /// Returns references to the elements of the tree in the order of a **post-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The post_order_vec is: [&1, &3, &2, &5, &7, &6, &4]
+    /// assert_eq!(bst.post_order_vec(), vec![&1, &3, &2, &5, &7, &6, &4]);
+    /// ```
+    fn post_order_vec(&self) -> Vec<&T> {
+        Tombstoned::live_refs(Node::iterative_post_order_vec(&self.root))
+    }
+
+    /// Returns references to the elements of the tree in the order of a **level-order traversal.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// // Given a tree that looks like:
+    ///  //         4
+    ///  //       /  \
+    ///  //      2    6
+    ///  //     / \  / \
+    ///  //    1  3 5   7
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(4);
+    /// bst.insert(6);
+    /// bst.insert(2);
+    /// bst.insert(7);
+    /// bst.insert(5);
+    /// bst.insert(3);
+    /// bst.insert(1);
+    ///
+    /// // The level_order_vec is: [&4, &2, &6, &1, &3, &5, &7]
+    /// assert_eq!(bst.level_order_vec(), vec![&4, &2, &6, &1, &3, &5, &7]);
+    /// ```
+    fn level_order_vec(&self) -> Vec<&T> {
+        Tombstoned::live_refs(Node::iterative_level_order_vec(&self.root))
+    }
+
+    /// Returns an iterator over [TombstoneBST::asc_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [TombstoneBST::in_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut asc_order_iter = bst.asc_order_iter();
+    ///
+    /// assert_eq!(asc_order_iter.next(), Some(&1));
+    /// assert_eq!(asc_order_iter.next(), Some(&2));
+    /// assert_eq!(asc_order_iter.next(), Some(&3));
+    /// assert_eq!(asc_order_iter.next(), Some(&4));
+    /// assert_eq!(asc_order_iter.next(), Some(&5));
+    /// assert_eq!(asc_order_iter.next(), None);
+    /// ```
+    fn asc_order_iter(&self) -> IntoIter<&T> {
+        self.in_order_iter()
+    }
+
+    /// Returns an iterator over [TombstoneBST::pre_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut pre_order_iter = bst.pre_order_iter();
+    ///
+    /// assert_eq!(pre_order_iter.next(), Some(&3));
+    /// assert_eq!(pre_order_iter.next(), Some(&1));
+    /// assert_eq!(pre_order_iter.next(), Some(&2));
+    /// assert_eq!(pre_order_iter.next(), Some(&4));
+    /// assert_eq!(pre_order_iter.next(), Some(&5));
+    /// assert_eq!(pre_order_iter.next(), None);
+    /// ```
+    fn pre_order_iter(&self) -> IntoIter<&T> {
+        self.pre_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [TombstoneBST::in_order_vec()].
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [TombstoneBST::asc_order_iter()] as the underlying
+    /// behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut in_order_iter = bst.in_order_iter();
+    ///
+    /// assert_eq!(in_order_iter.next(), Some(&1));
+    /// assert_eq!(in_order_iter.next(), Some(&2));
+    /// assert_eq!(in_order_iter.next(), Some(&3));
+    /// assert_eq!(in_order_iter.next(), Some(&4));
+    /// assert_eq!(in_order_iter.next(), Some(&5));
+    /// assert_eq!(in_order_iter.next(), None);
+    /// ```
+    fn in_order_iter(&self) -> IntoIter<&T> {
+        self.in_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [TombstoneBST::post_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut post_order_iter = bst.post_order_iter();
+    ///
+    /// assert_eq!(post_order_iter.next(), Some(&2));
+    /// assert_eq!(post_order_iter.next(), Some(&1));
+    /// assert_eq!(post_order_iter.next(), Some(&5));
+    /// assert_eq!(post_order_iter.next(), Some(&4));
+    /// assert_eq!(post_order_iter.next(), Some(&3));
+    /// assert_eq!(post_order_iter.next(), None);
+    /// ```
+    fn post_order_iter(&self) -> IntoIter<&T> {
+        self.post_order_vec().into_iter()
+    }
+
+    /// Returns an iterator over [TombstoneBST::level_order_vec()].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut level_order_iter = bst.level_order_iter();
+    ///
+    /// assert_eq!(level_order_iter.next(), Some(&3));
+    /// assert_eq!(level_order_iter.next(), Some(&1));
+    /// assert_eq!(level_order_iter.next(), Some(&4));
+    /// assert_eq!(level_order_iter.next(), Some(&2));
+    /// assert_eq!(level_order_iter.next(), Some(&5));
+    /// assert_eq!(level_order_iter.next(), None);
+    /// ```
+    fn level_order_iter(&self) -> IntoIter<&T> {
+        self.level_order_vec().into_iter()
+    }
+
+    /// Returns [TombstoneBST::asc_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [TombstoneBST::into_in_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_asc_order_iter = bst.into_asc_order_iter();
+    ///
+    /// assert_eq!(into_asc_order_iter.next(), Some(1));
+    /// assert_eq!(into_asc_order_iter.next(), Some(2));
+    /// assert_eq!(into_asc_order_iter.next(), Some(3));
+    /// assert_eq!(into_asc_order_iter.next(), Some(4));
+    /// assert_eq!(into_asc_order_iter.next(), Some(5));
+    /// assert_eq!(into_asc_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_asc_order_iter(self) -> IntoIter<T> {
+        self.into_in_order_iter()
+    }
+
+    /// Returns [TombstoneBST::pre_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_pre_order_iter = bst.into_pre_order_iter();
+    ///
+    /// assert_eq!(into_pre_order_iter.next(), Some(3));
+    /// assert_eq!(into_pre_order_iter.next(), Some(1));
+    /// assert_eq!(into_pre_order_iter.next(), Some(2));
+    /// assert_eq!(into_pre_order_iter.next(), Some(4));
+    /// assert_eq!(into_pre_order_iter.next(), Some(5));
+    /// assert_eq!(into_pre_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_pre_order_iter(self) -> IntoIter<T> {
+        Tombstoned::live_values(Node::iterative_consume_pre_order_vec(self.root))
+    }
+
+    /// Returns [TombstoneBST::in_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Important
+    ///
+    /// This function is analogous to [TombstoneBST::asc_order_iter()] as the
+    /// underlying behaviour is **_exactly the same_.**
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_in_order_iter = bst.into_in_order_iter();
+    ///
+    /// assert_eq!(into_in_order_iter.next(), Some(1));
+    /// assert_eq!(into_in_order_iter.next(), Some(2));
+    /// assert_eq!(into_in_order_iter.next(), Some(3));
+    /// assert_eq!(into_in_order_iter.next(), Some(4));
+    /// assert_eq!(into_in_order_iter.next(), Some(5));
+    /// assert_eq!(into_in_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_in_order_iter(self) -> IntoIter<T> {
+        Tombstoned::live_values(Node::iterative_consume_in_order_vec(self.root))
+    }
+
+    /// Returns [TombstoneBST::post_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_post_order_iter = bst.into_post_order_iter();
+    ///
+    /// assert_eq!(into_post_order_iter.next(), Some(2));
+    /// assert_eq!(into_post_order_iter.next(), Some(1));
+    /// assert_eq!(into_post_order_iter.next(), Some(5));
+    /// assert_eq!(into_post_order_iter.next(), Some(4));
+    /// assert_eq!(into_post_order_iter.next(), Some(3));
+    /// assert_eq!(into_post_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_post_order_iter(self) -> IntoIter<T> {
+        Tombstoned::live_values(Node::iterative_consume_post_order_vec(self.root))
+    }
+
+    /// Returns [TombstoneBST::level_order_iter()] **AND** consumes the tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, TombstoneBST};
+    ///
+    /// let mut bst = TombstoneBST::new();
+    /// bst.insert(3);
+    /// bst.insert(4);
+    /// bst.insert(5);
+    /// bst.insert(1);
+    /// bst.insert(2);
+    ///
+    /// let mut into_level_order_iter = bst.into_level_order_iter();
+    ///
+    /// assert_eq!(into_level_order_iter.next(), Some(3));
+    /// assert_eq!(into_level_order_iter.next(), Some(1));
+    /// assert_eq!(into_level_order_iter.next(), Some(4));
+    /// assert_eq!(into_level_order_iter.next(), Some(2));
+    /// assert_eq!(into_level_order_iter.next(), Some(5));
+    /// assert_eq!(into_level_order_iter.next(), None);
+    ///
+    /// // bst.insert(10); -> COMPILE ERROR
+    /// ```
+    fn into_level_order_iter(self) -> IntoIter<T> {
+        Tombstoned::live_values(Node::iterative_consume_level_order_vec(self.root))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BinarySearchTree, TombstoneBST};
+
+    #[test]
+    fn successfully_mark_removed_elements_as_tombstones() {
+        let mut bst = TombstoneBST::from_iter(vec![8, 4, 12, 2, 6]);
+
+        bst.remove(&4);
+        bst.remove(&4);
+        bst.remove(&7);
+
+        assert_eq!(bst.size(), 4);
+        assert_eq!(bst.tombstones(), 1);
+        assert!(!bst.contains(&4));
+        assert_eq!(bst.retrieve(&4), None);
+        assert_eq!(bst.pre_order_vec(), vec![&8, &2, &6, &12]);
+        assert_eq!(bst.height(), Some(2));
+    }
+
+    #[test]
+    fn verify_tree_of_tombstones_has_no_height() {
+        let mut bst = TombstoneBST::from_iter(vec![2, 1, 3]);
+        bst.remove(&1);
+        bst.remove(&3);
+        assert_eq!(bst.height(), Some(1));
+
+        bst.remove(&2);
+
+        assert_eq!(bst.tombstones(), 3);
+        assert!(bst.is_empty());
+        assert_eq!(bst.height(), None);
+        bst.insert(3);
+        assert_eq!(bst.height(), Some(1));
+    }
+
+    #[test]
+    fn successfully_revive_tombstone_on_insert() {
+        let mut bst = TombstoneBST::from_iter(vec![2, 1, 3]);
+        bst.remove(&1);
+
+        bst.insert(1);
+
+        assert_eq!(bst.size(), 3);
+        assert_eq!(bst.tombstones(), 0);
+        assert_eq!(bst.pre_order_vec(), vec![&2, &1, &3]);
+    }
+
+    #[test]
+    fn successfully_skip_tombstones_for_min_and_max() {
+        let mut bst = TombstoneBST::from_iter(vec![5, 3, 8, 1, 9]);
+        bst.remove(&1);
+        bst.remove(&9);
+
+        assert_eq!(bst.min(), Some(&3));
+        assert_eq!(bst.max(), Some(&8));
+        assert_eq!(bst.remove_min(), Some(3));
+        assert_eq!(bst.remove_max(), Some(8));
+
+        assert_eq!(bst.tombstones(), 0);
+        assert_eq!(bst.size(), 1);
+        assert_eq!(bst.remove_min(), Some(5));
+        assert_eq!(bst.remove_min(), None);
+    }
+
+    #[test]
+    fn successfully_compact_into_balanced_bst() {
+        let mut bst = TombstoneBST::from_iter(1..=10);
+        for value in 1..=3 {
+            bst.remove(&value);
+        }
+
+        assert_eq!(bst.compact(), 3);
+
+        assert_eq!(bst.tombstones(), 0);
+        assert_eq!(bst.size(), 7);
+        assert_eq!(bst.height(), Some(2));
+        assert_eq!(bst.asc_order_vec(), vec![&4, &5, &6, &7, &8, &9, &10]);
+    }
+
+    #[test]
+    fn successfully_compact_once_threshold_is_exceeded() {
+        let mut bst = TombstoneBST::with_compaction_threshold(0.25);
+        bst.extend(1..=8);
+
+        bst.remove(&1);
+        bst.remove(&2);
+        assert_eq!(bst.tombstones(), 2);
+
+        bst.remove(&3);
+        assert_eq!(bst.tombstones(), 0);
+        assert_eq!(bst.clone().tombstones(), 0);
+        assert_eq!(
+            bst.into_in_order_iter().collect::<Vec<_>>(),
+            vec![4, 5, 6, 7, 8]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "compaction threshold must be within 0.0..=1.0")]
+    fn panic_on_invalid_compaction_threshold() {
+        let _: TombstoneBST<i32> = TombstoneBST::with_compaction_threshold(1.5);
+    }
+
+    #[test]
+    fn successfully_consume_only_live_elements() {
+        let mut bst = TombstoneBST::from_iter(vec![4, 2, 6, 1, 3]);
+        bst.remove(&2);
+        bst.remove(&6);
+
+        assert_eq!(bst.level_order_vec(), vec![&4, &1, &3]);
+        assert_eq!(
+            bst.into_post_order_iter().collect::<Vec<_>>(),
+            vec![1, 3, 4]
+        );
+    }
+}
@@ -0,0 +1,332 @@
+use tracing::Level;
+
+/// Operation performed by an [IterativeBST](crate::IterativeBST) or
+/// [RecursiveBST](crate::RecursiveBST), emitted as an event of the `tracing` crate.
+///
+/// `depth` is the depth at which the search for the value ended: the depth of the node holding
+/// an equal value if one exists, otherwise the depth a new node would get. `comparisons` is the
+/// number of elements the value was compared against on the way, which is none for removals of
+/// the minimum or maximum, as they only follow the spine down to its end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TraceEvent {
+    /// An insertion, `inserted` being `false` if the value was already present.
+    Insert {
+        depth: usize,
+        comparisons: usize,
+        inserted: bool,
+    },
+    /// A removal, `removed` being `false` if the value was not present.
+    Remove {
+        depth: usize,
+        comparisons: usize,
+        removed: bool,
+    },
+    /// A bulk removal such as [retain_greater()](crate::Bst::retain_greater()), which removed
+    /// `count` elements in a single call.
+    RemoveMany { count: usize },
+    /// The tree has been rebuilt into a perfectly balanced one, see
+    /// [with_rebalance_factor()](crate::Bst::with_rebalance_factor()).
+    Rebalance { size: usize },
+}
+
+impl TraceEvent {
+    /// Insertion whose search ended at `depth`, `found` being whether an equal element was
+    /// already there.
+    pub(crate) fn insert_at(depth: usize, found: bool) -> TraceEvent {
+        TraceEvent::Insert {
+            depth,
+            comparisons: depth + found as usize,
+            inserted: !found,
+        }
+    }
+
+    /// Removal whose search ended at `depth`, `found` being whether an equal element was there.
+    pub(crate) fn remove_at(depth: usize, found: bool) -> TraceEvent {
+        TraceEvent::Remove {
+            depth,
+            comparisons: depth + found as usize,
+            removed: found,
+        }
+    }
+
+    /// Removal of the minimum or maximum from `depth`, `removed` being `false` if the tree was
+    /// empty.
+    pub(crate) fn remove_end(depth: usize, removed: bool) -> TraceEvent {
+        TraceEvent::Remove {
+            depth,
+            comparisons: 0,
+            removed,
+        }
+    }
+}
+
+/// Emits the event built by `event` at the `TRACE` level, building it only if a subscriber
+/// is interested in it.
+pub(crate) fn emit<F: FnOnce() -> TraceEvent>(event: F) {
+    if !tracing::enabled!(Level::TRACE) {
+        return;
+    }
+    match event() {
+        TraceEvent::Insert {
+            depth,
+            comparisons,
+            inserted,
+        } => tracing::trace!(depth, comparisons, inserted, "insert"),
+        TraceEvent::Remove {
+            depth,
+            comparisons,
+            removed,
+        } => tracing::trace!(depth, comparisons, removed, "remove"),
+        TraceEvent::RemoveMany { count } => tracing::trace!(count, "remove_many"),
+        TraceEvent::Rebalance { size } => tracing::trace!(size, "rebalance"),
+    }
+}
+
+#[cfg(all(test, feature = "iterative", feature = "recursive"))]
+mod tests {
+    use std::collections::HashMap;
+    use std::fmt::Debug;
+    use std::sync::{Arc, Mutex};
+
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    use super::TraceEvent;
+    use crate::{BinarySearchTree, IterativeBST, RecursiveBST};
+
+    /// Fields of an event, the message being recorded through `record_debug()`.
+    #[derive(Default)]
+    struct Fields {
+        message: String,
+        values: HashMap<&'static str, u64>,
+        flags: HashMap<&'static str, bool>,
+    }
+
+    impl Visit for Fields {
+        fn record_u64(&mut self, field: &Field, value: u64) {
+            self.values.insert(field.name(), value);
+        }
+
+        fn record_bool(&mut self, field: &Field, value: bool) {
+            self.flags.insert(field.name(), value);
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+            if field.name() == "message" {
+                self.message = format!("{value:?}");
+            }
+        }
+    }
+
+    impl Fields {
+        fn into_event(self) -> TraceEvent {
+            match self.message.as_str() {
+                "insert" => TraceEvent::Insert {
+                    depth: self.values["depth"] as usize,
+                    comparisons: self.values["comparisons"] as usize,
+                    inserted: self.flags["inserted"],
+                },
+                "remove" => TraceEvent::Remove {
+                    depth: self.values["depth"] as usize,
+                    comparisons: self.values["comparisons"] as usize,
+                    removed: self.flags["removed"],
+                },
+                "remove_many" => TraceEvent::RemoveMany {
+                    count: self.values["count"] as usize,
+                },
+                "rebalance" => TraceEvent::Rebalance {
+                    size: self.values["size"] as usize,
+                },
+                message => panic!("unexpected event {message}"),
+            }
+        }
+    }
+
+    /// Collects the events of the crate, ignoring spans.
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<TraceEvent>>>);
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+            metadata.target().starts_with("bst_rs")
+        }
+
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, event: &Event<'_>) {
+            let mut fields = Fields::default();
+            event.record(&mut fields);
+            self.0.lock().unwrap().push(fields.into_event());
+        }
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    fn record<R>(f: impl FnOnce() -> R) -> (R, Vec<TraceEvent>) {
+        let recorder = Recorder::default();
+        let result = tracing::subscriber::with_default(recorder.clone(), f);
+        let events = recorder.0.lock().unwrap().clone();
+        (result, events)
+    }
+
+    #[test]
+    fn successfully_trace_insert_and_remove() {
+        let (_, iterative) = record(|| {
+            let mut bst = IterativeBST::from(vec![5, 3, 8, 1]);
+            bst.remove_min();
+            bst.remove(&3);
+            bst.remove(&4);
+        });
+        let (_, recursive) = record(|| {
+            let mut bst = RecursiveBST::from(vec![5, 3, 8, 1]);
+            bst.remove_min();
+            bst.remove(&3);
+            bst.remove(&4);
+        });
+
+        assert_eq!(iterative, recursive);
+        assert_eq!(
+            iterative[4..],
+            [
+                TraceEvent::Remove {
+                    depth: 2,
+                    comparisons: 0,
+                    removed: true
+                },
+                TraceEvent::Remove {
+                    depth: 1,
+                    comparisons: 2,
+                    removed: true
+                },
+                TraceEvent::Remove {
+                    depth: 1,
+                    comparisons: 1,
+                    removed: false
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn successfully_trace_rebalance() {
+        let (bst, events) = record(|| {
+            let mut bst = IterativeBST::with_rebalance_factor(1.0);
+            bst.extend(1..=16);
+            bst
+        });
+
+        let rebalances: Vec<&TraceEvent> = events
+            .iter()
+            .filter(|event| matches!(event, TraceEvent::Rebalance { .. }))
+            .collect();
+        assert!(!rebalances.is_empty());
+        assert_eq!(events.len(), 16 + rebalances.len());
+        assert!(bst.height().unwrap() <= 4);
+    }
+
+    #[test]
+    fn successfully_restore_previous_subscriber() {
+        let (_, outer) = record(|| {
+            let (_, inner) = record(|| IterativeBST::from(vec![1]));
+            assert_eq!(inner.len(), 1);
+            IterativeBST::from(vec![1, 2])
+        });
+
+        assert_eq!(outer.len(), 2);
+        let (_, none) = record(|| {});
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn verify_nothing_is_traced_without_subscriber() {
+        let mut bst = RecursiveBST::from(vec![2, 1, 3]);
+        bst.remove(&1);
+
+        let (_, events) = record(|| bst.insert(1));
+        assert_eq!(
+            events,
+            vec![TraceEvent::Insert {
+                depth: 1,
+                comparisons: 1,
+                inserted: true
+            }]
+        );
+    }
+
+    #[test]
+    fn verify_rejected_push_max_is_not_traced() {
+        let (_, events) = record(|| {
+            let mut iterative = IterativeBST::from(vec![1, 3]);
+            assert!(iterative.push_max(2).is_err());
+            assert!(iterative.push_max(4).is_ok());
+            let mut recursive = RecursiveBST::from(vec![1, 3]);
+            assert!(recursive.push_max(3).is_err());
+        });
+
+        assert_eq!(events.len(), 5);
+        assert_eq!(
+            events[2],
+            TraceEvent::Insert {
+                depth: 2,
+                comparisons: 2,
+                inserted: true
+            }
+        );
+    }
+
+    #[test]
+    fn successfully_trace_bounded_insertions_like_insert() {
+        let (_, bounded) = record(|| {
+            let mut iterative = IterativeBST::new();
+            assert!(iterative.insert_bounded(2, 1).is_ok());
+            assert!(iterative.insert_bounded(1, 1).is_ok());
+            assert!(iterative.insert_bounded(1, 1).is_ok());
+            assert!(iterative.insert_bounded(0, 1).is_err());
+            let mut recursive = RecursiveBST::with_max_depth(1);
+            assert!(recursive.try_insert(2).is_ok());
+            assert!(recursive.try_insert(1).is_ok());
+            assert!(recursive.try_insert(1).is_ok());
+            assert!(recursive.try_insert(0).is_err());
+        });
+        let (_, inserted) = record(|| {
+            let iterative = IterativeBST::from(vec![2, 1, 1]);
+            (iterative, RecursiveBST::from(vec![2, 1, 1]))
+        });
+
+        assert_eq!(bounded, inserted);
+    }
+
+    #[test]
+    fn successfully_trace_bulk_removals_once_per_call() {
+        let (_, events) = record(|| {
+            let mut bst = IterativeBST::from(vec![4, 2, 6, 1, 3, 5, 7]);
+            assert_eq!(bst.retain_greater(&2), 2);
+            assert_eq!(bst.remove_max_n(2), vec![7, 6]);
+            assert_eq!(bst.drain_sorted().nth(1), Some(4));
+            assert_eq!(bst.remove_min_if(|_| false), None);
+            assert_eq!(bst.remove_max(), Some(5));
+            assert_eq!(bst.remove_max(), None);
+        });
+
+        assert_eq!(
+            events[7..],
+            [
+                TraceEvent::RemoveMany { count: 2 },
+                TraceEvent::RemoveMany { count: 2 },
+                TraceEvent::RemoveMany { count: 2 },
+                TraceEvent::RemoveMany { count: 0 },
+                TraceEvent::remove_end(0, true),
+                TraceEvent::remove_end(0, false),
+            ]
+        );
+    }
+}
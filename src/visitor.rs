@@ -0,0 +1,181 @@
+use crate::bst::{Bst, Strategy};
+use crate::node::Node;
+use crate::NodeRef;
+
+/// Callbacks of a single depth-first walk over a [Bst], see [Bst::accept()].
+///
+/// Every node is visited three times: before its left subtree (pre-order), between its subtrees
+/// (in-order) and after its right subtree (post-order). Each visit receives a [NodeRef] to
+/// inspect the element and its children, along with the depth of the node, the root being at
+/// depth `0`. All methods do nothing by default, so visitors only implement the orders they
+/// need.
+///
+/// This allows analyses such as pretty printers, validators or statistics to gather everything
+/// they need in one pass, instead of calling a separate traversal for every order.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{BinarySearchTree, IterativeBST, NodeRef, TreeVisitor};
+///
+/// #[derive(Default)]
+/// struct Stats {
+///     leaves: usize,
+///     deepest: usize,
+/// }
+///
+/// impl<'a> TreeVisitor<'a, i32> for Stats {
+///     fn visit_pre(&mut self, node: NodeRef<'a, i32>, depth: usize) {
+///         self.deepest = self.deepest.max(depth);
+///         if node.is_leaf() {
+///             self.leaves += 1;
+///         }
+///     }
+/// }
+///
+/// let bst = IterativeBST::from_iter(vec![4, 2, 6, 1, 3, 5]);
+/// let mut stats = Stats::default();
+/// bst.accept(&mut stats);
+///
+/// assert_eq!(stats.leaves, 3);
+/// assert_eq!(stats.deepest, 2);
+/// ```
+pub trait TreeVisitor<'a, T: Ord> {
+    /// Called when the walk enters `node`, before any of its subtrees.
+    fn visit_pre(&mut self, node: NodeRef<'a, T>, depth: usize) {
+        let _ = (node, depth);
+    }
+
+    /// Called after the left subtree of `node` has been walked, before its right subtree.
+    fn visit_in(&mut self, node: NodeRef<'a, T>, depth: usize) {
+        let _ = (node, depth);
+    }
+
+    /// Called when the walk leaves `node`, after both of its subtrees.
+    fn visit_post(&mut self, node: NodeRef<'a, T>, depth: usize) {
+        let _ = (node, depth);
+    }
+}
+
+/// Next visit of a node which is still on the stack of [Bst::accept()].
+enum Visit {
+    Pre,
+    In,
+    Post,
+}
+
+impl<T: Ord, S: Strategy> Bst<T, S> {
+    /// Walks the tree depth-first, calling the pre-order, in-order and post-order callbacks of
+    /// `visitor` for every node in a single pass (see [TreeVisitor]).
+    ///
+    /// The walk keeps an explicit stack no matter the [Strategy], so deep trees can't overflow
+    /// the call stack.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, NodeRef, RecursiveBST, TreeVisitor};
+    ///
+    /// // Prints the tree sideways, one line per element, indented by its depth
+    /// struct Printer(Vec<String>);
+    ///
+    /// impl<'a> TreeVisitor<'a, i32> for Printer {
+    ///     fn visit_in(&mut self, node: NodeRef<'a, i32>, depth: usize) {
+    ///         self.0.push(format!("{}{}", "  ".repeat(depth), node.value()));
+    ///     }
+    /// }
+    ///
+    /// let bst = RecursiveBST::from_iter(vec![2, 1, 3]);
+    /// let mut printer = Printer(Vec::new());
+    /// bst.accept(&mut printer);
+    ///
+    /// assert_eq!(printer.0, vec!["  1", "2", "  3"]);
+    /// ```
+    pub fn accept<'a, V: TreeVisitor<'a, T>>(&'a self, visitor: &mut V) {
+        let mut stack: Vec<(&Node<T>, usize, Visit)> = self
+            .root
+            .as_deref()
+            .map(|node| (node, 0, Visit::Pre))
+            .into_iter()
+            .collect();
+
+        while let Some((node, depth, visit)) = stack.pop() {
+            let node_ref = NodeRef::new(node);
+            match visit {
+                Visit::Pre => {
+                    visitor.visit_pre(node_ref, depth);
+                    stack.push((node, depth, Visit::In));
+                    if let Some(left) = node.left.as_deref() {
+                        stack.push((left, depth + 1, Visit::Pre));
+                    }
+                }
+                Visit::In => {
+                    visitor.visit_in(node_ref, depth);
+                    stack.push((node, depth, Visit::Post));
+                    if let Some(right) = node.right.as_deref() {
+                        stack.push((right, depth + 1, Visit::Pre));
+                    }
+                }
+                Visit::Post => visitor.visit_post(node_ref, depth),
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "iterative", feature = "recursive"))]
+mod tests {
+    use crate::{BinarySearchTree, IterativeBST, NodeRef, RecursiveBST, TreeVisitor};
+
+    #[derive(Default)]
+    struct Recorder<'a> {
+        pre: Vec<(&'a i32, usize)>,
+        in_order: Vec<&'a i32>,
+        post: Vec<&'a i32>,
+    }
+
+    impl<'a> TreeVisitor<'a, i32> for Recorder<'a> {
+        fn visit_pre(&mut self, node: NodeRef<'a, i32>, depth: usize) {
+            self.pre.push((node.value(), depth));
+        }
+
+        fn visit_in(&mut self, node: NodeRef<'a, i32>, _: usize) {
+            self.in_order.push(node.value());
+        }
+
+        fn visit_post(&mut self, node: NodeRef<'a, i32>, _: usize) {
+            self.post.push(node.value());
+        }
+    }
+
+    #[test]
+    fn successfully_visit_every_order_in_one_pass() {
+        let iterative = IterativeBST::from_iter(vec![8, 4, 12, 2, 6, 14, 1]);
+        let recursive = RecursiveBST::from_iter(vec![8, 4, 12, 2, 6, 14, 1]);
+
+        let mut recorder = Recorder::default();
+        iterative.accept(&mut recorder);
+
+        let pre: Vec<&i32> = recorder.pre.iter().map(|(value, _)| *value).collect();
+        let depths: Vec<usize> = recorder.pre.iter().map(|(_, depth)| *depth).collect();
+        assert_eq!(pre, iterative.pre_order_vec());
+        assert_eq!(depths, vec![0, 1, 2, 3, 2, 1, 2]);
+        assert_eq!(recorder.in_order, iterative.in_order_vec());
+        assert_eq!(recorder.post, iterative.post_order_vec());
+
+        let mut recorder = Recorder::default();
+        recursive.accept(&mut recorder);
+        assert_eq!(recorder.post, recursive.post_order_vec());
+    }
+
+    #[test]
+    fn successfully_accept_visitor_on_empty_bst() {
+        struct Panicking;
+        impl TreeVisitor<'_, i32> for Panicking {
+            fn visit_pre(&mut self, _: NodeRef<'_, i32>, _: usize) {
+                unreachable!()
+            }
+        }
+
+        IterativeBST::new().accept(&mut Panicking);
+    }
+}
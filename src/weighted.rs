@@ -0,0 +1,234 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::{Augment, AugmentedBST};
+
+/// [Augment] which maintains the total weight of every subtree, the weight of each element being
+/// computed by the given function.
+///
+/// Weights are summed up as `u64`, the total weight of the tree must therefore fit into a `u64`.
+#[derive(Clone, Copy)]
+pub struct Weighted<F> {
+    weight: F,
+}
+
+impl<F> Weighted<F> {
+    /// Creates a `Weighted` augmentation using `weight` to compute the weight of every element.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AugmentedBST, Weighted};
+    ///
+    /// let mut bst = AugmentedBST::new(Weighted::new(|value: &u64| *value));
+    /// bst.extend(vec![1, 2, 3]);
+    ///
+    /// assert_eq!(bst.total_weight(), 6);
+    /// ```
+    pub fn new(weight: F) -> Weighted<F> {
+        Weighted { weight }
+    }
+}
+
+impl<F> Debug for Weighted<F> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Weighted").finish_non_exhaustive()
+    }
+}
+
+impl<T, F: Fn(&T) -> u64> Augment<T> for Weighted<F> {
+    type Summary = u64;
+
+    fn summarize(&self, value: &T, left: Option<&u64>, right: Option<&u64>) -> u64 {
+        (self.weight)(value) + left.unwrap_or(&0) + right.unwrap_or(&0)
+    }
+}
+
+/// Binary Search Tree maintaining the total weight of every subtree (see [Weighted]).
+pub type WeightedBST<T, F> = AugmentedBST<T, Weighted<F>>;
+
+impl<T: Ord, F: Fn(&T) -> u64> WeightedBST<T, F> {
+    /// Returns the sum of the weights of all elements, `0` for an empty tree.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AugmentedBST, Weighted};
+    ///
+    /// let mut bst = AugmentedBST::new(Weighted::new(|name: &&str| name.len() as u64));
+    /// assert_eq!(bst.total_weight(), 0);
+    ///
+    /// bst.insert("alpha");
+    /// bst.insert("beta");
+    /// assert_eq!(bst.total_weight(), 9);
+    /// ```
+    pub fn total_weight(&self) -> u64 {
+        self.summary().copied().unwrap_or(0)
+    }
+
+    /// Returns the element covering `point` when the weights of all elements are laid out one
+    /// after another in **ascending order**, or `None` if `point` is not smaller than
+    /// [WeightedBST::total_weight()].
+    ///
+    /// An element of weight `w` covers `w` consecutive points, elements of weight `0` are
+    /// therefore never selected. Only a single root-to-leaf path is visited, O(h).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AugmentedBST, Weighted};
+    ///
+    /// let mut bst = AugmentedBST::new(Weighted::new(|value: &u64| *value));
+    /// bst.extend(vec![1, 2, 3]);
+    ///
+    /// // Points: 0 -> 1, 1..3 -> 2, 3..6 -> 3
+    /// assert_eq!(bst.select_weighted(0), Some(&1));
+    /// assert_eq!(bst.select_weighted(2), Some(&2));
+    /// assert_eq!(bst.select_weighted(5), Some(&3));
+    /// assert_eq!(bst.select_weighted(6), None);
+    /// ```
+    pub fn select_weighted(&self, point: u64) -> Option<&T> {
+        let mut point = point;
+        let mut current = self.root.as_deref()?;
+        if point >= current.summary {
+            return None;
+        }
+
+        loop {
+            let left = current.left.as_ref().map_or(0, |left| left.summary);
+            if point < left {
+                current = current.left.as_deref()?;
+                continue;
+            }
+            point -= left;
+
+            let right = current.right.as_ref().map_or(0, |right| right.summary);
+            let weight = current.summary - left - right;
+            if point < weight {
+                return Some(&current.value);
+            }
+            point -= weight;
+
+            current = current.right.as_deref()?;
+        }
+    }
+
+    /// Picks a random element with a probability proportional to its weight, in O(h).
+    ///
+    /// `rng` is called once with the [total weight](WeightedBST::total_weight()) and must return
+    /// a uniformly distributed number in `0..total`, which keeps the tree independent of any
+    /// particular random number generator. Returns `None` if the tree is empty or all weights
+    /// are `0`, in which case `rng` is not called.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{AugmentedBST, Weighted};
+    ///
+    /// // Servers with their capacity, the second one should get 3 out of 4 requests
+    /// let mut bst = AugmentedBST::new(Weighted::new(|server: &(&str, u64)| server.1));
+    /// bst.insert(("eu-1", 1));
+    /// bst.insert(("eu-2", 3));
+    ///
+    /// // A simple xorshift generator standing in for a real one
+    /// let mut state = 0x2545_f491_4f6c_dd1du64;
+    /// let mut rng = |total: u64| {
+    ///     state ^= state << 13;
+    ///     state ^= state >> 7;
+    ///     state ^= state << 17;
+    ///     state % total
+    /// };
+    ///
+    /// let picked = (0..1000)
+    ///     .filter(|_| bst.sample_weighted(&mut rng).unwrap().0 == "eu-2")
+    ///     .count();
+    /// assert!(650 < picked && picked < 850);
+    /// ```
+    pub fn sample_weighted<R: FnMut(u64) -> u64>(&self, mut rng: R) -> Option<&T> {
+        match self.total_weight() {
+            0 => None,
+            total => self.select_weighted(rng(total)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AugmentedBST, Weighted, WeightedBST};
+
+    fn weighted(values: Vec<u64>) -> WeightedBST<u64, fn(&u64) -> u64> {
+        let weight: fn(&u64) -> u64 = |value| *value % 10;
+        let mut bst = AugmentedBST::new(Weighted::new(weight));
+        bst.extend(values);
+        bst
+    }
+
+    #[test]
+    fn successfully_select_every_point_in_ascending_order() {
+        let bst = weighted(vec![13, 2, 40, 21, 35]);
+
+        // Weights: 2 -> 2, 13 -> 3, 21 -> 1, 35 -> 5, 40 -> 0
+        let expected: Vec<u64> = [(2, 2), (13, 3), (21, 1), (35, 5)]
+            .into_iter()
+            .flat_map(|(value, weight)| std::iter::repeat_n(value, weight))
+            .collect();
+        let selected: Vec<u64> = (0..bst.total_weight())
+            .map(|point| *bst.select_weighted(point).unwrap())
+            .collect();
+
+        assert_eq!(bst.total_weight(), 11);
+        assert_eq!(selected, expected);
+        assert_eq!(bst.select_weighted(11), None);
+    }
+
+    #[test]
+    fn successfully_update_weights_after_removal() {
+        let mut bst = weighted(vec![5, 3, 8, 1, 4, 7, 9]);
+        assert_eq!(bst.total_weight(), 37);
+
+        bst.remove(&5);
+        bst.remove(&1);
+
+        assert_eq!(bst.total_weight(), 31);
+        assert_eq!(bst.select_weighted(0), Some(&3));
+        assert_eq!(bst.select_weighted(7), Some(&7));
+        assert_eq!(bst.select_weighted(30), Some(&9));
+    }
+
+    #[test]
+    fn successfully_never_sample_empty_or_weightless_tree() {
+        let mut bst = weighted(vec![]);
+        let mut calls = 0;
+
+        assert_eq!(
+            bst.sample_weighted(|_| {
+                calls += 1;
+                0
+            }),
+            None
+        );
+
+        bst.extend(vec![10, 20, 30]);
+        assert_eq!(
+            bst.sample_weighted(|_| {
+                calls += 1;
+                0
+            }),
+            None
+        );
+        assert_eq!(calls, 0);
+    }
+
+    #[test]
+    fn successfully_sample_with_given_rng_bound() {
+        let bst = weighted(vec![1, 2, 3]);
+        let mut bounds = Vec::new();
+
+        let sampled = bst.sample_weighted(|total| {
+            bounds.push(total);
+            total - 1
+        });
+
+        assert_eq!(sampled, Some(&3));
+        assert_eq!(bounds, vec![6]);
+    }
+}
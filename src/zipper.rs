@@ -0,0 +1,283 @@
+use std::fmt::{Debug, Formatter};
+
+use crate::bst::{Bst, Iterative, Strategy};
+use crate::node::{HeapNode, Node};
+
+/// Cursor over the nodes of a [Bst] which owns the tree while navigating it.
+///
+/// The node in focus is detached from its parent, and every node on the way back to the root is
+/// kept on a path along with the side the walk went down. Moving the focus to a child or back
+/// to the parent therefore only moves a pointer between the focus and the path, making every
+/// step O(1), and edits at the focus don't need to descend from the root again.
+///
+/// [Zipper::into_tree()] zips the path back up and returns the tree.
+///
+/// # Example
+///
+/// ```rust
+/// use bst_rs::{BinarySearchTree, IterativeBST, Zipper};
+///
+/// let bst = IterativeBST::from_iter(vec![20, 10, 30, 25]);
+///
+/// let mut zipper = Zipper::new(bst);
+/// assert_eq!(zipper.focus(), Some(&20));
+///
+/// assert!(zipper.go_right());
+/// assert!(zipper.go_left());
+/// zipper.modify(|value| *value = 26);
+///
+/// assert!(zipper.go_up());
+/// assert_eq!(zipper.focus(), Some(&30));
+///
+/// let bst = zipper.into_tree();
+/// assert_eq!(bst.asc_order_vec(), vec![&10, &20, &26, &30]);
+/// ```
+pub struct Zipper<T: Ord, S: Strategy = Iterative> {
+    /// The walked tree, whose root is held by `focus` and `path` until it is zipped back up.
+    tree: Bst<T, S>,
+    focus: HeapNode<T>,
+    path: Vec<Crumb<T>>,
+}
+
+/// Parent of the focused subtree, with the child slot the walk went down left empty.
+struct Crumb<T: Ord> {
+    parent: Box<Node<T>>,
+    went_left: bool,
+}
+
+impl<T: Ord, S: Strategy> Zipper<T, S> {
+    /// Creates a zipper over `tree`, focused on its root.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, RecursiveBST, Zipper};
+    ///
+    /// let zipper = Zipper::new(RecursiveBST::from_iter(vec![2, 1, 3]));
+    /// assert_eq!(zipper.focus(), Some(&2));
+    ///
+    /// let empty = Zipper::new(RecursiveBST::<i32>::new());
+    /// assert_eq!(empty.focus(), None);
+    /// ```
+    pub fn new(mut tree: Bst<T, S>) -> Zipper<T, S> {
+        Zipper {
+            focus: tree.root.take(),
+            tree,
+            path: Vec::new(),
+        }
+    }
+
+    /// Returns a reference to the element in focus or `None` if the tree is empty.
+    pub fn focus(&self) -> Option<&T> {
+        self.focus.as_ref().map(|node| &node.value)
+    }
+
+    /// Returns the number of steps between the root and the focus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, Zipper};
+    ///
+    /// let mut zipper = Zipper::new(IterativeBST::from_iter(vec![3, 2, 1]));
+    /// assert_eq!(zipper.depth(), 0);
+    ///
+    /// zipper.go_left();
+    /// zipper.go_left();
+    /// assert_eq!(zipper.depth(), 2);
+    /// ```
+    pub fn depth(&self) -> usize {
+        self.path.len()
+    }
+
+    /// Moves the focus to the left child, holding the smaller elements.
+    ///
+    /// Returns `false` and keeps the focus where it is if there is no left child.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, Zipper};
+    ///
+    /// let mut zipper = Zipper::new(IterativeBST::from_iter(vec![2, 1]));
+    ///
+    /// assert!(zipper.go_left());
+    /// assert_eq!(zipper.focus(), Some(&1));
+    /// assert!(!zipper.go_left());
+    /// assert_eq!(zipper.focus(), Some(&1));
+    /// ```
+    pub fn go_left(&mut self) -> bool {
+        self.go_down(true)
+    }
+
+    /// Moves the focus to the right child, holding the bigger elements.
+    ///
+    /// Returns `false` and keeps the focus where it is if there is no right child.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, Zipper};
+    ///
+    /// let mut zipper = Zipper::new(IterativeBST::from_iter(vec![1, 2]));
+    ///
+    /// assert!(zipper.go_right());
+    /// assert_eq!(zipper.focus(), Some(&2));
+    /// assert!(!zipper.go_right());
+    /// ```
+    pub fn go_right(&mut self) -> bool {
+        self.go_down(false)
+    }
+
+    /// Moves the focus back to the parent.
+    ///
+    /// Returns `false` and keeps the focus where it is if the root is in focus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, Zipper};
+    ///
+    /// let mut zipper = Zipper::new(IterativeBST::from_iter(vec![2, 1]));
+    /// assert!(!zipper.go_up());
+    ///
+    /// zipper.go_left();
+    /// assert!(zipper.go_up());
+    /// assert_eq!(zipper.focus(), Some(&2));
+    /// ```
+    pub fn go_up(&mut self) -> bool {
+        let Crumb {
+            mut parent,
+            went_left,
+        } = match self.path.pop() {
+            None => return false,
+            Some(crumb) => crumb,
+        };
+
+        if went_left {
+            parent.left = self.focus.take();
+        } else {
+            parent.right = self.focus.take();
+        }
+        self.focus = Some(parent);
+
+        true
+    }
+
+    /// Applies `f` to the element in focus, doing nothing if the tree is empty.
+    ///
+    /// # Important
+    ///
+    /// Like [retrieve_as_mut()](crate::BinarySearchTree::retrieve_as_mut()), `f` must keep the
+    /// element between its in-order neighbours, otherwise later searches may miss elements.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use bst_rs::{BinarySearchTree, IterativeBST, Zipper};
+    ///
+    /// let mut zipper = Zipper::new(IterativeBST::from_iter(vec![(2, "b"), (1, "a")]));
+    /// zipper.go_left();
+    /// zipper.modify(|(_, name)| *name = "A");
+    ///
+    /// assert_eq!(zipper.into_tree().min(), Some(&(1, "A")));
+    /// ```
+    pub fn modify<F: FnOnce(&mut T)>(&mut self, f: F) {
+        if let Some(node) = &mut self.focus {
+            f(&mut node.value);
+        }
+    }
+
+    /// Zips the path back up to the root and returns the tree, consuming the zipper.
+    pub fn into_tree(mut self) -> Bst<T, S> {
+        while self.go_up() {}
+        self.tree.root = self.focus;
+        self.tree
+    }
+
+    fn go_down(&mut self, left: bool) -> bool {
+        let child = match &mut self.focus {
+            Some(node) if left => node.left.take(),
+            Some(node) => node.right.take(),
+            None => None,
+        };
+        if child.is_none() {
+            return false;
+        }
+
+        let parent = std::mem::replace(&mut self.focus, child).unwrap();
+        self.path.push(Crumb {
+            parent,
+            went_left: left,
+        });
+
+        true
+    }
+}
+
+impl<T: Ord + Debug, S: Strategy> Debug for Zipper<T, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Zipper")
+            .field("focus", &self.focus())
+            .field("depth", &self.depth())
+            .finish()
+    }
+}
+
+#[cfg(all(test, feature = "iterative", feature = "recursive"))]
+mod tests {
+    use crate::{BinarySearchTree, IterativeBST, RecursiveBST, Zipper};
+
+    #[test]
+    fn successfully_navigate_and_restore_tree() {
+        let bst = IterativeBST::from_iter(vec![8, 4, 12, 2, 6, 10, 14]);
+        let pre_order: Vec<i32> = bst.pre_order_vec().into_iter().copied().collect();
+
+        let mut zipper = Zipper::new(bst);
+        assert!(zipper.go_left());
+        assert!(zipper.go_right());
+        assert_eq!(zipper.focus(), Some(&6));
+        assert_eq!(zipper.depth(), 2);
+        assert!(!zipper.go_left());
+        assert!(zipper.go_up());
+        assert!(zipper.go_up());
+        assert!(!zipper.go_up());
+        assert!(zipper.go_right());
+        assert!(zipper.go_right());
+
+        let bst = zipper.into_tree();
+        assert_eq!(
+            bst.pre_order_iter().copied().collect::<Vec<i32>>(),
+            pre_order
+        );
+        assert_eq!(bst.size(), 7);
+    }
+
+    #[test]
+    fn successfully_modify_focused_elements() {
+        let mut zipper = Zipper::new(RecursiveBST::from_iter(vec![10, 5, 20, 15]));
+        zipper.modify(|value| *value = 11);
+        zipper.go_right();
+        zipper.go_left();
+        zipper.modify(|value| *value += 1);
+
+        let mut bst = zipper.into_tree();
+        assert_eq!(bst.asc_order_vec(), vec![&5, &11, &16, &20]);
+        assert!(bst.contains(&16));
+
+        bst.insert(13);
+        assert_eq!(bst.pre_order_vec(), vec![&11, &5, &20, &16, &13]);
+    }
+
+    #[test]
+    fn successfully_zip_empty_tree() {
+        let mut zipper = Zipper::new(IterativeBST::<i32>::new());
+        assert!(!zipper.go_left());
+        assert!(!zipper.go_right());
+        assert!(!zipper.go_up());
+        zipper.modify(|value| *value = 1);
+        assert_eq!(format!("{:?}", zipper), "Zipper { focus: None, depth: 0 }");
+
+        assert!(zipper.into_tree().is_empty());
+    }
+}